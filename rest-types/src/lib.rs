@@ -150,6 +150,11 @@ pub struct BookingLogTO {
     pub created_by: Option<Arc<str>>,
     #[serde(default)]
     pub deleted_by: Option<Arc<str>>,
+    /// Slot of the booking, left out by backends which only report times.
+    #[serde(default)]
+    pub slot_id: Option<Uuid>,
+    #[serde(default)]
+    pub sales_person_id: Option<Uuid>,
 }
 
 #[cfg(feature = "service-impl")]
@@ -166,6 +171,8 @@ impl From<&service::booking_log::BookingLog> for BookingLogTO {
             deleted: log.deleted,
             created_by: log.created_by.clone(),
             deleted_by: log.deleted_by.clone(),
+            slot_id: Some(log.slot_id),
+            sales_person_id: Some(log.sales_person_id),
         }
     }
 }
@@ -552,7 +559,7 @@ impl From<&service::reporting::EmployeeReport> for EmployeeReportTO {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EmployeeWorkDetailsTO {
     #[serde(default)]
     pub id: Uuid,
@@ -1168,6 +1175,39 @@ impl From<&WeekMessageTO> for service::week_message::WeekMessage {
     }
 }
 
+/// Marks a calendar week as published. While a week is published only shift
+/// planners may change its bookings.
+///
+/// Served by the backend's `week-publication` endpoints:
+/// `GET /week-publication/by-year-and-week/{year}/{week}`,
+/// `POST /week-publication` and `DELETE /week-publication/{id}`.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct WeekPublicationTO {
+    #[serde(default)]
+    pub id: Uuid,
+    pub year: u32,
+    pub calendar_week: u8,
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub published_at: Option<PrimitiveDateTime>,
+    #[serde(default)]
+    pub published_by: Option<Arc<str>>,
+    #[serde(default)]
+    #[schema(value_type = Option<String>, format = "date-time")]
+    pub deleted: Option<PrimitiveDateTime>,
+    #[serde(rename = "$version")]
+    #[serde(default)]
+    pub version: Uuid,
+}
+
+/// Someone else who has the same shiftplan week open.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ShiftplanViewerTO {
+    pub user: Arc<str>,
+    #[schema(value_type = String, format = "date-time")]
+    pub last_seen: PrimitiveDateTime,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct BillingPeriodValueTO {
     pub value_delta: f32,
@@ -1348,6 +1388,10 @@ pub struct UpdateTextTemplateRequestTO {
     pub template_type: Arc<str>,
     pub template_text: Arc<str>,
     pub template_engine: TemplateEngineTO,
+    /// Version the edit is based on; without it the update is not checked.
+    #[serde(rename = "$version")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<Uuid>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
@@ -5,23 +5,112 @@ use rest_types::{
     CreateBillingPeriodRequestTO, CreateTextTemplateRequestTO, CustomExtraHoursTO, DayOfWeekTO,
     EmployeeReportTO, EmployeeWorkDetailsTO, ExtraHoursCategoryTO, ExtraHoursTO,
     GenerateInvitationRequest, InvitationResponse, RoleTO, SalesPersonTO, SalesPersonUnavailableTO,
-    ShiftplanTO, ShortEmployeeReportTO, SlotTO, SpecialDayTO, TextTemplateTO,
+    ShiftplanTO, ShiftplanViewerTO, ShortEmployeeReportTO, SlotTO, SpecialDayTO, TextTemplateTO,
     UpdateTextTemplateRequestTO, UserRole, UserTO, VacationPayloadTO, WeekMessageTO,
-    WeeklySummaryTO,
+    WeekPublicationTO, WeeklySummaryTO,
 };
-use tracing::info;
+use serde::Serialize;
+use std::time::Duration;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use crate::{
     base_types::ImStr,
-    error::ShiftyError,
+    error::{
+        is_gateway_failure, is_transport_failure, ConflictEntity, ShiftyError, VersionConflict,
+    },
     js,
-    state::{AuthInfo, Config, ShiftplanAssignment},
+    state::{config::RequestPolicy, AuthInfo, Config, ShiftplanAssignment},
 };
 
-pub async fn fetch_auth_info(backend_url: Rc<str>) -> Result<Option<AuthInfo>, reqwest::Error> {
+/// Sends `request` with the timeout of `policy`. A GET whose attempt fails on
+/// the way or hits a gateway error is sent again after an exponential
+/// backoff. Other methods go out once: a repeated POST would create its
+/// entity twice, and the offline queue already retries failed mutations.
+async fn execute(
+    policy: RequestPolicy,
+    request: reqwest::RequestBuilder,
+) -> Result<reqwest::Response, reqwest::Error> {
+    let (client, request) = request
+        .timeout(Duration::from_millis(policy.timeout_ms.into()))
+        .build_split();
+    let request = request?;
+    let retries = if request.method() == reqwest::Method::GET {
+        policy.retries
+    } else {
+        0
+    };
+    let mut attempt = 0;
+    loop {
+        let Some(next) = request.try_clone().filter(|_| attempt < retries) else {
+            return client.execute(request).await;
+        };
+        let result = client.execute(next).await;
+        let transient = match &result {
+            Ok(response) => is_gateway_failure(response.status()),
+            Err(e) => is_transport_failure(e),
+        };
+        if !transient {
+            return result;
+        }
+        let delay = policy.backoff_ms(attempt);
+        warn!("GET {} failed, retrying in {delay} ms", request.url());
+        wait_ms(delay).await;
+        attempt += 1;
+    }
+}
+
+/// Waits between retries. Timers come from the browser, so native builds,
+/// i.e. tests, block the thread instead.
+#[cfg(target_arch = "wasm32")]
+async fn wait_ms(ms: u32) {
+    gloo_timers::future::TimeoutFuture::new(ms).await;
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn wait_ms(ms: u32) {
+    std::thread::sleep(Duration::from_millis(ms.into()));
+}
+
+/// GETs `url` through [`execute`].
+async fn get(
+    policy: RequestPolicy,
+    url: impl reqwest::IntoUrl,
+) -> Result<reqwest::Response, reqwest::Error> {
+    execute(policy, reqwest::Client::new().get(url)).await
+}
+
+/// Sends a versioned PUT. A 409 means `body` carries an outdated `$version`;
+/// the error then carries the entity the server has now, fetched by
+/// `theirs`, next to `yours`, what the user tried to save. If the entity is
+/// gone the conflict stays untyped.
+async fn put_versioned<B: Serialize, T>(
+    config: &Config,
+    url: String,
+    body: &B,
+    theirs: impl std::future::Future<Output = Result<Option<T>, reqwest::Error>>,
+    yours: T,
+    entity: fn(T) -> ConflictEntity,
+) -> Result<reqwest::Response, ShiftyError> {
+    let client = reqwest::Client::new();
+    let response = execute(config.requests, client.put(url).json(body)).await?;
+    if response.status() == reqwest::StatusCode::CONFLICT {
+        info!("Update returned 409 Conflict");
+        return Err(match theirs.await? {
+            Some(theirs) => ShiftyError::VersionConflict(Box::new(VersionConflict {
+                yours: entity(yours),
+                theirs: entity(theirs),
+            })),
+            None => ShiftyError::Conflict(String::new()),
+        });
+    }
+    response.error_for_status_ref()?;
+    Ok(response)
+}
+
+pub async fn fetch_auth_info(config: Config) -> Result<Option<AuthInfo>, reqwest::Error> {
     info!("Fetching username");
-    let response = reqwest::get(format!("{}/auth-info", backend_url)).await?;
+    let response = get(config.requests, format!("{}/auth-info", config.backend)).await?;
     if response.status() != 200 {
         return Ok(None);
     }
@@ -45,7 +134,8 @@ pub async fn load_config() -> Result<Config, reqwest::Error> {
         .expect("no host");
     let url = format!("{protocol}//{host}/assets/config.json");
     info!("URL: {url}");
-    let response = reqwest::get(url).await?;
+    // The policy comes with the config, so its own request uses the default.
+    let response = get(RequestPolicy::default(), url).await?;
     response.error_for_status_ref()?;
     let res: Config = response.json().await?;
     info!("Loaded");
@@ -60,7 +150,7 @@ pub async fn get_slots(
 ) -> Result<Rc<[SlotTO]>, reqwest::Error> {
     info!("Fetching slots");
     let url = format!("{}/slot/week/{year}/{week}/{shiftplan_id}", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -70,7 +160,7 @@ pub async fn get_slots(
 pub async fn get_all_shiftplans(config: Config) -> Result<Rc<[ShiftplanTO]>, reqwest::Error> {
     info!("Fetching shiftplan catalog");
     let url = format!("{}/shiftplan-catalog", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched shiftplan catalog");
@@ -92,7 +182,7 @@ pub async fn create_shiftplan(
         version: Uuid::nil(),
     };
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&shiftplan).send().await?;
+    let response = execute(config.requests, client.post(url).json(&shiftplan)).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Created shiftplan");
@@ -102,12 +192,24 @@ pub async fn create_shiftplan(
 pub async fn update_shiftplan(
     config: Config,
     shiftplan: ShiftplanTO,
-) -> Result<ShiftplanTO, reqwest::Error> {
+) -> Result<ShiftplanTO, ShiftyError> {
     info!("Updating shiftplan {}", shiftplan.id);
     let url = format!("{}/shiftplan-catalog/{}", config.backend, shiftplan.id);
-    let client = reqwest::Client::new();
-    let response = client.put(url).json(&shiftplan).send().await?;
-    response.error_for_status_ref()?;
+    let response = put_versioned(
+        &config,
+        url,
+        &shiftplan,
+        async {
+            Ok(get_all_shiftplans(config.clone())
+                .await?
+                .iter()
+                .find(|current| current.id == shiftplan.id)
+                .cloned())
+        },
+        shiftplan.clone(),
+        ConflictEntity::Shiftplan,
+    )
+    .await?;
     let res = response.json().await?;
     info!("Updated shiftplan");
     Ok(res)
@@ -117,7 +219,7 @@ pub async fn delete_shiftplan(config: Config, id: Uuid) -> Result<(), reqwest::E
     info!("Deleting shiftplan {id}");
     let url = format!("{}/shiftplan-catalog/{}", config.backend, id);
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Deleted shiftplan");
     Ok(())
@@ -126,7 +228,7 @@ pub async fn delete_shiftplan(config: Config, id: Uuid) -> Result<(), reqwest::E
 pub async fn get_slot(config: Config, slot_id: Uuid) -> Result<SlotTO, reqwest::Error> {
     info!("Fetching slot {slot_id}");
     let url = format!("{}/slot/{}", config.backend, slot_id);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -141,7 +243,7 @@ pub async fn update_slot(
 ) -> Result<(), reqwest::Error> {
     let url = format!("{}/shiftplan-edit/slot/{}/{}", config.backend, year, week);
     let client = reqwest::Client::new();
-    let response = client.put(url).json(&slot).send().await?;
+    let response = execute(config.requests, client.put(url).json(&slot)).await?;
     response.error_for_status_ref()?;
     info!("Updated slot");
     Ok(())
@@ -151,7 +253,7 @@ pub async fn post_slot(config: Config, slot: SlotTO) -> Result<bool, reqwest::Er
     info!("Adding slot");
     let url = format!("{}/slot", config.backend);
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&slot).send().await?;
+    let response = execute(config.requests, client.post(url).json(&slot)).await?;
     if response.status() == 409 {
         return Ok(false);
     }
@@ -172,7 +274,7 @@ pub async fn delete_slot_from(
         config.backend, slot_id, year, week
     );
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Deleted");
     Ok(())
@@ -185,7 +287,7 @@ pub async fn get_bookings_for_week(
 ) -> Result<Rc<[BookingTO]>, reqwest::Error> {
     info!("Fetching bookings for week {week} in year {year}");
     let url = format!("{}/booking/week/{year}/{week}", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -216,7 +318,7 @@ pub async fn add_booking(
         version: Uuid::nil(),
     };
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&booking_to).send().await?;
+    let response = execute(config.requests, client.post(url).json(&booking_to)).await?;
     response.error_for_status_ref()?;
     info!("Added");
     Ok(())
@@ -226,7 +328,7 @@ pub async fn remove_booking(config: Config, booking_id: Uuid) -> Result<(), reqw
     info!("Removing booking {booking_id}");
     let url = format!("{}/booking/{booking_id}", config.backend,);
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Removed");
     Ok(())
@@ -242,7 +344,7 @@ pub async fn copy_week(
     info!("Copying week {from_week} of year {from_year} to week {to_week} of year {to_year}");
     let url = format!("{}/booking/copy?from_year={from_year}&from_week={from_week}&to_year={to_year}&to_week={to_week}", config.backend);
     let client = reqwest::Client::new();
-    let response = client.post(url).send().await?;
+    let response = execute(config.requests, client.post(url)).await?;
     response.error_for_status_ref()?;
     info!("Copied");
     Ok(())
@@ -251,7 +353,7 @@ pub async fn copy_week(
 pub async fn get_sales_persons(config: Config) -> Result<Rc<[SalesPersonTO]>, reqwest::Error> {
     info!("Fetching sales persons");
     let url = format!("{}/sales-person", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -263,7 +365,7 @@ pub async fn get_current_sales_person(
 ) -> Result<Option<SalesPersonTO>, reqwest::Error> {
     info!("Fetching current sales person");
     let url = format!("{}/sales-person/current", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -276,7 +378,7 @@ pub async fn get_sales_person(
 ) -> Result<SalesPersonTO, reqwest::Error> {
     info!("Fetching sales person {sales_person_id}");
     let url = format!("{}/sales-person/{sales_person_id}", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -286,16 +388,26 @@ pub async fn get_sales_person(
 pub async fn put_sales_person(
     config: Config,
     sales_person: SalesPersonTO,
-) -> Result<(), reqwest::Error> {
+) -> Result<(), ShiftyError> {
     info!("Posting sales person");
     let url = format!(
         "{}/sales-person/{}",
         config.backend,
         sales_person.id.to_string()
     );
-    let client = reqwest::Client::new();
-    let response = client.put(url).json(&sales_person).send().await?;
-    response.error_for_status_ref()?;
+    put_versioned(
+        &config,
+        url,
+        &sales_person,
+        async {
+            Ok(Some(
+                get_sales_person(config.clone(), sales_person.id).await?,
+            ))
+        },
+        sales_person.clone(),
+        ConflictEntity::SalesPerson,
+    )
+    .await?;
     info!("Posted");
     Ok(())
 }
@@ -307,7 +419,7 @@ pub async fn post_sales_person(
     info!("Posting sales person");
     let url = format!("{}/sales-person", config.backend);
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&sales_person).send().await?;
+    let response = execute(config.requests, client.post(url).json(&sales_person)).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Posted");
@@ -320,7 +432,7 @@ pub async fn get_user_for_sales_person(
 ) -> Result<Option<Rc<str>>, reqwest::Error> {
     info!("Fetching user for sales person {sales_person_id}");
     let url = format!("{}/sales-person/{sales_person_id}/user", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -335,7 +447,7 @@ pub async fn post_user_to_sales_person(
     info!("Posting user {user_id} to sales person {sales_person_id}");
     let url = format!("{}/sales-person/{sales_person_id}/user", config.backend);
     let client = reqwest::Client::new();
-    let response = client.post(url).json(user_id.as_str()).send().await?;
+    let response = execute(config.requests, client.post(url).json(user_id.as_str())).await?;
     response.error_for_status_ref()?;
     info!("Posted");
     Ok(())
@@ -348,7 +460,7 @@ pub async fn delete_user_from_sales_person(
     info!("Delete user for sales person {sales_person_id}");
     let url = format!("{}/sales-person/{}/user", config.backend, sales_person_id);
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Deleted");
     Ok(())
@@ -364,7 +476,7 @@ pub async fn get_short_reports(
         "{}/report?year={}&until_week={}",
         config.backend, year, calendar_week
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -382,7 +494,7 @@ pub async fn get_employee_reports(
         "{}/report/{}?year={}&until_week={}",
         config.backend, sales_person_id, year, calendar_week
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -415,7 +527,7 @@ pub async fn add_extra_hour(
         version: Uuid::nil(),
     };
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&booking_to).send().await?;
+    let response = execute(config.requests, client.post(url).json(&booking_to)).await?;
     response.error_for_status_ref()?;
     info!("Added");
     Ok(())
@@ -432,7 +544,7 @@ pub async fn get_extra_hours_for_year(
         "{}/extra-hours/by-sales-person/{}?year={}&until_week={}",
         config.backend, sales_person_id, year, until_week,
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -443,7 +555,7 @@ pub async fn delete_extra_hour(config: Config, extra_hour_id: Uuid) -> Result<()
     info!("Deleting extra hour {extra_hour_id}");
     let url = format!("{}/extra-hours/{}", config.backend, extra_hour_id);
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Deleted");
     Ok(())
@@ -455,13 +567,29 @@ pub async fn update_extra_hour(
 ) -> Result<ExtraHoursTO, ShiftyError> {
     info!("Updating extra hour {}", extra_hours.id);
     let url = format!("{}/extra-hours/{}", config.backend, extra_hours.id);
-    let client = reqwest::Client::new();
-    let response = client.put(url).json(&extra_hours).send().await?;
-    if response.status() == reqwest::StatusCode::CONFLICT {
-        info!("Update returned 409 Conflict");
-        return Err(ShiftyError::Conflict(String::new()));
-    }
-    response.error_for_status_ref()?;
+    // The entries are listed by ISO week, so the whole year ends with its
+    // last ISO week, 52 or 53.
+    let (year, _, _) = extra_hours.date_time.to_iso_week_date();
+    let response = put_versioned(
+        &config,
+        url,
+        &extra_hours,
+        async {
+            Ok(get_extra_hours_for_year(
+                config.clone(),
+                extra_hours.sales_person_id,
+                year as u32,
+                time::util::weeks_in_year(year),
+            )
+            .await?
+            .iter()
+            .find(|current| current.id == extra_hours.id)
+            .cloned())
+        },
+        extra_hours.clone(),
+        ConflictEntity::ExtraHours,
+    )
+    .await?;
     let updated: ExtraHoursTO = response.json().await?;
     info!("Updated");
     Ok(updated)
@@ -470,7 +598,7 @@ pub async fn update_extra_hour(
 pub async fn get_version(config: Config) -> Result<Rc<str>, reqwest::Error> {
     info!("Fetching version");
     let url = format!("{}/version", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.text().await?;
     info!("Fetched");
@@ -488,7 +616,7 @@ pub async fn get_unavailable_sales_person_days_for_week(
         "{}/sales-person/{sales_person_id}/unavailable?year={year}&calendar_week={week}",
         config.backend
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -517,7 +645,7 @@ pub async fn create_unavailable_sales_person_day(
         version: Uuid::nil(),
     };
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&unavailable_to).send().await?;
+    let response = execute(config.requests, client.post(url).json(&unavailable_to)).await?;
     response.error_for_status_ref()?;
     info!("Created");
     Ok(())
@@ -533,7 +661,7 @@ pub async fn delete_unavailable_sales_person_day(
         config.backend, unavailable_id
     );
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Deleted");
     Ok(())
@@ -546,7 +674,7 @@ pub async fn get_working_hours_for_week(
 ) -> Result<Rc<[ShortEmployeeReportTO]>, reqwest::Error> {
     info!("Fetching working hours for week {week} of year {year}");
     let url = format!("{}/report/week/{}/{}", config.backend, year, week);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -563,7 +691,7 @@ pub async fn get_balance_until_week(
         "{}/report?year={}&until_week={}",
         config.backend, year, week
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -573,7 +701,7 @@ pub async fn get_balance_until_week(
 pub async fn get_all_users(config: Config) -> Result<Rc<[UserTO]>, reqwest::Error> {
     info!("Fetching all users");
     let url = format!("{}/permission/user", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -583,7 +711,7 @@ pub async fn get_all_users(config: Config) -> Result<Rc<[UserTO]>, reqwest::Erro
 pub async fn get_all_roles(config: Config) -> Result<Rc<[RoleTO]>, reqwest::Error> {
     info!("Fetching all roles");
     let url = format!("{}/permission/role", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -600,7 +728,7 @@ pub async fn get_roles_from_user(
         config.backend,
         user_id.as_str()
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -610,7 +738,7 @@ pub async fn get_roles_from_user(
 pub async fn add_role_to_user(config: Config, user_role: UserRole) -> Result<(), reqwest::Error> {
     let url = format!("{}/permission/user-role", config.backend,);
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&user_role).send().await?;
+    let response = execute(config.requests, client.post(url).json(&user_role)).await?;
     response.error_for_status_ref()?;
     info!("Added");
     Ok(())
@@ -622,7 +750,7 @@ pub async fn remove_role_from_user(
 ) -> Result<(), reqwest::Error> {
     let url = format!("{}/permission/user-role", config.backend,);
     let client = reqwest::Client::new();
-    let response = client.delete(url).json(&user_role).send().await?;
+    let response = execute(config.requests, client.delete(url).json(&user_role)).await?;
     response.error_for_status_ref()?;
     info!("Removed");
     Ok(())
@@ -632,7 +760,7 @@ pub async fn add_user(config: Config, user: UserTO) -> Result<(), reqwest::Error
     info!("Adding user");
     let url = format!("{}/permission/user", config.backend);
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&user).send().await?;
+    let response = execute(config.requests, client.post(url).json(&user)).await?;
     response.error_for_status_ref()?;
     info!("Added");
     Ok(())
@@ -642,7 +770,11 @@ pub async fn delete_user(config: Config, user_id: ImStr) -> Result<(), reqwest::
     info!("Deleting user {user_id}");
     let url = format!("{}/permission/user/", config.backend);
     let client = reqwest::Client::new();
-    let response = client.delete(url).json(&user_id.to_string()).send().await?;
+    let response = execute(
+        config.requests,
+        client.delete(url).json(&user_id.to_string()),
+    )
+    .await?;
     response.error_for_status_ref()?;
     info!("Deleted user");
     Ok(())
@@ -657,7 +789,7 @@ pub async fn get_booking_conflicts_for_week(
         "{}/booking-information/conflicts/for-week/{}/{}",
         config.backend, year, week,
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     Ok(res)
@@ -670,7 +802,7 @@ pub async fn get_booking_log(
 ) -> Result<Rc<[BookingLogTO]>, reqwest::Error> {
     info!("Fetching booking log for week {week} in year {year}");
     let url = format!("{}/booking-log/{}/{}", config.backend, year, week);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched booking log");
@@ -685,7 +817,7 @@ pub async fn get_weekly_overview(
         "{}/booking-information/weekly-resource-report/{}",
         config.backend, year,
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     Ok(res)
@@ -697,7 +829,7 @@ pub async fn get_special_days_for_week(
     week: u8,
 ) -> Result<Rc<[SpecialDayTO]>, reqwest::Error> {
     let url = format!("{}/special-days/for-week/{}/{}", config.backend, year, week,);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     Ok(res)
@@ -711,7 +843,7 @@ pub async fn get_employee_work_details_for_sales_person(
         "{}/working-hours/for-sales-person/{}",
         config.backend, sales_person_id,
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     Ok(res)
@@ -723,7 +855,7 @@ pub async fn post_employee_work_details(
 ) -> Result<(), reqwest::Error> {
     let url = format!("{}/working-hours", config.backend,);
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&work_details).send().await?;
+    let response = execute(config.requests, client.post(url).json(&work_details)).await?;
     response.error_for_status_ref()?;
     info!("Posted");
     Ok(())
@@ -732,15 +864,30 @@ pub async fn post_employee_work_details(
 pub async fn put_employee_work_details(
     config: Config,
     work_details: EmployeeWorkDetailsTO,
-) -> Result<(), reqwest::Error> {
+) -> Result<(), ShiftyError> {
     let url = format!(
         "{}/working-hours/{}",
         config.backend,
         work_details.id.to_string()
     );
-    let client = reqwest::Client::new();
-    let response = client.put(url).json(&work_details).send().await?;
-    response.error_for_status_ref()?;
+    put_versioned(
+        &config,
+        url,
+        &work_details,
+        async {
+            Ok(get_employee_work_details_for_sales_person(
+                config.clone(),
+                work_details.sales_person_id,
+            )
+            .await?
+            .iter()
+            .find(|current| current.id == work_details.id)
+            .cloned())
+        },
+        work_details.clone(),
+        ConflictEntity::EmployeeWorkDetails,
+    )
+    .await?;
     info!("Updated");
     Ok(())
 }
@@ -751,7 +898,7 @@ pub async fn delete_employee_work_details(
 ) -> Result<(), reqwest::Error> {
     let url = format!("{}/working-hours/{}", config.backend, work_details_id);
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Deleted");
     Ok(())
@@ -772,7 +919,7 @@ pub async fn add_vacation(
         description: description.as_str().into(),
     };
     let client = reqwest::Client::new();
-    let response = client.put(url).json(&vacation_to).send().await?;
+    let response = execute(config.requests, client.put(url).json(&vacation_to)).await?;
     response.error_for_status_ref()?;
     info!("Added");
     Ok(())
@@ -789,7 +936,7 @@ pub async fn get_shiftplan_week(
         "{}/shiftplan-info/{shiftplan_id}/{year}/{week}",
         config.backend
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -816,7 +963,7 @@ pub async fn get_shiftplan_day(
         "{}/shiftplan-info/day/{year}/{week}/{day_str}",
         config.backend
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched day aggregate");
@@ -832,13 +979,29 @@ pub async fn get_custom_extra_hours_by_sales_person(
         "{}/custom-extra-hours/by-sales-person/{sales_person_id}",
         config.backend
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
     Ok(res)
 }
 
+pub async fn get_custom_extra_hours(
+    config: Config,
+    id: Uuid,
+) -> Result<Option<CustomExtraHoursTO>, reqwest::Error> {
+    info!("Fetching custom extra hours {id}");
+    let url = format!("{}/custom-extra-hours/{id}", config.backend);
+    let response = get(config.requests, url).await?;
+    if response.status() == 404 {
+        return Ok(None);
+    }
+    response.error_for_status_ref()?;
+    let res = response.json().await?;
+    info!("Fetched");
+    Ok(Some(res))
+}
+
 pub async fn post_custom_extra_hours(
     config: Config,
     custom_extra_hours: CustomExtraHoursTO,
@@ -846,7 +1009,7 @@ pub async fn post_custom_extra_hours(
     info!("Creating custom extra hours: {}", custom_extra_hours.name);
     let url = format!("{}/custom-extra-hours", config.backend);
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&custom_extra_hours).send().await?;
+    let response = execute(config.requests, client.post(url).json(&custom_extra_hours)).await?;
     response.error_for_status_ref()?;
     info!("Created");
     Ok(())
@@ -855,15 +1018,23 @@ pub async fn post_custom_extra_hours(
 pub async fn put_custom_extra_hours(
     config: Config,
     custom_extra_hours: CustomExtraHoursTO,
-) -> Result<(), reqwest::Error> {
+) -> Result<(), ShiftyError> {
     info!("Updating custom extra hours: {}", custom_extra_hours.name);
     let url = format!(
         "{}/custom-extra-hours/{}",
         config.backend, custom_extra_hours.id
     );
-    let client = reqwest::Client::new();
-    let response = client.put(url).json(&custom_extra_hours).send().await?;
-    response.error_for_status_ref()?;
+    // Fetched by id, entries without an assigned sales person are not
+    // listed anywhere else.
+    put_versioned(
+        &config,
+        url,
+        &custom_extra_hours,
+        get_custom_extra_hours(config.clone(), custom_extra_hours.id),
+        custom_extra_hours.clone(),
+        ConflictEntity::CustomExtraHours,
+    )
+    .await?;
     info!("Updated");
     Ok(())
 }
@@ -878,7 +1049,7 @@ pub async fn delete_custom_extra_hours(
         config.backend, custom_extra_hours_id
     );
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Deleted custom extra hours");
     Ok(())
@@ -894,7 +1065,7 @@ pub async fn get_week_message(
         "{}/week-message/by-year-and-week/{}/{}",
         config.backend, year, week
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     if response.status() == 404 {
         return Ok(None);
     }
@@ -914,7 +1085,7 @@ pub async fn post_week_message(
     );
     let url = format!("{}/week-message", config.backend);
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&week_message).send().await?;
+    let response = execute(config.requests, client.post(url).json(&week_message)).await?;
     response.error_for_status_ref()?;
     info!("Posted week message");
     Ok(())
@@ -923,26 +1094,114 @@ pub async fn post_week_message(
 pub async fn put_week_message(
     config: Config,
     week_message: WeekMessageTO,
-) -> Result<(), reqwest::Error> {
+) -> Result<(), ShiftyError> {
     info!(
         "Updating week message for {}/{}",
         week_message.year, week_message.calendar_week
     );
     let url = format!("{}/week-message/{}", config.backend, week_message.id);
+    put_versioned(
+        &config,
+        url,
+        &week_message,
+        get_week_message(
+            config.clone(),
+            week_message.year,
+            week_message.calendar_week,
+        ),
+        week_message.clone(),
+        ConflictEntity::WeekMessage,
+    )
+    .await?;
+    info!("Updated week message");
+    Ok(())
+}
+
+pub async fn get_week_publication(
+    config: Config,
+    year: u32,
+    week: u8,
+) -> Result<Option<WeekPublicationTO>, reqwest::Error> {
+    info!("Fetching week publication for {year}/{week}");
+    let url = format!(
+        "{}/week-publication/by-year-and-week/{}/{}",
+        config.backend, year, week
+    );
+    let response = get(config.requests, url).await?;
+    if response.status() == 404 {
+        return Ok(None);
+    }
+    response.error_for_status_ref()?;
+    let res = response.json().await?;
+    info!("Fetched week publication");
+    Ok(Some(res))
+}
+
+pub async fn post_week_publication(
+    config: Config,
+    week_publication: WeekPublicationTO,
+) -> Result<WeekPublicationTO, reqwest::Error> {
+    info!(
+        "Publishing week {}/{}",
+        week_publication.year, week_publication.calendar_week
+    );
+    let url = format!("{}/week-publication", config.backend);
     let client = reqwest::Client::new();
-    let response = client.put(url).json(&week_message).send().await?;
+    let response = execute(config.requests, client.post(url).json(&week_publication)).await?;
     response.error_for_status_ref()?;
-    info!("Updated week message");
+    let res = response.json().await?;
+    info!("Published week");
+    Ok(res)
+}
+
+pub async fn delete_week_publication(config: Config, id: Uuid) -> Result<(), reqwest::Error> {
+    info!("Unpublishing week {id}");
+    let url = format!("{}/week-publication/{}", config.backend, id);
+    let client = reqwest::Client::new();
+    let response = execute(config.requests, client.delete(url)).await?;
+    response.error_for_status_ref()?;
+    info!("Unpublished week");
     Ok(())
 }
 
+/// Announces that the current user has the shiftplan week open and returns
+/// everyone else who does. A backend without presence support reports nobody.
+pub async fn put_shiftplan_presence(
+    config: Config,
+    shiftplan_id: Uuid,
+    year: u32,
+    week: u8,
+) -> Result<Rc<[ShiftplanViewerTO]>, reqwest::Error> {
+    let url = format!(
+        "{}/shiftplan-presence/{}/{}/{}",
+        config.backend, shiftplan_id, year, week
+    );
+    let client = reqwest::Client::new();
+    let response = execute(config.requests, client.put(url)).await?;
+    if response.status() == 404 {
+        return Ok([].into());
+    }
+    response.error_for_status_ref()?;
+    let res = response.json().await?;
+    Ok(res)
+}
+
+/// Server-sent events announcing booking and slot changes of a shiftplan week.
+#[cfg(target_arch = "wasm32")]
+pub fn shiftplan_feed_url(config: &Config, shiftplan_id: Uuid, year: u32, week: u8) -> String {
+    format!(
+        "{}/shiftplan-feed/{}/{}/{}",
+        config.backend, shiftplan_id, year, week
+    )
+}
+
 pub async fn get_sales_person_by_user(
     config: Config,
     username: ImStr,
 ) -> Result<Option<SalesPersonTO>, reqwest::Error> {
     info!("Fetching sales person for user {username}");
     let url = format!("{}/sales-person/by-user/{}", config.backend, username);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     if response.status() == 404 {
         return Ok(None);
     }
@@ -956,7 +1215,7 @@ pub async fn delete_billing_period(config: Config, id: Uuid) -> Result<(), reqwe
     info!("Deleting billing period {id}");
     let url = format!("{}/billing-period/{}", config.backend, id);
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Deleted billing period");
     Ok(())
@@ -965,7 +1224,7 @@ pub async fn delete_billing_period(config: Config, id: Uuid) -> Result<(), reqwe
 pub async fn get_billing_periods(config: Config) -> Result<Rc<[BillingPeriodTO]>, reqwest::Error> {
     info!("Fetching billing periods");
     let url = format!("{}/billing-period", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -978,7 +1237,7 @@ pub async fn get_billing_period(
 ) -> Result<BillingPeriodTO, reqwest::Error> {
     info!("Fetching billing period {billing_period_id}");
     let url = format!("{}/billing-period/{}", config.backend, billing_period_id);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched");
@@ -993,7 +1252,7 @@ pub async fn post_billing_period(
     let url = format!("{}/billing-period", config.backend);
     let request_payload = CreateBillingPeriodRequestTO { end_date };
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&request_payload).send().await?;
+    let response = execute(config.requests, client.post(url).json(&request_payload)).await?;
     response.error_for_status_ref()?;
     info!("Created billing period");
     Ok(())
@@ -1003,7 +1262,7 @@ pub async fn post_billing_period(
 pub async fn get_text_templates(config: Config) -> Result<Rc<[TextTemplateTO]>, reqwest::Error> {
     info!("Fetching all text templates");
     let url = format!("{}/text-templates", config.backend);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched text templates");
@@ -1019,7 +1278,7 @@ pub async fn get_text_templates_by_type(
         "{}/text-templates/by-type/{}",
         config.backend, template_type
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched text templates by type");
@@ -1032,7 +1291,7 @@ pub async fn get_text_template(
 ) -> Result<TextTemplateTO, reqwest::Error> {
     info!("Fetching text template {template_id}");
     let url = format!("{}/text-templates/{}", config.backend, template_id);
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched text template");
@@ -1046,7 +1305,7 @@ pub async fn create_text_template(
     info!("Creating text template");
     let url = format!("{}/text-templates", config.backend);
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&template).send().await?;
+    let response = execute(config.requests, client.post(url).json(&template)).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Created text template");
@@ -1057,12 +1316,29 @@ pub async fn update_text_template(
     config: Config,
     template_id: Uuid,
     template: UpdateTextTemplateRequestTO,
-) -> Result<TextTemplateTO, reqwest::Error> {
+) -> Result<TextTemplateTO, ShiftyError> {
     info!("Updating text template {template_id}");
     let url = format!("{}/text-templates/{}", config.backend, template_id);
-    let client = reqwest::Client::new();
-    let response = client.put(url).json(&template).send().await?;
-    response.error_for_status_ref()?;
+    let response = put_versioned(
+        &config,
+        url,
+        &template,
+        async { Ok(Some(get_text_template(config.clone(), template_id).await?)) },
+        TextTemplateTO {
+            id: template_id,
+            name: template.name.clone(),
+            template_type: template.template_type.clone(),
+            template_text: template.template_text.clone(),
+            template_engine: template.template_engine.clone(),
+            created_at: None,
+            created_by: None,
+            deleted: None,
+            deleted_by: None,
+            version: template.version.unwrap_or_default(),
+        },
+        ConflictEntity::TextTemplate,
+    )
+    .await?;
     let res = response.json().await?;
     info!("Updated text template");
     Ok(res)
@@ -1072,7 +1348,7 @@ pub async fn delete_text_template(config: Config, template_id: Uuid) -> Result<(
     info!("Deleting text template {template_id}");
     let url = format!("{}/text-templates/{}", config.backend, template_id);
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Deleted text template");
     Ok(())
@@ -1089,7 +1365,7 @@ pub async fn generate_custom_report(
         config.backend, billing_period_id, template_id
     );
     let client = reqwest::Client::new();
-    let response = client.post(url).send().await?;
+    let response = execute(config.requests, client.post(url)).await?;
     response.error_for_status_ref()?;
     let res = response.text().await?;
     info!("Generated custom report");
@@ -1103,7 +1379,7 @@ pub async fn generate_block_report(
     info!("Generating block report with template {template_id}");
     let url = format!("{}/block-report/{}", config.backend, template_id);
     let client = reqwest::Client::new();
-    let response = client.get(url).send().await?;
+    let response = execute(config.requests, client.get(url)).await?;
     response.error_for_status_ref()?;
     let res = response.text().await?;
     info!("Generated block report");
@@ -1117,7 +1393,7 @@ pub async fn generate_invitation(
     info!("Generating invitation for user {}", request.username);
     let url = format!("{}/user-invitation/invitation", config.backend);
     let client = reqwest::Client::new();
-    let response = client.post(url).json(&request).send().await?;
+    let response = execute(config.requests, client.post(url).json(&request)).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Generated invitation");
@@ -1135,7 +1411,7 @@ pub async fn list_user_invitations(
     );
     info!("Invitation API URL: {}", url);
 
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     info!("Response status: {}", response.status());
 
     response.error_for_status_ref()?;
@@ -1177,7 +1453,7 @@ pub async fn revoke_invitation(config: Config, invitation_id: Uuid) -> Result<()
         config.backend, invitation_id
     );
     let client = reqwest::Client::new();
-    let response = client.delete(url).send().await?;
+    let response = execute(config.requests, client.delete(url)).await?;
     response.error_for_status_ref()?;
     info!("Revoked invitation");
     Ok(())
@@ -1193,7 +1469,7 @@ pub async fn revoke_session_for_invitation(
         config.backend, invitation_id
     );
     let client = reqwest::Client::new();
-    let response = client.post(url).send().await?;
+    let response = execute(config.requests, client.post(url)).await?;
     response.error_for_status_ref()?;
     info!("Revoked session for invitation");
     Ok(())
@@ -1209,7 +1485,7 @@ pub async fn get_shiftplan_assignments(
         "{}/sales-person-shiftplan/{}/shiftplans",
         config.backend, sales_person_id
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched shiftplan assignments");
@@ -1227,7 +1503,7 @@ pub async fn set_shiftplan_assignments(
         config.backend, sales_person_id
     );
     let client = reqwest::Client::new();
-    let response = client.put(url).json(assignments).send().await?;
+    let response = execute(config.requests, client.put(url).json(assignments)).await?;
     response.error_for_status_ref()?;
     info!("Set shiftplan assignments");
     Ok(())
@@ -1242,7 +1518,7 @@ pub async fn get_bookable_sales_persons(
         "{}/sales-person-shiftplan/by-shiftplan/{}",
         config.backend, shiftplan_id
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched bookable sales persons");
@@ -1261,7 +1537,7 @@ pub async fn get_blocks(
         "{}/blocks/{}/{}/{}/{}",
         config.backend, from_year, from_week, to_year, to_week
     );
-    let response = reqwest::get(url).await?;
+    let response = get(config.requests, url).await?;
     response.error_for_status_ref()?;
     let res = response.json().await?;
     info!("Fetched blocks");
@@ -1,6 +1,7 @@
 use crate::auth::Auth;
 use crate::component::dropdown_base::DropdownBase;
 use crate::component::tooltip::TooltipBase;
+use crate::component::version_conflict_dialog::VersionConflictBase;
 use crate::component::{Footer, TopBar};
 use crate::page::NotAuthenticated;
 use crate::router::Route;
@@ -19,13 +20,18 @@ pub fn App() -> Element {
     use_coroutine(service::user_management::user_management_service);
     use_coroutine(service::booking_conflict::booking_conflicts_service);
     use_coroutine(service::booking_log::booking_log_service);
+    use_coroutine(service::live_update::live_update_service);
+    use_coroutine(service::offline_queue::offline_queue_service);
+    use_coroutine(service::booking_pattern::booking_pattern_service);
+    use_coroutine(service::shiftplan_draft::shiftplan_draft_service);
     use_coroutine(service::weekly_summary::weekly_summary_service);
     use_coroutine(service::employee_work_details::employee_work_details_service);
     use_coroutine(service::employee::employee_service);
     use_coroutine(service::slot_edit::slot_edit_service);
     use_coroutine(service::billing_period::billing_period_service);
+    use_coroutine(service::version_conflict::version_conflict_service);
     let config = CONFIG.read();
-    if !config.backend.is_empty() {
+    if config.is_loaded() {
         let title = config.application_title.clone();
         let is_prod = config.is_prod;
         let env_short_description = config.env_short_description.clone();
@@ -44,6 +50,7 @@ pub fn App() -> Element {
             div { class: "flex flex-col",
                 DropdownBase {}
                 TooltipBase {}
+                VersionConflictBase {}
                 Auth {
                     authenticated: rsx! {
                         Router::<Route> {}
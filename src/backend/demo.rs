@@ -0,0 +1,119 @@
+//! Offline demo mode.
+//!
+//! When `Config::demo` is set, [`super::from_config`] hands out a single
+//! [`InMemoryBackend`] per thread. Its [`FixtureStore`] is seeded from
+//! [`FixtureStore::demo`] on first use and written back to `localStorage`
+//! after every mutation, so a demo survives page reloads.
+
+use std::cell::RefCell;
+
+use super::{FixtureStore, InMemoryBackend};
+
+#[allow(dead_code)]
+pub const DEMO_STORE_KEY: &str = "shifty.demo.store";
+
+thread_local! {
+    static DEMO_BACKEND: RefCell<Option<InMemoryBackend>> = const { RefCell::new(None) };
+}
+
+/// The shared demo backend, restored from `localStorage` or freshly seeded.
+pub fn demo_backend() -> InMemoryBackend {
+    DEMO_BACKEND.with(|demo| {
+        demo.borrow_mut()
+            .get_or_insert_with(|| {
+                let backend = match load_store() {
+                    Some(store) => InMemoryBackend::new(store),
+                    None => {
+                        let backend = InMemoryBackend::demo();
+                        save_store(&backend.snapshot());
+                        backend
+                    }
+                };
+                backend.with_change_hook(save_store)
+            })
+            .clone()
+    })
+}
+
+/// Drops the persisted demo data; the next [`demo_backend`] call reseeds it.
+pub fn reset_demo_store() {
+    clear_store();
+    DEMO_BACKEND.with(|demo| *demo.borrow_mut() = None);
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load_store() -> Option<FixtureStore> {
+    local_storage()
+        .and_then(|s| s.get_item(DEMO_STORE_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_store(store: &FixtureStore) {
+    if let (Some(storage), Ok(json)) = (local_storage(), serde_json::to_string(store)) {
+        let _ = storage.set_item(DEMO_STORE_KEY, &json);
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn clear_store() {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(DEMO_STORE_KEY);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_store() -> Option<FixtureStore> {
+    None
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn save_store(_store: &FixtureStore) {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn clear_store() {}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use super::*;
+    use crate::{
+        backend::{fixtures::MAIN_SHIFTPLAN_ID, from_config, ShiftyBackend},
+        state::Config,
+    };
+
+    #[test]
+    fn storage_key_is_namespaced() {
+        assert!(DEMO_STORE_KEY.starts_with("shifty."));
+    }
+
+    #[test]
+    fn from_config_serves_demo_config_without_backend_url() {
+        let config = Config {
+            demo: true,
+            ..Config::default()
+        };
+        let plans = block_on(from_config(&config).get_all_shiftplans()).unwrap();
+        assert!(plans.iter().any(|plan| plan.id == MAIN_SHIFTPLAN_ID));
+        let auth_info = block_on(from_config(&config).fetch_auth_info()).unwrap();
+        assert!(auth_info.is_some());
+    }
+
+    #[test]
+    fn demo_backend_is_shared_until_reset() {
+        let first = demo_backend();
+        block_on(first.delete_shiftplan(MAIN_SHIFTPLAN_ID)).unwrap();
+        let plans = block_on(demo_backend().get_all_shiftplans()).unwrap();
+        assert!(plans.iter().all(|plan| plan.id != MAIN_SHIFTPLAN_ID));
+
+        reset_demo_store();
+        let plans = block_on(demo_backend().get_all_shiftplans()).unwrap();
+        assert!(plans.iter().any(|plan| plan.id == MAIN_SHIFTPLAN_ID));
+    }
+}
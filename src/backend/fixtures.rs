@@ -0,0 +1,337 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use rest_types::{
+    BillingPeriodTO, BookingTO, CustomExtraHoursTO, DayOfWeekTO, EmployeeWorkDetailsTO,
+    ExtraHoursCategoryTO, ExtraHoursTO, InvitationResponse, SalesPersonTO,
+    SalesPersonUnavailableTO, ShiftplanTO, SlotTO, SpecialDayTO, TemplateEngineTO, TextTemplateTO,
+    WeekMessageTO, WeekPublicationTO,
+};
+use serde::{Deserialize, Serialize};
+use time::{macros::time, Date, PrimitiveDateTime, Time};
+use uuid::Uuid;
+
+use crate::state::ShiftplanAssignment;
+
+/// Complete data set behind an [`InMemoryBackend`](super::InMemoryBackend).
+///
+/// The store is plain serde data so it can be persisted and restored as a
+/// whole.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FixtureStore {
+    pub auth_user: String,
+    pub privileges: Vec<String>,
+    pub shiftplans: Vec<ShiftplanTO>,
+    pub slots: Vec<SlotTO>,
+    pub bookings: Vec<BookingTO>,
+    pub sales_persons: Vec<SalesPersonTO>,
+    pub sales_person_users: BTreeMap<Uuid, String>,
+    pub shiftplan_assignments: BTreeMap<Uuid, Vec<ShiftplanAssignment>>,
+    pub unavailable_days: Vec<SalesPersonUnavailableTO>,
+    pub work_details: Vec<EmployeeWorkDetailsTO>,
+    pub extra_hours: Vec<ExtraHoursTO>,
+    pub custom_extra_hours: Vec<CustomExtraHoursTO>,
+    pub special_days: Vec<SpecialDayTO>,
+    pub week_messages: Vec<WeekMessageTO>,
+    #[serde(default)]
+    pub week_publications: Vec<WeekPublicationTO>,
+    #[serde(default)]
+    pub shiftplan_viewers: Vec<ShiftplanViewer>,
+    pub billing_periods: Vec<BillingPeriodTO>,
+    pub text_templates: Vec<TextTemplateTO>,
+    pub users: Vec<String>,
+    pub roles: Vec<String>,
+    pub user_roles: Vec<(String, String)>,
+    pub invitations: Vec<InvitationResponse>,
+}
+
+/// Last heartbeat of a user looking at a shiftplan week.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ShiftplanViewer {
+    pub user: String,
+    pub shiftplan_id: Uuid,
+    pub year: u32,
+    pub week: u8,
+    pub last_seen: PrimitiveDateTime,
+}
+
+pub const DEMO_USER: &str = "demo";
+pub const MAIN_SHIFTPLAN_ID: Uuid = Uuid::from_u128(0x5f1f_0001);
+pub const CAFE_SHIFTPLAN_ID: Uuid = Uuid::from_u128(0x5f1f_0002);
+
+const PERSONS: [(&str, &str, bool, Option<&str>); 5] = [
+    ("Anna", "#f3b4c4", true, Some(DEMO_USER)),
+    ("Ben", "#b4d4f3", true, Some("ben")),
+    ("Clara", "#c4f3b4", false, Some("clara")),
+    ("David", "#f3e0b4", true, None),
+    ("Eva", "#d9c4f3", false, None),
+];
+
+const MAIN_SHIFTS: [(Time, Time, u8); 3] = [
+    (time!(9:00), time!(12:00), 2),
+    (time!(12:00), time!(15:00), 2),
+    (time!(15:00), time!(18:00), 1),
+];
+
+const WORKDAYS: [DayOfWeekTO; 6] = [
+    DayOfWeekTO::Monday,
+    DayOfWeekTO::Tuesday,
+    DayOfWeekTO::Wednesday,
+    DayOfWeekTO::Thursday,
+    DayOfWeekTO::Friday,
+    DayOfWeekTO::Saturday,
+];
+
+pub fn sales_person_id(index: usize) -> Uuid {
+    Uuid::from_u128(0x5a1e_0000 + index as u128)
+}
+
+fn stamp(date: Date) -> PrimitiveDateTime {
+    PrimitiveDateTime::new(date, time!(8:00))
+}
+
+impl FixtureStore {
+    /// Demo data set around the ISO week containing `today`: two shiftplans,
+    /// five sales persons and bookings for the previous and the current week.
+    pub fn demo(today: Date) -> Self {
+        let (year, week, _) = today.to_iso_week_date();
+        let year = year as u32;
+        let monday =
+            Date::from_iso_week_date(year as i32, week, time::Weekday::Monday).unwrap_or(today);
+        let previous_monday = monday - time::Duration::weeks(1);
+        let (previous_year, previous_week, _) = previous_monday.to_iso_week_date();
+        let valid_from = Date::from_ordinal_date(year as i32 - 1, 1).unwrap_or(monday);
+
+        let mut store = FixtureStore {
+            auth_user: DEMO_USER.into(),
+            privileges: ["admin", "hr", "sales", "shiftplanner", "shiftplan.edit"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            users: PERSONS
+                .iter()
+                .filter_map(|(_, _, _, user)| user.map(String::from))
+                .collect(),
+            roles: ["admin", "hr", "sales", "shiftplanner"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            ..Default::default()
+        };
+        store.user_roles = ["admin", "hr", "sales", "shiftplanner"]
+            .into_iter()
+            .map(|role| (DEMO_USER.to_string(), role.to_string()))
+            .collect();
+
+        store.shiftplans = vec![
+            ShiftplanTO {
+                id: MAIN_SHIFTPLAN_ID,
+                name: "Main shop".into(),
+                is_planning: false,
+                deleted: None,
+                version: Uuid::from_u128(1),
+            },
+            ShiftplanTO {
+                id: CAFE_SHIFTPLAN_ID,
+                name: "Café".into(),
+                is_planning: false,
+                deleted: None,
+                version: Uuid::from_u128(1),
+            },
+        ];
+
+        for (index, (name, color, is_paid, user)) in PERSONS.iter().enumerate() {
+            let id = sales_person_id(index);
+            store.sales_persons.push(SalesPersonTO {
+                id,
+                name: (*name).into(),
+                background_color: (*color).into(),
+                is_paid: Some(*is_paid),
+                inactive: false,
+                deleted: None,
+                version: Uuid::from_u128(1),
+            });
+            if let Some(user) = user {
+                store.sales_person_users.insert(id, user.to_string());
+            }
+            store.shiftplan_assignments.insert(
+                id,
+                vec![
+                    ShiftplanAssignment {
+                        shiftplan_id: MAIN_SHIFTPLAN_ID,
+                        permission_level: "available".into(),
+                    },
+                    ShiftplanAssignment {
+                        shiftplan_id: CAFE_SHIFTPLAN_ID,
+                        permission_level: "available".into(),
+                    },
+                ],
+            );
+            if *is_paid {
+                store.work_details.push(EmployeeWorkDetailsTO {
+                    id: Uuid::from_u128(0x3d00_0000 + index as u128),
+                    sales_person_id: id,
+                    expected_hours: if index == 0 { 30.0 } else { 20.0 },
+                    from_day_of_week: DayOfWeekTO::Monday,
+                    from_calendar_week: 1,
+                    from_year: year - 1,
+                    to_day_of_week: DayOfWeekTO::Sunday,
+                    to_calendar_week: 52,
+                    to_year: year + 1,
+                    workdays_per_week: 5,
+                    is_dynamic: false,
+                    cap_planned_hours_to_expected: false,
+                    monday: true,
+                    tuesday: true,
+                    wednesday: true,
+                    thursday: true,
+                    friday: true,
+                    saturday: false,
+                    sunday: false,
+                    vacation_days: 25,
+                    days_per_week: 5,
+                    hours_per_day: if index == 0 { 6.0 } else { 4.0 },
+                    hours_per_holiday: if index == 0 { 6.0 } else { 4.0 },
+                    created: Some(stamp(valid_from)),
+                    deleted: None,
+                    version: Uuid::from_u128(1),
+                });
+            }
+        }
+
+        let mut slot_number = 0u128;
+        for day in WORKDAYS {
+            for (from, to, min_resources) in MAIN_SHIFTS {
+                slot_number += 1;
+                store.slots.push(SlotTO {
+                    id: Uuid::from_u128(0x5107_0000 + slot_number),
+                    day_of_week: day,
+                    from,
+                    to,
+                    min_resources,
+                    valid_from,
+                    valid_to: None,
+                    deleted: None,
+                    version: Uuid::from_u128(1),
+                    shiftplan_id: Some(MAIN_SHIFTPLAN_ID),
+                });
+            }
+        }
+        store.slots.push(SlotTO {
+            id: Uuid::from_u128(0x5107_1000),
+            day_of_week: DayOfWeekTO::Saturday,
+            from: time!(10:00),
+            to: time!(14:00),
+            min_resources: 1,
+            valid_from,
+            valid_to: None,
+            deleted: None,
+            version: Uuid::from_u128(1),
+            shiftplan_id: Some(CAFE_SHIFTPLAN_ID),
+        });
+
+        // Fully staffed previous week, a few gaps in the current one.
+        let mut booking_number = 0u128;
+        let slots = store.slots.clone();
+        for (booking_year, booking_week, leave_gaps, created) in [
+            (previous_year as u32, previous_week, false, previous_monday),
+            (year, week, true, monday),
+        ] {
+            for (slot_index, slot) in slots.iter().enumerate() {
+                for seat in 0..slot.min_resources as usize {
+                    if leave_gaps && seat > 0 && slot_index % 4 == 1 {
+                        continue;
+                    }
+                    let person_index = (slot_index + seat * 2) % PERSONS.len();
+                    booking_number += 1;
+                    store.bookings.push(BookingTO {
+                        id: Uuid::from_u128(0xb00c_0000 + booking_number),
+                        sales_person_id: sales_person_id(person_index),
+                        slot_id: slot.id,
+                        calendar_week: booking_week as i32,
+                        year: booking_year,
+                        created: Some(stamp(created - time::Duration::days(3))),
+                        deleted: None,
+                        created_by: Some(DEMO_USER.into()),
+                        deleted_by: None,
+                        version: Uuid::from_u128(1),
+                    });
+                }
+            }
+        }
+
+        store.unavailable_days.push(SalesPersonUnavailableTO {
+            id: Uuid::from_u128(0x0a7a_0001),
+            sales_person_id: sales_person_id(2),
+            year,
+            calendar_week: week,
+            day_of_week: DayOfWeekTO::Wednesday,
+            created: Some(stamp(monday)),
+            deleted: None,
+            version: Uuid::from_u128(1),
+        });
+
+        store.extra_hours = vec![
+            ExtraHoursTO {
+                id: Uuid::from_u128(0xe7a0_0001),
+                sales_person_id: sales_person_id(0),
+                amount: 6.0,
+                category: ExtraHoursCategoryTO::Vacation,
+                description: "Vacation".into(),
+                date_time: stamp(previous_monday + time::Duration::days(4)),
+                created: Some(stamp(previous_monday)),
+                deleted: None,
+                version: Uuid::from_u128(1),
+            },
+            ExtraHoursTO {
+                id: Uuid::from_u128(0xe7a0_0002),
+                sales_person_id: sales_person_id(1),
+                amount: 2.0,
+                category: ExtraHoursCategoryTO::ExtraWork,
+                description: "Inventory".into(),
+                date_time: stamp(previous_monday + time::Duration::days(1)),
+                created: Some(stamp(previous_monday)),
+                deleted: None,
+                version: Uuid::from_u128(1),
+            },
+        ];
+
+        store.week_messages.push(WeekMessageTO {
+            id: Uuid::from_u128(0x3e55_0001),
+            year,
+            calendar_week: week,
+            message: "Welcome to the Shifty demo. Changes are stored in this browser only.".into(),
+            created: Some(stamp(monday)),
+            deleted: None,
+            version: Uuid::from_u128(1),
+        });
+
+        store.text_templates = vec![
+            TextTemplateTO {
+                id: Uuid::from_u128(0x7e70_0001),
+                name: Some("Billing summary".into()),
+                template_type: "billing-period".into(),
+                template_text: Arc::from("Billing period summary"),
+                template_engine: TemplateEngineTO::MiniJinja,
+                created_at: Some(stamp(valid_from)),
+                created_by: Some(DEMO_USER.into()),
+                deleted: None,
+                deleted_by: None,
+                version: Uuid::from_u128(1),
+            },
+            TextTemplateTO {
+                id: Uuid::from_u128(0x7e70_0002),
+                name: Some("Upcoming shifts".into()),
+                template_type: "shiftplan-report".into(),
+                template_text: Arc::from("Upcoming shifts"),
+                template_engine: TemplateEngineTO::MiniJinja,
+                created_at: Some(stamp(valid_from)),
+                created_by: Some(DEMO_USER.into()),
+                deleted: None,
+                deleted_by: None,
+                version: Uuid::from_u128(1),
+            },
+        ];
+
+        store
+    }
+}
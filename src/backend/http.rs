@@ -0,0 +1,623 @@
+use std::{future::Future, rc::Rc};
+
+use rest_types::{
+    BillingPeriodTO, BlockTO, BookingConflictTO, BookingLogTO, BookingTO,
+    CreateTextTemplateRequestTO, CustomExtraHoursTO, DayOfWeekTO, EmployeeReportTO,
+    EmployeeWorkDetailsTO, ExtraHoursCategoryTO, ExtraHoursTO, GenerateInvitationRequest,
+    InvitationResponse, RoleTO, SalesPersonTO, SalesPersonUnavailableTO, ShiftplanDayAggregateTO,
+    ShiftplanTO, ShiftplanViewerTO, ShiftplanWeekTO, ShortEmployeeReportTO, SlotTO, TextTemplateTO,
+    UpdateTextTemplateRequestTO, UserRole, UserTO, WeekMessageTO, WeekPublicationTO,
+    WeeklySummaryTO,
+};
+use uuid::Uuid;
+
+use super::{BackendFuture, ShiftyBackend};
+use crate::{
+    api,
+    base_types::ImStr,
+    error::ShiftyError,
+    state::{AuthInfo, Config, ShiftplanAssignment},
+};
+
+/// Talks to the real backend via the functions in `crate::api`.
+pub struct HttpBackend {
+    config: Config,
+}
+
+impl HttpBackend {
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+}
+
+fn http<'a, T, E>(request: impl Future<Output = Result<T, E>> + 'a) -> BackendFuture<'a, T>
+where
+    ShiftyError: From<E>,
+{
+    Box::pin(async move { Ok(request.await?) })
+}
+
+impl ShiftyBackend for HttpBackend {
+    fn fetch_auth_info(&self) -> BackendFuture<'_, Option<AuthInfo>> {
+        http(api::fetch_auth_info(self.config.clone()))
+    }
+    fn get_version(&self) -> BackendFuture<'_, Rc<str>> {
+        http(api::get_version(self.config.clone()))
+    }
+
+    fn get_all_shiftplans(&self) -> BackendFuture<'_, Rc<[ShiftplanTO]>> {
+        http(api::get_all_shiftplans(self.config.clone()))
+    }
+    fn create_shiftplan<'a>(
+        &'a self,
+        name: &'a str,
+        is_planning: bool,
+    ) -> BackendFuture<'a, ShiftplanTO> {
+        http(api::create_shiftplan(
+            self.config.clone(),
+            name,
+            is_planning,
+        ))
+    }
+    fn update_shiftplan(&self, shiftplan: ShiftplanTO) -> BackendFuture<'_, ShiftplanTO> {
+        http(api::update_shiftplan(self.config.clone(), shiftplan))
+    }
+    fn delete_shiftplan(&self, id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::delete_shiftplan(self.config.clone(), id))
+    }
+    fn get_shiftplan_week(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, ShiftplanWeekTO> {
+        http(api::get_shiftplan_week(
+            self.config.clone(),
+            shiftplan_id,
+            year,
+            week,
+        ))
+    }
+    fn get_shiftplan_day(
+        &self,
+        year: u32,
+        week: u8,
+        day_of_week: DayOfWeekTO,
+    ) -> BackendFuture<'_, ShiftplanDayAggregateTO> {
+        http(api::get_shiftplan_day(
+            self.config.clone(),
+            year,
+            week,
+            day_of_week,
+        ))
+    }
+
+    fn get_slots(
+        &self,
+        year: u32,
+        week: u8,
+        shiftplan_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[SlotTO]>> {
+        http(api::get_slots(
+            self.config.clone(),
+            year,
+            week,
+            shiftplan_id,
+        ))
+    }
+    fn get_slot(&self, slot_id: Uuid) -> BackendFuture<'_, SlotTO> {
+        http(api::get_slot(self.config.clone(), slot_id))
+    }
+    fn update_slot(&self, slot: SlotTO, year: u32, week: u8) -> BackendFuture<'_, ()> {
+        http(api::update_slot(self.config.clone(), slot, year, week))
+    }
+    fn post_slot(&self, slot: SlotTO) -> BackendFuture<'_, bool> {
+        http(api::post_slot(self.config.clone(), slot))
+    }
+    fn delete_slot_from(&self, slot_id: Uuid, year: u32, week: u8) -> BackendFuture<'_, ()> {
+        http(api::delete_slot_from(
+            self.config.clone(),
+            slot_id,
+            year,
+            week,
+        ))
+    }
+
+    fn get_bookings_for_week(&self, week: u8, year: u32) -> BackendFuture<'_, Rc<[BookingTO]>> {
+        http(api::get_bookings_for_week(self.config.clone(), week, year))
+    }
+    fn add_booking(
+        &self,
+        sales_person_id: Uuid,
+        slot_id: Uuid,
+        week: u8,
+        year: u32,
+    ) -> BackendFuture<'_, ()> {
+        http(api::add_booking(
+            self.config.clone(),
+            sales_person_id,
+            slot_id,
+            week,
+            year,
+        ))
+    }
+    fn remove_booking(&self, booking_id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::remove_booking(self.config.clone(), booking_id))
+    }
+    fn copy_week(
+        &self,
+        from_week: u8,
+        from_year: u32,
+        to_week: u8,
+        to_year: u32,
+    ) -> BackendFuture<'_, ()> {
+        http(api::copy_week(
+            self.config.clone(),
+            from_week,
+            from_year,
+            to_week,
+            to_year,
+        ))
+    }
+    fn get_booking_conflicts_for_week(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[BookingConflictTO]>> {
+        http(api::get_booking_conflicts_for_week(
+            self.config.clone(),
+            year,
+            week,
+        ))
+    }
+    fn get_booking_log(&self, year: u32, week: u8) -> BackendFuture<'_, Rc<[BookingLogTO]>> {
+        http(api::get_booking_log(self.config.clone(), year, week))
+    }
+
+    fn get_sales_persons(&self) -> BackendFuture<'_, Rc<[SalesPersonTO]>> {
+        http(api::get_sales_persons(self.config.clone()))
+    }
+    fn get_current_sales_person(&self) -> BackendFuture<'_, Option<SalesPersonTO>> {
+        http(api::get_current_sales_person(self.config.clone()))
+    }
+    fn get_sales_person(&self, sales_person_id: Uuid) -> BackendFuture<'_, SalesPersonTO> {
+        http(api::get_sales_person(self.config.clone(), sales_person_id))
+    }
+    fn put_sales_person(&self, sales_person: SalesPersonTO) -> BackendFuture<'_, ()> {
+        http(api::put_sales_person(self.config.clone(), sales_person))
+    }
+    fn post_sales_person(&self, sales_person: SalesPersonTO) -> BackendFuture<'_, SalesPersonTO> {
+        http(api::post_sales_person(self.config.clone(), sales_person))
+    }
+    fn get_user_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Option<Rc<str>>> {
+        http(api::get_user_for_sales_person(
+            self.config.clone(),
+            sales_person_id,
+        ))
+    }
+    fn post_user_to_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        user_id: ImStr,
+    ) -> BackendFuture<'_, ()> {
+        http(api::post_user_to_sales_person(
+            self.config.clone(),
+            sales_person_id,
+            user_id,
+        ))
+    }
+    fn delete_user_from_sales_person(&self, sales_person_id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::delete_user_from_sales_person(
+            self.config.clone(),
+            sales_person_id,
+        ))
+    }
+    fn get_sales_person_by_user(
+        &self,
+        username: ImStr,
+    ) -> BackendFuture<'_, Option<SalesPersonTO>> {
+        http(api::get_sales_person_by_user(self.config.clone(), username))
+    }
+    fn get_shiftplan_assignments(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Vec<ShiftplanAssignment>> {
+        http(api::get_shiftplan_assignments(
+            self.config.clone(),
+            sales_person_id,
+        ))
+    }
+    fn set_shiftplan_assignments<'a>(
+        &'a self,
+        sales_person_id: Uuid,
+        assignments: &'a [ShiftplanAssignment],
+    ) -> BackendFuture<'a, ()> {
+        http(api::set_shiftplan_assignments(
+            self.config.clone(),
+            sales_person_id,
+            assignments,
+        ))
+    }
+    fn get_bookable_sales_persons(
+        &self,
+        shiftplan_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[SalesPersonTO]>> {
+        http(api::get_bookable_sales_persons(
+            self.config.clone(),
+            shiftplan_id,
+        ))
+    }
+
+    fn get_unavailable_sales_person_days_for_week(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[SalesPersonUnavailableTO]>> {
+        http(api::get_unavailable_sales_person_days_for_week(
+            self.config.clone(),
+            sales_person_id,
+            year,
+            week,
+        ))
+    }
+    fn create_unavailable_sales_person_day(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+        day_of_week: DayOfWeekTO,
+    ) -> BackendFuture<'_, ()> {
+        http(api::create_unavailable_sales_person_day(
+            self.config.clone(),
+            sales_person_id,
+            year,
+            week,
+            day_of_week,
+        ))
+    }
+    fn delete_unavailable_sales_person_day(&self, unavailable_id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::delete_unavailable_sales_person_day(
+            self.config.clone(),
+            unavailable_id,
+        ))
+    }
+
+    fn get_short_reports(
+        &self,
+        year: u32,
+        calendar_week: u8,
+    ) -> BackendFuture<'_, Rc<[ShortEmployeeReportTO]>> {
+        http(api::get_short_reports(
+            self.config.clone(),
+            year,
+            calendar_week,
+        ))
+    }
+    fn get_employee_reports(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        calendar_week: u8,
+    ) -> BackendFuture<'_, Rc<EmployeeReportTO>> {
+        http(api::get_employee_reports(
+            self.config.clone(),
+            sales_person_id,
+            year,
+            calendar_week,
+        ))
+    }
+    fn get_working_hours_for_week(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[ShortEmployeeReportTO]>> {
+        http(api::get_working_hours_for_week(
+            self.config.clone(),
+            year,
+            week,
+        ))
+    }
+    fn get_balance_until_week(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[ShortEmployeeReportTO]>> {
+        http(api::get_balance_until_week(self.config.clone(), year, week))
+    }
+    fn get_weekly_overview(&self, year: u32) -> BackendFuture<'_, Rc<[WeeklySummaryTO]>> {
+        http(api::get_weekly_overview(self.config.clone(), year))
+    }
+
+    fn add_extra_hour(
+        &self,
+        sales_person_id: Uuid,
+        amount: f32,
+        category: ExtraHoursCategoryTO,
+        description: String,
+        date_time: String,
+    ) -> BackendFuture<'_, ()> {
+        http(api::add_extra_hour(
+            self.config.clone(),
+            sales_person_id,
+            amount,
+            category,
+            description,
+            date_time,
+        ))
+    }
+    fn get_extra_hours_for_year(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        until_week: u8,
+    ) -> BackendFuture<'_, Rc<[ExtraHoursTO]>> {
+        http(api::get_extra_hours_for_year(
+            self.config.clone(),
+            sales_person_id,
+            year,
+            until_week,
+        ))
+    }
+    fn delete_extra_hour(&self, extra_hour_id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::delete_extra_hour(self.config.clone(), extra_hour_id))
+    }
+    fn update_extra_hour(&self, extra_hours: ExtraHoursTO) -> BackendFuture<'_, ExtraHoursTO> {
+        http(api::update_extra_hour(self.config.clone(), extra_hours))
+    }
+    fn add_vacation(
+        &self,
+        sales_person_id: Uuid,
+        from: time::Date,
+        to: time::Date,
+        description: ImStr,
+    ) -> BackendFuture<'_, ()> {
+        http(api::add_vacation(
+            self.config.clone(),
+            sales_person_id,
+            from,
+            to,
+            description,
+        ))
+    }
+    fn get_custom_extra_hours_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[CustomExtraHoursTO]>> {
+        http(api::get_custom_extra_hours_by_sales_person(
+            self.config.clone(),
+            sales_person_id,
+        ))
+    }
+    fn post_custom_extra_hours(
+        &self,
+        custom_extra_hours: CustomExtraHoursTO,
+    ) -> BackendFuture<'_, ()> {
+        http(api::post_custom_extra_hours(
+            self.config.clone(),
+            custom_extra_hours,
+        ))
+    }
+    fn put_custom_extra_hours(
+        &self,
+        custom_extra_hours: CustomExtraHoursTO,
+    ) -> BackendFuture<'_, ()> {
+        http(api::put_custom_extra_hours(
+            self.config.clone(),
+            custom_extra_hours,
+        ))
+    }
+    fn delete_custom_extra_hours(&self, custom_extra_hours_id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::delete_custom_extra_hours(
+            self.config.clone(),
+            custom_extra_hours_id,
+        ))
+    }
+
+    fn get_employee_work_details_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[EmployeeWorkDetailsTO]>> {
+        http(api::get_employee_work_details_for_sales_person(
+            self.config.clone(),
+            sales_person_id,
+        ))
+    }
+    fn post_employee_work_details(
+        &self,
+        work_details: EmployeeWorkDetailsTO,
+    ) -> BackendFuture<'_, ()> {
+        http(api::post_employee_work_details(
+            self.config.clone(),
+            work_details,
+        ))
+    }
+    fn put_employee_work_details(
+        &self,
+        work_details: EmployeeWorkDetailsTO,
+    ) -> BackendFuture<'_, ()> {
+        http(api::put_employee_work_details(
+            self.config.clone(),
+            work_details,
+        ))
+    }
+    fn delete_employee_work_details(&self, work_details_id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::delete_employee_work_details(
+            self.config.clone(),
+            work_details_id,
+        ))
+    }
+
+    fn get_all_users(&self) -> BackendFuture<'_, Rc<[UserTO]>> {
+        http(api::get_all_users(self.config.clone()))
+    }
+    fn get_all_roles(&self) -> BackendFuture<'_, Rc<[RoleTO]>> {
+        http(api::get_all_roles(self.config.clone()))
+    }
+    fn get_roles_from_user(&self, user_id: ImStr) -> BackendFuture<'_, Rc<[RoleTO]>> {
+        http(api::get_roles_from_user(self.config.clone(), user_id))
+    }
+    fn add_role_to_user(&self, user_role: UserRole) -> BackendFuture<'_, ()> {
+        http(api::add_role_to_user(self.config.clone(), user_role))
+    }
+    fn remove_role_from_user(&self, user_role: UserRole) -> BackendFuture<'_, ()> {
+        http(api::remove_role_from_user(self.config.clone(), user_role))
+    }
+    fn add_user(&self, user: UserTO) -> BackendFuture<'_, ()> {
+        http(api::add_user(self.config.clone(), user))
+    }
+    fn delete_user(&self, user_id: ImStr) -> BackendFuture<'_, ()> {
+        http(api::delete_user(self.config.clone(), user_id))
+    }
+
+    fn get_week_message(&self, year: u32, week: u8) -> BackendFuture<'_, Option<WeekMessageTO>> {
+        http(api::get_week_message(self.config.clone(), year, week))
+    }
+    fn post_week_message(&self, week_message: WeekMessageTO) -> BackendFuture<'_, ()> {
+        http(api::post_week_message(self.config.clone(), week_message))
+    }
+    fn put_week_message(&self, week_message: WeekMessageTO) -> BackendFuture<'_, ()> {
+        http(api::put_week_message(self.config.clone(), week_message))
+    }
+
+    fn get_week_publication(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Option<WeekPublicationTO>> {
+        http(api::get_week_publication(self.config.clone(), year, week))
+    }
+    fn post_week_publication(
+        &self,
+        week_publication: WeekPublicationTO,
+    ) -> BackendFuture<'_, WeekPublicationTO> {
+        http(api::post_week_publication(
+            self.config.clone(),
+            week_publication,
+        ))
+    }
+    fn delete_week_publication(&self, id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::delete_week_publication(self.config.clone(), id))
+    }
+
+    fn put_shiftplan_presence(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[ShiftplanViewerTO]>> {
+        http(api::put_shiftplan_presence(
+            self.config.clone(),
+            shiftplan_id,
+            year,
+            week,
+        ))
+    }
+
+    fn get_billing_periods(&self) -> BackendFuture<'_, Rc<[BillingPeriodTO]>> {
+        http(api::get_billing_periods(self.config.clone()))
+    }
+    fn get_billing_period(&self, billing_period_id: Uuid) -> BackendFuture<'_, BillingPeriodTO> {
+        http(api::get_billing_period(
+            self.config.clone(),
+            billing_period_id,
+        ))
+    }
+    fn post_billing_period(&self, end_date: time::Date) -> BackendFuture<'_, ()> {
+        http(api::post_billing_period(self.config.clone(), end_date))
+    }
+    fn delete_billing_period(&self, id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::delete_billing_period(self.config.clone(), id))
+    }
+
+    fn get_text_templates(&self) -> BackendFuture<'_, Rc<[TextTemplateTO]>> {
+        http(api::get_text_templates(self.config.clone()))
+    }
+    fn get_text_templates_by_type<'a>(
+        &'a self,
+        template_type: &'a str,
+    ) -> BackendFuture<'a, Rc<[TextTemplateTO]>> {
+        http(api::get_text_templates_by_type(
+            self.config.clone(),
+            template_type,
+        ))
+    }
+    fn get_text_template(&self, template_id: Uuid) -> BackendFuture<'_, TextTemplateTO> {
+        http(api::get_text_template(self.config.clone(), template_id))
+    }
+    fn create_text_template(
+        &self,
+        template: CreateTextTemplateRequestTO,
+    ) -> BackendFuture<'_, TextTemplateTO> {
+        http(api::create_text_template(self.config.clone(), template))
+    }
+    fn update_text_template(
+        &self,
+        template_id: Uuid,
+        template: UpdateTextTemplateRequestTO,
+    ) -> BackendFuture<'_, TextTemplateTO> {
+        http(api::update_text_template(
+            self.config.clone(),
+            template_id,
+            template,
+        ))
+    }
+    fn delete_text_template(&self, template_id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::delete_text_template(self.config.clone(), template_id))
+    }
+    fn generate_custom_report(
+        &self,
+        billing_period_id: Uuid,
+        template_id: Uuid,
+    ) -> BackendFuture<'_, String> {
+        http(api::generate_custom_report(
+            self.config.clone(),
+            billing_period_id,
+            template_id,
+        ))
+    }
+    fn generate_block_report(&self, template_id: Uuid) -> BackendFuture<'_, String> {
+        http(api::generate_block_report(self.config.clone(), template_id))
+    }
+
+    fn generate_invitation(
+        &self,
+        request: GenerateInvitationRequest,
+    ) -> BackendFuture<'_, InvitationResponse> {
+        http(api::generate_invitation(self.config.clone(), request))
+    }
+    fn list_user_invitations(
+        &self,
+        username: ImStr,
+    ) -> BackendFuture<'_, Rc<[InvitationResponse]>> {
+        http(api::list_user_invitations(self.config.clone(), username))
+    }
+    fn revoke_invitation(&self, invitation_id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::revoke_invitation(self.config.clone(), invitation_id))
+    }
+    fn revoke_session_for_invitation(&self, invitation_id: Uuid) -> BackendFuture<'_, ()> {
+        http(api::revoke_session_for_invitation(
+            self.config.clone(),
+            invitation_id,
+        ))
+    }
+
+    fn get_blocks(
+        &self,
+        from_year: u32,
+        from_week: u8,
+        to_year: u32,
+        to_week: u8,
+    ) -> BackendFuture<'_, Rc<[BlockTO]>> {
+        http(api::get_blocks(
+            self.config.clone(),
+            from_year,
+            from_week,
+            to_year,
+            to_week,
+        ))
+    }
+}
@@ -0,0 +1,2279 @@
+use std::{cell::RefCell, collections::BTreeMap, future, rc::Rc, sync::Arc};
+
+use rest_types::{
+    BillingPeriodSalesPersonTO, BillingPeriodTO, BillingPeriodValueTO, BlockTO, BookingConflictTO,
+    BookingLogTO, BookingTO, CreateTextTemplateRequestTO, CustomExtraHoursTO, DayOfWeekTO,
+    EmployeeReportTO, EmployeeWorkDetailsTO, ExtraHoursCategoryTO, ExtraHoursReportCategoryTO,
+    ExtraHoursTO, GenerateInvitationRequest, InvitationResponse, InvitationStatus, PlanDayViewTO,
+    RoleTO, SalesPersonTO, SalesPersonUnavailableTO, ShiftplanBookingTO, ShiftplanDayAggregateTO,
+    ShiftplanDayTO, ShiftplanSlotTO, ShiftplanTO, ShiftplanViewerTO, ShiftplanWeekTO,
+    ShortEmployeeReportTO, SlotTO, TextTemplateTO, UpdateTextTemplateRequestTO, UserRole, UserTO,
+    WeekMessageTO, WeekPublicationTO, WeeklySummaryTO, WorkingHoursDayTO,
+    WorkingHoursPerSalesPersonTO, WorkingHoursReportTO,
+};
+use time::{macros::format_description, Date, Duration, PrimitiveDateTime, Weekday};
+use uuid::Uuid;
+
+use super::{fixtures::ShiftplanViewer, BackendFuture, FixtureStore, ShiftyBackend};
+use crate::{
+    base_types::ImStr,
+    error::{ConflictEntity, ShiftyError, VersionConflict},
+    state::{AuthInfo, ShiftplanAssignment},
+};
+
+const DAYS: [DayOfWeekTO; 7] = [
+    DayOfWeekTO::Monday,
+    DayOfWeekTO::Tuesday,
+    DayOfWeekTO::Wednesday,
+    DayOfWeekTO::Thursday,
+    DayOfWeekTO::Friday,
+    DayOfWeekTO::Saturday,
+    DayOfWeekTO::Sunday,
+];
+
+type ChangeHook = Rc<dyn Fn(&FixtureStore)>;
+
+/// Answers every request from a [`FixtureStore`] held in memory.
+///
+/// Clones share the same store, so a clone handed to a page sees the
+/// mutations made through another one.
+#[derive(Clone)]
+pub struct InMemoryBackend {
+    store: Rc<RefCell<FixtureStore>>,
+    on_change: Option<ChangeHook>,
+}
+
+impl InMemoryBackend {
+    pub fn new(store: FixtureStore) -> Self {
+        Self {
+            store: Rc::new(RefCell::new(store)),
+            on_change: None,
+        }
+    }
+
+    /// Store seeded with [`FixtureStore::demo`] around today's date.
+    pub fn demo() -> Self {
+        Self::new(FixtureStore::demo(now().date()))
+    }
+
+    /// Calls `hook` with the updated store after every successful mutation,
+    /// e.g. to persist it.
+    pub fn with_change_hook(mut self, hook: impl Fn(&FixtureStore) + 'static) -> Self {
+        self.on_change = Some(Rc::new(hook));
+        self
+    }
+
+    pub fn snapshot(&self) -> FixtureStore {
+        self.store.borrow().clone()
+    }
+
+    fn query<'a, T: 'a>(
+        &self,
+        f: impl FnOnce(&FixtureStore) -> Result<T, ShiftyError>,
+    ) -> BackendFuture<'a, T> {
+        Box::pin(future::ready(f(&self.store.borrow())))
+    }
+
+    fn update<'a, T: 'a>(
+        &self,
+        f: impl FnOnce(&mut FixtureStore) -> Result<T, ShiftyError>,
+    ) -> BackendFuture<'a, T> {
+        let result = f(&mut self.store.borrow_mut());
+        if let (Ok(_), Some(on_change)) = (&result, &self.on_change) {
+            on_change(&self.store.borrow());
+        }
+        Box::pin(future::ready(result))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn now() -> PrimitiveDateTime {
+    crate::js::current_datetime()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now() -> PrimitiveDateTime {
+    let now = time::OffsetDateTime::now_utc();
+    PrimitiveDateTime::new(now.date(), now.time())
+}
+
+/// Rejects an update based on an outdated version, like the backend's 409.
+fn version_conflict(yours: ConflictEntity, theirs: ConflictEntity) -> ShiftyError {
+    ShiftyError::VersionConflict(Box::new(VersionConflict { yours, theirs }))
+}
+
+/// How long a viewer counts as present after their last heartbeat.
+const PRESENCE_TIMEOUT: time::Duration = time::Duration::seconds(60);
+
+fn not_found(what: &str, id: impl std::fmt::Display) -> ShiftyError {
+    ShiftyError::Backend(format!("{what} {id} not found"))
+}
+
+fn monday_of(year: u32, week: u8) -> Result<Date, ShiftyError> {
+    Ok(Date::from_iso_week_date(
+        year as i32,
+        week,
+        Weekday::Monday,
+    )?)
+}
+
+fn week_of(date: Date) -> (u32, u8) {
+    let (year, week, _) = date.to_iso_week_date();
+    (year as u32, week)
+}
+
+fn day_date(monday: Date, day_of_week: DayOfWeekTO) -> Date {
+    monday + Duration::days(Weekday::from(day_of_week).number_days_from_monday() as i64)
+}
+
+fn slot_hours(slot: &SlotTO) -> f32 {
+    (slot.to - slot.from).as_seconds_f32() / 3600.0
+}
+
+fn slot_active_in_week(slot: &SlotTO, monday: Date) -> bool {
+    let sunday = monday + Duration::days(6);
+    slot.deleted.is_none()
+        && slot.valid_from <= sunday
+        && slot.valid_to.is_none_or(|valid_to| valid_to >= monday)
+}
+
+fn parse_date_time(date_time: &str) -> Result<PrimitiveDateTime, ShiftyError> {
+    PrimitiveDateTime::parse(
+        date_time,
+        format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]"),
+    )
+    .or_else(|_| {
+        PrimitiveDateTime::parse(
+            date_time,
+            format_description!("[year]-[month]-[day]T[hour]:[minute]"),
+        )
+    })
+    .map_err(|_| ShiftyError::Backend(format!("invalid date time {date_time}")))
+}
+
+/// Hours of one sales person, summed over some period.
+#[derive(Clone, Copy, Debug, Default)]
+struct Hours {
+    expected: f32,
+    shiftplan: f32,
+    extra_work: f32,
+    vacation: f32,
+    sick_leave: f32,
+    holiday: f32,
+    unpaid_leave: f32,
+    volunteer: f32,
+    unavailable: f32,
+}
+
+impl Hours {
+    fn overall(&self) -> f32 {
+        self.shiftplan + self.extra_work + self.vacation + self.sick_leave + self.holiday
+    }
+
+    fn balance(&self) -> f32 {
+        self.overall() - self.expected
+    }
+
+    fn add(&mut self, other: &Hours) {
+        self.expected += other.expected;
+        self.shiftplan += other.shiftplan;
+        self.extra_work += other.extra_work;
+        self.vacation += other.vacation;
+        self.sick_leave += other.sick_leave;
+        self.holiday += other.holiday;
+        self.unpaid_leave += other.unpaid_leave;
+        self.volunteer += other.volunteer;
+        self.unavailable += other.unavailable;
+    }
+}
+
+fn days_of(hours: f32, work_details: Option<&EmployeeWorkDetailsTO>) -> f32 {
+    match work_details {
+        Some(details) if details.hours_per_day > 0.0 => hours / details.hours_per_day,
+        _ => 0.0,
+    }
+}
+
+fn report_entry(
+    from: Date,
+    to: Date,
+    hours: &Hours,
+    days: Vec<WorkingHoursDayTO>,
+    work_details: Option<&EmployeeWorkDetailsTO>,
+) -> WorkingHoursReportTO {
+    WorkingHoursReportTO {
+        from,
+        to,
+        expected_hours: hours.expected,
+        dynamic_hours: hours.expected,
+        overall_hours: hours.overall(),
+        balance: hours.balance(),
+        days_per_week: work_details.map(|d| d.days_per_week).unwrap_or(0),
+        workdays_per_week: work_details
+            .map(|d| d.workdays_per_week as f32)
+            .unwrap_or(0.0),
+        shiftplan_hours: hours.shiftplan,
+        extra_work_hours: hours.extra_work,
+        vacation_hours: hours.vacation,
+        vacation_days: days_of(hours.vacation, work_details),
+        sick_leave_hours: hours.sick_leave,
+        sick_leave_days: days_of(hours.sick_leave, work_details),
+        holiday_hours: hours.holiday,
+        holiday_days: days_of(hours.holiday, work_details),
+        unpaid_leave_hours: hours.unpaid_leave,
+        volunteer_hours: hours.volunteer,
+        absence_days: days_of(
+            hours.vacation + hours.sick_leave + hours.holiday,
+            work_details,
+        ),
+        custom_extra_hours: Arc::new([]),
+        days: days.into(),
+    }
+}
+
+impl FixtureStore {
+    fn active_bookings(&self, year: u32, week: u8) -> impl Iterator<Item = &BookingTO> {
+        self.bookings.iter().filter(move |booking| {
+            booking.deleted.is_none()
+                && booking.year == year
+                && booking.calendar_week == week as i32
+        })
+    }
+
+    fn slot(&self, slot_id: Uuid) -> Result<&SlotTO, ShiftyError> {
+        self.slots
+            .iter()
+            .find(|slot| slot.id == slot_id)
+            .ok_or_else(|| not_found("slot", slot_id))
+    }
+
+    fn week_publication(&self, year: u32, week: u8) -> Option<&WeekPublicationTO> {
+        self.week_publications.iter().find(|publication| {
+            publication.deleted.is_none()
+                && publication.year == year
+                && publication.calendar_week == week
+        })
+    }
+
+    /// Published weeks only accept booking changes from shift planners.
+    fn check_week_editable(&self, year: u32, week: u8) -> Result<(), ShiftyError> {
+        if self.week_publication(year, week).is_some()
+            && !self
+                .privileges
+                .iter()
+                .any(|privilege| privilege == "shiftplanner")
+        {
+            return Err(ShiftyError::Backend(format!(
+                "week {week}/{year} is published"
+            )));
+        }
+        Ok(())
+    }
+
+    fn sales_person(&self, sales_person_id: Uuid) -> Result<&SalesPersonTO, ShiftyError> {
+        self.sales_persons
+            .iter()
+            .find(|sales_person| sales_person.id == sales_person_id)
+            .ok_or_else(|| not_found("sales person", sales_person_id))
+    }
+
+    fn plan_slots(
+        &self,
+        shiftplan_id: Uuid,
+        monday: Date,
+        day_of_week: DayOfWeekTO,
+    ) -> Vec<ShiftplanSlotTO> {
+        let (year, week) = week_of(monday);
+        let mut slots: Vec<&SlotTO> = self
+            .slots
+            .iter()
+            .filter(|slot| {
+                slot.shiftplan_id == Some(shiftplan_id)
+                    && slot.day_of_week == day_of_week
+                    && slot_active_in_week(slot, monday)
+            })
+            .collect();
+        slots.sort_by_key(|slot| slot.from);
+        slots
+            .into_iter()
+            .map(|slot| ShiftplanSlotTO {
+                slot: slot.clone(),
+                bookings: self
+                    .active_bookings(year, week)
+                    .filter(|booking| booking.slot_id == slot.id)
+                    .filter_map(|booking| {
+                        let sales_person = self.sales_person(booking.sales_person_id).ok()?;
+                        let user = self.sales_person_users.get(&sales_person.id);
+                        Some(ShiftplanBookingTO {
+                            booking: booking.clone(),
+                            sales_person: Arc::new(sales_person.clone()),
+                            self_added: Some(
+                                user.is_some()
+                                    && booking.created_by.as_deref() == user.map(String::as_str),
+                            ),
+                        })
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn work_details_for_week(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+    ) -> Option<&EmployeeWorkDetailsTO> {
+        self.work_details.iter().find(|details| {
+            details.deleted.is_none()
+                && details.sales_person_id == sales_person_id
+                && (details.from_year, details.from_calendar_week) <= (year, week)
+                && (year, week) <= (details.to_year, details.to_calendar_week)
+        })
+    }
+
+    fn week_hours(
+        &self,
+        sales_person: &SalesPersonTO,
+        year: u32,
+        week: u8,
+    ) -> Result<(Hours, Vec<WorkingHoursDayTO>), ShiftyError> {
+        let monday = monday_of(year, week)?;
+        let is_paid = sales_person.is_paid.unwrap_or(false);
+        let mut hours = Hours::default();
+        let mut days = Vec::new();
+
+        if is_paid {
+            if let Some(details) = self.work_details_for_week(sales_person.id, year, week) {
+                hours.expected = details.expected_hours;
+            }
+        }
+        for booking in self
+            .active_bookings(year, week)
+            .filter(|booking| booking.sales_person_id == sales_person.id)
+        {
+            let Ok(slot) = self.slot(booking.slot_id) else {
+                continue;
+            };
+            let amount = slot_hours(slot);
+            if is_paid {
+                hours.shiftplan += amount;
+            } else {
+                hours.volunteer += amount;
+            }
+            days.push(WorkingHoursDayTO {
+                date: day_date(monday, slot.day_of_week),
+                hours: amount,
+                category: ExtraHoursReportCategoryTO::Shiftplan,
+            });
+        }
+        for extra_hours in self.extra_hours.iter().filter(|extra_hours| {
+            extra_hours.deleted.is_none()
+                && extra_hours.sales_person_id == sales_person.id
+                && week_of(extra_hours.date_time.date()) == (year, week)
+        }) {
+            let amount = extra_hours.amount;
+            let category = match extra_hours.category {
+                ExtraHoursCategoryTO::ExtraWork => {
+                    hours.extra_work += amount;
+                    ExtraHoursReportCategoryTO::ExtraWork
+                }
+                ExtraHoursCategoryTO::Vacation => {
+                    hours.vacation += amount;
+                    ExtraHoursReportCategoryTO::Vacation
+                }
+                ExtraHoursCategoryTO::SickLeave => {
+                    hours.sick_leave += amount;
+                    ExtraHoursReportCategoryTO::SickLeave
+                }
+                ExtraHoursCategoryTO::Holiday => {
+                    hours.holiday += amount;
+                    ExtraHoursReportCategoryTO::Holiday
+                }
+                ExtraHoursCategoryTO::Unavailable => {
+                    hours.unavailable += amount;
+                    ExtraHoursReportCategoryTO::Unavailable
+                }
+                ExtraHoursCategoryTO::UnpaidLeave => {
+                    hours.unpaid_leave += amount;
+                    ExtraHoursReportCategoryTO::UnpaidLeave
+                }
+                ExtraHoursCategoryTO::VolunteerWork => {
+                    hours.volunteer += amount;
+                    ExtraHoursReportCategoryTO::VolunteerWork
+                }
+                ExtraHoursCategoryTO::Custom(id) => ExtraHoursReportCategoryTO::Custom(id),
+            };
+            days.push(WorkingHoursDayTO {
+                date: extra_hours.date_time.date(),
+                hours: amount,
+                category,
+            });
+        }
+        hours.expected = (hours.expected - hours.unpaid_leave).max(0.0);
+        days.sort_by_key(|day| day.date);
+        Ok((hours, days))
+    }
+
+    fn hours_until_week(
+        &self,
+        sales_person: &SalesPersonTO,
+        year: u32,
+        until_week: u8,
+    ) -> Result<Hours, ShiftyError> {
+        let mut total = Hours::default();
+        for week in 1..=until_week {
+            total.add(&self.week_hours(sales_person, year, week)?.0);
+        }
+        Ok(total)
+    }
+
+    fn hours_between(
+        &self,
+        sales_person: &SalesPersonTO,
+        from: Date,
+        to: Date,
+    ) -> Result<Hours, ShiftyError> {
+        let mut total = Hours::default();
+        let (year, week) = week_of(from);
+        let mut monday = monday_of(year, week)?;
+        while monday <= to {
+            let (year, week) = week_of(monday);
+            total.add(&self.week_hours(sales_person, year, week)?.0);
+            monday += Duration::weeks(1);
+        }
+        Ok(total)
+    }
+
+    fn short_reports(
+        &self,
+        year: u32,
+        week: u8,
+        single_week: bool,
+    ) -> Result<Rc<[ShortEmployeeReportTO]>, ShiftyError> {
+        self.sales_persons
+            .iter()
+            .filter(|sales_person| sales_person.deleted.is_none())
+            .map(|sales_person| {
+                let until = self.hours_until_week(sales_person, year, week)?;
+                let hours = if single_week {
+                    self.week_hours(sales_person, year, week)?.0
+                } else {
+                    until
+                };
+                Ok(ShortEmployeeReportTO {
+                    sales_person: sales_person.clone(),
+                    balance_hours: until.balance(),
+                    expected_hours: hours.expected,
+                    dynamic_hours: hours.expected,
+                    overall_hours: hours.overall(),
+                    volunteer_hours: hours.volunteer,
+                })
+            })
+            .collect()
+    }
+
+    fn blocks(&self, from: Date, to: Date) -> Result<Vec<BlockTO>, ShiftyError> {
+        let mut blocks = Vec::new();
+        let (year, week) = week_of(from);
+        let mut monday = monday_of(year, week)?;
+        while monday <= to {
+            let (year, week) = week_of(monday);
+            let mut by_person_and_day: BTreeMap<(Uuid, u8), Vec<(&BookingTO, &SlotTO)>> =
+                BTreeMap::new();
+            for booking in self.active_bookings(year, week) {
+                let Ok(slot) = self.slot(booking.slot_id) else {
+                    continue;
+                };
+                let day = Weekday::from(slot.day_of_week).number_days_from_monday();
+                by_person_and_day
+                    .entry((booking.sales_person_id, day))
+                    .or_default()
+                    .push((booking, slot));
+            }
+            for ((sales_person_id, _), mut entries) in by_person_and_day {
+                entries.sort_by_key(|(_, slot)| slot.from);
+                let sales_person = self.sales_person(sales_person_id).ok().cloned();
+                let mut current: Option<BlockTO> = None;
+                for (booking, slot) in entries {
+                    match current.as_mut() {
+                        Some(block) if block.to == slot.from => {
+                            block.to = slot.to;
+                            block.bookings.push(booking.clone());
+                            block.slots.push(slot.clone());
+                        }
+                        _ => {
+                            blocks.extend(current.take());
+                            current = Some(BlockTO {
+                                year,
+                                week,
+                                sales_person: sales_person.clone(),
+                                day_of_week: slot.day_of_week,
+                                from: slot.from,
+                                to: slot.to,
+                                bookings: vec![booking.clone()],
+                                slots: vec![slot.clone()],
+                            });
+                        }
+                    }
+                }
+                blocks.extend(current);
+            }
+            monday += Duration::weeks(1);
+        }
+        Ok(blocks)
+    }
+}
+
+impl ShiftyBackend for InMemoryBackend {
+    fn fetch_auth_info(&self) -> BackendFuture<'_, Option<AuthInfo>> {
+        self.query(|store| {
+            Ok(Some(AuthInfo {
+                user: store.auth_user.as_str().into(),
+                privileges: store
+                    .privileges
+                    .iter()
+                    .map(|privilege| privilege.as_str().into())
+                    .collect(),
+                authenticated: true,
+            }))
+        })
+    }
+    fn get_version(&self) -> BackendFuture<'_, Rc<str>> {
+        self.query(|_| Ok("in-memory".into()))
+    }
+
+    fn get_all_shiftplans(&self) -> BackendFuture<'_, Rc<[ShiftplanTO]>> {
+        self.query(|store| {
+            Ok(store
+                .shiftplans
+                .iter()
+                .filter(|shiftplan| shiftplan.deleted.is_none())
+                .cloned()
+                .collect())
+        })
+    }
+    fn create_shiftplan<'a>(
+        &'a self,
+        name: &'a str,
+        is_planning: bool,
+    ) -> BackendFuture<'a, ShiftplanTO> {
+        self.update(|store| {
+            let shiftplan = ShiftplanTO {
+                id: Uuid::new_v4(),
+                name: name.into(),
+                is_planning,
+                deleted: None,
+                version: Uuid::new_v4(),
+            };
+            store.shiftplans.push(shiftplan.clone());
+            Ok(shiftplan)
+        })
+    }
+    fn update_shiftplan(&self, shiftplan: ShiftplanTO) -> BackendFuture<'_, ShiftplanTO> {
+        self.update(|store| {
+            let existing = store
+                .shiftplans
+                .iter_mut()
+                .find(|existing| existing.id == shiftplan.id)
+                .ok_or_else(|| not_found("shiftplan", shiftplan.id))?;
+            if existing.version != shiftplan.version {
+                return Err(version_conflict(
+                    ConflictEntity::Shiftplan(shiftplan),
+                    ConflictEntity::Shiftplan(existing.clone()),
+                ));
+            }
+            *existing = ShiftplanTO {
+                version: Uuid::new_v4(),
+                ..shiftplan
+            };
+            Ok(existing.clone())
+        })
+    }
+    fn delete_shiftplan(&self, id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let existing = store
+                .shiftplans
+                .iter_mut()
+                .find(|existing| existing.id == id)
+                .ok_or_else(|| not_found("shiftplan", id))?;
+            existing.deleted = Some(now());
+            Ok(())
+        })
+    }
+    fn get_shiftplan_week(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, ShiftplanWeekTO> {
+        self.query(|store| {
+            let monday = monday_of(year, week)?;
+            Ok(ShiftplanWeekTO {
+                year,
+                calendar_week: week,
+                days: DAYS
+                    .into_iter()
+                    .map(|day_of_week| ShiftplanDayTO {
+                        day_of_week,
+                        slots: store.plan_slots(shiftplan_id, monday, day_of_week),
+                    })
+                    .collect(),
+            })
+        })
+    }
+    fn get_shiftplan_day(
+        &self,
+        year: u32,
+        week: u8,
+        day_of_week: DayOfWeekTO,
+    ) -> BackendFuture<'_, ShiftplanDayAggregateTO> {
+        self.query(|store| {
+            let monday = monday_of(year, week)?;
+            Ok(ShiftplanDayAggregateTO {
+                year,
+                calendar_week: week,
+                day_of_week,
+                plans: store
+                    .shiftplans
+                    .iter()
+                    .filter(|shiftplan| shiftplan.deleted.is_none())
+                    .map(|shiftplan| PlanDayViewTO {
+                        shiftplan: shiftplan.clone(),
+                        slots: store.plan_slots(shiftplan.id, monday, day_of_week),
+                    })
+                    .collect(),
+            })
+        })
+    }
+
+    fn get_slots(
+        &self,
+        year: u32,
+        week: u8,
+        shiftplan_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[SlotTO]>> {
+        self.query(|store| {
+            let monday = monday_of(year, week)?;
+            Ok(store
+                .slots
+                .iter()
+                .filter(|slot| {
+                    slot.shiftplan_id == Some(shiftplan_id) && slot_active_in_week(slot, monday)
+                })
+                .cloned()
+                .collect())
+        })
+    }
+    fn get_slot(&self, slot_id: Uuid) -> BackendFuture<'_, SlotTO> {
+        self.query(|store| store.slot(slot_id).cloned())
+    }
+    fn update_slot(&self, slot: SlotTO, year: u32, week: u8) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let monday = monday_of(year, week)?;
+            let existing = store
+                .slots
+                .iter_mut()
+                .find(|existing| existing.id == slot.id)
+                .ok_or_else(|| not_found("slot", slot.id))?;
+            if existing.valid_from >= monday {
+                *existing = SlotTO {
+                    valid_from: existing.valid_from,
+                    version: Uuid::new_v4(),
+                    ..slot
+                };
+                return Ok(());
+            }
+            // Split the slot: the old version ends before `week`, bookings
+            // from `week` on move over to the new version.
+            existing.valid_to = Some(monday - Duration::days(1));
+            let successor = SlotTO {
+                id: Uuid::new_v4(),
+                valid_from: monday,
+                version: Uuid::new_v4(),
+                ..slot
+            };
+            for booking in store.bookings.iter_mut().filter(|booking| {
+                booking.slot_id == slot.id
+                    && (booking.year, booking.calendar_week) >= (year, week as i32)
+            }) {
+                booking.slot_id = successor.id;
+            }
+            store.slots.push(successor);
+            Ok(())
+        })
+    }
+    fn post_slot(&self, slot: SlotTO) -> BackendFuture<'_, bool> {
+        self.update(|store| {
+            let overlaps = store.slots.iter().any(|existing| {
+                existing.deleted.is_none()
+                    && existing.shiftplan_id == slot.shiftplan_id
+                    && existing.day_of_week == slot.day_of_week
+                    && existing.from < slot.to
+                    && slot.from < existing.to
+                    && existing
+                        .valid_to
+                        .is_none_or(|valid_to| valid_to >= slot.valid_from)
+                    && slot
+                        .valid_to
+                        .is_none_or(|valid_to| valid_to >= existing.valid_from)
+            });
+            if overlaps {
+                return Ok(false);
+            }
+            store.slots.push(SlotTO {
+                id: Uuid::new_v4(),
+                version: Uuid::new_v4(),
+                ..slot
+            });
+            Ok(true)
+        })
+    }
+    fn delete_slot_from(&self, slot_id: Uuid, year: u32, week: u8) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let monday = monday_of(year, week)?;
+            let existing = store
+                .slots
+                .iter_mut()
+                .find(|existing| existing.id == slot_id)
+                .ok_or_else(|| not_found("slot", slot_id))?;
+            if existing.valid_from >= monday {
+                existing.deleted = Some(now());
+            } else {
+                existing.valid_to = Some(monday - Duration::days(1));
+            }
+            existing.version = Uuid::new_v4();
+            Ok(())
+        })
+    }
+
+    fn get_bookings_for_week(&self, week: u8, year: u32) -> BackendFuture<'_, Rc<[BookingTO]>> {
+        self.query(|store| Ok(store.active_bookings(year, week).cloned().collect()))
+    }
+    fn add_booking(
+        &self,
+        sales_person_id: Uuid,
+        slot_id: Uuid,
+        week: u8,
+        year: u32,
+    ) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store.slot(slot_id)?;
+            store.sales_person(sales_person_id)?;
+            store.check_week_editable(year, week)?;
+            store.bookings.push(BookingTO {
+                id: Uuid::new_v4(),
+                sales_person_id,
+                slot_id,
+                calendar_week: week as i32,
+                year,
+                created: Some(now()),
+                deleted: None,
+                created_by: Some(store.auth_user.as_str().into()),
+                deleted_by: None,
+                version: Uuid::new_v4(),
+            });
+            Ok(())
+        })
+    }
+    fn remove_booking(&self, booking_id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let deleted_by: Arc<str> = store.auth_user.as_str().into();
+            let (year, week) = store
+                .bookings
+                .iter()
+                .find(|booking| booking.id == booking_id && booking.deleted.is_none())
+                .map(|booking| (booking.year, booking.calendar_week as u8))
+                .ok_or_else(|| not_found("booking", booking_id))?;
+            store.check_week_editable(year, week)?;
+            let booking = store
+                .bookings
+                .iter_mut()
+                .find(|booking| booking.id == booking_id && booking.deleted.is_none())
+                .ok_or_else(|| not_found("booking", booking_id))?;
+            booking.deleted = Some(now());
+            booking.deleted_by = Some(deleted_by);
+            booking.version = Uuid::new_v4();
+            Ok(())
+        })
+    }
+    fn copy_week(
+        &self,
+        from_week: u8,
+        from_year: u32,
+        to_week: u8,
+        to_year: u32,
+    ) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let target_monday = monday_of(to_year, to_week)?;
+            let created_by: Arc<str> = store.auth_user.as_str().into();
+            let copies: Vec<BookingTO> = store
+                .active_bookings(from_year, from_week)
+                .filter(|booking| {
+                    store
+                        .slot(booking.slot_id)
+                        .is_ok_and(|slot| slot_active_in_week(slot, target_monday))
+                        && !store.active_bookings(to_year, to_week).any(|existing| {
+                            existing.slot_id == booking.slot_id
+                                && existing.sales_person_id == booking.sales_person_id
+                        })
+                })
+                .map(|booking| BookingTO {
+                    id: Uuid::new_v4(),
+                    calendar_week: to_week as i32,
+                    year: to_year,
+                    created: Some(now()),
+                    created_by: Some(created_by.clone()),
+                    version: Uuid::new_v4(),
+                    ..booking.clone()
+                })
+                .collect();
+            store.bookings.extend(copies);
+            Ok(())
+        })
+    }
+    fn get_booking_conflicts_for_week(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[BookingConflictTO]>> {
+        self.query(|store| {
+            let monday = monday_of(year, week)?;
+            Ok(store
+                .active_bookings(year, week)
+                .filter_map(|booking| {
+                    let slot = store.slot(booking.slot_id).ok()?;
+                    let sales_person = store.sales_person(booking.sales_person_id).ok()?;
+                    let date = day_date(monday, slot.day_of_week);
+                    let unavailable = store.unavailable_days.iter().any(|day| {
+                        day.deleted.is_none()
+                            && day.sales_person_id == booking.sales_person_id
+                            && day.year == year
+                            && day.calendar_week == week
+                            && day.day_of_week == slot.day_of_week
+                    });
+                    let absent = store.extra_hours.iter().any(|extra_hours| {
+                        extra_hours.deleted.is_none()
+                            && extra_hours.sales_person_id == booking.sales_person_id
+                            && extra_hours.date_time.date() == date
+                            && matches!(
+                                extra_hours.category,
+                                ExtraHoursCategoryTO::Vacation
+                                    | ExtraHoursCategoryTO::SickLeave
+                                    | ExtraHoursCategoryTO::Unavailable
+                            )
+                    });
+                    (unavailable || absent).then(|| BookingConflictTO {
+                        booking: booking.clone(),
+                        slot: Arc::new(slot.clone()),
+                        sales_person: Arc::new(sales_person.clone()),
+                    })
+                })
+                .collect())
+        })
+    }
+    fn get_booking_log(&self, year: u32, week: u8) -> BackendFuture<'_, Rc<[BookingLogTO]>> {
+        self.query(|store| {
+            let monday = monday_of(year, week)?;
+            Ok(store
+                .bookings
+                .iter()
+                .filter(|booking| booking.year == year && booking.calendar_week == week as i32)
+                .filter_map(|booking| {
+                    let slot = store.slot(booking.slot_id).ok()?;
+                    let sales_person = store.sales_person(booking.sales_person_id).ok()?;
+                    Some(BookingLogTO {
+                        year,
+                        calendar_week: week,
+                        day_of_week: slot.day_of_week,
+                        name: sales_person.name.clone(),
+                        time_from: slot.from,
+                        time_to: slot.to,
+                        created: booking.created.unwrap_or_else(|| {
+                            PrimitiveDateTime::new(monday, time::Time::MIDNIGHT)
+                        }),
+                        deleted: booking.deleted,
+                        created_by: booking.created_by.clone(),
+                        deleted_by: booking.deleted_by.clone(),
+                        slot_id: Some(booking.slot_id),
+                        sales_person_id: Some(booking.sales_person_id),
+                    })
+                })
+                .collect())
+        })
+    }
+
+    fn get_sales_persons(&self) -> BackendFuture<'_, Rc<[SalesPersonTO]>> {
+        self.query(|store| {
+            Ok(store
+                .sales_persons
+                .iter()
+                .filter(|sales_person| sales_person.deleted.is_none())
+                .cloned()
+                .collect())
+        })
+    }
+    fn get_current_sales_person(&self) -> BackendFuture<'_, Option<SalesPersonTO>> {
+        self.query(|store| {
+            Ok(store
+                .sales_person_users
+                .iter()
+                .find(|(_, user)| **user == store.auth_user)
+                .and_then(|(id, _)| store.sales_person(*id).ok())
+                .cloned())
+        })
+    }
+    fn get_sales_person(&self, sales_person_id: Uuid) -> BackendFuture<'_, SalesPersonTO> {
+        self.query(|store| store.sales_person(sales_person_id).cloned())
+    }
+    fn put_sales_person(&self, sales_person: SalesPersonTO) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let existing = store
+                .sales_persons
+                .iter_mut()
+                .find(|existing| existing.id == sales_person.id)
+                .ok_or_else(|| not_found("sales person", sales_person.id))?;
+            if existing.version != sales_person.version {
+                return Err(version_conflict(
+                    ConflictEntity::SalesPerson(sales_person),
+                    ConflictEntity::SalesPerson(existing.clone()),
+                ));
+            }
+            *existing = SalesPersonTO {
+                version: Uuid::new_v4(),
+                ..sales_person
+            };
+            Ok(())
+        })
+    }
+    fn post_sales_person(&self, sales_person: SalesPersonTO) -> BackendFuture<'_, SalesPersonTO> {
+        self.update(|store| {
+            let sales_person = SalesPersonTO {
+                id: Uuid::new_v4(),
+                version: Uuid::new_v4(),
+                ..sales_person
+            };
+            store.sales_persons.push(sales_person.clone());
+            Ok(sales_person)
+        })
+    }
+    fn get_user_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Option<Rc<str>>> {
+        self.query(|store| {
+            Ok(store
+                .sales_person_users
+                .get(&sales_person_id)
+                .map(|user| user.as_str().into()))
+        })
+    }
+    fn post_user_to_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        user_id: ImStr,
+    ) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store
+                .sales_person_users
+                .insert(sales_person_id, user_id.to_string());
+            Ok(())
+        })
+    }
+    fn delete_user_from_sales_person(&self, sales_person_id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store.sales_person_users.remove(&sales_person_id);
+            Ok(())
+        })
+    }
+    fn get_sales_person_by_user(
+        &self,
+        username: ImStr,
+    ) -> BackendFuture<'_, Option<SalesPersonTO>> {
+        self.query(|store| {
+            Ok(store
+                .sales_person_users
+                .iter()
+                .find(|(_, user)| user.as_str() == username.as_str())
+                .and_then(|(id, _)| store.sales_person(*id).ok())
+                .cloned())
+        })
+    }
+    fn get_shiftplan_assignments(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Vec<ShiftplanAssignment>> {
+        self.query(|store| {
+            Ok(store
+                .shiftplan_assignments
+                .get(&sales_person_id)
+                .cloned()
+                .unwrap_or_default())
+        })
+    }
+    fn set_shiftplan_assignments<'a>(
+        &'a self,
+        sales_person_id: Uuid,
+        assignments: &'a [ShiftplanAssignment],
+    ) -> BackendFuture<'a, ()> {
+        self.update(|store| {
+            store
+                .shiftplan_assignments
+                .insert(sales_person_id, assignments.to_vec());
+            Ok(())
+        })
+    }
+    fn get_bookable_sales_persons(
+        &self,
+        shiftplan_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[SalesPersonTO]>> {
+        self.query(|store| {
+            Ok(store
+                .sales_persons
+                .iter()
+                .filter(|sales_person| {
+                    sales_person.deleted.is_none()
+                        && !sales_person.inactive
+                        && store
+                            .shiftplan_assignments
+                            .get(&sales_person.id)
+                            .is_some_and(|assignments| {
+                                assignments
+                                    .iter()
+                                    .any(|assignment| assignment.shiftplan_id == shiftplan_id)
+                            })
+                })
+                .cloned()
+                .collect())
+        })
+    }
+
+    fn get_unavailable_sales_person_days_for_week(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[SalesPersonUnavailableTO]>> {
+        self.query(|store| {
+            Ok(store
+                .unavailable_days
+                .iter()
+                .filter(|day| {
+                    day.deleted.is_none()
+                        && day.sales_person_id == sales_person_id
+                        && day.year == year
+                        && day.calendar_week == week
+                })
+                .cloned()
+                .collect())
+        })
+    }
+    fn create_unavailable_sales_person_day(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+        day_of_week: DayOfWeekTO,
+    ) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store.unavailable_days.push(SalesPersonUnavailableTO {
+                id: Uuid::new_v4(),
+                sales_person_id,
+                year,
+                calendar_week: week,
+                day_of_week,
+                created: Some(now()),
+                deleted: None,
+                version: Uuid::new_v4(),
+            });
+            Ok(())
+        })
+    }
+    fn delete_unavailable_sales_person_day(&self, unavailable_id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let day = store
+                .unavailable_days
+                .iter_mut()
+                .find(|day| day.id == unavailable_id)
+                .ok_or_else(|| not_found("unavailable day", unavailable_id))?;
+            day.deleted = Some(now());
+            Ok(())
+        })
+    }
+
+    fn get_short_reports(
+        &self,
+        year: u32,
+        calendar_week: u8,
+    ) -> BackendFuture<'_, Rc<[ShortEmployeeReportTO]>> {
+        self.query(|store| store.short_reports(year, calendar_week, false))
+    }
+    fn get_employee_reports(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        calendar_week: u8,
+    ) -> BackendFuture<'_, Rc<EmployeeReportTO>> {
+        self.query(|store| {
+            let sales_person = store.sales_person(sales_person_id)?;
+            let work_details = store.work_details_for_week(sales_person_id, year, calendar_week);
+            let mut total = Hours::default();
+            let mut by_week = Vec::new();
+            let mut months: BTreeMap<u8, (Date, Date, Hours, Vec<WorkingHoursDayTO>)> =
+                BTreeMap::new();
+            for week in 1..=calendar_week {
+                let monday = monday_of(year, week)?;
+                let sunday = monday + Duration::days(6);
+                let (hours, days) = store.week_hours(sales_person, year, week)?;
+                total.add(&hours);
+                by_week.push(report_entry(monday, sunday, &hours, days, work_details));
+
+                let (_, month_days) = store.week_hours(sales_person, year, week)?;
+                let month = months.entry(monday.month() as u8).or_insert((
+                    monday,
+                    sunday,
+                    Hours::default(),
+                    Vec::new(),
+                ));
+                month.1 = sunday;
+                month.2.add(&hours);
+                month.3.extend(month_days);
+            }
+            let by_month: Vec<WorkingHoursReportTO> = months
+                .into_values()
+                .map(|(from, to, hours, days)| report_entry(from, to, &hours, days, work_details))
+                .collect();
+            Ok(Rc::new(EmployeeReportTO {
+                sales_person: Arc::new(sales_person.clone()),
+                balance_hours: total.balance(),
+                overall_hours: total.overall(),
+                expected_hours: total.expected,
+                dynamic_hours: total.expected,
+                shiftplan_hours: total.shiftplan,
+                extra_work_hours: total.extra_work,
+                vacation_hours: total.vacation,
+                sick_leave_hours: total.sick_leave,
+                holiday_hours: total.holiday,
+                unpaid_leave_hours: total.unpaid_leave,
+                volunteer_hours: total.volunteer,
+                vacation_carryover: 0,
+                vacation_days: days_of(total.vacation, work_details),
+                vacation_entitlement: work_details
+                    .map(|details| details.vacation_days as f32)
+                    .unwrap_or(0.0),
+                sick_leave_days: days_of(total.sick_leave, work_details),
+                holiday_days: days_of(total.holiday, work_details),
+                absence_days: days_of(
+                    total.vacation + total.sick_leave + total.holiday,
+                    work_details,
+                ),
+                carryover_hours: 0.0,
+                custom_extra_hours: Arc::new([]),
+                by_week: by_week.into(),
+                by_month: by_month.into(),
+            }))
+        })
+    }
+    fn get_working_hours_for_week(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[ShortEmployeeReportTO]>> {
+        self.query(|store| store.short_reports(year, week, true))
+    }
+    fn get_balance_until_week(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[ShortEmployeeReportTO]>> {
+        self.query(|store| store.short_reports(year, week, false))
+    }
+    fn get_weekly_overview(&self, year: u32) -> BackendFuture<'_, Rc<[WeeklySummaryTO]>> {
+        self.query(|store| {
+            let planning_plans: Vec<Uuid> = store
+                .shiftplans
+                .iter()
+                .filter(|shiftplan| shiftplan.is_planning || shiftplan.deleted.is_some())
+                .map(|shiftplan| shiftplan.id)
+                .collect();
+            (1..=time::util::weeks_in_year(year as i32))
+                .map(|week| {
+                    let monday = monday_of(year, week)?;
+                    let required_hours = store
+                        .slots
+                        .iter()
+                        .filter(|slot| {
+                            slot_active_in_week(slot, monday)
+                                && !slot
+                                    .shiftplan_id
+                                    .is_some_and(|id| planning_plans.contains(&id))
+                        })
+                        .map(|slot| slot_hours(slot) * slot.min_resources as f32)
+                        .sum();
+                    let mut per_day = [0.0f32; 7];
+                    for booking in store.active_bookings(year, week) {
+                        if let Ok(slot) = store.slot(booking.slot_id) {
+                            let day = Weekday::from(slot.day_of_week).number_days_from_monday();
+                            per_day[day as usize] += slot_hours(slot);
+                        }
+                    }
+                    let mut paid_hours = 0.0;
+                    let mut volunteer_hours = 0.0;
+                    let mut per_sales_person = Vec::new();
+                    for sales_person in store
+                        .sales_persons
+                        .iter()
+                        .filter(|sales_person| sales_person.deleted.is_none())
+                    {
+                        let (hours, _) = store.week_hours(sales_person, year, week)?;
+                        paid_hours += hours.expected;
+                        volunteer_hours += hours.volunteer;
+                        per_sales_person.push(WorkingHoursPerSalesPersonTO {
+                            sales_person_id: sales_person.id,
+                            sales_person_name: sales_person.name.clone(),
+                            available_hours: hours.expected + hours.volunteer,
+                            absence_hours: hours.vacation + hours.sick_leave + hours.holiday,
+                            vacation_hours: hours.vacation,
+                            sick_leave_hours: hours.sick_leave,
+                            holiday_hours: hours.holiday,
+                            unavailable_hours: hours.unavailable,
+                            custom_absence_hours: Arc::new([]),
+                        });
+                    }
+                    Ok(WeeklySummaryTO {
+                        year,
+                        week,
+                        overall_available_hours: paid_hours + volunteer_hours,
+                        required_hours,
+                        paid_hours,
+                        volunteer_hours,
+                        monday_available_hours: per_day[0],
+                        tuesday_available_hours: per_day[1],
+                        wednesday_available_hours: per_day[2],
+                        thursday_available_hours: per_day[3],
+                        friday_available_hours: per_day[4],
+                        saturday_available_hours: per_day[5],
+                        sunday_available_hours: per_day[6],
+                        working_hours_per_sales_person: per_sales_person.into(),
+                    })
+                })
+                .collect()
+        })
+    }
+
+    fn add_extra_hour(
+        &self,
+        sales_person_id: Uuid,
+        amount: f32,
+        category: ExtraHoursCategoryTO,
+        description: String,
+        date_time: String,
+    ) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store.extra_hours.push(ExtraHoursTO {
+                id: Uuid::new_v4(),
+                sales_person_id,
+                amount,
+                category,
+                description: description.into(),
+                date_time: parse_date_time(&date_time)?,
+                created: Some(now()),
+                deleted: None,
+                version: Uuid::new_v4(),
+            });
+            Ok(())
+        })
+    }
+    fn get_extra_hours_for_year(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        until_week: u8,
+    ) -> BackendFuture<'_, Rc<[ExtraHoursTO]>> {
+        self.query(|store| {
+            Ok(store
+                .extra_hours
+                .iter()
+                .filter(|extra_hours| {
+                    let (extra_year, extra_week) = week_of(extra_hours.date_time.date());
+                    extra_hours.deleted.is_none()
+                        && extra_hours.sales_person_id == sales_person_id
+                        && extra_year == year
+                        && extra_week <= until_week
+                })
+                .cloned()
+                .collect())
+        })
+    }
+    fn delete_extra_hour(&self, extra_hour_id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let extra_hours = store
+                .extra_hours
+                .iter_mut()
+                .find(|extra_hours| extra_hours.id == extra_hour_id)
+                .ok_or_else(|| not_found("extra hours", extra_hour_id))?;
+            extra_hours.deleted = Some(now());
+            Ok(())
+        })
+    }
+    fn update_extra_hour(&self, extra_hours: ExtraHoursTO) -> BackendFuture<'_, ExtraHoursTO> {
+        self.update(|store| {
+            let existing = store
+                .extra_hours
+                .iter_mut()
+                .find(|existing| existing.id == extra_hours.id)
+                .ok_or_else(|| not_found("extra hours", extra_hours.id))?;
+            if existing.version != extra_hours.version {
+                return Err(version_conflict(
+                    ConflictEntity::ExtraHours(extra_hours),
+                    ConflictEntity::ExtraHours(existing.clone()),
+                ));
+            }
+            *existing = ExtraHoursTO {
+                version: Uuid::new_v4(),
+                ..extra_hours
+            };
+            Ok(existing.clone())
+        })
+    }
+    fn add_vacation(
+        &self,
+        sales_person_id: Uuid,
+        from: Date,
+        to: Date,
+        description: ImStr,
+    ) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let mut date = from;
+            while date <= to {
+                let (year, week) = week_of(date);
+                let details = store.work_details_for_week(sales_person_id, year, week);
+                let is_workday = details.map_or(date.weekday() != Weekday::Sunday, |details| {
+                    match date.weekday() {
+                        Weekday::Monday => details.monday,
+                        Weekday::Tuesday => details.tuesday,
+                        Weekday::Wednesday => details.wednesday,
+                        Weekday::Thursday => details.thursday,
+                        Weekday::Friday => details.friday,
+                        Weekday::Saturday => details.saturday,
+                        Weekday::Sunday => details.sunday,
+                    }
+                });
+                if is_workday {
+                    let amount = details.map(|details| details.hours_per_day).unwrap_or(8.0);
+                    store.extra_hours.push(ExtraHoursTO {
+                        id: Uuid::new_v4(),
+                        sales_person_id,
+                        amount,
+                        category: ExtraHoursCategoryTO::Vacation,
+                        description: description.as_str().into(),
+                        date_time: PrimitiveDateTime::new(date, time::Time::MIDNIGHT),
+                        created: Some(now()),
+                        deleted: None,
+                        version: Uuid::new_v4(),
+                    });
+                }
+                date += Duration::days(1);
+            }
+            Ok(())
+        })
+    }
+    fn get_custom_extra_hours_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[CustomExtraHoursTO]>> {
+        self.query(|store| {
+            Ok(store
+                .custom_extra_hours
+                .iter()
+                .filter(|custom| {
+                    custom.deleted.is_none()
+                        && custom.assigned_sales_person_ids.contains(&sales_person_id)
+                })
+                .cloned()
+                .collect())
+        })
+    }
+    fn post_custom_extra_hours(
+        &self,
+        custom_extra_hours: CustomExtraHoursTO,
+    ) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store.custom_extra_hours.push(CustomExtraHoursTO {
+                id: Uuid::new_v4(),
+                created: Some(now()),
+                version: Uuid::new_v4(),
+                ..custom_extra_hours
+            });
+            Ok(())
+        })
+    }
+    fn put_custom_extra_hours(
+        &self,
+        custom_extra_hours: CustomExtraHoursTO,
+    ) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let existing = store
+                .custom_extra_hours
+                .iter_mut()
+                .find(|existing| existing.id == custom_extra_hours.id)
+                .ok_or_else(|| not_found("custom extra hours", custom_extra_hours.id))?;
+            if existing.version != custom_extra_hours.version {
+                return Err(version_conflict(
+                    ConflictEntity::CustomExtraHours(custom_extra_hours),
+                    ConflictEntity::CustomExtraHours(existing.clone()),
+                ));
+            }
+            *existing = CustomExtraHoursTO {
+                created: existing.created,
+                version: Uuid::new_v4(),
+                ..custom_extra_hours
+            };
+            Ok(())
+        })
+    }
+    fn delete_custom_extra_hours(&self, custom_extra_hours_id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let existing = store
+                .custom_extra_hours
+                .iter_mut()
+                .find(|existing| existing.id == custom_extra_hours_id)
+                .ok_or_else(|| not_found("custom extra hours", custom_extra_hours_id))?;
+            existing.deleted = Some(now());
+            Ok(())
+        })
+    }
+
+    fn get_employee_work_details_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[EmployeeWorkDetailsTO]>> {
+        self.query(|store| {
+            Ok(store
+                .work_details
+                .iter()
+                .filter(|details| {
+                    details.deleted.is_none() && details.sales_person_id == sales_person_id
+                })
+                .cloned()
+                .collect())
+        })
+    }
+    fn post_employee_work_details(
+        &self,
+        work_details: EmployeeWorkDetailsTO,
+    ) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store.work_details.push(EmployeeWorkDetailsTO {
+                id: Uuid::new_v4(),
+                created: Some(now()),
+                version: Uuid::new_v4(),
+                ..work_details
+            });
+            Ok(())
+        })
+    }
+    fn put_employee_work_details(
+        &self,
+        work_details: EmployeeWorkDetailsTO,
+    ) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let existing = store
+                .work_details
+                .iter_mut()
+                .find(|existing| existing.id == work_details.id)
+                .ok_or_else(|| not_found("work details", work_details.id))?;
+            if existing.version != work_details.version {
+                return Err(version_conflict(
+                    ConflictEntity::EmployeeWorkDetails(work_details),
+                    ConflictEntity::EmployeeWorkDetails(existing.clone()),
+                ));
+            }
+            *existing = EmployeeWorkDetailsTO {
+                version: Uuid::new_v4(),
+                ..work_details
+            };
+            Ok(())
+        })
+    }
+    fn delete_employee_work_details(&self, work_details_id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let existing = store
+                .work_details
+                .iter_mut()
+                .find(|existing| existing.id == work_details_id)
+                .ok_or_else(|| not_found("work details", work_details_id))?;
+            existing.deleted = Some(now());
+            Ok(())
+        })
+    }
+
+    fn get_all_users(&self) -> BackendFuture<'_, Rc<[UserTO]>> {
+        self.query(|store| {
+            Ok(store
+                .users
+                .iter()
+                .map(|name| UserTO { name: name.clone() })
+                .collect())
+        })
+    }
+    fn get_all_roles(&self) -> BackendFuture<'_, Rc<[RoleTO]>> {
+        self.query(|store| {
+            Ok(store
+                .roles
+                .iter()
+                .map(|name| RoleTO { name: name.clone() })
+                .collect())
+        })
+    }
+    fn get_roles_from_user(&self, user_id: ImStr) -> BackendFuture<'_, Rc<[RoleTO]>> {
+        self.query(|store| {
+            Ok(store
+                .user_roles
+                .iter()
+                .filter(|(user, _)| user.as_str() == user_id.as_str())
+                .map(|(_, role)| RoleTO { name: role.clone() })
+                .collect())
+        })
+    }
+    fn add_role_to_user(&self, user_role: UserRole) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let entry = (user_role.user, user_role.role);
+            if !store.user_roles.contains(&entry) {
+                store.user_roles.push(entry);
+            }
+            Ok(())
+        })
+    }
+    fn remove_role_from_user(&self, user_role: UserRole) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store
+                .user_roles
+                .retain(|(user, role)| *user != user_role.user || *role != user_role.role);
+            Ok(())
+        })
+    }
+    fn add_user(&self, user: UserTO) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            if !store.users.contains(&user.name) {
+                store.users.push(user.name);
+            }
+            Ok(())
+        })
+    }
+    fn delete_user(&self, user_id: ImStr) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store.users.retain(|user| user.as_str() != user_id.as_str());
+            store
+                .user_roles
+                .retain(|(user, _)| user.as_str() != user_id.as_str());
+            Ok(())
+        })
+    }
+
+    fn get_week_message(&self, year: u32, week: u8) -> BackendFuture<'_, Option<WeekMessageTO>> {
+        self.query(|store| {
+            Ok(store
+                .week_messages
+                .iter()
+                .find(|message| {
+                    message.deleted.is_none()
+                        && message.year == year
+                        && message.calendar_week == week
+                })
+                .cloned())
+        })
+    }
+    fn post_week_message(&self, week_message: WeekMessageTO) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store.week_messages.push(WeekMessageTO {
+                id: Uuid::new_v4(),
+                created: Some(now()),
+                version: Uuid::new_v4(),
+                ..week_message
+            });
+            Ok(())
+        })
+    }
+    fn put_week_message(&self, week_message: WeekMessageTO) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let existing = store
+                .week_messages
+                .iter_mut()
+                .find(|existing| existing.id == week_message.id)
+                .ok_or_else(|| not_found("week message", week_message.id))?;
+            if existing.version != week_message.version {
+                return Err(version_conflict(
+                    ConflictEntity::WeekMessage(week_message),
+                    ConflictEntity::WeekMessage(existing.clone()),
+                ));
+            }
+            *existing = WeekMessageTO {
+                version: Uuid::new_v4(),
+                ..week_message
+            };
+            Ok(())
+        })
+    }
+
+    fn get_week_publication(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Option<WeekPublicationTO>> {
+        self.query(|store| Ok(store.week_publication(year, week).cloned()))
+    }
+    fn post_week_publication(
+        &self,
+        week_publication: WeekPublicationTO,
+    ) -> BackendFuture<'_, WeekPublicationTO> {
+        self.update(|store| {
+            if let Some(existing) =
+                store.week_publication(week_publication.year, week_publication.calendar_week)
+            {
+                return Ok(existing.clone());
+            }
+            let created = WeekPublicationTO {
+                id: Uuid::new_v4(),
+                published_at: Some(now()),
+                published_by: Some(store.auth_user.as_str().into()),
+                deleted: None,
+                version: Uuid::new_v4(),
+                ..week_publication
+            };
+            store.week_publications.push(created.clone());
+            Ok(created)
+        })
+    }
+    fn delete_week_publication(&self, id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let publication = store
+                .week_publications
+                .iter_mut()
+                .find(|publication| publication.id == id && publication.deleted.is_none())
+                .ok_or_else(|| not_found("week publication", id))?;
+            publication.deleted = Some(now());
+            publication.version = Uuid::new_v4();
+            Ok(())
+        })
+    }
+
+    fn put_shiftplan_presence(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[ShiftplanViewerTO]>> {
+        self.update(|store| {
+            let now = now();
+            let user = store.auth_user.clone();
+            store
+                .shiftplan_viewers
+                .retain(|viewer| viewer.user != user && now - viewer.last_seen <= PRESENCE_TIMEOUT);
+            let viewers = store
+                .shiftplan_viewers
+                .iter()
+                .filter(|viewer| {
+                    viewer.shiftplan_id == shiftplan_id
+                        && viewer.year == year
+                        && viewer.week == week
+                })
+                .map(|viewer| ShiftplanViewerTO {
+                    user: viewer.user.as_str().into(),
+                    last_seen: viewer.last_seen,
+                })
+                .collect();
+            store.shiftplan_viewers.push(ShiftplanViewer {
+                user,
+                shiftplan_id,
+                year,
+                week,
+                last_seen: now,
+            });
+            Ok(viewers)
+        })
+    }
+
+    fn get_billing_periods(&self) -> BackendFuture<'_, Rc<[BillingPeriodTO]>> {
+        self.query(|store| {
+            Ok(store
+                .billing_periods
+                .iter()
+                .filter(|period| period.deleted_at.is_none())
+                .cloned()
+                .collect())
+        })
+    }
+    fn get_billing_period(&self, billing_period_id: Uuid) -> BackendFuture<'_, BillingPeriodTO> {
+        self.query(|store| {
+            store
+                .billing_periods
+                .iter()
+                .find(|period| period.id == billing_period_id)
+                .cloned()
+                .ok_or_else(|| not_found("billing period", billing_period_id))
+        })
+    }
+    fn post_billing_period(&self, end_date: Date) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let start_date = store
+                .billing_periods
+                .iter()
+                .filter(|period| period.deleted_at.is_none())
+                .map(|period| period.end_date + Duration::days(1))
+                .max()
+                .unwrap_or(Date::from_ordinal_date(end_date.year(), 1)?);
+            let created_at = now();
+            let created_by: Arc<str> = store.auth_user.as_str().into();
+            let (end_year, end_week) = week_of(end_date);
+            let sales_persons = store
+                .sales_persons
+                .iter()
+                .filter(|sales_person| sales_person.deleted.is_none())
+                .map(|sales_person| {
+                    let period = store.hours_between(sales_person, start_date, end_date)?;
+                    let year_to_date = store.hours_until_week(sales_person, end_year, end_week)?;
+                    let value = |delta: f32, ytd: f32| BillingPeriodValueTO {
+                        value_delta: delta,
+                        value_ytd_from: ytd - delta,
+                        value_ytd_to: ytd,
+                        value_full_year: ytd,
+                    };
+                    let values = BTreeMap::from([
+                        (
+                            "balance".to_string(),
+                            value(period.balance(), year_to_date.balance()),
+                        ),
+                        (
+                            "expected_hours".to_string(),
+                            value(period.expected, year_to_date.expected),
+                        ),
+                        (
+                            "overall_hours".to_string(),
+                            value(period.overall(), year_to_date.overall()),
+                        ),
+                        (
+                            "volunteer_hours".to_string(),
+                            value(period.volunteer, year_to_date.volunteer),
+                        ),
+                    ]);
+                    Ok(BillingPeriodSalesPersonTO {
+                        id: Uuid::new_v4(),
+                        sales_person_id: sales_person.id,
+                        values,
+                        created_at,
+                        created_by: created_by.clone(),
+                        deleted_at: None,
+                        deleted_by: None,
+                    })
+                })
+                .collect::<Result<Arc<[_]>, ShiftyError>>()?;
+            store.billing_periods.push(BillingPeriodTO {
+                id: Uuid::new_v4(),
+                start_date,
+                end_date,
+                sales_persons,
+                created_at,
+                created_by,
+                deleted_at: None,
+                deleted_by: None,
+            });
+            Ok(())
+        })
+    }
+    fn delete_billing_period(&self, id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let deleted_by: Arc<str> = store.auth_user.as_str().into();
+            let period = store
+                .billing_periods
+                .iter_mut()
+                .find(|period| period.id == id)
+                .ok_or_else(|| not_found("billing period", id))?;
+            period.deleted_at = Some(now());
+            period.deleted_by = Some(deleted_by);
+            Ok(())
+        })
+    }
+
+    fn get_text_templates(&self) -> BackendFuture<'_, Rc<[TextTemplateTO]>> {
+        self.query(|store| {
+            Ok(store
+                .text_templates
+                .iter()
+                .filter(|template| template.deleted.is_none())
+                .cloned()
+                .collect())
+        })
+    }
+    fn get_text_templates_by_type<'a>(
+        &'a self,
+        template_type: &'a str,
+    ) -> BackendFuture<'a, Rc<[TextTemplateTO]>> {
+        self.query(|store| {
+            Ok(store
+                .text_templates
+                .iter()
+                .filter(|template| {
+                    template.deleted.is_none() && &*template.template_type == template_type
+                })
+                .cloned()
+                .collect())
+        })
+    }
+    fn get_text_template(&self, template_id: Uuid) -> BackendFuture<'_, TextTemplateTO> {
+        self.query(|store| {
+            store
+                .text_templates
+                .iter()
+                .find(|template| template.id == template_id)
+                .cloned()
+                .ok_or_else(|| not_found("text template", template_id))
+        })
+    }
+    fn create_text_template(
+        &self,
+        template: CreateTextTemplateRequestTO,
+    ) -> BackendFuture<'_, TextTemplateTO> {
+        self.update(|store| {
+            let template = TextTemplateTO {
+                id: Uuid::new_v4(),
+                name: template.name,
+                template_type: template.template_type,
+                template_text: template.template_text,
+                template_engine: template.template_engine,
+                created_at: Some(now()),
+                created_by: Some(store.auth_user.as_str().into()),
+                deleted: None,
+                deleted_by: None,
+                version: Uuid::new_v4(),
+            };
+            store.text_templates.push(template.clone());
+            Ok(template)
+        })
+    }
+    fn update_text_template(
+        &self,
+        template_id: Uuid,
+        template: UpdateTextTemplateRequestTO,
+    ) -> BackendFuture<'_, TextTemplateTO> {
+        self.update(|store| {
+            let existing = store
+                .text_templates
+                .iter_mut()
+                .find(|existing| existing.id == template_id)
+                .ok_or_else(|| not_found("text template", template_id))?;
+            if let Some(version) = template
+                .version
+                .filter(|version| *version != existing.version)
+            {
+                let theirs = existing.clone();
+                return Err(version_conflict(
+                    ConflictEntity::TextTemplate(TextTemplateTO {
+                        name: template.name,
+                        template_type: template.template_type,
+                        template_text: template.template_text,
+                        template_engine: template.template_engine,
+                        version,
+                        ..theirs.clone()
+                    }),
+                    ConflictEntity::TextTemplate(theirs),
+                ));
+            }
+            existing.name = template.name;
+            existing.template_type = template.template_type;
+            existing.template_text = template.template_text;
+            existing.template_engine = template.template_engine;
+            existing.version = Uuid::new_v4();
+            Ok(existing.clone())
+        })
+    }
+    fn delete_text_template(&self, template_id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let deleted_by: Arc<str> = store.auth_user.as_str().into();
+            let existing = store
+                .text_templates
+                .iter_mut()
+                .find(|existing| existing.id == template_id)
+                .ok_or_else(|| not_found("text template", template_id))?;
+            existing.deleted = Some(now());
+            existing.deleted_by = Some(deleted_by);
+            Ok(())
+        })
+    }
+    fn generate_custom_report(
+        &self,
+        billing_period_id: Uuid,
+        template_id: Uuid,
+    ) -> BackendFuture<'_, String> {
+        self.query(|store| {
+            let template = store
+                .text_templates
+                .iter()
+                .find(|template| template.id == template_id)
+                .ok_or_else(|| not_found("text template", template_id))?;
+            let period = store
+                .billing_periods
+                .iter()
+                .find(|period| period.id == billing_period_id)
+                .ok_or_else(|| not_found("billing period", billing_period_id))?;
+            let mut report = format!(
+                "{}\n{} - {}\n",
+                template.template_text, period.start_date, period.end_date
+            );
+            for entry in period.sales_persons.iter() {
+                let name = store
+                    .sales_person(entry.sales_person_id)
+                    .map(|sales_person| sales_person.name.to_string())
+                    .unwrap_or_default();
+                let balance = entry
+                    .values
+                    .get("balance")
+                    .map(|value| value.value_delta)
+                    .unwrap_or_default();
+                report.push_str(&format!("{name}: {balance:.2}\n"));
+            }
+            Ok(report)
+        })
+    }
+    fn generate_block_report(&self, template_id: Uuid) -> BackendFuture<'_, String> {
+        self.query(|store| {
+            let template = store
+                .text_templates
+                .iter()
+                .find(|template| template.id == template_id)
+                .ok_or_else(|| not_found("text template", template_id))?;
+            let today = now().date();
+            let mut report = format!("{}\n", template.template_text);
+            for block in store.blocks(today, today + Duration::weeks(2))? {
+                let name = block
+                    .sales_person
+                    .as_ref()
+                    .map(|sales_person| sales_person.name.to_string())
+                    .unwrap_or_default();
+                let date = day_date(monday_of(block.year, block.week)?, block.day_of_week);
+                report.push_str(&format!("{date} {}-{} {name}\n", block.from, block.to));
+            }
+            Ok(report)
+        })
+    }
+
+    fn generate_invitation(
+        &self,
+        request: GenerateInvitationRequest,
+    ) -> BackendFuture<'_, InvitationResponse> {
+        self.update(|store| {
+            let token = Uuid::new_v4();
+            let invitation = InvitationResponse {
+                id: Uuid::new_v4(),
+                username: request.username,
+                token,
+                invitation_link: format!("/auth/invitation/{token}"),
+                status: InvitationStatus::Valid,
+                redeemed_at: None,
+            };
+            store.invitations.push(invitation.clone());
+            Ok(invitation)
+        })
+    }
+    fn list_user_invitations(
+        &self,
+        username: ImStr,
+    ) -> BackendFuture<'_, Rc<[InvitationResponse]>> {
+        self.query(|store| {
+            Ok(store
+                .invitations
+                .iter()
+                .filter(|invitation| invitation.username == username.as_str())
+                .cloned()
+                .collect())
+        })
+    }
+    fn revoke_invitation(&self, invitation_id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            store
+                .invitations
+                .retain(|invitation| invitation.id != invitation_id);
+            Ok(())
+        })
+    }
+    fn revoke_session_for_invitation(&self, invitation_id: Uuid) -> BackendFuture<'_, ()> {
+        self.update(|store| {
+            let invitation = store
+                .invitations
+                .iter_mut()
+                .find(|invitation| invitation.id == invitation_id)
+                .ok_or_else(|| not_found("invitation", invitation_id))?;
+            invitation.status = InvitationStatus::SessionRevoked;
+            Ok(())
+        })
+    }
+
+    fn get_blocks(
+        &self,
+        from_year: u32,
+        from_week: u8,
+        to_year: u32,
+        to_week: u8,
+    ) -> BackendFuture<'_, Rc<[BlockTO]>> {
+        self.query(|store| {
+            let from = monday_of(from_year, from_week)?;
+            let to = monday_of(to_year, to_week)?;
+            Ok(store.blocks(from, to)?.into())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+    use time::macros::{date, time};
+
+    use super::*;
+    use crate::backend::fixtures::{sales_person_id, MAIN_SHIFTPLAN_ID};
+
+    // Wednesday of ISO week 23/2025.
+    const TODAY: Date = date!(2025 - 06 - 04);
+
+    fn backend() -> InMemoryBackend {
+        InMemoryBackend::new(FixtureStore::demo(TODAY))
+    }
+
+    fn first_slot_on(backend: &InMemoryBackend, day_of_week: DayOfWeekTO) -> SlotTO {
+        backend
+            .snapshot()
+            .slots
+            .into_iter()
+            .find(|slot| {
+                slot.day_of_week == day_of_week && slot.shiftplan_id == Some(MAIN_SHIFTPLAN_ID)
+            })
+            .expect("fixture slot")
+    }
+
+    #[test]
+    fn demo_week_contains_slots_and_bookings() {
+        let week = block_on(backend().get_shiftplan_week(MAIN_SHIFTPLAN_ID, 2025, 23)).unwrap();
+        assert_eq!(week.days.len(), 7);
+        let slots: Vec<_> = week.days.iter().flat_map(|day| day.slots.iter()).collect();
+        assert_eq!(slots.len(), 18);
+        assert!(slots.iter().any(|slot| !slot.bookings.is_empty()));
+        let sunday = week
+            .days
+            .iter()
+            .find(|day| day.day_of_week == DayOfWeekTO::Sunday)
+            .unwrap();
+        assert!(sunday.slots.is_empty());
+    }
+
+    #[test]
+    fn add_and_remove_booking_round_trip() {
+        let backend = backend();
+        let slot = first_slot_on(&backend, DayOfWeekTO::Monday);
+        let anna = sales_person_id(0);
+        block_on(backend.add_booking(anna, slot.id, 30, 2025)).unwrap();
+
+        let week = block_on(backend.get_shiftplan_week(MAIN_SHIFTPLAN_ID, 2025, 30)).unwrap();
+        let booking = week
+            .days
+            .iter()
+            .flat_map(|day| day.slots.iter())
+            .flat_map(|slot| slot.bookings.iter())
+            .find(|booking| booking.sales_person.id == anna)
+            .expect("booking is listed");
+        assert_eq!(booking.self_added, Some(true));
+        assert_eq!(booking.booking.created_by.as_deref(), Some("demo"));
+
+        let booking_id = booking.booking.id;
+        block_on(backend.remove_booking(booking_id)).unwrap();
+        assert!(block_on(backend.get_bookings_for_week(30, 2025))
+            .unwrap()
+            .is_empty());
+        let log = block_on(backend.get_booking_log(2025, 30)).unwrap();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].deleted_by.as_deref(), Some("demo"));
+        assert!(block_on(backend.remove_booking(booking_id)).is_err());
+    }
+
+    #[test]
+    fn copy_week_skips_existing_bookings() {
+        let backend = backend();
+        let source = block_on(backend.get_bookings_for_week(22, 2025))
+            .unwrap()
+            .len();
+        block_on(backend.copy_week(22, 2025, 30, 2025)).unwrap();
+        block_on(backend.copy_week(22, 2025, 30, 2025)).unwrap();
+        let copied = block_on(backend.get_bookings_for_week(30, 2025)).unwrap();
+        assert_eq!(copied.len(), source);
+    }
+
+    #[test]
+    fn unavailable_day_produces_conflict() {
+        let backend = backend();
+        let slot = first_slot_on(&backend, DayOfWeekTO::Wednesday);
+        let clara = sales_person_id(2);
+        let before = block_on(backend.get_booking_conflicts_for_week(2025, 23))
+            .unwrap()
+            .len();
+        block_on(backend.add_booking(clara, slot.id, 23, 2025)).unwrap();
+        let conflicts = block_on(backend.get_booking_conflicts_for_week(2025, 23)).unwrap();
+        assert_eq!(conflicts.len(), before + 1);
+        assert!(conflicts
+            .iter()
+            .any(|conflict| conflict.sales_person.id == clara && conflict.slot.id == slot.id));
+    }
+
+    #[test]
+    fn update_slot_in_later_week_splits_the_slot() {
+        let backend = backend();
+        let slot = first_slot_on(&backend, DayOfWeekTO::Monday);
+        block_on(backend.add_booking(sales_person_id(1), slot.id, 30, 2025)).unwrap();
+        block_on(backend.update_slot(
+            SlotTO {
+                min_resources: 4,
+                ..slot.clone()
+            },
+            2025,
+            30,
+        ))
+        .unwrap();
+
+        let old = block_on(backend.get_slot(slot.id)).unwrap();
+        assert_eq!(old.valid_to, Some(date!(2025 - 07 - 20)));
+        let later = block_on(backend.get_slots(2025, 30, MAIN_SHIFTPLAN_ID)).unwrap();
+        let successor = later
+            .iter()
+            .find(|candidate| {
+                candidate.day_of_week == DayOfWeekTO::Monday && candidate.from == slot.from
+            })
+            .unwrap();
+        assert_ne!(successor.id, slot.id);
+        assert_eq!(successor.min_resources, 4);
+        let bookings = block_on(backend.get_bookings_for_week(30, 2025)).unwrap();
+        assert!(bookings
+            .iter()
+            .all(|booking| booking.slot_id == successor.id));
+    }
+
+    #[test]
+    fn post_slot_rejects_overlaps() {
+        let backend = backend();
+        let slot = first_slot_on(&backend, DayOfWeekTO::Monday);
+        let overlapping = SlotTO {
+            id: Uuid::nil(),
+            from: time!(10:00),
+            to: time!(11:00),
+            ..slot.clone()
+        };
+        assert!(!block_on(backend.post_slot(overlapping)).unwrap());
+        let evening = SlotTO {
+            id: Uuid::nil(),
+            from: time!(18:00),
+            to: time!(20:00),
+            ..slot
+        };
+        assert!(block_on(backend.post_slot(evening)).unwrap());
+    }
+
+    #[test]
+    fn working_hours_reflect_bookings() {
+        let backend = backend();
+        let reports = block_on(backend.get_working_hours_for_week(2025, 23)).unwrap();
+        let anna = reports
+            .iter()
+            .find(|report| report.sales_person.id == sales_person_id(0))
+            .unwrap();
+        assert_eq!(anna.expected_hours, 30.0);
+        assert!(anna.overall_hours > 0.0);
+        let clara = reports
+            .iter()
+            .find(|report| report.sales_person.id == sales_person_id(2))
+            .unwrap();
+        assert_eq!(clara.expected_hours, 0.0);
+        assert!(clara.volunteer_hours > 0.0);
+    }
+
+    #[test]
+    fn published_week_only_accepts_shiftplanner_bookings() {
+        let mut store = FixtureStore::demo(TODAY);
+        store
+            .privileges
+            .retain(|privilege| privilege != "shiftplanner");
+        let backend = InMemoryBackend::new(store);
+        let slot = first_slot_on(&backend, DayOfWeekTO::Monday);
+        let anna = sales_person_id(0);
+        let publication = block_on(backend.post_week_publication(WeekPublicationTO {
+            id: Uuid::nil(),
+            year: 2025,
+            calendar_week: 30,
+            published_at: None,
+            published_by: None,
+            deleted: None,
+            version: Uuid::nil(),
+        }))
+        .unwrap();
+        assert_eq!(publication.published_by.as_deref(), Some("demo"));
+        assert!(block_on(backend.add_booking(anna, slot.id, 30, 2025)).is_err());
+        block_on(backend.add_booking(anna, slot.id, 31, 2025)).unwrap();
+
+        block_on(backend.delete_week_publication(publication.id)).unwrap();
+        assert!(block_on(backend.get_week_publication(2025, 30))
+            .unwrap()
+            .is_none());
+        block_on(backend.add_booking(anna, slot.id, 30, 2025)).unwrap();
+    }
+
+    #[test]
+    fn presence_lists_other_recent_viewers_of_the_week() {
+        let mut store = FixtureStore::demo(TODAY);
+        let viewer = |user: &str, last_seen| ShiftplanViewer {
+            user: user.into(),
+            shiftplan_id: MAIN_SHIFTPLAN_ID,
+            year: 2025,
+            week: 23,
+            last_seen,
+        };
+        store.shiftplan_viewers = vec![
+            viewer("ben", now()),
+            viewer("clara", now() - time::Duration::minutes(5)),
+            ShiftplanViewer {
+                week: 24,
+                ..viewer("lea", now())
+            },
+        ];
+        let backend = InMemoryBackend::new(store);
+        let viewers =
+            block_on(backend.put_shiftplan_presence(MAIN_SHIFTPLAN_ID, 2025, 23)).unwrap();
+        let users: Vec<_> = viewers.iter().map(|viewer| viewer.user.as_ref()).collect();
+        assert_eq!(users, vec!["ben"]);
+
+        let viewers =
+            block_on(backend.put_shiftplan_presence(MAIN_SHIFTPLAN_ID, 2025, 24)).unwrap();
+        let users: Vec<_> = viewers.iter().map(|viewer| viewer.user.as_ref()).collect();
+        assert_eq!(users, vec!["lea"]);
+        assert_eq!(backend.snapshot().shiftplan_viewers.len(), 3);
+    }
+
+    #[test]
+    fn update_extra_hour_checks_version() {
+        let backend = backend();
+        let extra_hours = backend.snapshot().extra_hours[0].clone();
+        let updated = block_on(backend.update_extra_hour(ExtraHoursTO {
+            amount: 3.0,
+            ..extra_hours.clone()
+        }))
+        .unwrap();
+        assert_ne!(updated.version, extra_hours.version);
+        match block_on(backend.update_extra_hour(extra_hours.clone())) {
+            Err(ShiftyError::VersionConflict(conflict)) => {
+                let (ConflictEntity::ExtraHours(yours), ConflictEntity::ExtraHours(theirs)) =
+                    (conflict.yours, conflict.theirs)
+                else {
+                    panic!("expected extra hours on both sides");
+                };
+                assert_eq!(yours.version, extra_hours.version);
+                assert_eq!(theirs.version, updated.version);
+                assert_eq!(theirs.amount, 3.0);
+            }
+            other => panic!("expected a version conflict, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! Backend abstraction.
+//!
+//! Every endpoint the frontend talks to is described by [`ShiftyBackend`].
+//! [`HttpBackend`] forwards to the reqwest functions in `crate::api`, while
+//! [`InMemoryBackend`] answers from a [`FixtureStore`] held in memory. Loader
+//! functions and services resolve the backend through [`from_config`], so
+//! pages can be rendered in SSR tests (or run without a server) by installing
+//! an in-memory backend for the current thread. In demo mode the in-memory
+//! store is persisted in `localStorage`, see [`demo`].
+
+mod demo;
+mod fixtures;
+mod http;
+mod in_memory;
+
+use std::{cell::RefCell, rc::Rc};
+
+use futures::future::LocalBoxFuture;
+use rest_types::{
+    BillingPeriodTO, BlockTO, BookingConflictTO, BookingLogTO, BookingTO,
+    CreateTextTemplateRequestTO, CustomExtraHoursTO, DayOfWeekTO, EmployeeReportTO,
+    EmployeeWorkDetailsTO, ExtraHoursCategoryTO, ExtraHoursTO, GenerateInvitationRequest,
+    InvitationResponse, RoleTO, SalesPersonTO, SalesPersonUnavailableTO, ShiftplanDayAggregateTO,
+    ShiftplanTO, ShiftplanViewerTO, ShiftplanWeekTO, ShortEmployeeReportTO, SlotTO, TextTemplateTO,
+    UpdateTextTemplateRequestTO, UserRole, UserTO, WeekMessageTO, WeekPublicationTO,
+    WeeklySummaryTO,
+};
+use uuid::Uuid;
+
+use crate::{
+    base_types::ImStr,
+    error::ShiftyError,
+    state::{AuthInfo, Config, ShiftplanAssignment},
+};
+
+pub use demo::reset_demo_store;
+pub use fixtures::FixtureStore;
+pub use http::HttpBackend;
+pub use in_memory::InMemoryBackend;
+
+pub type BackendFuture<'a, T> = LocalBoxFuture<'a, Result<T, ShiftyError>>;
+
+/// All endpoints of the shifty backend.
+///
+/// Method names and arguments mirror the functions in `crate::api`, minus the
+/// `Config` which is owned by the implementation.
+pub trait ShiftyBackend {
+    // Auth and misc
+    fn fetch_auth_info(&self) -> BackendFuture<'_, Option<AuthInfo>>;
+    fn get_version(&self) -> BackendFuture<'_, Rc<str>>;
+
+    // Shiftplans
+    fn get_all_shiftplans(&self) -> BackendFuture<'_, Rc<[ShiftplanTO]>>;
+    fn create_shiftplan<'a>(
+        &'a self,
+        name: &'a str,
+        is_planning: bool,
+    ) -> BackendFuture<'a, ShiftplanTO>;
+    fn update_shiftplan(&self, shiftplan: ShiftplanTO) -> BackendFuture<'_, ShiftplanTO>;
+    fn delete_shiftplan(&self, id: Uuid) -> BackendFuture<'_, ()>;
+    fn get_shiftplan_week(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, ShiftplanWeekTO>;
+    fn get_shiftplan_day(
+        &self,
+        year: u32,
+        week: u8,
+        day_of_week: DayOfWeekTO,
+    ) -> BackendFuture<'_, ShiftplanDayAggregateTO>;
+
+    // Slots
+    fn get_slots(&self, year: u32, week: u8, shiftplan_id: Uuid)
+        -> BackendFuture<'_, Rc<[SlotTO]>>;
+    fn get_slot(&self, slot_id: Uuid) -> BackendFuture<'_, SlotTO>;
+    fn update_slot(&self, slot: SlotTO, year: u32, week: u8) -> BackendFuture<'_, ()>;
+    /// Returns `false` if the slot overlaps an existing one.
+    fn post_slot(&self, slot: SlotTO) -> BackendFuture<'_, bool>;
+    fn delete_slot_from(&self, slot_id: Uuid, year: u32, week: u8) -> BackendFuture<'_, ()>;
+
+    // Bookings
+    fn get_bookings_for_week(&self, week: u8, year: u32) -> BackendFuture<'_, Rc<[BookingTO]>>;
+    fn add_booking(
+        &self,
+        sales_person_id: Uuid,
+        slot_id: Uuid,
+        week: u8,
+        year: u32,
+    ) -> BackendFuture<'_, ()>;
+    fn remove_booking(&self, booking_id: Uuid) -> BackendFuture<'_, ()>;
+    fn copy_week(
+        &self,
+        from_week: u8,
+        from_year: u32,
+        to_week: u8,
+        to_year: u32,
+    ) -> BackendFuture<'_, ()>;
+    fn get_booking_conflicts_for_week(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[BookingConflictTO]>>;
+    fn get_booking_log(&self, year: u32, week: u8) -> BackendFuture<'_, Rc<[BookingLogTO]>>;
+
+    // Sales persons
+    fn get_sales_persons(&self) -> BackendFuture<'_, Rc<[SalesPersonTO]>>;
+    fn get_current_sales_person(&self) -> BackendFuture<'_, Option<SalesPersonTO>>;
+    fn get_sales_person(&self, sales_person_id: Uuid) -> BackendFuture<'_, SalesPersonTO>;
+    fn put_sales_person(&self, sales_person: SalesPersonTO) -> BackendFuture<'_, ()>;
+    fn post_sales_person(&self, sales_person: SalesPersonTO) -> BackendFuture<'_, SalesPersonTO>;
+    fn get_user_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Option<Rc<str>>>;
+    fn post_user_to_sales_person(
+        &self,
+        sales_person_id: Uuid,
+        user_id: ImStr,
+    ) -> BackendFuture<'_, ()>;
+    fn delete_user_from_sales_person(&self, sales_person_id: Uuid) -> BackendFuture<'_, ()>;
+    fn get_sales_person_by_user(&self, username: ImStr)
+        -> BackendFuture<'_, Option<SalesPersonTO>>;
+    fn get_shiftplan_assignments(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Vec<ShiftplanAssignment>>;
+    fn set_shiftplan_assignments<'a>(
+        &'a self,
+        sales_person_id: Uuid,
+        assignments: &'a [ShiftplanAssignment],
+    ) -> BackendFuture<'a, ()>;
+    fn get_bookable_sales_persons(
+        &self,
+        shiftplan_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[SalesPersonTO]>>;
+
+    // Availability
+    fn get_unavailable_sales_person_days_for_week(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[SalesPersonUnavailableTO]>>;
+    fn create_unavailable_sales_person_day(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+        day_of_week: DayOfWeekTO,
+    ) -> BackendFuture<'_, ()>;
+    fn delete_unavailable_sales_person_day(&self, unavailable_id: Uuid) -> BackendFuture<'_, ()>;
+
+    // Reports
+    fn get_short_reports(
+        &self,
+        year: u32,
+        calendar_week: u8,
+    ) -> BackendFuture<'_, Rc<[ShortEmployeeReportTO]>>;
+    fn get_employee_reports(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        calendar_week: u8,
+    ) -> BackendFuture<'_, Rc<EmployeeReportTO>>;
+    fn get_working_hours_for_week(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[ShortEmployeeReportTO]>>;
+    fn get_balance_until_week(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[ShortEmployeeReportTO]>>;
+    fn get_weekly_overview(&self, year: u32) -> BackendFuture<'_, Rc<[WeeklySummaryTO]>>;
+
+    // Extra hours
+    fn add_extra_hour(
+        &self,
+        sales_person_id: Uuid,
+        amount: f32,
+        category: ExtraHoursCategoryTO,
+        description: String,
+        date_time: String,
+    ) -> BackendFuture<'_, ()>;
+    fn get_extra_hours_for_year(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        until_week: u8,
+    ) -> BackendFuture<'_, Rc<[ExtraHoursTO]>>;
+    fn delete_extra_hour(&self, extra_hour_id: Uuid) -> BackendFuture<'_, ()>;
+    fn update_extra_hour(&self, extra_hours: ExtraHoursTO) -> BackendFuture<'_, ExtraHoursTO>;
+    fn add_vacation(
+        &self,
+        sales_person_id: Uuid,
+        from: time::Date,
+        to: time::Date,
+        description: ImStr,
+    ) -> BackendFuture<'_, ()>;
+    fn get_custom_extra_hours_by_sales_person(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[CustomExtraHoursTO]>>;
+    fn post_custom_extra_hours(
+        &self,
+        custom_extra_hours: CustomExtraHoursTO,
+    ) -> BackendFuture<'_, ()>;
+    fn put_custom_extra_hours(
+        &self,
+        custom_extra_hours: CustomExtraHoursTO,
+    ) -> BackendFuture<'_, ()>;
+    fn delete_custom_extra_hours(&self, custom_extra_hours_id: Uuid) -> BackendFuture<'_, ()>;
+
+    // Work details
+    fn get_employee_work_details_for_sales_person(
+        &self,
+        sales_person_id: Uuid,
+    ) -> BackendFuture<'_, Rc<[EmployeeWorkDetailsTO]>>;
+    fn post_employee_work_details(
+        &self,
+        work_details: EmployeeWorkDetailsTO,
+    ) -> BackendFuture<'_, ()>;
+    fn put_employee_work_details(
+        &self,
+        work_details: EmployeeWorkDetailsTO,
+    ) -> BackendFuture<'_, ()>;
+    fn delete_employee_work_details(&self, work_details_id: Uuid) -> BackendFuture<'_, ()>;
+
+    // Users and roles
+    fn get_all_users(&self) -> BackendFuture<'_, Rc<[UserTO]>>;
+    fn get_all_roles(&self) -> BackendFuture<'_, Rc<[RoleTO]>>;
+    fn get_roles_from_user(&self, user_id: ImStr) -> BackendFuture<'_, Rc<[RoleTO]>>;
+    fn add_role_to_user(&self, user_role: UserRole) -> BackendFuture<'_, ()>;
+    fn remove_role_from_user(&self, user_role: UserRole) -> BackendFuture<'_, ()>;
+    fn add_user(&self, user: UserTO) -> BackendFuture<'_, ()>;
+    fn delete_user(&self, user_id: ImStr) -> BackendFuture<'_, ()>;
+
+    // Week messages
+    fn get_week_message(&self, year: u32, week: u8) -> BackendFuture<'_, Option<WeekMessageTO>>;
+    fn post_week_message(&self, week_message: WeekMessageTO) -> BackendFuture<'_, ()>;
+    fn put_week_message(&self, week_message: WeekMessageTO) -> BackendFuture<'_, ()>;
+
+    // Week publications
+    fn get_week_publication(
+        &self,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Option<WeekPublicationTO>>;
+    fn post_week_publication(
+        &self,
+        week_publication: WeekPublicationTO,
+    ) -> BackendFuture<'_, WeekPublicationTO>;
+    fn delete_week_publication(&self, id: Uuid) -> BackendFuture<'_, ()>;
+
+    // Presence
+    fn put_shiftplan_presence(
+        &self,
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+    ) -> BackendFuture<'_, Rc<[ShiftplanViewerTO]>>;
+
+    // Billing periods
+    fn get_billing_periods(&self) -> BackendFuture<'_, Rc<[BillingPeriodTO]>>;
+    fn get_billing_period(&self, billing_period_id: Uuid) -> BackendFuture<'_, BillingPeriodTO>;
+    fn post_billing_period(&self, end_date: time::Date) -> BackendFuture<'_, ()>;
+    fn delete_billing_period(&self, id: Uuid) -> BackendFuture<'_, ()>;
+
+    // Text templates and reports
+    fn get_text_templates(&self) -> BackendFuture<'_, Rc<[TextTemplateTO]>>;
+    fn get_text_templates_by_type<'a>(
+        &'a self,
+        template_type: &'a str,
+    ) -> BackendFuture<'a, Rc<[TextTemplateTO]>>;
+    fn get_text_template(&self, template_id: Uuid) -> BackendFuture<'_, TextTemplateTO>;
+    fn create_text_template(
+        &self,
+        template: CreateTextTemplateRequestTO,
+    ) -> BackendFuture<'_, TextTemplateTO>;
+    fn update_text_template(
+        &self,
+        template_id: Uuid,
+        template: UpdateTextTemplateRequestTO,
+    ) -> BackendFuture<'_, TextTemplateTO>;
+    fn delete_text_template(&self, template_id: Uuid) -> BackendFuture<'_, ()>;
+    fn generate_custom_report(
+        &self,
+        billing_period_id: Uuid,
+        template_id: Uuid,
+    ) -> BackendFuture<'_, String>;
+    fn generate_block_report(&self, template_id: Uuid) -> BackendFuture<'_, String>;
+
+    // Invitations
+    fn generate_invitation(
+        &self,
+        request: GenerateInvitationRequest,
+    ) -> BackendFuture<'_, InvitationResponse>;
+    fn list_user_invitations(&self, username: ImStr)
+        -> BackendFuture<'_, Rc<[InvitationResponse]>>;
+    fn revoke_invitation(&self, invitation_id: Uuid) -> BackendFuture<'_, ()>;
+    fn revoke_session_for_invitation(&self, invitation_id: Uuid) -> BackendFuture<'_, ()>;
+
+    // Blocks
+    fn get_blocks(
+        &self,
+        from_year: u32,
+        from_week: u8,
+        to_year: u32,
+        to_week: u8,
+    ) -> BackendFuture<'_, Rc<[BlockTO]>>;
+}
+
+thread_local! {
+    static INSTALLED_BACKEND: RefCell<Option<Rc<dyn ShiftyBackend>>> = const { RefCell::new(None) };
+}
+
+/// Routes all [`from_config`] lookups on the current thread to `backend`,
+/// or back to HTTP when `None` is passed. Cached loader results of the
+/// previous backend are dropped.
+pub fn install(backend: Option<Rc<dyn ShiftyBackend>>) {
+    INSTALLED_BACKEND.with(|installed| *installed.borrow_mut() = backend);
+    crate::loader::cache::clear();
+}
+
+/// Backend to use for the given config.
+pub fn from_config(config: &Config) -> Rc<dyn ShiftyBackend> {
+    if let Some(backend) = INSTALLED_BACKEND.with(|installed| installed.borrow().clone()) {
+        return backend;
+    }
+    if config.demo {
+        return Rc::new(demo::demo_backend());
+    }
+    Rc::new(HttpBackend::new(config.clone()))
+}
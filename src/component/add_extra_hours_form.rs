@@ -6,8 +6,8 @@ use tracing::info;
 use uuid::Uuid;
 
 use crate::{
-    api,
-    error::{result_handler, ShiftyError},
+    backend,
+    error::result_handler,
     i18n::Key,
     js,
     service::{config::CONFIG, i18n::I18N},
@@ -72,11 +72,9 @@ pub fn AddExtraHoursForm(props: AddExtraHoursFormProps) -> Element {
                 match action {
                     AddExtraHoursFormAction::LoadCustomExtraHours => {
                         info!("AddExtraHoursForm: Executing LoadCustomExtraHours action for sales_person_id: {}", sales_person_id);
-                        match api::get_custom_extra_hours_by_sales_person(
-                            config.clone(),
-                            sales_person_id,
-                        )
-                        .await
+                        match backend::from_config(&config)
+                            .get_custom_extra_hours_by_sales_person(sales_person_id)
+                            .await
                         {
                             Ok(hours) => {
                                 info!(
@@ -111,27 +109,21 @@ pub fn AddExtraHoursForm(props: AddExtraHoursFormProps) -> Element {
                             let to = time::Date::parse(&*to.read(), &format)
                                 .unwrap_or(date!(1970 - 01 - 01));
                             result_handler(
-                                api::add_vacation(
-                                    config.to_owned(),
-                                    sales_person_id,
-                                    from,
-                                    to,
-                                    description.into(),
-                                )
-                                .await
-                                .map_err(ShiftyError::from),
+                                backend::from_config(&config)
+                                    .add_vacation(sales_person_id, from, to, description.into())
+                                    .await,
                             );
                         } else {
                             result_handler(
-                                api::add_extra_hour(
-                                    config.to_owned(),
-                                    sales_person_id,
-                                    amount,
-                                    (&category).into(),
-                                    description,
-                                    when,
-                                )
-                                .await,
+                                backend::from_config(&config)
+                                    .add_extra_hour(
+                                        sales_person_id,
+                                        amount,
+                                        (&category).into(),
+                                        description,
+                                        when,
+                                    )
+                                    .await,
                             );
                         }
 
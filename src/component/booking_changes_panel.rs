@@ -0,0 +1,129 @@
+//! Bookings of the shown week added or removed after a chosen moment,
+//! grouped by person and day, with a plain-text copy for the team.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use time::PrimitiveDateTime;
+
+use crate::{
+    base_types::ImStr,
+    component::{
+        atoms::{Btn, BtnVariant},
+        form::{Field, TextInput},
+    },
+    i18n::Key,
+    js,
+    service::i18n::I18N,
+    state::{
+        booking_changes::{
+            change_sign, format_datetime_local, parse_datetime_local, BookingChangeKind,
+            BookingChangeReport,
+        },
+        booking_log::BookingLog,
+        week::Week,
+    },
+};
+
+pub(crate) fn change_class(kind: BookingChangeKind) -> &'static str {
+    match kind {
+        BookingChangeKind::Added => "px-1.5 rounded-sm text-small font-mono bg-good-soft text-good",
+        BookingChangeKind::Removed => "px-1.5 rounded-sm text-small font-mono bg-bad-soft text-bad",
+    }
+}
+
+#[derive(PartialEq, Clone, Props)]
+pub struct BookingChangesPanelProps {
+    pub bookings: Rc<[BookingLog]>,
+    pub week: Week,
+    /// Cut-off shown first, usually when the week was published.
+    pub default_since: PrimitiveDateTime,
+}
+
+#[component]
+pub fn BookingChangesPanel(props: BookingChangesPanelProps) -> Element {
+    let i18n = I18N.read().clone();
+    let default_since = props.default_since;
+    let mut since = use_signal(|| format_datetime_local(default_since));
+    let mut copy_status = use_signal(|| None::<ImStr>);
+
+    let report = parse_datetime_local(&since.read())
+        .map(|since| BookingChangeReport::build(&props.bookings, props.week, since));
+    let text = report.as_ref().map(|report| report.to_text(&i18n));
+    let copied: ImStr = i18n.t(Key::BookingChangesCopied).as_ref().into();
+
+    rsx! {
+        div { class: "bg-surface border border-border rounded-lg p-4",
+            div { class: "flex flex-wrap items-end gap-3 mb-3",
+                h2 { class: "text-h2 font-semibold text-ink flex-1", "{i18n.t(Key::BookingChangesTitle)}" }
+                Field { label: ImStr::from(i18n.t(Key::BookingChangesSince).as_ref()),
+                    TextInput {
+                        value: ImStr::from(since.read().as_str()),
+                        input_type: ImStr::from("datetime-local"),
+                        on_change: move |value: ImStr| {
+                            since.set(value.to_string());
+                            copy_status.set(None);
+                        },
+                    }
+                }
+                Btn {
+                    variant: BtnVariant::Secondary,
+                    disabled: text.is_none(),
+                    on_click: move |_| {
+                        let Some(text) = text.clone() else {
+                            return;
+                        };
+                        let copied = copied.clone();
+                        spawn(async move {
+                            match js::copy_to_clipboard(&text).await {
+                                Ok(_) => copy_status.set(Some(copied)),
+                                Err(err) => copy_status.set(Some(ImStr::from(format!("{err:?}")))),
+                            }
+                        });
+                    },
+                    "{i18n.t(Key::BookingChangesCopy)}"
+                }
+            }
+            if let Some(status) = copy_status.read().as_ref() {
+                div { class: "mb-2 text-small text-ink-muted", "{status}" }
+            }
+            if let Some(report) = report.as_ref() {
+                if report.is_empty() {
+                    div { class: "text-ink-muted italic", "{i18n.t(Key::BookingChangesNone)}" }
+                } else {
+                    div { class: "mb-2 text-small text-ink-muted",
+                        {
+                            i18n.t_m(
+                                Key::BookingChangesSummary,
+                                [
+                                    ("added", report.count(BookingChangeKind::Added).to_string().as_str()),
+                                    ("removed", report.count(BookingChangeKind::Removed).to_string().as_str()),
+                                ]
+                                .into(),
+                            )
+                        }
+                    }
+                    ul { class: "divide-y divide-border border border-border rounded-md",
+                        for person in report.persons.iter() {
+                            li { class: "px-3 py-2",
+                                div { class: "font-semibold text-ink", "{person.sales_person_name}" }
+                                for (day, changes) in person.days.iter() {
+                                    div { class: "flex flex-wrap items-center gap-2 mt-1 text-small",
+                                        span { class: "w-28 text-ink-muted", "{day.i18n_string(&i18n)}" }
+                                        for change in changes.iter() {
+                                            span {
+                                                class: change_class(change.kind),
+                                                title: change.by.as_deref().unwrap_or_default(),
+                                                "{change_sign(change.kind)} {change.time_from.hour():02}:{change.time_from.minute():02}–{change.time_to.hour():02}:{change.time_to.minute():02}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,294 @@
+//! Undo/redo controls of the shift plan page.
+//!
+//! Renders the undo and redo buttons plus a popover listing the recorded
+//! [`BookingEdit`]s, and installs the Ctrl+Z / Ctrl+Shift+Z (Cmd on macOS)
+//! keyboard shortcuts for as long as the component is mounted.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::{
+    i18n::{I18n, Key, Locale},
+    service::i18n::I18N,
+    state::booking_history::{BookingEdit, BookingEditKind, BookingHistory},
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HistoryShortcut {
+    Undo,
+    Redo,
+}
+
+/// Pure helper mapping a keydown to an undo/redo shortcut. `Ctrl+Y` is
+/// accepted as redo as well since Windows users expect it.
+pub(crate) fn history_shortcut(
+    key: &str,
+    ctrl_or_meta: bool,
+    shift: bool,
+) -> Option<HistoryShortcut> {
+    if !ctrl_or_meta {
+        return None;
+    }
+    match (key, shift) {
+        ("z" | "Z", false) => Some(HistoryShortcut::Undo),
+        ("z" | "Z", true) | ("y" | "Y", false) => Some(HistoryShortcut::Redo),
+        _ => None,
+    }
+}
+
+/// Text fields keep their own undo behaviour.
+pub(crate) fn is_text_input_tag(tag_name: &str) -> bool {
+    matches!(
+        tag_name.to_ascii_uppercase().as_str(),
+        "INPUT" | "TEXTAREA" | "SELECT"
+    )
+}
+
+pub(crate) fn edit_kind_label(i18n: &I18n<Key, Locale>, kind: BookingEditKind) -> Rc<str> {
+    match kind {
+        BookingEditKind::Add => i18n.t(Key::ShiftplanHistoryAdded),
+        BookingEditKind::Remove => i18n.t(Key::ShiftplanHistoryRemoved),
+        BookingEditKind::Move => i18n.t(Key::ShiftplanHistoryMoved),
+        BookingEditKind::CopyWeek => i18n.t(Key::ShiftplanHistoryCopiedWeek),
+        BookingEditKind::AutoFill => i18n.t(Key::ShiftplanHistoryAutoFilled),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+struct HistoryShortcutGuard {
+    closure: wasm_bindgen::closure::Closure<dyn FnMut(web_sys::KeyboardEvent)>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for HistoryShortcutGuard {
+    fn drop(&mut self) {
+        use wasm_bindgen::JsCast;
+        if let Some(window) = web_sys::window() {
+            let _ = window.remove_event_listener_with_callback(
+                "keydown",
+                self.closure.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn install_history_shortcuts(
+    on_shortcut: EventHandler<HistoryShortcut>,
+) -> Option<Rc<HistoryShortcutGuard>> {
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+
+    let window = web_sys::window()?;
+    let closure: Closure<dyn FnMut(web_sys::KeyboardEvent)> =
+        Closure::wrap(Box::new(move |event: web_sys::KeyboardEvent| {
+            let in_text_field = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+                .map(|element| {
+                    is_text_input_tag(&element.tag_name()) || element.is_content_editable()
+                })
+                .unwrap_or(false);
+            if in_text_field {
+                return;
+            }
+            if let Some(shortcut) = history_shortcut(
+                &event.key(),
+                event.ctrl_key() || event.meta_key(),
+                event.shift_key(),
+            ) {
+                event.prevent_default();
+                on_shortcut.call(shortcut);
+            }
+        }));
+    let _ = window.add_event_listener_with_callback("keydown", closure.as_ref().unchecked_ref());
+    Some(Rc::new(HistoryShortcutGuard { closure }))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn install_history_shortcuts(_on_shortcut: EventHandler<HistoryShortcut>) -> Option<Rc<()>> {
+    None
+}
+
+#[derive(PartialEq, Clone, Props)]
+pub struct BookingHistoryPopoverProps {
+    pub history: BookingHistory,
+    pub on_undo: EventHandler<()>,
+    pub on_redo: EventHandler<()>,
+}
+
+#[component]
+pub fn BookingHistoryPopover(props: BookingHistoryPopoverProps) -> Element {
+    let i18n = I18N.read().clone();
+    let mut open = use_signal(|| false);
+    let on_undo = props.on_undo;
+    let on_redo = props.on_redo;
+    use_hook(move || {
+        install_history_shortcuts(EventHandler::new(move |shortcut| match shortcut {
+            HistoryShortcut::Undo => on_undo.call(()),
+            HistoryShortcut::Redo => on_redo.call(()),
+        }))
+    });
+
+    let can_undo = props.history.next_undo().is_some();
+    let can_redo = props.history.next_redo().is_some();
+    let undo_entries: Vec<BookingEdit> = props.history.undo_entries().cloned().collect();
+    let redo_entries: Vec<BookingEdit> = props.history.redo_entries().cloned().collect();
+    let button_class = "w-7 h-7 inline-flex items-center justify-center border border-border-strong rounded-md text-ink-soft bg-surface hover:bg-surface-alt disabled:opacity-40 disabled:cursor-not-allowed";
+    let undo_title = format!("{} (Ctrl+Z)", i18n.t(Key::ShiftplanUndo));
+    let redo_title = format!("{} (Ctrl+Shift+Z)", i18n.t(Key::ShiftplanRedo));
+
+    rsx! {
+        span { class: "relative inline-flex items-center gap-1",
+            button {
+                r#type: "button",
+                class: button_class,
+                disabled: !can_undo,
+                title: "{undo_title}",
+                "aria-label": "{undo_title}",
+                onclick: move |_| on_undo.call(()),
+                "↶"
+            }
+            button {
+                r#type: "button",
+                class: button_class,
+                disabled: !can_redo,
+                title: "{redo_title}",
+                "aria-label": "{redo_title}",
+                onclick: move |_| on_redo.call(()),
+                "↷"
+            }
+            button {
+                r#type: "button",
+                class: "px-2 py-1 rounded-md text-small text-ink-soft hover:bg-surface-alt",
+                "aria-expanded": *open.read(),
+                onclick: move |_| {
+                    let was_open = *open.read();
+                    open.set(!was_open);
+                },
+                "{i18n.t(Key::ShiftplanHistory)}"
+            }
+            if *open.read() {
+                div { class: "absolute top-9 left-0 z-50 w-72 max-h-80 overflow-y-auto bg-surface text-ink border border-border rounded-md shadow-lg p-2 flex flex-col gap-1",
+                    if undo_entries.is_empty() && redo_entries.is_empty() {
+                        div { class: "text-small text-ink-muted p-1", "{i18n.t(Key::ShiftplanHistoryEmpty)}" }
+                    }
+                    for edit in redo_entries.iter().rev() {
+                        HistoryRow { edit: edit.clone(), undone: true }
+                    }
+                    for edit in undo_entries.iter() {
+                        HistoryRow { edit: edit.clone(), undone: false }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Props)]
+struct HistoryRowProps {
+    edit: BookingEdit,
+    undone: bool,
+}
+
+#[component]
+fn HistoryRow(props: HistoryRowProps) -> Element {
+    let i18n = I18N.read().clone();
+    let kind = edit_kind_label(&i18n, props.edit.kind);
+    rsx! {
+        div {
+            class: if props.undone { "text-small p-1 rounded-sm text-ink-muted line-through" } else { "text-small p-1 rounded-sm" },
+            span { class: "font-semibold mr-1", "{kind}" }
+            "{props.edit.summary}"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::booking_history::BookingKey;
+    use uuid::Uuid;
+
+    #[test]
+    fn ctrl_z_undoes() {
+        assert_eq!(
+            history_shortcut("z", true, false),
+            Some(HistoryShortcut::Undo)
+        );
+    }
+
+    #[test]
+    fn ctrl_shift_z_and_ctrl_y_redo() {
+        assert_eq!(
+            history_shortcut("Z", true, true),
+            Some(HistoryShortcut::Redo)
+        );
+        assert_eq!(
+            history_shortcut("y", true, false),
+            Some(HistoryShortcut::Redo)
+        );
+    }
+
+    #[test]
+    fn plain_keys_are_ignored() {
+        assert_eq!(history_shortcut("z", false, false), None);
+        assert_eq!(history_shortcut("x", true, false), None);
+    }
+
+    #[test]
+    fn text_fields_keep_their_own_undo() {
+        assert!(is_text_input_tag("TEXTAREA"));
+        assert!(is_text_input_tag("input"));
+        assert!(!is_text_input_tag("DIV"));
+    }
+
+    fn render(comp: fn() -> Element) -> String {
+        let mut vdom = VirtualDom::new(comp);
+        vdom.rebuild_in_place();
+        dioxus_ssr::render(&vdom)
+    }
+
+    #[test]
+    fn buttons_are_disabled_without_history() {
+        fn app() -> Element {
+            rsx! {
+                BookingHistoryPopover {
+                    history: BookingHistory::default(),
+                    on_undo: |_| {},
+                    on_redo: |_| {},
+                }
+            }
+        }
+        let html = render(app);
+        assert_eq!(html.matches("disabled=true").count(), 2, "{html}");
+    }
+
+    #[test]
+    fn buttons_are_enabled_with_undo_and_redo_entries() {
+        fn app() -> Element {
+            let mut history = BookingHistory::default();
+            for sales_person in 1..=2 {
+                history.record(BookingEdit {
+                    kind: BookingEditKind::Add,
+                    summary: "Anna".into(),
+                    added: [BookingKey {
+                        sales_person_id: Uuid::from_u128(sales_person),
+                        slot_id: Uuid::nil(),
+                        week: 23,
+                        year: 2025,
+                    }]
+                    .into(),
+                    removed: [].into(),
+                });
+            }
+            history.undone();
+            rsx! {
+                BookingHistoryPopover { history, on_undo: |_| {}, on_redo: |_| {} }
+            }
+        }
+        let html = render(app);
+        assert!(!html.contains("disabled=true"), "{html}");
+    }
+}
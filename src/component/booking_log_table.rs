@@ -306,6 +306,8 @@ mod tests {
                 time!(10:00),
             )),
             deleted_by: Some("admin".into()),
+            slot_id: None,
+            sales_person_id: None,
         }
     }
 
@@ -321,6 +323,8 @@ mod tests {
             created_by: Some("admin".into()),
             deleted: None,
             deleted_by: None,
+            slot_id: None,
+            sales_person_id: None,
         }
     }
 
@@ -336,6 +340,8 @@ mod tests {
             created_by: None,
             deleted: None,
             deleted_by: None,
+            slot_id: None,
+            sales_person_id: None,
         }
     }
 
@@ -0,0 +1,110 @@
+//! Popover of a booked person chip: who booked it, when, whether the person
+//! added it themselves, and the history of the slot in that week.
+
+use dioxus::prelude::*;
+
+use crate::{
+    component::booking_changes_panel::change_class,
+    i18n::Key,
+    loader,
+    service::{config::CONFIG, i18n::I18N},
+    state::{
+        booking_changes::change_sign,
+        booking_log::slot_history,
+        shiftplan::{Booking, Slot},
+    },
+};
+
+fn format_time(time: time::Time) -> String {
+    format!("{:02}:{:02}", time.hour(), time.minute())
+}
+
+#[derive(PartialEq, Clone, Props)]
+pub struct BookingProvenancePopoverProps {
+    pub booking: Booking,
+    pub slot: Slot,
+    /// Viewport position of the click which opened the popover.
+    pub x: f64,
+    pub y: f64,
+    pub on_close: EventHandler<()>,
+}
+
+#[component]
+pub fn BookingProvenancePopover(props: BookingProvenancePopoverProps) -> Element {
+    let i18n = I18N.read().clone();
+    let year = props.booking.year;
+    let week = props.booking.week;
+    let log = use_resource(move || loader::load_booking_log(CONFIG.read().clone(), year, week));
+    let on_close = props.on_close;
+
+    let booked_by = match (&props.booking.created, &props.booking.created_by) {
+        (Some(created), Some(created_by)) => i18n.t_m(
+            Key::BookingProvenanceBookedBy,
+            [
+                ("user", created_by.as_ref()),
+                ("date", i18n.format_date(&created.date()).as_ref()),
+                ("time", format_time(created.time()).as_str()),
+            ]
+            .into(),
+        ),
+        _ => i18n.t(Key::BookingNoInfo),
+    };
+    let history = match &*log.read_unchecked() {
+        Some(Ok(log)) => Some(Ok(slot_history(log, &props.slot))),
+        Some(Err(err)) => Some(Err(err.to_string())),
+        None => None,
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-40",
+            onclick: move |evt| {
+                evt.stop_propagation();
+                on_close.call(());
+            },
+        }
+        div {
+            class: "fixed z-50 w-72 max-h-80 overflow-y-auto bg-surface text-ink border border-border rounded-md shadow-lg p-3 flex flex-col gap-2 text-small",
+            style: format!("left: {}px; top: {}px;", props.x, props.y + 12.0),
+            onclick: move |evt| evt.stop_propagation(),
+            div { class: "font-semibold", "{props.booking.label}" }
+            div { "{booked_by}" }
+            div { class: "text-ink-muted",
+                if props.booking.self_added {
+                    "{i18n.t(Key::BookingProvenanceSelfAdded)}"
+                } else {
+                    "{i18n.t(Key::BookingProvenanceAddedByOther)}"
+                }
+            }
+            div { class: "pt-2 border-t border-border font-semibold",
+                "{i18n.t(Key::BookingProvenanceSlotHistory)}"
+            }
+            match history {
+                None => rsx! {
+                    div { class: "text-ink-muted", "{i18n.t(Key::BookingLogLoading)}" }
+                },
+                Some(Err(err)) => rsx! {
+                    div { class: "text-bad", "{err}" }
+                },
+                Some(Ok(history)) if history.is_empty() => rsx! {
+                    div { class: "text-ink-muted italic", "{i18n.t(Key::BookingProvenanceNoHistory)}" }
+                },
+                Some(Ok(history)) => rsx! {
+                    ul { class: "flex flex-col gap-1",
+                        for entry in history.iter() {
+                            li { class: "flex items-center gap-2",
+                                span { class: change_class(entry.kind), "{change_sign(entry.kind)}" }
+                                span { class: "flex-1", "{entry.sales_person_name}" }
+                                span {
+                                    class: "text-ink-muted",
+                                    title: entry.by.as_deref().unwrap_or_default(),
+                                    "{i18n.format_date(&entry.at.date())} {format_time(entry.at.time())}"
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! Compact staffing overview of several weeks of one shift plan.
+//!
+//! Every row is a week, every cell a day tinted by its [`CoverageLevel`].
+//! Clicking a row hands the week to `on_select_week` so the page can open it
+//! in the regular week grid.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::{
+    i18n::Key,
+    service::i18n::I18N,
+    state::{
+        coverage::{CoverageLevel, DayCoverage, WeekCoverage},
+        week::Week,
+        Weekday,
+    },
+};
+
+fn level_class(level: CoverageLevel) -> &'static str {
+    match level {
+        CoverageLevel::NoSlots => "bg-surface-alt text-ink-faint",
+        CoverageLevel::Covered => "bg-good-soft text-good",
+        CoverageLevel::Partial => "bg-warn-soft text-warn",
+        CoverageLevel::Uncovered => "bg-bad-soft text-bad",
+    }
+}
+
+fn level_label_key(level: CoverageLevel) -> Key {
+    match level {
+        CoverageLevel::NoSlots => Key::CoverageNoSlots,
+        CoverageLevel::Covered => Key::CoverageCovered,
+        CoverageLevel::Partial => Key::CoveragePartial,
+        CoverageLevel::Uncovered => Key::CoverageUncovered,
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CoverageHeatmapProps {
+    pub weeks: Rc<[WeekCoverage]>,
+    /// Week currently selected in the shift plan; its row is highlighted.
+    pub current_week: Week,
+    pub on_select_week: EventHandler<Week>,
+}
+
+#[component]
+pub fn CoverageHeatmap(props: CoverageHeatmapProps) -> Element {
+    let i18n = I18N.read().clone();
+    let show_sunday = props.weeks.iter().any(WeekCoverage::has_sunday_slots);
+    let days: Vec<Weekday> = (0..if show_sunday { 7 } else { 6 })
+        .map(Weekday::from_num_from_monday)
+        .collect();
+    let grid_style = format!(
+        "display: grid; grid-template-columns: 96px repeat({}, minmax(64px, 1fr));",
+        days.len()
+    );
+    let on_select_week = props.on_select_week;
+
+    rsx! {
+        div { class: "bg-surface border border-border rounded-lg overflow-auto",
+            div { style: "{grid_style}",
+                div { class: "bg-surface-alt border-b border-border" }
+                for day in days.iter() {
+                    div { class: "bg-surface-alt border-b border-border px-2 py-1.5 text-small font-semibold text-ink-soft text-center",
+                        "{day.i18n_short_string(&i18n)}"
+                    }
+                }
+                for week_coverage in props.weeks.iter() {
+                    {
+                        let week = week_coverage.week;
+                        let is_current = week == props.current_week;
+                        let row_cells: Vec<DayCoverage> = week_coverage
+                            .days
+                            .iter()
+                            .take(days.len())
+                            .cloned()
+                            .collect();
+                        let week_label = i18n.t_m(
+                            Key::CoverageWeekLabel,
+                            [
+                                ("week", week.week.to_string().as_str()),
+                                ("year", week.year.to_string().as_str()),
+                            ]
+                            .into(),
+                        );
+                        rsx! {
+                            button {
+                                class: if is_current {
+                                    "border-b border-border px-2 py-1.5 text-left text-small font-semibold text-accent bg-accent-soft"
+                                } else {
+                                    "border-b border-border px-2 py-1.5 text-left text-small font-semibold text-ink hover:bg-surface-alt"
+                                },
+                                onclick: move |_| on_select_week.call(week),
+                                "{week_label}"
+                            }
+                            for day in row_cells {
+                                {
+                                    let title = i18n.t_m(
+                                        Key::CoverageDayTitle,
+                                        [
+                                            ("understaffed", day.understaffed.to_string().as_str()),
+                                            ("slots", day.slots.to_string().as_str()),
+                                        ]
+                                        .into(),
+                                    );
+                                    rsx! {
+                                        button {
+                                            class: "border-b border-l border-border px-1 py-1.5 flex flex-col items-center gap-0.5 hover:brightness-95 {level_class(day.level())}",
+                                            title: "{title}",
+                                            onclick: move |_| on_select_week.call(week),
+                                            span { class: "text-micro font-mono text-ink-muted",
+                                                "{day.date.day():02}.{u8::from(day.date.month()):02}"
+                                            }
+                                            if day.slots > 0 {
+                                                span { class: "text-small font-mono font-semibold",
+                                                    "{day.filled}/{day.required}"
+                                                }
+                                            } else {
+                                                span { class: "text-small", "–" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        div { class: "mt-2 flex flex-wrap items-center gap-3 text-small text-ink-muted",
+            for level in [
+                CoverageLevel::Covered,
+                CoverageLevel::Partial,
+                CoverageLevel::Uncovered,
+                CoverageLevel::NoSlots,
+            ] {
+                span { class: "inline-flex items-center gap-1.5",
+                    span { class: "inline-block w-3 h-3 rounded-sm border border-border {level_class(level)}" }
+                    "{i18n.t(level_label_key(level))}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{shiftplan::Booking, Shiftplan, Slot};
+    use uuid::Uuid;
+
+    fn coverage(week: u8, booked: usize) -> WeekCoverage {
+        let slot_id = Uuid::from_u128(u128::from(week));
+        let shiftplan = Shiftplan {
+            week,
+            year: 2025,
+            slots: [Slot {
+                id: slot_id,
+                day_of_week: Weekday::Monday,
+                from: time::Time::from_hms(9, 0, 0).unwrap(),
+                to: time::Time::from_hms(12, 0, 0).unwrap(),
+                min_resources: 2,
+                bookings: (0..booked)
+                    .map(|n| Booking {
+                        id: Uuid::from_u128(n as u128),
+                        sales_person_id: Uuid::from_u128(n as u128),
+                        slot_id,
+                        week,
+                        year: 2025,
+                        label: "".into(),
+                        background_color: "".into(),
+                        self_added: false,
+                        created: None,
+                        created_by: None,
+                    })
+                    .collect(),
+            }]
+            .into(),
+        };
+        WeekCoverage::from_shiftplan(&shiftplan).unwrap()
+    }
+
+    fn render(comp: fn() -> Element) -> String {
+        let mut vdom = VirtualDom::new(comp);
+        vdom.rebuild_in_place();
+        dioxus_ssr::render(&vdom)
+    }
+
+    #[test]
+    fn heatmap_tints_days_by_coverage() {
+        fn app() -> Element {
+            rsx! {
+                CoverageHeatmap {
+                    weeks: Rc::from([coverage(23, 2), coverage(24, 0)]),
+                    current_week: Week { year: 2025, week: 23 },
+                    on_select_week: |_| {},
+                }
+            }
+        }
+        let html = render(app);
+        assert!(html.contains("2/2"));
+        assert!(html.contains("0/2"));
+        assert!(html.contains("bg-good-soft text-good"));
+        assert!(html.contains("bg-bad-soft text-bad"));
+        // Monday through Saturday plus the week column; no Sunday slots.
+        assert!(html.contains("repeat(6,"));
+    }
+}
@@ -20,6 +20,9 @@ pub struct DayAggregateViewProps {
 
     #[props(default = false)]
     pub is_shiftplanner: bool,
+    /// The week is published; only shift planners get add and remove.
+    #[props(default = false)]
+    pub week_published: bool,
 }
 
 #[component]
@@ -124,6 +127,7 @@ pub fn DayAggregateView(props: DayAggregateViewProps) -> Element {
                                         button_types: props.button_types.clone(),
                                         dropdown_entries: props.dropdown_entries.clone(),
                                         is_shiftplanner: props.is_shiftplanner,
+                                        week_published: props.week_published,
                                     }
                                 }
                             }
@@ -233,6 +237,57 @@ mod tests {
         }
     }
 
+    fn render(comp: fn() -> Element) -> String {
+        let mut vdom = VirtualDom::new(comp);
+        vdom.rebuild_in_place();
+        dioxus_ssr::render(&vdom)
+    }
+
+    #[test]
+    fn published_week_hides_add_for_viewers() {
+        fn viewer() -> Element {
+            rsx! {
+                DayAggregateView {
+                    day_aggregate: make_day_aggregate(vec![make_slot(Weekday::Monday)]),
+                    highlight_item_id: Some(uuid::Uuid::from_u128(1)),
+                    add_event: None,
+                    remove_event: None,
+                    item_clicked: None,
+                    button_types: WeekViewButtonTypes::AddRemove,
+                    dropdown_entries: None,
+                    is_shiftplanner: false,
+                    week_published: true,
+                }
+            }
+        }
+        fn planner() -> Element {
+            rsx! {
+                DayAggregateView {
+                    day_aggregate: make_day_aggregate(vec![make_slot(Weekday::Monday)]),
+                    highlight_item_id: Some(uuid::Uuid::from_u128(1)),
+                    add_event: None,
+                    remove_event: None,
+                    item_clicked: None,
+                    button_types: WeekViewButtonTypes::AddRemove,
+                    dropdown_entries: None,
+                    is_shiftplanner: true,
+                    week_published: true,
+                }
+            }
+        }
+
+        let html = render(viewer);
+        assert!(
+            !html.contains(">+</button>"),
+            "viewer got an add button: {html}"
+        );
+        let html = render(planner);
+        assert!(
+            html.contains(">+</button>"),
+            "planner lost the add button: {html}"
+        );
+    }
+
     // --- next_day tests ---
 
     #[test]
@@ -1,11 +1,16 @@
 use dioxus::prelude::*;
 
-use crate::service::error::ERROR_STORE;
+use crate::{error::ShiftyError, service::error::ERROR_STORE};
 
 #[component]
 pub fn ErrorView() -> Element {
     let error = ERROR_STORE.read();
-    if let Some(ref error) = error.error {
+    // Version conflicts are shown by `VersionConflictBase`.
+    if let Some(ref error) = error
+        .error
+        .as_ref()
+        .filter(|error| !matches!(error, ShiftyError::VersionConflict(_)))
+    {
         rsx! {
             div {
                 class: "error-view",
@@ -17,12 +17,12 @@ use futures_util::StreamExt;
 use time::macros::{date, format_description};
 use uuid::Uuid;
 
-use crate::api;
+use crate::backend;
 use crate::base_types::ImStr;
 use crate::component::atoms::{Btn, BtnVariant};
 use crate::component::form::{Field, SelectInput, TextInput};
 use crate::component::{Dialog, DialogVariant};
-use crate::error::{result_handler, ShiftyError};
+use crate::error::result_handler;
 use crate::i18n::Key;
 use crate::js;
 use crate::service::{
@@ -160,9 +160,9 @@ pub fn ExtraHoursModal(props: ExtraHoursModalProps) -> Element {
                 match action {
                     ExtraHoursModalAction::LoadCustomExtraHours => {
                         let config = CONFIG.read().clone();
-                        if let Ok(hours) =
-                            api::get_custom_extra_hours_by_sales_person(config, sales_person_id)
-                                .await
+                        if let Ok(hours) = backend::from_config(&config)
+                            .get_custom_extra_hours_by_sales_person(sales_person_id)
+                            .await
                         {
                             let definitions: Rc<[CustomExtraHoursDefinition]> =
                                 hours.iter().map(|h| h.into()).collect();
@@ -197,29 +197,28 @@ pub fn ExtraHoursModal(props: ExtraHoursModalProps) -> Element {
                             let to_date = time::Date::parse(&*to.read(), &date_format)
                                 .unwrap_or(date!(1970 - 01 - 01));
                             result_handler(
-                                api::add_vacation(
-                                    config.to_owned(),
-                                    sales_person_id,
-                                    from_date,
-                                    to_date,
-                                    description_value.into(),
-                                )
-                                .await
-                                .map_err(ShiftyError::from),
+                                backend::from_config(&config)
+                                    .add_vacation(
+                                        sales_person_id,
+                                        from_date,
+                                        to_date,
+                                        description_value.into(),
+                                    )
+                                    .await,
                             );
                             let _ = amount_value;
                             on_saved.call(());
                         } else {
                             result_handler(
-                                api::add_extra_hour(
-                                    config,
-                                    sales_person_id,
-                                    amount_value,
-                                    (&category_value).into(),
-                                    description_value,
-                                    when_value,
-                                )
-                                .await,
+                                backend::from_config(&config)
+                                    .add_extra_hour(
+                                        sales_person_id,
+                                        amount_value,
+                                        (&category_value).into(),
+                                        description_value,
+                                        when_value,
+                                    )
+                                    .await,
                             );
                             on_saved.call(());
                         }
@@ -1,12 +1,15 @@
 use dioxus::prelude::*;
 
-use crate::{api, service::config::CONFIG};
+use crate::{backend, service::config::CONFIG};
 
 #[component]
 pub fn Footer() -> Element {
     let version = env!("CARGO_PKG_VERSION");
     let config = CONFIG.read().clone();
-    let version_resource = use_resource(move || api::get_version(config.clone()));
+    let version_resource = use_resource(move || {
+        let backend = backend::from_config(&config);
+        async move { backend.get_version().await }
+    });
     rsx! {
         footer {
             class: "text-small text-ink-muted p-2 flex flex-row",
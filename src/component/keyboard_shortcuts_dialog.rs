@@ -0,0 +1,52 @@
+//! Cheat sheet for the keyboard shortcuts of the week grid.
+
+use dioxus::prelude::*;
+
+use crate::{
+    base_types::ImStr,
+    component::dialog::{Dialog, DialogVariant},
+    i18n::Key,
+    service::i18n::I18N,
+};
+
+/// Shortcut keys and the text describing them, in display order.
+pub(crate) const SHORTCUTS: [(&str, Key); 8] = [
+    ("← → ↑ ↓", Key::ShortcutMove),
+    ("Enter", Key::ShortcutPick),
+    ("1 – 9", Key::ShortcutRecent),
+    ("Delete", Key::ShortcutRemove),
+    ("Page ↑ / Page ↓", Key::ShortcutWeek),
+    ("Ctrl+Z", Key::ShortcutUndo),
+    ("Ctrl+Shift+Z", Key::ShortcutRedo),
+    ("?", Key::ShortcutHelp),
+];
+
+#[derive(Props, Clone, PartialEq)]
+pub struct KeyboardShortcutsDialogProps {
+    pub on_close: EventHandler<()>,
+}
+
+#[component]
+pub fn KeyboardShortcutsDialog(props: KeyboardShortcutsDialogProps) -> Element {
+    let i18n = I18N.read().clone();
+    let on_close = props.on_close;
+    rsx! {
+        Dialog {
+            open: true,
+            on_close: move |_| on_close.call(()),
+            title: ImStr::from(i18n.t(Key::ShortcutsTitle).as_ref()),
+            subtitle: Some(ImStr::from(i18n.t(Key::ShortcutsHint).as_ref())),
+            variant: DialogVariant::Auto,
+            dl { class: "grid grid-cols-[auto_1fr] gap-x-4 gap-y-2 text-body",
+                for (keys , description) in SHORTCUTS {
+                    dt {
+                        kbd { class: "px-1.5 py-0.5 rounded-sm border border-border-strong bg-surface-alt font-mono text-small text-ink",
+                            "{keys}"
+                        }
+                    }
+                    dd { class: "text-ink-soft", "{i18n.t(description)}" }
+                }
+            }
+        }
+    }
+}
@@ -3,8 +3,12 @@ pub mod add_extra_hours_choice;
 pub mod add_extra_hours_form;
 pub mod atoms;
 pub mod base_components;
+pub mod booking_changes_panel;
+pub mod booking_history_popover;
 pub mod booking_log_table;
+pub mod booking_provenance_popover;
 pub mod contract_modal;
+pub mod coverage_heatmap;
 pub mod day_aggregate_view;
 pub mod dialog;
 pub mod dropdown_base;
@@ -18,12 +22,20 @@ pub mod error_view;
 pub mod extra_hours_modal;
 pub mod footer;
 pub mod form;
+pub mod keyboard_shortcuts_dialog;
+pub mod offline_queue_panel;
 pub mod overlay;
+pub mod person_picker_dialog;
+pub mod shiftplan_draft_dialog;
 pub mod shiftplan_tab_bar;
 pub mod slot_edit;
+pub mod slot_structure_dialog;
+pub mod time_travel_bar;
 pub mod tooltip;
 pub mod top_bar;
 pub mod user_management_tab_bar;
+pub mod version_conflict_dialog;
+pub mod week_copy_dialog;
 pub mod week_view;
 pub mod weekly_overview_chart;
 pub mod working_hours_mini_overview;
@@ -0,0 +1,133 @@
+//! Strip above the week grid listing changes which wait in the offline
+//! queue, with the reason for every change the backend rejected.
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    component::atoms::{Btn, BtnVariant},
+    i18n::Key,
+    service::i18n::I18N,
+    state::offline_queue::OfflineQueue,
+};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct OfflineQueuePanelProps {
+    pub queue: OfflineQueue,
+    #[props(default = false)]
+    pub replaying: bool,
+    pub on_retry: EventHandler<()>,
+    pub on_discard: EventHandler<Uuid>,
+}
+
+#[component]
+pub fn OfflineQueuePanel(props: OfflineQueuePanelProps) -> Element {
+    if props.queue.items.is_empty() {
+        return rsx! {};
+    }
+    let i18n = I18N.read().clone();
+    let count = props.queue.items.len().to_string();
+    let title = i18n.t_m(Key::OfflineQueuePending, [("count", count.as_str())].into());
+    let panel_class = if props.queue.failed_count() > 0 {
+        "mb-3 flex flex-col gap-1 px-3 py-2 rounded-md border border-bad bg-bad-soft text-small print:hidden"
+    } else {
+        "mb-3 flex flex-col gap-1 px-3 py-2 rounded-md border border-warn bg-warn-soft text-small print:hidden"
+    };
+    let on_retry = props.on_retry;
+    let on_discard = props.on_discard;
+
+    rsx! {
+        div { class: panel_class,
+            div { class: "flex items-center gap-2",
+                span { class: "font-semibold text-ink", "{title}" }
+                span { class: "flex-1" }
+                if props.replaying {
+                    span { class: "text-ink-muted", {i18n.t(Key::OfflineQueueSending)} }
+                }
+                Btn {
+                    variant: BtnVariant::Secondary,
+                    disabled: props.replaying,
+                    on_click: move |_| on_retry.call(()),
+                    {i18n.t(Key::OfflineQueueSendNow)}
+                }
+            }
+            ul { class: "flex flex-col gap-0.5",
+                for item in props.queue.items.iter() {
+                    {
+                        let id = item.id;
+                        let failure = item.failure.as_ref().map(|reason| {
+                            i18n.t_m(Key::OfflineQueueRejected, [("reason", reason.as_ref())].into())
+                        });
+                        rsx! {
+                            li { key: "{id}", class: "flex items-center gap-2",
+                                span { class: "text-ink", "{item.summary}" }
+                                if let Some(failure) = failure {
+                                    span { class: "text-bad", "{failure}" }
+                                }
+                                span { class: "flex-1" }
+                                Btn {
+                                    variant: BtnVariant::Ghost,
+                                    on_click: move |_| on_discard.call(id),
+                                    {i18n.t(Key::OfflineQueueDiscard)}
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{booking_history::BookingKey, offline_queue::QueuedMutation};
+
+    fn render(comp: fn() -> Element) -> String {
+        let mut vdom = VirtualDom::new(comp);
+        vdom.rebuild_in_place();
+        dioxus_ssr::render(&vdom)
+    }
+
+    #[test]
+    fn lists_items_with_the_rejection_reason() {
+        fn app() -> Element {
+            let mut queue = OfflineQueue::default();
+            let key = BookingKey {
+                sales_person_id: Uuid::from_u128(1),
+                slot_id: Uuid::from_u128(2),
+                week: 23,
+                year: 2025,
+            };
+            queue.push(QueuedMutation::AddBooking(key), "Anna · Monday".into());
+            queue.push(
+                QueuedMutation::AddBooking(BookingKey { week: 24, ..key }),
+                "Ben · Tuesday".into(),
+            );
+            queue.items[1].failure = Some("slot is full".into());
+            rsx! {
+                OfflineQueuePanel { queue, on_retry: |_| {}, on_discard: |_| {} }
+            }
+        }
+        let html = render(app);
+        assert!(html.contains("2 changes not sent yet"), "{html}");
+        assert!(html.contains("Anna · Monday") && html.contains("Ben · Tuesday"));
+        assert!(html.contains("Rejected: slot is full"), "{html}");
+        assert!(html.contains("border-bad"), "{html}");
+    }
+
+    #[test]
+    fn empty_queue_renders_nothing() {
+        fn app() -> Element {
+            rsx! {
+                OfflineQueuePanel {
+                    queue: OfflineQueue::default(),
+                    on_retry: |_| {},
+                    on_discard: |_| {},
+                }
+            }
+        }
+        assert_eq!(render(app), "");
+    }
+}
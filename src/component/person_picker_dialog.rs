@@ -0,0 +1,171 @@
+//! Dialog for booking a person into a slot from the keyboard.
+//!
+//! Recently used persons are listed first with their digit shortcut. Typing
+//! filters the list and Enter books the first match.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    base_types::ImStr,
+    component::{
+        atoms::PersonChip,
+        dialog::{Dialog, DialogVariant},
+    },
+    i18n::Key,
+    service::i18n::I18N,
+    state::shiftplan::SalesPerson,
+};
+
+/// Active persons matching `query`, recently used ones first, leaving out
+/// everyone in `exclude`.
+pub(crate) fn filter_persons(
+    sales_persons: &[SalesPerson],
+    recent: &[Uuid],
+    exclude: &[Uuid],
+    query: &str,
+) -> Vec<SalesPerson> {
+    let query = query.trim().to_lowercase();
+    let mut persons: Vec<SalesPerson> = sales_persons
+        .iter()
+        .filter(|sales_person| !sales_person.inactive && !exclude.contains(&sales_person.id))
+        .filter(|sales_person| sales_person.name.to_lowercase().contains(&query))
+        .cloned()
+        .collect();
+    persons.sort_by_key(|sales_person| {
+        (
+            recent
+                .iter()
+                .position(|id| *id == sales_person.id)
+                .unwrap_or(usize::MAX),
+            sales_person.name.clone(),
+        )
+    });
+    persons
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PersonPickerDialogProps {
+    pub sales_persons: Rc<[SalesPerson]>,
+    pub recent: Rc<[Uuid]>,
+    /// Persons already booked into the slot.
+    pub exclude: Rc<[Uuid]>,
+    pub on_pick: EventHandler<Uuid>,
+    pub on_close: EventHandler<()>,
+}
+
+#[component]
+pub fn PersonPickerDialog(props: PersonPickerDialogProps) -> Element {
+    let i18n = I18N.read().clone();
+    let mut query = use_signal(String::new);
+    let persons = filter_persons(
+        &props.sales_persons,
+        &props.recent,
+        &props.exclude,
+        &query.read(),
+    );
+    let first = persons.first().map(|sales_person| sales_person.id);
+    let on_pick = props.on_pick;
+    let on_close = props.on_close;
+
+    rsx! {
+        Dialog {
+            open: true,
+            on_close: move |_| on_close.call(()),
+            title: ImStr::from(i18n.t(Key::PersonPickerTitle).as_ref()),
+            variant: DialogVariant::Auto,
+            input {
+                class: "h-[34px] px-[10px] border border-border-strong rounded-md bg-surface text-ink text-body w-full min-w-0 form-input",
+                r#type: "text",
+                autofocus: true,
+                placeholder: "{i18n.t(Key::PersonPickerFilter)}",
+                value: "{query}",
+                oninput: move |event| query.set(event.data.value()),
+                onkeydown: move |event: KeyboardEvent| {
+                    if event.key() == dioxus::prelude::Key::Enter {
+                        if let Some(id) = first {
+                            on_pick.call(id);
+                        }
+                    }
+                },
+            }
+            ul { class: "mt-3 flex flex-col gap-1 max-h-[320px] overflow-y-auto",
+                if persons.is_empty() {
+                    li { class: "text-small text-ink-muted italic", "{i18n.t(Key::PersonPickerEmpty)}" }
+                }
+                for sales_person in persons.iter() {
+                    {
+                        let id = sales_person.id;
+                        let shortcut = props
+                            .recent
+                            .iter()
+                            .position(|recent| *recent == id)
+                            .map(|index| (index + 1).to_string());
+                        rsx! {
+                            li {
+                                button {
+                                    class: "w-full flex items-center gap-2 px-2 py-1 rounded-md text-left hover:bg-surface-alt",
+                                    onclick: move |_| on_pick.call(id),
+                                    span { class: "w-4 font-mono text-small text-ink-muted",
+                                        {shortcut.unwrap_or_default()}
+                                    }
+                                    PersonChip {
+                                        name: ImStr::from(sales_person.name.as_ref()),
+                                        color: Some(ImStr::from(sales_person.background_color.as_ref())),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn person(id: u128, name: &str, inactive: bool) -> SalesPerson {
+        SalesPerson {
+            id: Uuid::from_u128(id),
+            name: name.into(),
+            background_color: "#dbe0ff".into(),
+            is_paid: true,
+            inactive,
+            version: Uuid::nil(),
+        }
+    }
+
+    #[test]
+    fn recent_persons_come_first() {
+        let persons = [
+            person(1, "Anna", false),
+            person(2, "Ben", false),
+            person(3, "Clara", false),
+        ];
+        let names: Vec<Rc<str>> = filter_persons(&persons, &[Uuid::from_u128(3)], &[], "")
+            .into_iter()
+            .map(|sales_person| sales_person.name)
+            .collect();
+        assert_eq!(names, vec!["Clara".into(), "Anna".into(), "Ben".into()]);
+    }
+
+    #[test]
+    fn filter_skips_inactive_excluded_and_non_matching_persons() {
+        let persons = [
+            person(1, "Anna", false),
+            person(2, "Hanna", true),
+            person(3, "Annika", false),
+            person(4, "Ben", false),
+        ];
+        let ids: Vec<u128> = filter_persons(&persons, &[], &[Uuid::from_u128(3)], "ANN")
+            .into_iter()
+            .map(|sales_person| sales_person.id.as_u128())
+            .collect();
+        assert_eq!(ids, vec![1]);
+    }
+}
@@ -0,0 +1,272 @@
+//! Dialogs of the draft workflow: cloning a live shiftplan into a planning
+//! shiftplan and publishing the draft's bookings back for a week range.
+
+use dioxus::prelude::*;
+use rest_types::ShiftplanTO;
+
+use crate::{
+    base_types::ImStr,
+    component::{
+        atoms::{Btn, BtnVariant},
+        dialog::{Dialog, DialogVariant},
+        form::{Field, TextInput},
+    },
+    i18n::Key,
+    loader,
+    service::{config::CONFIG, i18n::I18N, slot_edit::trigger_shiftplan_refresh},
+    state::{
+        shiftplan_draft::{DraftPublishReport, ShiftplanDraft},
+        week::Week,
+    },
+};
+
+/// Parses a week range from two `YYYY-Www` inputs, `None` if a week is
+/// invalid or the range is reversed.
+pub(crate) fn parse_week_range(from: &str, until: &str) -> Option<(Week, Week)> {
+    let from = Week::parse_iso(from)?;
+    let until = Week::parse_iso(until)?;
+    (from <= until).then_some((from, until))
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CreateDraftDialogProps {
+    /// The live shiftplan to clone.
+    pub live: ShiftplanTO,
+    pub current_week: Week,
+    pub on_close: EventHandler<()>,
+    pub on_created: EventHandler<ShiftplanDraft>,
+}
+
+#[component]
+pub fn CreateDraftDialog(props: CreateDraftDialogProps) -> Element {
+    let i18n = I18N.read().clone();
+    let current_week = props.current_week;
+    let default_name = i18n
+        .t_m(
+            Key::DraftNameDefault,
+            [("name", props.live.name.as_ref())].into(),
+        )
+        .to_string();
+    let mut name = use_signal(|| default_name);
+    let mut from = use_signal(|| current_week.to_iso_string());
+    let mut until = use_signal(|| current_week.to_iso_string());
+    let mut saving = use_signal(|| false);
+    let mut error = use_signal(|| None::<ImStr>);
+
+    let range = parse_week_range(&from.read(), &until.read());
+    let live_id = props.live.id;
+    let on_close = props.on_close;
+    let on_close_footer = props.on_close;
+    let on_created = props.on_created;
+
+    rsx! {
+        Dialog {
+            open: true,
+            on_close: move |_| on_close.call(()),
+            title: ImStr::from(i18n.t(Key::DraftCreateTitle).as_ref()),
+            variant: DialogVariant::Auto,
+            width: 480,
+            footer: Some(rsx! {
+                Btn {
+                    variant: BtnVariant::Secondary,
+                    on_click: move |_| on_close_footer.call(()),
+                    "{i18n.t(Key::Cancel)}"
+                }
+                Btn {
+                    variant: BtnVariant::Primary,
+                    disabled: range.is_none() || name.read().trim().is_empty() || *saving.read(),
+                    on_click: move |_| {
+                        let Some((from, until)) = range else {
+                            return;
+                        };
+                        let name = name.read().trim().to_string();
+                        saving.set(true);
+                        error.set(None);
+                        spawn(async move {
+                            match loader::create_shiftplan_draft(
+                                CONFIG.read().clone(),
+                                live_id,
+                                &name,
+                                from,
+                                until,
+                            )
+                            .await
+                            {
+                                Ok(draft) => on_created.call(draft),
+                                Err(err) => error.set(Some(ImStr::from(err.to_string()))),
+                            }
+                            saving.set(false);
+                        });
+                    },
+                    "{i18n.t(Key::DraftCreate)}"
+                }
+            }),
+            p { class: "mb-3 text-small text-ink-muted", "{i18n.t(Key::DraftCreateHint)}" }
+            div { class: "grid grid-cols-2 gap-3 max-md:grid-cols-1",
+                Field { label: ImStr::from(i18n.t(Key::DraftName).as_ref()), span: Some(2),
+                    TextInput {
+                        value: ImStr::from(name.read().as_str()),
+                        on_change: move |value: ImStr| name.set(value.to_string()),
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::DraftFromWeek).as_ref()),
+                    TextInput {
+                        value: ImStr::from(from.read().as_str()),
+                        input_type: ImStr::from("week"),
+                        on_change: move |value: ImStr| from.set(value.to_string()),
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::DraftUntilWeek).as_ref()),
+                    TextInput {
+                        value: ImStr::from(until.read().as_str()),
+                        input_type: ImStr::from("week"),
+                        on_change: move |value: ImStr| until.set(value.to_string()),
+                    }
+                }
+            }
+            if range.is_none() {
+                div { class: "mt-3 text-small text-bad", "{i18n.t(Key::DraftInvalidRange)}" }
+            }
+            if let Some(error) = error.read().as_ref() {
+                div { class: "mt-3 text-small text-bad", "{error}" }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PublishDraftDialogProps {
+    pub draft: ShiftplanDraft,
+    pub current_week: Week,
+    pub on_close: EventHandler<()>,
+}
+
+#[component]
+pub fn PublishDraftDialog(props: PublishDraftDialogProps) -> Element {
+    let i18n = I18N.read().clone();
+    let current_week = props.current_week;
+    let mut from = use_signal(|| current_week.to_iso_string());
+    let mut until = use_signal(|| current_week.to_iso_string());
+    let mut publishing = use_signal(|| false);
+    let mut report = use_signal(|| None::<DraftPublishReport>);
+    let mut error = use_signal(|| None::<ImStr>);
+
+    let range = parse_week_range(&from.read(), &until.read());
+    let draft = props.draft;
+    let on_close = props.on_close;
+    let on_close_footer = props.on_close;
+
+    rsx! {
+        Dialog {
+            open: true,
+            on_close: move |_| on_close.call(()),
+            title: ImStr::from(i18n.t(Key::DraftPublishTitle).as_ref()),
+            variant: DialogVariant::Auto,
+            width: 480,
+            footer: Some(rsx! {
+                Btn {
+                    variant: BtnVariant::Secondary,
+                    on_click: move |_| on_close_footer.call(()),
+                    "{i18n.t(Key::Cancel)}"
+                }
+                Btn {
+                    variant: BtnVariant::Primary,
+                    disabled: range.is_none() || *publishing.read(),
+                    on_click: move |_| {
+                        let Some((from, until)) = range else {
+                            return;
+                        };
+                        publishing.set(true);
+                        error.set(None);
+                        report.set(None);
+                        spawn(async move {
+                            match loader::publish_shiftplan_draft(
+                                CONFIG.read().clone(),
+                                draft,
+                                from,
+                                until,
+                            )
+                            .await
+                            {
+                                Ok(result) => report.set(Some(result)),
+                                Err(err) => error.set(Some(ImStr::from(err.to_string()))),
+                            }
+                            publishing.set(false);
+                            trigger_shiftplan_refresh();
+                        });
+                    },
+                    "{i18n.t(Key::DraftPublish)}"
+                }
+            }),
+            p { class: "mb-3 text-small text-ink-muted", "{i18n.t(Key::DraftPublishHint)}" }
+            div { class: "grid grid-cols-2 gap-3 max-md:grid-cols-1",
+                Field { label: ImStr::from(i18n.t(Key::DraftFromWeek).as_ref()),
+                    TextInput {
+                        value: ImStr::from(from.read().as_str()),
+                        input_type: ImStr::from("week"),
+                        on_change: move |value: ImStr| {
+                            from.set(value.to_string());
+                            report.set(None);
+                        },
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::DraftUntilWeek).as_ref()),
+                    TextInput {
+                        value: ImStr::from(until.read().as_str()),
+                        input_type: ImStr::from("week"),
+                        on_change: move |value: ImStr| {
+                            until.set(value.to_string());
+                            report.set(None);
+                        },
+                    }
+                }
+            }
+            if range.is_none() {
+                div { class: "mt-3 text-small text-bad", "{i18n.t(Key::DraftInvalidRange)}" }
+            }
+            if let Some(error) = error.read().as_ref() {
+                div { class: "mt-3 text-small text-bad", "{error}" }
+            }
+            if let Some(report) = report.read().as_ref() {
+                div { class: "mt-3 px-3 py-2 rounded-md bg-good-soft text-small text-ink",
+                    {
+                        i18n.t_m(
+                            Key::DraftPublishReport,
+                            [
+                                ("weeks", report.weeks.to_string().as_str()),
+                                ("added", report.added.to_string().as_str()),
+                                ("removed", report.removed.to_string().as_str()),
+                                ("skipped", report.skipped_slots.to_string().as_str()),
+                            ]
+                            .into(),
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn week_range_must_be_ordered() {
+        assert_eq!(
+            parse_week_range("2025-W22", "2025-W25"),
+            Some((
+                Week {
+                    year: 2025,
+                    week: 22
+                },
+                Week {
+                    year: 2025,
+                    week: 25
+                },
+            ))
+        );
+        assert_eq!(parse_week_range("2025-W25", "2025-W22"), None);
+        assert_eq!(parse_week_range("", "2025-W22"), None);
+    }
+}
@@ -4,11 +4,12 @@ use dioxus::prelude::*;
 use rest_types::ShiftplanTO;
 use uuid::Uuid;
 
-use crate::api;
+use crate::backend;
 use crate::base_types::ImStr;
 use crate::component::atoms::btn::{Btn, BtnVariant};
 use crate::component::dialog::{Dialog, DialogVariant};
 use crate::component::form::{Field, FormCheckbox, TextInput};
+use crate::error::result_handler;
 use crate::i18n::Key;
 use crate::service::i18n::I18N;
 use crate::state::Config;
@@ -145,7 +146,7 @@ pub fn ShiftplanTabBar(props: ShiftplanTabBarProps) -> Element {
                             spawn(async move {
                                 match mode {
                                     ShiftplanDialogMode::Create => {
-                                        if let Ok(created) = api::create_shiftplan(config, &name, is_planning).await {
+                                        if let Ok(created) = backend::from_config(&config).create_shiftplan(&name, is_planning).await {
                                             on_catalog_changed.call(Some(created.id));
                                         }
                                     }
@@ -153,7 +154,9 @@ pub fn ShiftplanTabBar(props: ShiftplanTabBarProps) -> Element {
                                         original.name = name.into();
                                         original.is_planning = is_planning;
                                         let id = original.id;
-                                        let _ = api::update_shiftplan(config, original).await;
+                                        result_handler(
+                                            backend::from_config(&config).update_shiftplan(original).await,
+                                        );
                                         on_catalog_changed.call(Some(id));
                                     }
                                     ShiftplanDialogMode::Hidden => {}
@@ -220,7 +223,7 @@ pub fn ShiftplanTabBar(props: ShiftplanTabBarProps) -> Element {
                             let on_catalog_changed = on_catalog_changed;
                             delete_confirm_id.set(None);
                             spawn(async move {
-                                let _ = api::delete_shiftplan(config, delete_id).await;
+                                let _ = backend::from_config(&config).delete_shiftplan(delete_id).await;
                                 on_catalog_changed.call(None);
                             });
                         },
@@ -0,0 +1,485 @@
+//! Bulk editor for the slot structure of one shiftplan.
+//!
+//! The planner selects slots of the loaded week, shifts their times, changes
+//! `min_resources` or removes them, and copies a weekday's layout onto other
+//! weekdays or into another shiftplan. Nothing is written until the draft is
+//! saved; all changes then take effect in the same `valid_from` week.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use rest_types::ShiftplanTO;
+use uuid::Uuid;
+
+use crate::{
+    base_types::ImStr,
+    component::{
+        atoms::{Btn, BtnVariant},
+        dialog::{Dialog, DialogVariant},
+        form::{Field, FormCheckbox, SelectInput, TextInput},
+    },
+    i18n::{I18n, Key, Locale},
+    loader,
+    service::{config::CONFIG, i18n::I18N, slot_edit::trigger_shiftplan_refresh},
+    state::{
+        slot_edit::SlotEditItem,
+        slot_structure::{DraftSlot, SlotStructureChange, SlotStructureDraft, SlotStructureReport},
+        week::Week,
+        Weekday,
+    },
+};
+
+/// Time shifts offered in the toolbar, in minutes.
+const SHIFT_STEPS: [(i64, &str); 4] = [
+    (-60, "−1 h"),
+    (-15, "−15 min"),
+    (15, "+15 min"),
+    (60, "+1 h"),
+];
+
+/// What a change did, in the words of the slot badges.
+pub(crate) fn change_label(i18n: &I18n<Key, Locale>, change: &SlotStructureChange) -> Rc<str> {
+    match change {
+        SlotStructureChange::Create(_) => i18n.t(Key::SlotStructureNew),
+        SlotStructureChange::Update(_) => i18n.t(Key::SlotStructureChanged),
+        SlotStructureChange::Delete(_) => i18n.t(Key::SlotStructureRemoved),
+    }
+}
+
+pub(crate) fn slot_badge(
+    i18n: &I18n<Key, Locale>,
+    draft: &SlotStructureDraft,
+    slot: &DraftSlot,
+) -> Option<(Rc<str>, &'static str)> {
+    if slot.removed {
+        Some((
+            i18n.t(Key::SlotStructureRemoved),
+            "px-1.5 rounded-sm text-micro bg-bad-soft text-bad",
+        ))
+    } else if slot.is_new() {
+        Some((
+            i18n.t(Key::SlotStructureNew),
+            "px-1.5 rounded-sm text-micro bg-good-soft text-good",
+        ))
+    } else if draft.is_changed(slot) {
+        Some((
+            i18n.t(Key::SlotStructureChanged),
+            "px-1.5 rounded-sm text-micro bg-warn-soft text-warn",
+        ))
+    } else {
+        None
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SlotStructureDialogProps {
+    /// Week whose slots are loaded; also the default `valid_from`.
+    pub current_week: Week,
+    pub shiftplan_id: Uuid,
+    pub shiftplans: Rc<[ShiftplanTO]>,
+    pub on_close: EventHandler<()>,
+}
+
+#[component]
+pub fn SlotStructureDialog(props: SlotStructureDialogProps) -> Element {
+    let i18n = I18N.read().clone();
+    let current_week = props.current_week;
+    let shiftplan_id = props.shiftplan_id;
+    let mut draft = use_signal(|| None::<SlotStructureDraft>);
+    let mut valid_from = use_signal(|| current_week.to_iso_string());
+    let mut min_resources = use_signal(|| "1".to_string());
+    let mut source_day = use_signal(|| Weekday::Monday);
+    let mut target_days = use_signal(Vec::<Weekday>::new);
+    let mut target_shiftplan = use_signal(|| None::<Uuid>);
+    let mut replace = use_signal(|| true);
+    let mut saving = use_signal(|| false);
+    let mut message = use_signal(|| None::<ImStr>);
+    let mut report = use_signal(|| None::<SlotStructureReport>);
+
+    let load = move || {
+        spawn(async move {
+            match loader::load_slot_structure(
+                CONFIG.read().clone(),
+                current_week.year,
+                current_week.week,
+                shiftplan_id,
+            )
+            .await
+            {
+                Ok(slots) => draft.set(Some(SlotStructureDraft::new(slots))),
+                Err(err) => message.set(Some(ImStr::from(err.to_string()))),
+            }
+        });
+    };
+    use_hook(load);
+
+    let valid_from_week = Week::parse_iso(&valid_from.read());
+    let changes = draft
+        .read()
+        .as_ref()
+        .map(|draft| draft.changes())
+        .unwrap_or_default();
+    let change_count = changes.len();
+    let selected_count = draft
+        .read()
+        .as_ref()
+        .map(SlotStructureDraft::selected_count)
+        .unwrap_or(0);
+    let save_label = i18n.t_m(
+        Key::SlotStructureSave,
+        [("count", change_count.to_string().as_str())].into(),
+    );
+    let selected_label = i18n.t_m(
+        Key::SlotStructureSelected,
+        [("count", selected_count.to_string().as_str())].into(),
+    );
+    let days: Vec<Weekday> = (0..7).map(Weekday::from_num_from_monday).collect();
+    let shiftplans = props.shiftplans.clone();
+    let shiftplan_name = move |id: Option<Uuid>| -> ImStr {
+        shiftplans
+            .iter()
+            .find(|shiftplan| Some(shiftplan.id) == id)
+            .map(|shiftplan| ImStr::from(shiftplan.name.as_ref()))
+            .unwrap_or_default()
+    };
+    // Deleted slots are only known by id; a failed delete leaves the slot in
+    // the reloaded draft.
+    let failed_slot = move |change: &SlotStructureChange| -> Option<SlotEditItem> {
+        match change {
+            SlotStructureChange::Create(slot) | SlotStructureChange::Update(slot) => {
+                Some(slot.clone())
+            }
+            SlotStructureChange::Delete(slot_id) => draft.read().as_ref().and_then(|draft| {
+                draft
+                    .original
+                    .iter()
+                    .find(|slot| slot.id == *slot_id)
+                    .cloned()
+            }),
+        }
+    };
+    let target_value = target_shiftplan()
+        .map(|id| id.to_string())
+        .unwrap_or_default();
+    let on_close = props.on_close;
+    let on_close_footer = props.on_close;
+
+    rsx! {
+        Dialog {
+            open: true,
+            on_close: move |_| on_close.call(()),
+            title: ImStr::from(i18n.t(Key::SlotStructureTitle).as_ref()),
+            subtitle: Some(shiftplan_name(Some(shiftplan_id))),
+            variant: DialogVariant::Auto,
+            width: 760,
+            footer: Some(rsx! {
+                Btn {
+                    variant: BtnVariant::Secondary,
+                    on_click: move |_| on_close_footer.call(()),
+                    "{i18n.t(Key::Cancel)}"
+                }
+                Btn {
+                    variant: BtnVariant::Primary,
+                    disabled: change_count == 0 || valid_from_week.is_none() || *saving.read(),
+                    on_click: move |_| {
+                        let Some(valid_from) = valid_from_week else {
+                            return;
+                        };
+                        let changes = changes.clone();
+                        saving.set(true);
+                        message.set(None);
+                        spawn(async move {
+                            match loader::save_slot_structure(CONFIG.read().clone(), &changes, valid_from)
+                                .await
+                            {
+                                Ok(result) => {
+                                    trigger_shiftplan_refresh();
+                                    if result.rejected.is_empty() && result.failed.is_empty() {
+                                        on_close.call(());
+                                    } else {
+                                        report.set(Some(result));
+                                        load();
+                                    }
+                                }
+                                Err(err) => message.set(Some(ImStr::from(err.to_string()))),
+                            }
+                            saving.set(false);
+                        });
+                    },
+                    "{save_label}"
+                }
+            }),
+            div { class: "grid grid-cols-2 gap-3 max-md:grid-cols-1",
+                Field {
+                    label: ImStr::from(i18n.t(Key::SlotStructureValidFrom).as_ref()),
+                    hint: Some(ImStr::from(i18n.t(Key::SlotStructureValidFromHint).as_ref())),
+                    TextInput {
+                        value: ImStr::from(valid_from.read().as_str()),
+                        input_type: ImStr::from("week"),
+                        on_change: move |value: ImStr| valid_from.set(value.to_string()),
+                    }
+                }
+            }
+            div { class: "mt-4 flex flex-wrap items-center gap-2 p-2 rounded-md bg-surface-alt",
+                span { class: "text-small text-ink-muted mr-2", "{selected_label}" }
+                for (delta , label) in SHIFT_STEPS {
+                    Btn {
+                        variant: BtnVariant::Secondary,
+                        disabled: selected_count == 0,
+                        on_click: move |_| {
+                            let shifted = draft
+                                .write()
+                                .as_mut()
+                                .is_some_and(|draft| draft.shift_selected(delta));
+                            message
+                                .set(
+                                    (!shifted)
+                                        .then(|| ImStr::from(
+                                            I18N.read().t(Key::SlotStructureShiftOutOfDay).as_ref(),
+                                        )),
+                                );
+                        },
+                        "{label}"
+                    }
+                }
+                div { class: "w-20",
+                    TextInput {
+                        value: ImStr::from(min_resources.read().as_str()),
+                        input_type: ImStr::from("number"),
+                        on_change: move |value: ImStr| min_resources.set(value.to_string()),
+                    }
+                }
+                Btn {
+                    variant: BtnVariant::Secondary,
+                    disabled: selected_count == 0 || min_resources.read().parse::<u8>().is_err(),
+                    on_click: move |_| {
+                        if let Ok(value) = min_resources.read().parse::<u8>() {
+                            if let Some(draft) = draft.write().as_mut() {
+                                draft.set_min_resources(value);
+                            }
+                        }
+                    },
+                    "{i18n.t(Key::SlotStructureApplyMinResources)}"
+                }
+                Btn {
+                    variant: BtnVariant::Danger,
+                    disabled: selected_count == 0,
+                    on_click: move |_| {
+                        if let Some(draft) = draft.write().as_mut() {
+                            draft.remove_selected();
+                        }
+                    },
+                    "{i18n.t(Key::SlotStructureRemove)}"
+                }
+            }
+            div { class: "mt-3 flex flex-col gap-2 p-2 rounded-md border border-border",
+                div { class: "flex flex-wrap items-end gap-3",
+                    Field { label: ImStr::from(i18n.t(Key::SlotStructureDuplicateSource).as_ref()),
+                        SelectInput {
+                            on_change: move |value: ImStr| {
+                                if let Ok(num) = value.as_str().parse::<u8>() {
+                                    source_day.set(Weekday::from_num_from_monday(num));
+                                }
+                            },
+                            for day in days.iter() {
+                                option {
+                                    value: "{day.num_from_monday()}",
+                                    selected: *day == source_day(),
+                                    "{day.i18n_string(&i18n)}"
+                                }
+                            }
+                        }
+                    }
+                    Field { label: ImStr::from(i18n.t(Key::SlotStructureDuplicateShiftplan).as_ref()),
+                        SelectInput {
+                            on_change: move |value: ImStr| target_shiftplan.set(value.as_str().parse().ok()),
+                            option { value: "", selected: target_value.is_empty(),
+                                "{shiftplan_name(Some(shiftplan_id))}"
+                            }
+                            for shiftplan in props
+                                .shiftplans
+                                .iter()
+                                .filter(|shiftplan| shiftplan.deleted.is_none() && shiftplan.id != shiftplan_id)
+                            {
+                                option {
+                                    value: "{shiftplan.id}",
+                                    selected: target_value == shiftplan.id.to_string(),
+                                    "{shiftplan.name}"
+                                }
+                            }
+                        }
+                    }
+                }
+                div { class: "flex flex-wrap items-center gap-3",
+                    span { class: "text-small text-ink-muted", "{i18n.t(Key::SlotStructureDuplicateTargets)}" }
+                    for day in days.iter().copied() {
+                        FormCheckbox {
+                            value: target_days.read().contains(&day),
+                            on_change: move |checked: bool| {
+                                let mut targets = target_days.write();
+                                targets.retain(|target| *target != day);
+                                if checked {
+                                    targets.push(day);
+                                }
+                            },
+                            label: rsx! { "{day.i18n_short_string(&i18n)}" },
+                        }
+                    }
+                }
+                div { class: "flex flex-wrap items-center justify-between gap-3",
+                    FormCheckbox {
+                        value: *replace.read(),
+                        disabled: target_shiftplan().is_some(),
+                        on_change: move |value: bool| replace.set(value),
+                        label: rsx! { "{i18n.t(Key::SlotStructureDuplicateReplace)}" },
+                    }
+                    Btn {
+                        variant: BtnVariant::Secondary,
+                        disabled: target_days.read().is_empty(),
+                        on_click: move |_| {
+                            let targets = target_days.read().clone();
+                            if let Some(draft) = draft.write().as_mut() {
+                                draft.duplicate_day(source_day(), &targets, target_shiftplan(), replace());
+                            }
+                        },
+                        "{i18n.t(Key::SlotStructureDuplicate)}"
+                    }
+                }
+            }
+            if valid_from_week.is_none() {
+                div { class: "mt-3 text-small text-bad", "{i18n.t(Key::CopyWeekInvalidRange)}" }
+            }
+            if let Some(message) = message.read().as_ref() {
+                div { class: "mt-3 text-small text-bad", "{message}" }
+            }
+            if let Some(report) = report.read().as_ref().filter(|report| !report.failed.is_empty()) {
+                div { class: "mt-3 p-2 rounded-md bg-bad-soft text-small text-bad",
+                    {
+                        i18n.t_m(
+                            Key::SlotStructureFailed,
+                            [
+                                ("count", report.failed.len().to_string().as_str()),
+                                ("saved", report.saved.to_string().as_str()),
+                            ]
+                            .into(),
+                        )
+                    }
+                    ul { class: "mt-1 list-disc pl-5",
+                        for (change , error) in report.failed.iter() {
+                            li {
+                                "{change_label(&i18n, change)}"
+                                if let Some(slot) = failed_slot(change) {
+                                    " · {slot.day_of_week.i18n_string(&i18n)} {slot.from.hour():02}:{slot.from.minute():02}–{slot.to.hour():02}:{slot.to.minute():02} · {shiftplan_name(slot.shiftplan_id)}"
+                                }
+                                ": {error}"
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(report) = report.read().as_ref().filter(|report| !report.rejected.is_empty()) {
+                div { class: "mt-3 p-2 rounded-md bg-warn-soft text-small text-warn",
+                    {
+                        i18n.t_m(
+                            Key::SlotStructureRejected,
+                            [("count", report.rejected.len().to_string().as_str())].into(),
+                        )
+                    }
+                    ul { class: "mt-1 list-disc pl-5",
+                        for slot in report.rejected.iter() {
+                            li {
+                                "{slot.day_of_week.i18n_string(&i18n)} {slot.from.hour():02}:{slot.from.minute():02}–{slot.to.hour():02}:{slot.to.minute():02} · {shiftplan_name(slot.shiftplan_id)}"
+                            }
+                        }
+                    }
+                }
+            }
+            match draft.read().as_ref() {
+                None => rsx! {
+                    div { class: "mt-4 text-small text-ink-muted", "{i18n.t(Key::SlotStructureLoading)}" }
+                },
+                Some(current) if current.slots.is_empty() => rsx! {
+                    div { class: "mt-4 text-small text-ink-muted italic", "{i18n.t(Key::SlotStructureEmpty)}" }
+                },
+                Some(current) => rsx! {
+                    div { class: "mt-4 flex flex-col gap-3",
+                        for day in days.iter().copied().filter(|day| current.slots.iter().any(|slot| slot.item.day_of_week == *day)) {
+                            div { class: "border border-border rounded-md",
+                                button {
+                                    class: "w-full px-3 py-1.5 bg-surface-alt text-left text-small font-semibold text-ink-soft hover:text-ink",
+                                    onclick: move |_| {
+                                        if let Some(draft) = draft.write().as_mut() {
+                                            draft.toggle_day(day);
+                                        }
+                                    },
+                                    "{day.i18n_string(&i18n)}"
+                                }
+                                for (index , slot) in current.slots.iter().enumerate().filter(|(_, slot)| slot.item.day_of_week == day) {
+                                    label {
+                                        class: if slot.removed { "flex items-center gap-3 px-3 py-1.5 border-t border-border text-body text-ink-muted line-through" } else { "flex items-center gap-3 px-3 py-1.5 border-t border-border text-body text-ink cursor-pointer" },
+                                        input {
+                                            r#type: "checkbox",
+                                            checked: slot.selected,
+                                            disabled: slot.removed,
+                                            onchange: move |_| {
+                                                if let Some(draft) = draft.write().as_mut() {
+                                                    draft.toggle(index);
+                                                }
+                                            },
+                                        }
+                                        span { class: "font-mono", "{slot.item.from.hour():02}:{slot.item.from.minute():02}–{slot.item.to.hour():02}:{slot.item.to.minute():02}" }
+                                        span { class: "text-small text-ink-muted",
+                                            {
+                                                i18n.t_m(
+                                                    Key::SlotStructureMinResources,
+                                                    [("count", slot.item.min_resources.to_string().as_str())].into(),
+                                                )
+                                            }
+                                        }
+                                        if slot.item.shiftplan_id != Some(shiftplan_id) {
+                                            span { class: "text-small text-accent", "→ {shiftplan_name(slot.item.shiftplan_id)}" }
+                                        }
+                                        if let Some((label, class)) = slot_badge(&i18n, current, slot) {
+                                            span { class: "ml-auto {class}", "{label}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{i18n::generate, state::slot_edit::SlotEditItem};
+
+    #[test]
+    fn badge_marks_removed_new_and_changed_slots() {
+        let i18n = generate(Locale::En);
+        let item = SlotEditItem {
+            id: Uuid::from_u128(1),
+            shiftplan_id: Some(Uuid::from_u128(50)),
+            ..SlotEditItem::empty()
+        };
+        let mut draft = SlotStructureDraft::new(Rc::from([item]));
+        assert_eq!(slot_badge(&i18n, &draft, &draft.slots[0]), None);
+
+        draft.toggle(0);
+        draft.set_min_resources(3);
+        let (_, class) = slot_badge(&i18n, &draft, &draft.slots[0]).unwrap();
+        assert!(class.contains("bg-warn-soft"));
+
+        draft.duplicate_day(Weekday::Monday, &[Weekday::Friday], None, false);
+        let (label, _) = slot_badge(&i18n, &draft, &draft.slots[1]).unwrap();
+        assert_eq!(label, i18n.t(Key::SlotStructureNew));
+
+        draft.remove_selected();
+        let (label, _) = slot_badge(&i18n, &draft, &draft.slots[0]).unwrap();
+        assert_eq!(label, i18n.t(Key::SlotStructureRemoved));
+    }
+}
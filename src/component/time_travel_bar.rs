@@ -0,0 +1,135 @@
+//! Slider above the week grid that picks the moment the week is shown at.
+//!
+//! The slider steps through the moments a booking of the week was created or
+//! deleted; the date-time input reaches any moment in between.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use time::PrimitiveDateTime;
+
+use crate::{
+    base_types::ImStr,
+    component::{
+        atoms::{Btn, BtnVariant},
+        form::TextInput,
+    },
+    i18n::Key,
+    service::i18n::I18N,
+    state::{
+        booking_changes::{format_datetime_local, parse_datetime_local},
+        time_travel::moment_index,
+    },
+};
+
+const STEP_BTN_CLASSES: &str = "w-7 h-7 inline-flex items-center justify-center border border-border-strong rounded-md font-mono text-ink-soft bg-surface hover:bg-surface-alt disabled:opacity-50";
+
+#[derive(PartialEq, Clone, Props)]
+pub struct TimeTravelBarProps {
+    /// Sorted moments at which the week changed.
+    pub moments: Rc<[PrimitiveDateTime]>,
+    pub moment: PrimitiveDateTime,
+    pub on_change: EventHandler<PrimitiveDateTime>,
+    pub on_close: EventHandler<()>,
+}
+
+#[component]
+pub fn TimeTravelBar(props: TimeTravelBarProps) -> Element {
+    let i18n = I18N.read().clone();
+    let moments = props.moments.clone();
+    let index = moment_index(&moments, props.moment);
+    let last = moments.len().saturating_sub(1);
+    let on_change = props.on_change;
+    let on_close = props.on_close;
+    let at_str = i18n.t_m(
+        Key::TimeTravelAt,
+        [
+            ("date", i18n.format_date(&props.moment.date()).as_ref()),
+            (
+                "time",
+                format!("{:02}:{:02}", props.moment.hour(), props.moment.minute()).as_str(),
+            ),
+        ]
+        .into(),
+    );
+    let previous = moments
+        .iter()
+        .rev()
+        .find(|moment| **moment < props.moment)
+        .copied();
+    let next = moments
+        .iter()
+        .find(|moment| **moment > props.moment)
+        .copied();
+    let slider_moments = moments.clone();
+
+    rsx! {
+        div { class: "mb-3 flex flex-col gap-2 px-3 py-2 rounded-md border border-border bg-warn-soft text-small text-ink print:hidden",
+            div { class: "flex flex-wrap items-center gap-3",
+                span { class: "font-semibold", "{at_str}" }
+                span { class: "text-ink-muted", "{i18n.t(Key::TimeTravelHint)}" }
+                span { class: "flex-1" }
+                Btn {
+                    variant: BtnVariant::Secondary,
+                    on_click: move |_| on_close.call(()),
+                    "{i18n.t(Key::TimeTravelClose)}"
+                }
+            }
+            if moments.is_empty() {
+                span { class: "text-ink-muted italic", "{i18n.t(Key::TimeTravelNoLog)}" }
+            } else {
+                div { class: "flex items-center gap-2",
+                    button {
+                        class: STEP_BTN_CLASSES,
+                        disabled: previous.is_none(),
+                        onclick: move |_| {
+                            if let Some(previous) = previous {
+                                on_change.call(previous);
+                            }
+                        },
+                        "‹"
+                    }
+                    input {
+                        class: "flex-1 accent-accent",
+                        r#type: "range",
+                        min: "0",
+                        max: "{last}",
+                        step: "1",
+                        value: "{index}",
+                        oninput: move |event| {
+                            if let Some(moment) = event
+                                .value()
+                                .parse::<usize>()
+                                .ok()
+                                .and_then(|index| slider_moments.get(index))
+                            {
+                                on_change.call(*moment);
+                            }
+                        },
+                    }
+                    button {
+                        class: STEP_BTN_CLASSES,
+                        disabled: next.is_none(),
+                        onclick: move |_| {
+                            if let Some(next) = next {
+                                on_change.call(next);
+                            }
+                        },
+                        "›"
+                    }
+                    div { class: "w-48",
+                        TextInput {
+                            value: ImStr::from(format_datetime_local(props.moment)),
+                            input_type: ImStr::from("datetime-local"),
+                            on_change: move |value: ImStr| {
+                                if let Some(moment) = parse_datetime_local(value.as_str()) {
+                                    on_change.call(moment);
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::rc::Rc;
 use dioxus::prelude::*;
 
 use crate::{
+    backend,
     base_types::ImStr,
     component::dropdown_base::DropdownTrigger,
     i18n::Key,
@@ -27,6 +28,8 @@ pub(crate) struct NavVisibility {
     pub billing_periods: bool,
     pub user_management: bool,
     pub templates: bool,
+    pub booking_patterns: bool,
+    pub booking_log: bool,
 }
 
 pub(crate) fn nav_visibility(auth_info: Option<&AuthInfo>, is_paid: bool) -> NavVisibility {
@@ -41,6 +44,8 @@ pub(crate) fn nav_visibility(auth_info: Option<&AuthInfo>, is_paid: bool) -> Nav
         billing_periods: show_reports,
         user_management: has("admin"),
         templates: has("admin"),
+        booking_patterns: has("shiftplanner"),
+        booking_log: has("shiftplanner"),
     }
 }
 
@@ -54,6 +59,8 @@ pub(crate) enum NavTarget {
     BillingPeriods,
     UserManagement,
     Templates,
+    BookingPatterns,
+    BookingLog,
 }
 
 pub(crate) fn is_active_for(target: NavTarget, route: &Route) -> bool {
@@ -78,6 +85,8 @@ pub(crate) fn is_active_for(target: NavTarget, route: &Route) -> bool {
                 | Route::SalesPersonDetails { .. }
         ),
         NavTarget::Templates => matches!(route, Route::TextTemplateManagement {}),
+        NavTarget::BookingPatterns => matches!(route, Route::BookingPatterns {}),
+        NavTarget::BookingLog => matches!(route, Route::BookingLogPage {}),
     }
 }
 
@@ -110,6 +119,8 @@ pub(crate) fn is_admin_target(target: NavTarget) -> bool {
             | NavTarget::BillingPeriods
             | NavTarget::UserManagement
             | NavTarget::Templates
+            | NavTarget::BookingPatterns
+            | NavTarget::BookingLog
     )
 }
 
@@ -283,6 +294,7 @@ fn TopBarRouted() -> Element {
     let you_are_label = i18n.t(Key::TopBarYouAreLabel);
     let logout_label = i18n.t(Key::Logout);
     let login_label = i18n.t(Key::Login);
+    let reset_demo_label = i18n.t(Key::TopBarResetDemoData);
 
     let employee = {
         let config = config.clone();
@@ -367,6 +379,20 @@ fn TopBarRouted() -> Element {
                 i18n.t(Key::TextTemplateManagement).to_string(),
             ));
         }
+        if visibility.booking_patterns {
+            items.push((
+                NavTarget::BookingPatterns,
+                Route::BookingPatterns {},
+                i18n.t(Key::BookingPatterns).to_string(),
+            ));
+        }
+        if visibility.booking_log {
+            items.push((
+                NavTarget::BookingLog,
+                Route::BookingLogPage {},
+                i18n.t(Key::BookingLogTitle).to_string(),
+            ));
+        }
         items
     };
 
@@ -483,6 +509,18 @@ fn TopBarRouted() -> Element {
             }
 
             div { class: "ml-auto flex items-center gap-2 flex-shrink-0",
+                if config.demo {
+                    button {
+                        class: "px-3 py-1 rounded-md border border-border bg-transparent text-ink-soft text-small max-md:hidden",
+                        onclick: move |_| {
+                            backend::reset_demo_store();
+                            if let Some(window) = web_sys::window() {
+                                let _ = window.location().reload();
+                            }
+                        },
+                        "{reset_demo_label}"
+                    }
+                }
                 // Theme button glyph: 15 px is the design's specific icon-glyph size
                 // (Shifty Preview.html line 322). It sits between body (14 px) and
                 // lg (16 px) — kept as an arbitrary value because it is a glyph
@@ -680,6 +718,22 @@ mod tests {
         assert!(!v.my_time);
     }
 
+    #[test]
+    fn nav_visibility_booking_patterns_require_shiftplanner() {
+        let v = nav_visibility(Some(&auth_with(&["shiftplanner"])), false);
+        assert!(v.booking_patterns);
+        let v = nav_visibility(Some(&auth_with(&["sales", "hr", "admin"])), false);
+        assert!(!v.booking_patterns);
+        assert!(is_admin_target(NavTarget::BookingPatterns));
+    }
+
+    #[test]
+    fn nav_visibility_booking_log_requires_shiftplanner() {
+        assert!(nav_visibility(Some(&auth_with(&["shiftplanner"])), false).booking_log);
+        assert!(!nav_visibility(Some(&auth_with(&["sales", "hr"])), false).booking_log);
+        assert!(is_admin_target(NavTarget::BookingLog));
+    }
+
     #[test]
     fn nav_visibility_hr_shows_employees_and_billing_periods() {
         let auth = auth_with(&["hr"]);
@@ -875,6 +929,8 @@ mod tests {
             NavTarget::BillingPeriods => Route::BillingPeriods {},
             NavTarget::UserManagement => Route::UserManagementPage {},
             NavTarget::Templates => Route::TextTemplateManagement {},
+            NavTarget::BookingPatterns => Route::BookingPatterns {},
+            NavTarget::BookingLog => Route::BookingLogPage {},
         };
         (target, route, label.to_string())
     }
@@ -1020,6 +1076,12 @@ mod tests {
         if v.templates {
             items.push(nav_entry(NavTarget::Templates, "Textvorlagen"));
         }
+        if v.booking_patterns {
+            items.push(nav_entry(
+                NavTarget::BookingPatterns,
+                "Wiederkehrende Schichten",
+            ));
+        }
         items
     }
 
@@ -0,0 +1,222 @@
+//! Dialog for a version conflict: another user saved the entry while the
+//! current user was editing it.
+//!
+//! Every differing field is listed with both values. Per field the user
+//! keeps either their own or the saved value; "Save selection" merges
+//! accordingly, "Overwrite" keeps all of their own values and "Reload"
+//! drops them. [`VersionConflictBase`] shows the dialog for the conflict in
+//! [`ERROR_STORE`] and is mounted once in the app shell.
+
+use std::{collections::BTreeSet, rc::Rc};
+
+use dioxus::prelude::*;
+
+use crate::{
+    base_types::ImStr,
+    component::{
+        atoms::{Btn, BtnVariant},
+        dialog::{Dialog, DialogVariant},
+    },
+    error::ShiftyError,
+    i18n::Key,
+    service::{
+        error::{ErrorStore, ERROR_STORE},
+        i18n::I18N,
+        version_conflict::VersionConflictAction,
+    },
+    state::version_conflict::{field_label, FieldDiff},
+};
+
+const HEADER_CLASSES: &str = "px-3 py-2 text-micro font-semibold text-ink-muted";
+
+pub(crate) fn value_class(chosen: bool) -> &'static str {
+    if chosen {
+        "px-3 py-2 align-top bg-accent-soft text-ink"
+    } else {
+        "px-3 py-2 align-top text-ink-muted line-through"
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct VersionConflictDialogProps {
+    pub diffs: Rc<[FieldDiff]>,
+    pub on_close: EventHandler<()>,
+    pub on_reload: EventHandler<()>,
+    pub on_overwrite: EventHandler<()>,
+    /// Receives the fields for which the user keeps their own value.
+    pub on_merge: EventHandler<BTreeSet<Rc<str>>>,
+}
+
+#[component]
+pub fn VersionConflictDialog(props: VersionConflictDialogProps) -> Element {
+    let i18n = I18N.read().clone();
+    // Fields for which the saved value wins. Everything else keeps the
+    // user's value, so an untouched dialog merges like "Overwrite".
+    let mut keep_theirs = use_signal(BTreeSet::<Rc<str>>::new);
+    let keep_yours: BTreeSet<Rc<str>> = props
+        .diffs
+        .iter()
+        .map(|diff| diff.field.clone())
+        .filter(|field| !keep_theirs.read().contains(field))
+        .collect();
+
+    let on_close = props.on_close;
+    let on_reload = props.on_reload;
+    let on_overwrite = props.on_overwrite;
+    let on_merge = props.on_merge;
+
+    rsx! {
+        Dialog {
+            open: true,
+            on_close: move |_| on_close.call(()),
+            title: ImStr::from(i18n.t(Key::VersionConflictTitle).as_ref()),
+            subtitle: Some(ImStr::from(i18n.t(Key::VersionConflictHint).as_ref())),
+            variant: DialogVariant::Auto,
+            width: 640,
+            footer: Some(rsx! {
+                Btn {
+                    variant: BtnVariant::Secondary,
+                    on_click: move |_| on_reload.call(()),
+                    "{i18n.t(Key::VersionConflictReload)}"
+                }
+                Btn {
+                    variant: BtnVariant::Danger,
+                    on_click: move |_| on_overwrite.call(()),
+                    "{i18n.t(Key::VersionConflictOverwrite)}"
+                }
+                Btn {
+                    variant: BtnVariant::Primary,
+                    on_click: move |_| on_merge.call(keep_yours.clone()),
+                    "{i18n.t(Key::VersionConflictMerge)}"
+                }
+            }),
+            div { class: "overflow-x-auto border border-border rounded-lg",
+                table { class: "w-full text-small font-normal border-collapse",
+                    thead {
+                        tr { class: "bg-surface-alt text-left",
+                            th { class: HEADER_CLASSES, {i18n.t(Key::VersionConflictField)} }
+                            th { class: HEADER_CLASSES, {i18n.t(Key::VersionConflictYours)} }
+                            th { class: HEADER_CLASSES, {i18n.t(Key::VersionConflictTheirs)} }
+                        }
+                    }
+                    tbody {
+                        for diff in props.diffs.iter() {
+                            {
+                                let theirs_chosen = keep_theirs.read().contains(&diff.field);
+                                let yours_field = diff.field.clone();
+                                let theirs_field = diff.field.clone();
+                                rsx! {
+                                    tr { key: "{diff.field}", class: "border-t border-border",
+                                        td { class: "px-3 py-2 align-top text-ink font-medium",
+                                            "{field_label(&diff.field)}"
+                                        }
+                                        td { class: value_class(!theirs_chosen),
+                                            label { class: "flex items-start gap-2 cursor-pointer",
+                                                input {
+                                                    r#type: "radio",
+                                                    name: "conflict-{diff.field}",
+                                                    checked: !theirs_chosen,
+                                                    onchange: move |_| {
+                                                        keep_theirs.write().remove(&yours_field);
+                                                    },
+                                                }
+                                                span { class: "break-all", "{diff.yours}" }
+                                            }
+                                        }
+                                        td { class: value_class(theirs_chosen),
+                                            label { class: "flex items-start gap-2 cursor-pointer",
+                                                input {
+                                                    r#type: "radio",
+                                                    name: "conflict-{diff.field}",
+                                                    checked: theirs_chosen,
+                                                    onchange: move |_| {
+                                                        keep_theirs.write().insert(theirs_field.clone());
+                                                    },
+                                                }
+                                                span { class: "break-all", "{diff.theirs}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Shows [`VersionConflictDialog`] while [`ERROR_STORE`] holds a version
+/// conflict.
+#[component]
+pub fn VersionConflictBase() -> Element {
+    let service = use_coroutine_handle::<VersionConflictAction>();
+    let conflict = match ERROR_STORE.read().error.as_ref() {
+        Some(ShiftyError::VersionConflict(conflict)) => conflict.as_ref().clone(),
+        _ => return rsx! {},
+    };
+    let diffs: Rc<[FieldDiff]> = match conflict.diffs() {
+        Ok(diffs) => diffs.into(),
+        Err(_) => return rsx! {},
+    };
+    let overwrite_conflict = conflict.clone();
+
+    rsx! {
+        VersionConflictDialog {
+            diffs,
+            on_close: move |_| *ERROR_STORE.write() = ErrorStore::default(),
+            on_reload: move |_| service.send(VersionConflictAction::Reload),
+            on_overwrite: move |_| {
+                if let Ok(entity) = overwrite_conflict.overwrite() {
+                    service.send(VersionConflictAction::Save(entity));
+                }
+            },
+            on_merge: move |keep_yours: BTreeSet<Rc<str>>| {
+                if let Ok(entity) = conflict.merged(&keep_yours) {
+                    service.send(VersionConflictAction::Save(entity));
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(comp: fn() -> Element) -> String {
+        let mut vdom = VirtualDom::new(comp);
+        vdom.rebuild_in_place();
+        dioxus_ssr::render(&vdom)
+    }
+
+    #[test]
+    fn lists_both_values_of_every_differing_field() {
+        fn app() -> Element {
+            rsx! {
+                VersionConflictDialog {
+                    diffs: Rc::from(vec![FieldDiff {
+                        field: "background_color".into(),
+                        yours: "#ff0000".into(),
+                        theirs: "#00ff00".into(),
+                    }]),
+                    on_close: |_| {},
+                    on_reload: |_| {},
+                    on_overwrite: |_| {},
+                    on_merge: |_| {},
+                }
+            }
+        }
+        let html = render(app);
+        assert!(html.contains("Background color"), "missing label: {html}");
+        assert!(html.contains("#ff0000") && html.contains("#00ff00"));
+        assert_eq!(html.matches("type=\"radio\"").count(), 2, "{html}");
+    }
+
+    #[test]
+    fn unchosen_value_is_struck_through() {
+        assert!(value_class(false).contains("line-through"));
+        assert!(value_class(true).contains("bg-accent-soft"));
+    }
+}
@@ -0,0 +1,334 @@
+//! Dialog for copying the bookings of one week into one or many other weeks.
+//!
+//! The planner picks the source week, the target range, optionally a single
+//! shiftplan and whether people marked unavailable are skipped. A preview
+//! lists every booking with its outcome before anything is written; the
+//! confirmed [`WeekCopyPreview`] is handed to `on_confirm`.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use rest_types::ShiftplanTO;
+use uuid::Uuid;
+
+use crate::{
+    base_types::ImStr,
+    component::{
+        atoms::{Btn, BtnVariant},
+        dialog::{Dialog, DialogVariant},
+        form::{Field, FormCheckbox, SelectInput, TextInput},
+    },
+    i18n::{I18n, Key, Locale},
+    loader,
+    service::{config::CONFIG, i18n::I18N},
+    state::{
+        week::Week,
+        week_copy::{WeekCopyPreview, WeekCopyRequest, WeekCopyStatus, WEEK_COPY_MAX_TARGET_WEEKS},
+    },
+};
+
+/// Parses the dialog inputs into a request, `None` if a week is invalid or
+/// the target range is reversed or longer than
+/// [`WEEK_COPY_MAX_TARGET_WEEKS`].
+pub(crate) fn build_request(
+    source: &str,
+    target_from: &str,
+    target_until: &str,
+    shiftplan_id: Option<Uuid>,
+    skip_unavailable: bool,
+) -> Option<WeekCopyRequest> {
+    let request = WeekCopyRequest {
+        source: Week::parse_iso(source)?,
+        target_from: Week::parse_iso(target_from)?,
+        target_until: Week::parse_iso(target_until)?,
+        shiftplan_id,
+        skip_unavailable,
+    };
+    (request.target_from <= request.target_until && request.target_weeks().is_ok())
+        .then_some(request)
+}
+
+pub(crate) fn status_label(i18n: &I18n<Key, Locale>, status: WeekCopyStatus) -> Rc<str> {
+    match status {
+        WeekCopyStatus::Create => i18n.t(Key::CopyWeekStatusCreate),
+        WeekCopyStatus::Conflict => i18n.t(Key::CopyWeekStatusConflict),
+        WeekCopyStatus::SkippedUnavailable => i18n.t(Key::CopyWeekStatusSkipped),
+        WeekCopyStatus::AlreadyBooked => i18n.t(Key::CopyWeekStatusExisting),
+    }
+}
+
+pub(crate) fn status_badge_class(status: WeekCopyStatus) -> &'static str {
+    match status {
+        WeekCopyStatus::Create => "px-1.5 rounded-sm text-micro bg-good-soft text-good",
+        WeekCopyStatus::Conflict => "px-1.5 rounded-sm text-micro bg-bad-soft text-bad",
+        WeekCopyStatus::SkippedUnavailable => "px-1.5 rounded-sm text-micro bg-warn-soft text-warn",
+        WeekCopyStatus::AlreadyBooked => {
+            "px-1.5 rounded-sm text-micro bg-surface-alt text-ink-muted"
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct WeekCopyDialogProps {
+    /// Week currently shown in the shift plan; the default target.
+    pub current_week: Week,
+    pub shiftplans: Rc<[ShiftplanTO]>,
+    pub selected_shiftplan_id: Option<Uuid>,
+    pub on_close: EventHandler<()>,
+    pub on_confirm: EventHandler<WeekCopyPreview>,
+}
+
+#[component]
+pub fn WeekCopyDialog(props: WeekCopyDialogProps) -> Element {
+    let i18n = I18N.read().clone();
+    let current_week = props.current_week;
+    let mut source = use_signal(|| {
+        current_week
+            .previous()
+            .map(|week| week.to_iso_string())
+            .unwrap_or_default()
+    });
+    let mut target_from = use_signal(|| current_week.to_iso_string());
+    let mut target_until = use_signal(|| current_week.to_iso_string());
+    let mut shiftplan_id = use_signal(|| props.selected_shiftplan_id);
+    let mut skip_unavailable = use_signal(|| true);
+    let mut preview = use_signal(|| None::<WeekCopyPreview>);
+    let mut loading = use_signal(|| false);
+    let mut error = use_signal(|| None::<ImStr>);
+
+    let request = build_request(
+        &source.read(),
+        &target_from.read(),
+        &target_until.read(),
+        *shiftplan_id.read(),
+        *skip_unavailable.read(),
+    );
+    // Every input change invalidates the preview so a stale one is never
+    // committed.
+    let mut reset_preview = move || {
+        preview.set(None);
+        error.set(None);
+    };
+
+    let to_create = preview
+        .read()
+        .as_ref()
+        .map(|preview| preview.to_create().len())
+        .unwrap_or(0);
+    let confirm_label = i18n.t_m(
+        Key::CopyWeekConfirm,
+        [("count", to_create.to_string().as_str())].into(),
+    );
+    let on_close = props.on_close;
+    let on_close_footer = props.on_close;
+    let on_confirm = props.on_confirm;
+    let selected_value = shiftplan_id().map(|id| id.to_string()).unwrap_or_default();
+
+    rsx! {
+        Dialog {
+            open: true,
+            on_close: move |_| on_close.call(()),
+            title: ImStr::from(i18n.t(Key::CopyWeekTitle).as_ref()),
+            variant: DialogVariant::Auto,
+            width: 640,
+            footer: Some(rsx! {
+                Btn {
+                    variant: BtnVariant::Secondary,
+                    on_click: move |_| on_close_footer.call(()),
+                    "{i18n.t(Key::Cancel)}"
+                }
+                Btn {
+                    variant: BtnVariant::Secondary,
+                    disabled: request.is_none() || *loading.read(),
+                    on_click: move |_| {
+                        let Some(request) = request else {
+                            return;
+                        };
+                        loading.set(true);
+                        error.set(None);
+                        spawn(async move {
+                            match loader::preview_week_copy(CONFIG.read().clone(), request).await {
+                                Ok(result) => preview.set(Some(result)),
+                                Err(err) => error.set(Some(ImStr::from(err.to_string()))),
+                            }
+                            loading.set(false);
+                        });
+                    },
+                    "{i18n.t(Key::CopyWeekPreview)}"
+                }
+                Btn {
+                    variant: BtnVariant::Primary,
+                    disabled: to_create == 0,
+                    on_click: move |_| {
+                        if let Some(preview) = preview.read().clone() {
+                            on_confirm.call(preview);
+                        }
+                    },
+                    "{confirm_label}"
+                }
+            }),
+            div { class: "grid grid-cols-2 gap-3 max-md:grid-cols-1",
+                Field { label: ImStr::from(i18n.t(Key::CopyWeekSource).as_ref()),
+                    TextInput {
+                        value: ImStr::from(source.read().as_str()),
+                        input_type: ImStr::from("week"),
+                        on_change: move |value: ImStr| {
+                            source.set(value.to_string());
+                            reset_preview();
+                        },
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::CopyWeekShiftplan).as_ref()),
+                    SelectInput {
+                        on_change: move |value: ImStr| {
+                            shiftplan_id.set(value.as_str().parse().ok());
+                            reset_preview();
+                        },
+                        option { value: "", selected: selected_value.is_empty(),
+                            "{i18n.t(Key::CopyWeekAllShiftplans)}"
+                        }
+                        for shiftplan in props.shiftplans.iter().filter(|shiftplan| shiftplan.deleted.is_none()) {
+                            option {
+                                value: "{shiftplan.id}",
+                                selected: selected_value == shiftplan.id.to_string(),
+                                "{shiftplan.name}"
+                            }
+                        }
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::CopyWeekTargetFrom).as_ref()),
+                    TextInput {
+                        value: ImStr::from(target_from.read().as_str()),
+                        input_type: ImStr::from("week"),
+                        on_change: move |value: ImStr| {
+                            target_from.set(value.to_string());
+                            reset_preview();
+                        },
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::CopyWeekTargetUntil).as_ref()),
+                    TextInput {
+                        value: ImStr::from(target_until.read().as_str()),
+                        input_type: ImStr::from("week"),
+                        on_change: move |value: ImStr| {
+                            target_until.set(value.to_string());
+                            reset_preview();
+                        },
+                    }
+                }
+            }
+            div { class: "mt-3",
+                FormCheckbox {
+                    value: *skip_unavailable.read(),
+                    on_change: move |value: bool| {
+                        skip_unavailable.set(value);
+                        reset_preview();
+                    },
+                    label: rsx! { "{i18n.t(Key::CopyWeekSkipUnavailable)}" },
+                }
+            }
+            if request.is_none() {
+                div { class: "mt-3 text-small text-bad",
+                    {
+                        i18n.t_m(
+                            Key::CopyWeekInvalidRange,
+                            [("max", WEEK_COPY_MAX_TARGET_WEEKS.to_string().as_str())].into(),
+                        )
+                    }
+                }
+            }
+            if let Some(error) = error.read().as_ref() {
+                div { class: "mt-3 text-small text-bad", "{error}" }
+            }
+            if let Some(preview) = preview.read().as_ref() {
+                div { class: "mt-4 flex flex-col gap-3",
+                    for preview_week in preview.weeks.iter() {
+                        div { class: "border border-border rounded-md",
+                            div { class: "flex flex-wrap items-center gap-2 px-3 py-2 bg-surface-alt text-small font-semibold",
+                                span {
+                                    {
+                                        i18n.t_m(
+                                            Key::CopyWeekWeekHeading,
+                                            [
+                                                ("week", preview_week.week.week.to_string().as_str()),
+                                                ("year", preview_week.week.year.to_string().as_str()),
+                                            ]
+                                            .into(),
+                                        )
+                                    }
+                                }
+                                for status in [
+                                    WeekCopyStatus::Create,
+                                    WeekCopyStatus::Conflict,
+                                    WeekCopyStatus::SkippedUnavailable,
+                                    WeekCopyStatus::AlreadyBooked,
+                                ] {
+                                    if preview_week.count(status) > 0 {
+                                        span { class: status_badge_class(status),
+                                            "{status_label(&i18n, status)}: {preview_week.count(status)}"
+                                        }
+                                    }
+                                }
+                            }
+                            ul { class: "divide-y divide-border",
+                                for booking in preview_week.bookings.iter() {
+                                    li { class: "flex items-center gap-2 px-3 py-1 text-small",
+                                        span { class: "font-mono text-ink-muted w-40 flex-shrink-0",
+                                            "{booking.day_of_week.i18n_string(&i18n)} {booking.from.hour():02}:{booking.from.minute():02}–{booking.to.hour():02}:{booking.to.minute():02}"
+                                        }
+                                        span { class: "flex-1 min-w-0 truncate", "{booking.sales_person_name}" }
+                                        span { class: status_badge_class(booking.status),
+                                            "{status_label(&i18n, booking.status)}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_request_parses_weeks() {
+        let request = build_request("2025-W22", "2025-W23", "2025-W30", None, true).unwrap();
+        assert_eq!(
+            request.source,
+            Week {
+                year: 2025,
+                week: 22
+            }
+        );
+        assert_eq!(request.target_until.week, 30);
+        assert!(request.skip_unavailable);
+    }
+
+    #[test]
+    fn build_request_rejects_reversed_range() {
+        assert!(build_request("2025-W22", "2025-W30", "2025-W23", None, true).is_none());
+    }
+
+    #[test]
+    fn build_request_rejects_too_many_target_weeks() {
+        assert!(build_request("2024-W52", "2025-W01", "2025-W26", None, true).is_some());
+        assert!(build_request("2024-W52", "2025-W01", "2025-W52", None, true).is_none());
+    }
+
+    #[test]
+    fn build_request_rejects_invalid_weeks() {
+        assert!(build_request("", "2025-W23", "2025-W23", None, true).is_none());
+        assert!(build_request("2025-W22", "next week", "2025-W23", None, true).is_none());
+    }
+
+    #[test]
+    fn conflict_badge_uses_bad_tokens() {
+        let class = status_badge_class(WeekCopyStatus::Conflict);
+        assert!(class.contains("bg-bad-soft") && class.contains("text-bad"));
+    }
+}
@@ -1,6 +1,7 @@
 use std::rc::Rc;
 
 use crate::component::atoms::PersonChip;
+use crate::component::booking_provenance_popover::BookingProvenancePopover;
 use crate::i18n::Key;
 use crate::service::weekly_summary::WEEKLY_SUMMARY_STORE;
 use crate::state::shiftplan::Identifiable;
@@ -9,7 +10,18 @@ use crate::{
     component::dropdown_base::DropdownTrigger,
     service::i18n::I18N,
     service::tooltip::TooltipAction,
-    state::{self, dropdown::DropdownEntry, Slot, Weekday},
+    state::{
+        self,
+        auto_fill::AutoFillAssignment,
+        booking_history::BookingKey,
+        dropdown::DropdownEntry,
+        grid_focus::{move_focus, GridCommand, GridFocus, GridKey},
+        live_update::LiveChanges,
+        offline_queue::PendingBooking,
+        shiftplan::{Booking, BookingConflict, BookingMove},
+        shiftplan_draft::{DraftDiffKind, DraftDiffMark},
+        Slot, Weekday,
+    },
 };
 use dioxus::prelude::*;
 use gloo_timers::future::TimeoutFuture;
@@ -609,14 +621,18 @@ pub enum CellButton {
 }
 
 /// Pure helper that resolves which (if any) button a single cell renders.
+/// `week_locked` hides add and remove in a published week for viewers
+/// without the `shiftplanner` privilege.
 pub fn resolve_cell_button(
     button_types: &WeekViewButtonTypes,
     editing_person_id: Option<Uuid>,
     booking_sales_person_ids: &[Uuid],
+    week_locked: bool,
 ) -> CellButton {
     match button_types {
         WeekViewButtonTypes::None => CellButton::None,
         WeekViewButtonTypes::Dropdown => CellButton::Dropdown,
+        WeekViewButtonTypes::AddRemove if week_locked => CellButton::None,
         WeekViewButtonTypes::AddRemove => match editing_person_id {
             None => CellButton::None,
             Some(id) => {
@@ -630,13 +646,47 @@ pub fn resolve_cell_button(
     }
 }
 
+/// Booking currently dragged inside a [`WeekView`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DraggedBooking {
+    pub sales_person_id: Uuid,
+    pub from_slot_id: Uuid,
+}
+
+/// Drag state shared by all cells of one [`WeekView`].
+#[derive(Copy, Clone)]
+struct BookingDrag(Signal<Option<DraggedBooking>>);
+
+/// Pure helper deciding whether `target` accepts the dragged booking: not
+/// the slot it came from, and only if the person is not booked there yet.
+pub fn accepts_drop(dragged: &DraggedBooking, target: &Slot) -> bool {
+    dragged.from_slot_id != target.id
+        && !target
+            .bookings
+            .iter()
+            .any(|booking| booking.sales_person_id == dragged.sales_person_id)
+}
+
+/// Tells the browser this is a move and attaches a payload; Firefox refuses
+/// to start a drag without data.
+fn mark_drag_as_move(evt: &Event<DragData>, payload: &str) {
+    if let Some(data_transfer) = evt
+        .data()
+        .downcast::<web_sys::DragEvent>()
+        .and_then(|event| event.data_transfer())
+    {
+        data_transfer.set_effect_allowed("move");
+        let _ = data_transfer.set_data("text/plain", payload);
+    }
+}
+
 #[cfg(test)]
 mod cell_button_tests {
     use super::*;
 
     #[test]
     fn cell_button_dropdown_in_dropdown_mode() {
-        let result = resolve_cell_button(&WeekViewButtonTypes::Dropdown, None, &[]);
+        let result = resolve_cell_button(&WeekViewButtonTypes::Dropdown, None, &[], false);
         assert_eq!(result, CellButton::Dropdown);
     }
 
@@ -646,13 +696,14 @@ mod cell_button_tests {
             &WeekViewButtonTypes::None,
             Some(Uuid::from_u128(1)),
             &[Uuid::from_u128(1)],
+            false,
         );
         assert_eq!(result, CellButton::None);
     }
 
     #[test]
     fn cell_button_none_when_no_editing_person() {
-        let result = resolve_cell_button(&WeekViewButtonTypes::AddRemove, None, &[]);
+        let result = resolve_cell_button(&WeekViewButtonTypes::AddRemove, None, &[], false);
         assert_eq!(result, CellButton::None);
     }
 
@@ -660,7 +711,12 @@ mod cell_button_tests {
     fn cell_button_remove_when_editing_person_in_cell() {
         let editing = Uuid::from_u128(7);
         let bookings = vec![Uuid::from_u128(1), editing, Uuid::from_u128(2)];
-        let result = resolve_cell_button(&WeekViewButtonTypes::AddRemove, Some(editing), &bookings);
+        let result = resolve_cell_button(
+            &WeekViewButtonTypes::AddRemove,
+            Some(editing),
+            &bookings,
+            false,
+        );
         assert_eq!(result, CellButton::Remove);
     }
 
@@ -668,7 +724,12 @@ mod cell_button_tests {
     fn cell_button_add_when_editing_person_not_in_cell() {
         let editing = Uuid::from_u128(7);
         let bookings = vec![Uuid::from_u128(1), Uuid::from_u128(2)];
-        let result = resolve_cell_button(&WeekViewButtonTypes::AddRemove, Some(editing), &bookings);
+        let result = resolve_cell_button(
+            &WeekViewButtonTypes::AddRemove,
+            Some(editing),
+            &bookings,
+            false,
+        );
         assert_eq!(result, CellButton::Add);
     }
 
@@ -678,10 +739,84 @@ mod cell_button_tests {
             &WeekViewButtonTypes::AddRemove,
             Some(Uuid::from_u128(1)),
             &[],
+            false,
         );
         assert_eq!(result, CellButton::Add);
     }
 
+    #[test]
+    fn cell_button_hidden_in_locked_week() {
+        let editing = Uuid::from_u128(7);
+        let add = resolve_cell_button(&WeekViewButtonTypes::AddRemove, Some(editing), &[], true);
+        let remove = resolve_cell_button(
+            &WeekViewButtonTypes::AddRemove,
+            Some(editing),
+            &[editing],
+            true,
+        );
+        assert_eq!(add, CellButton::None);
+        assert_eq!(remove, CellButton::None);
+        assert_eq!(
+            resolve_cell_button(&WeekViewButtonTypes::Dropdown, None, &[], true),
+            CellButton::Dropdown
+        );
+    }
+
+    fn slot_with_bookings(id: Uuid, sales_person_ids: &[Uuid]) -> Slot {
+        Slot {
+            id,
+            day_of_week: Weekday::Monday,
+            from: time::Time::from_hms(9, 0, 0).unwrap(),
+            to: time::Time::from_hms(12, 0, 0).unwrap(),
+            bookings: sales_person_ids
+                .iter()
+                .map(|sales_person_id| state::shiftplan::Booking {
+                    id: Uuid::new_v4(),
+                    sales_person_id: *sales_person_id,
+                    slot_id: id,
+                    week: 23,
+                    year: 2025,
+                    label: "Anna".into(),
+                    background_color: "#FFF".into(),
+                    self_added: false,
+                    created: None,
+                    created_by: None,
+                })
+                .collect(),
+            min_resources: 1,
+        }
+    }
+
+    #[test]
+    fn accepts_drop_on_other_slot_without_the_person() {
+        let dragged = DraggedBooking {
+            sales_person_id: Uuid::from_u128(1),
+            from_slot_id: Uuid::from_u128(10),
+        };
+        let target = slot_with_bookings(Uuid::from_u128(11), &[Uuid::from_u128(2)]);
+        assert!(accepts_drop(&dragged, &target));
+    }
+
+    #[test]
+    fn rejects_drop_on_source_slot() {
+        let dragged = DraggedBooking {
+            sales_person_id: Uuid::from_u128(1),
+            from_slot_id: Uuid::from_u128(10),
+        };
+        let target = slot_with_bookings(Uuid::from_u128(10), &[Uuid::from_u128(1)]);
+        assert!(!accepts_drop(&dragged, &target));
+    }
+
+    #[test]
+    fn rejects_drop_on_slot_already_booked_by_person() {
+        let dragged = DraggedBooking {
+            sales_person_id: Uuid::from_u128(1),
+            from_slot_id: Uuid::from_u128(10),
+        };
+        let target = slot_with_bookings(Uuid::from_u128(11), &[Uuid::from_u128(1)]);
+        assert!(!accepts_drop(&dragged, &target));
+    }
+
     #[test]
     fn cell_background_class_understaffed_is_warn_soft() {
         assert_eq!(cell_background_class(true, false), "bg-warn-soft");
@@ -703,6 +838,40 @@ mod cell_button_tests {
         assert_eq!(cell_background_class(true, true), "bg-bad-soft");
     }
 
+    #[test]
+    fn staffing_compares_filled_with_need() {
+        assert_eq!(staffing(1, 2), Staffing::Under);
+        assert_eq!(staffing(2, 2), Staffing::Met);
+        assert_eq!(staffing(3, 2), Staffing::Over);
+    }
+
+    #[test]
+    fn staffing_background_class_tints_over_and_under() {
+        assert_eq!(
+            staffing_background_class(Staffing::Under, false, true),
+            "bg-warn-soft"
+        );
+        assert_eq!(staffing_background_class(Staffing::Met, false, true), "");
+        assert_eq!(
+            staffing_background_class(Staffing::Over, false, true),
+            "bg-accent-soft"
+        );
+    }
+
+    #[test]
+    fn staffing_background_class_keeps_only_discourage_when_off() {
+        assert_eq!(staffing_background_class(Staffing::Under, false, false), "");
+        assert_eq!(staffing_background_class(Staffing::Over, false, false), "");
+        assert_eq!(
+            staffing_background_class(Staffing::Over, true, false),
+            "bg-bad-soft"
+        );
+        assert_eq!(
+            staffing_background_class(Staffing::Over, true, true),
+            "bg-bad-soft"
+        );
+    }
+
     #[test]
     fn min_resources_class_understaffed_is_warn() {
         assert_eq!(min_resources_class(true), "text-warn");
@@ -788,6 +957,9 @@ pub struct WeekViewProps {
     pub highlight_item_id: Option<Uuid>,
     pub add_event: Option<EventHandler<Slot>>,
     pub remove_event: Option<EventHandler<Slot>>,
+    /// Called when a booking chip is dropped onto another slot. Dragging is
+    /// only enabled in [`WeekViewButtonTypes::AddRemove`] mode.
+    pub move_event: Option<EventHandler<BookingMove>>,
     pub item_clicked: Option<EventHandler<Uuid>>,
     pub date_of_monday: Option<time::Date>,
     pub title_double_clicked: Option<EventHandler<Weekday>>,
@@ -802,6 +974,114 @@ pub struct WeekViewProps {
 
     #[props(default = false)]
     pub is_shiftplanner: bool,
+
+    /// Bookings suggested by the auto-fill solver, rendered as dashed chips
+    /// next to the real bookings until they are applied or discarded.
+    #[props(default = Rc::new([]))]
+    pub proposed_bookings: Rc<[AutoFillAssignment]>,
+
+    /// Bookings produced by a recurring booking pattern; their chips carry
+    /// a `↻` marker.
+    #[props(default = Rc::new([]))]
+    pub recurring_bookings: Rc<[BookingKey]>,
+
+    /// Bookings reported by the conflict check; their chips get a marker.
+    #[props(default = Rc::new([]))]
+    pub conflicts: Rc<[BookingConflict]>,
+
+    /// Tints cells by [`Staffing`] and marks conflicting bookings. When off,
+    /// only the unavailable-day tint remains.
+    #[props(default = true)]
+    pub highlight_staffing: bool,
+
+    /// Enables keyboard navigation. Arrow keys move the focus ring inside
+    /// the grid; the other shortcuts are handed to this handler.
+    pub on_grid_command: Option<EventHandler<GridCommand>>,
+
+    /// Bookings which differ from the other side of a draft comparison.
+    #[props(default = Rc::new([]))]
+    pub diff_marks: Rc<[DraftDiffMark]>,
+
+    /// The week is published; only shift planners get add and remove.
+    #[props(default = false)]
+    pub week_published: bool,
+
+    /// Changes another planner just made; they pulse until cleared.
+    #[props(default = LiveChanges::default())]
+    pub live_changes: LiveChanges,
+
+    /// Booking changes which wait in the offline queue.
+    #[props(default = Rc::new([]))]
+    pub pending_bookings: Rc<[PendingBooking]>,
+}
+
+/// Maps a grid key to the command for the focused slot or booking. Arrow
+/// keys are handled by the grid itself and yield `None`.
+pub(crate) fn grid_command(key: GridKey, focus: Option<GridFocus>) -> Option<GridCommand> {
+    match key {
+        GridKey::PreviousWeek => Some(GridCommand::PreviousWeek),
+        GridKey::NextWeek => Some(GridCommand::NextWeek),
+        GridKey::Help => Some(GridCommand::ShowHelp),
+        GridKey::Enter => focus.map(|focus| GridCommand::OpenPicker(focus.slot_id)),
+        GridKey::Delete => focus.and_then(|focus| {
+            focus
+                .sales_person_id
+                .map(|sales_person_id| GridCommand::RemoveBooking {
+                    slot_id: focus.slot_id,
+                    sales_person_id,
+                })
+        }),
+        GridKey::Recent(index) => focus.map(|focus| GridCommand::PickRecent {
+            slot_id: focus.slot_id,
+            index,
+        }),
+        GridKey::Up | GridKey::Down | GridKey::Left | GridKey::Right => None,
+    }
+}
+
+#[cfg(test)]
+mod grid_command_tests {
+    use super::*;
+
+    #[test]
+    fn grid_command_delete_needs_a_focused_booking() {
+        let slot_id = Uuid::from_u128(1);
+        assert_eq!(
+            grid_command(GridKey::Delete, Some(GridFocus::slot(slot_id))),
+            None
+        );
+        assert_eq!(
+            grid_command(
+                GridKey::Delete,
+                Some(GridFocus {
+                    slot_id,
+                    sales_person_id: Some(Uuid::from_u128(2)),
+                })
+            ),
+            Some(GridCommand::RemoveBooking {
+                slot_id,
+                sales_person_id: Uuid::from_u128(2),
+            })
+        );
+    }
+
+    #[test]
+    fn grid_command_enter_and_digits_need_a_focus() {
+        let slot_id = Uuid::from_u128(1);
+        assert_eq!(grid_command(GridKey::Enter, None), None);
+        assert_eq!(
+            grid_command(GridKey::Enter, Some(GridFocus::slot(slot_id))),
+            Some(GridCommand::OpenPicker(slot_id))
+        );
+        assert_eq!(
+            grid_command(GridKey::Recent(2), Some(GridFocus::slot(slot_id))),
+            Some(GridCommand::PickRecent { slot_id, index: 2 })
+        );
+        assert_eq!(
+            grid_command(GridKey::NextWeek, None),
+            Some(GridCommand::NextWeek)
+        );
+    }
 }
 
 enum Zoom {
@@ -842,6 +1122,23 @@ struct WeekCellChipProps {
     tooltip: Option<ImStr>,
     sales_person_id: Uuid,
     item_clicked: Option<EventHandler<Uuid>>,
+    drag_started: Option<EventHandler<Uuid>>,
+    drag_ended: Option<EventHandler<()>>,
+    /// Title of the conflict marker, `None` if the booking has no conflict.
+    conflict: Option<ImStr>,
+    /// Keyboard focus is on this booking.
+    #[props(default = false)]
+    focused: bool,
+    /// How the booking differs from the other side of a draft comparison.
+    #[props(default = None)]
+    diff: Option<DraftDiffKind>,
+    /// Booking and slot shown in the provenance popover on click, `None` if
+    /// the chip has no popover.
+    #[props(default = None)]
+    provenance: Option<(Booking, Slot)>,
+    /// Another planner just added the booking.
+    #[props(default = false)]
+    live: bool,
 }
 
 #[component]
@@ -852,9 +1149,60 @@ fn WeekCellChip(props: WeekCellChipProps) -> Element {
     let item_clicked = props.item_clicked;
     let tooltip = props.tooltip.clone();
     let tooltip_for_touch = props.tooltip.clone();
+    let drag_started = props.drag_started;
+    let drag_ended = props.drag_ended;
+    let has_provenance = props.provenance.is_some();
+    let mut popover_at = use_signal(|| None::<(f64, f64)>);
     rsx! {
         div {
+            class: format!(
+                "{} {}",
+                if drag_started.is_some() {
+                    "cursor-grab"
+                } else if has_provenance {
+                    "cursor-pointer"
+                } else {
+                    ""
+                },
+                if props.focused {
+                    "rounded-sm ring-2 ring-accent"
+                } else if props.conflict.is_some() {
+                    "rounded-sm ring-2 ring-bad"
+                } else if props.live {
+                    "rounded-sm ring-2 ring-accent animate-pulse"
+                } else {
+                    match props.diff {
+                        Some(DraftDiffKind::Added) => "rounded-sm ring-2 ring-good",
+                        Some(DraftDiffKind::Removed) => "rounded-sm ring-2 ring-warn opacity-70",
+                        None => "",
+                    }
+                },
+            ),
+            title: props.conflict.clone(),
+            draggable: drag_started.is_some(),
+            ondragstart: move |evt: Event<DragData>| {
+                if let Some(task) = timeout_task.read().as_ref() {
+                    task.cancel();
+                }
+                *timeout_task.write() = None;
+                if let Some(handler) = drag_started {
+                    mark_drag_as_move(&evt, &id.to_string());
+                    handler.call(id);
+                }
+            },
+            ondragend: move |_| {
+                if let Some(handler) = drag_ended {
+                    handler.call(());
+                }
+            },
+            onclick: move |e: Event<MouseData>| {
+                if has_provenance {
+                    let coords = e.data().client_coordinates();
+                    popover_at.set(Some((coords.x, coords.y)));
+                }
+            },
             ondoubleclick: move |_| {
+                popover_at.set(None);
                 if let Some(handler) = item_clicked {
                     handler.call(id);
                 }
@@ -933,11 +1281,25 @@ fn WeekCellChip(props: WeekCellChipProps) -> Element {
                 *timeout_task.write() = None;
             },
             PersonChip {
-                name: props.label.clone(),
+                name: match (props.conflict.is_some(), props.diff) {
+                    (true, _) => ImStr::from(format!("⚠ {}", props.label)),
+                    (false, Some(DraftDiffKind::Added)) => ImStr::from(format!("+ {}", props.label)),
+                    (false, Some(DraftDiffKind::Removed)) => ImStr::from(format!("− {}", props.label)),
+                    (false, None) => props.label.clone(),
+                },
                 color: Some(props.color.clone()),
                 bold: props.bold,
             }
         }
+        if let (Some((x, y)), Some((booking, slot))) = (*popover_at.read(), props.provenance.clone()) {
+            BookingProvenancePopover {
+                booking,
+                slot,
+                x,
+                y,
+                on_close: move |_| popover_at.set(None),
+            }
+        }
     }
 }
 
@@ -976,6 +1338,63 @@ pub(crate) fn min_resources_class(missing: bool) -> &'static str {
     }
 }
 
+/// Staffing of a slot compared to its `min_resources`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Staffing {
+    Under,
+    Met,
+    Over,
+}
+
+pub(crate) fn staffing(filled: usize, need: usize) -> Staffing {
+    match filled.cmp(&need) {
+        std::cmp::Ordering::Less => Staffing::Under,
+        std::cmp::Ordering::Equal => Staffing::Met,
+        std::cmp::Ordering::Greater => Staffing::Over,
+    }
+}
+
+/// Cell background with staffing highlighting. Unavailable days keep their
+/// `bad` tint; with `highlight` off nothing else is tinted.
+pub(crate) fn staffing_background_class(
+    staffing: Staffing,
+    discourage: bool,
+    highlight: bool,
+) -> &'static str {
+    match staffing {
+        _ if !highlight => cell_background_class(false, discourage),
+        Staffing::Over if !discourage => "bg-accent-soft",
+        staffing => cell_background_class(staffing == Staffing::Under, discourage),
+    }
+}
+
+/// Legend for the tints and markers of the week grid.
+#[component]
+pub fn StaffingLegend() -> Element {
+    let i18n = I18N.read().clone();
+    let swatch = "inline-block w-3 h-3 rounded-sm border border-border";
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-3 text-small text-ink-muted",
+            span { class: "inline-flex items-center gap-1.5",
+                span { class: "{swatch} bg-warn-soft" }
+                "{i18n.t(Key::StaffingLegendUnder)}"
+            }
+            span { class: "inline-flex items-center gap-1.5",
+                span { class: "{swatch} bg-accent-soft" }
+                "{i18n.t(Key::StaffingLegendOver)}"
+            }
+            span { class: "inline-flex items-center gap-1.5",
+                span { class: "{swatch} bg-bad-soft" }
+                "{i18n.t(Key::StaffingLegendUnavailable)}"
+            }
+            span { class: "inline-flex items-center gap-1.5",
+                span { class: "inline-block w-3 h-3 rounded-sm ring-2 ring-bad" }
+                "{i18n.t(Key::StaffingLegendConflict)}"
+            }
+        }
+    }
+}
+
 #[derive(PartialEq, Clone, Props)]
 struct WeekDayHeaderProps {
     weekday: Weekday,
@@ -1018,11 +1437,41 @@ pub struct WeekCellSlotProps {
     pub highlight_item_id: Option<Uuid>,
     pub add_event: Option<EventHandler<Slot>>,
     pub remove_event: Option<EventHandler<Slot>>,
+    pub move_event: Option<EventHandler<BookingMove>>,
     pub item_clicked: Option<EventHandler<Uuid>>,
     pub discourage: bool,
     pub button_types: WeekViewButtonTypes,
     pub dropdown_entries: Option<Rc<[DropdownEntry]>>,
     pub is_shiftplanner: bool,
+    #[props(default = Rc::new([]))]
+    pub proposed: Rc<[AutoFillAssignment]>,
+    /// People whose booking in this slot comes from a recurring pattern.
+    #[props(default = Rc::new([]))]
+    pub recurring: Rc<[Uuid]>,
+    /// People whose booking in this slot is reported as a conflict.
+    #[props(default = Rc::new([]))]
+    pub conflicts: Rc<[Uuid]>,
+    /// People whose booking in this slot differs in a draft comparison.
+    #[props(default = Rc::new([]))]
+    pub diff: Rc<[(Uuid, DraftDiffKind)]>,
+    #[props(default = false)]
+    pub week_published: bool,
+    /// Bookings in this slot another planner just added.
+    #[props(default = Rc::new([]))]
+    pub live_bookings: Rc<[Uuid]>,
+    /// Another planner just changed the slot or removed one of its bookings.
+    #[props(default = false)]
+    pub live_slot: bool,
+    /// Queued changes of this slot which have not reached the backend.
+    #[props(default = Rc::new([]))]
+    pub pending: Rc<[PendingBooking]>,
+    #[props(default = true)]
+    pub highlight_staffing: bool,
+    /// Keyboard focus inside this slot, if any.
+    #[props(default = None)]
+    pub focus: Option<GridFocus>,
+    /// Called when the cell is clicked so the keyboard focus follows.
+    pub on_focus: Option<EventHandler<GridFocus>>,
 }
 
 #[component]
@@ -1033,13 +1482,17 @@ pub fn WeekCellSlot(props: WeekCellSlotProps) -> Element {
     let height = (slot.to_hour() - slot.from_hour()) * SCALING;
     let filled = slot.bookings.len();
     let need = slot.min_resources as usize;
-    let missing = filled < need;
-    let bg_class = cell_background_class(missing, props.discourage);
-    let mr_class = min_resources_class(missing);
+    let staffing = staffing(filled, need);
+    let bg_class = staffing_background_class(staffing, props.discourage, props.highlight_staffing);
+    let mr_class = min_resources_class(props.highlight_staffing && staffing == Staffing::Under);
 
     let booking_ids: Vec<Uuid> = slot.bookings.iter().map(|b| b.sales_person_id).collect();
-    let cell_button =
-        resolve_cell_button(&props.button_types, props.highlight_item_id, &booking_ids);
+    let cell_button = resolve_cell_button(
+        &props.button_types,
+        props.highlight_item_id,
+        &booking_ids,
+        props.week_published && !props.is_shiftplanner,
+    );
     let btn_class = cell_button_classes(cell_button);
 
     let filled_str = i18n.t_m_rc(
@@ -1052,20 +1505,91 @@ pub fn WeekCellSlot(props: WeekCellSlotProps) -> Element {
     );
 
     let add_label: ImStr = i18n.t(Key::ShiftplanCellAddTitle).as_ref().into();
+    let proposed_label: ImStr = i18n.t(Key::AutoFillProposed).as_ref().into();
     let remove_label: ImStr = i18n.t(Key::ShiftplanCellRemoveTitle).as_ref().into();
+    let conflict_label: ImStr = i18n.t(Key::StaffingLegendConflict).as_ref().into();
+    let pending_label: ImStr = i18n.t(Key::OfflineQueuePendingChip).as_ref().into();
+    let rejected_label: ImStr = i18n.t(Key::OfflineQueueRejectedChip).as_ref().into();
 
     let slot_for_add = slot.clone();
     let slot_for_remove = slot.clone();
+    let slot_for_drop = slot.clone();
     let slot_id_str: ImStr = slot.id.to_string().into();
 
+    // Drag-and-drop moves are only offered where the `+`/`−` buttons are.
+    let drag = try_use_context::<BookingDrag>()
+        .filter(|_| props.move_event.is_some())
+        .filter(|_| props.button_types == WeekViewButtonTypes::AddRemove);
+    let mut drop_hover = use_signal(|| false);
+    let accepts = drag
+        .and_then(|drag| *drag.0.read())
+        .is_some_and(|dragged| accepts_drop(&dragged, slot));
+    let slot_id = slot.id;
+    let drag_started = drag.map(|drag| {
+        EventHandler::new(move |sales_person_id: Uuid| {
+            let BookingDrag(mut dragged) = drag;
+            dragged.set(Some(DraggedBooking {
+                sales_person_id,
+                from_slot_id: slot_id,
+            }));
+        })
+    });
+    let drag_ended = drag.map(|drag| {
+        EventHandler::new(move |_| {
+            let BookingDrag(mut dragged) = drag;
+            dragged.set(None);
+        })
+    });
+
+    let slot_focused = props.focus == Some(GridFocus::slot(slot_id));
+    let on_focus = props.on_focus;
+
     rsx! {
         div {
             class: format!(
-                "absolute left-0 right-0 border-t border-border overflow-hidden {} {}",
-                bg_class,
+                "absolute left-0 right-0 border-t border-border overflow-hidden {} {} {}",
+                if accepts && *drop_hover.read() { "bg-accent-soft" } else { bg_class },
                 if props.discourage { "cursor-not-allowed" } else { "" },
+                if slot_focused {
+                    "ring-2 ring-inset ring-accent z-10"
+                } else if props.live_slot {
+                    "ring-2 ring-inset ring-accent animate-pulse"
+                } else {
+                    ""
+                },
             ),
             style: format!("top: {}px; height: {}px;", top, height),
+            onclick: move |_| {
+                if let Some(handler) = on_focus {
+                    handler.call(GridFocus::slot(slot_id));
+                }
+            },
+            ondragover: move |evt| {
+                if accepts {
+                    evt.prevent_default();
+                    drop_hover.set(true);
+                }
+            },
+            ondragleave: move |_| drop_hover.set(false),
+            ondrop: move |evt| {
+                evt.prevent_default();
+                drop_hover.set(false);
+                let Some(BookingDrag(mut dragged)) = drag else {
+                    return;
+                };
+                let Some(dragged_booking) = dragged.take() else {
+                    return;
+                };
+                if let Some(handler) = &props.move_event {
+                    if accepts_drop(&dragged_booking, &slot_for_drop) {
+                        handler.call(BookingMove {
+                            sales_person_id: dragged_booking.sales_person_id,
+                            from_slot_id: dragged_booking.from_slot_id,
+                            to_slot_id: slot_for_drop.id,
+                        });
+                    }
+                }
+            },
             span {
                 class: format!("font-mono text-small font-bold {}", mr_class),
                 style: "position: absolute; top: 6px; left: 8px; pointer-events: none; line-height: 18px;",
@@ -1074,15 +1598,27 @@ pub fn WeekCellSlot(props: WeekCellSlotProps) -> Element {
             div {
                 class: "flex flex-wrap content-start gap-1 overflow-y-auto overflow-x-hidden",
                 style: "position: absolute; inset: 6px 32px 6px 38px;",
-                for booking in slot.bookings.iter() {
+                for booking in slot.bookings.iter().filter(|booking| {
+                    !props
+                        .pending
+                        .iter()
+                        .any(|pending| pending.removal && pending.key.sales_person_id == booking.sales_person_id)
+                }) {
                     {
-                        let label: ImStr = if booking.self_added {
-                            format!("{}*", booking.label).into()
-                        } else {
-                            booking.label.to_string().into()
+                        let label: ImStr = match (
+                            booking.self_added,
+                            props.recurring.contains(&booking.sales_person_id),
+                        ) {
+                            (true, true) => format!("{}* ↻", booking.label).into(),
+                            (true, false) => format!("{}*", booking.label).into(),
+                            (false, true) => format!("{} ↻", booking.label).into(),
+                            (false, false) => booking.label.to_string().into(),
                         };
                         let color: ImStr = booking.background_color.to_string().into();
                         let bold = Some(booking.sales_person_id) == props.highlight_item_id;
+                        let conflict = (props.highlight_staffing
+                            && props.conflicts.contains(&booking.sales_person_id))
+                            .then(|| conflict_label.clone());
                         let tooltip = if props.is_shiftplanner {
                             match (&booking.created, &booking.created_by) {
                                 (Some(created), Some(created_by)) => {
@@ -1109,10 +1645,51 @@ pub fn WeekCellSlot(props: WeekCellSlotProps) -> Element {
                                 tooltip,
                                 sales_person_id: booking.sales_person_id,
                                 item_clicked: props.item_clicked,
+                                drag_started,
+                                drag_ended,
+                                conflict,
+                                focused: props.focus.is_some_and(|focus| focus.sales_person_id == Some(booking.sales_person_id)),
+                                diff: props
+                                    .diff
+                                    .iter()
+                                    .find(|(sales_person_id, _)| *sales_person_id == booking.sales_person_id)
+                                    .map(|(_, kind)| *kind),
+                                provenance: props
+                                    .is_shiftplanner
+                                    .then(|| (booking.clone(), slot.clone())),
+                                live: props.live_bookings.contains(&booking.id),
                             }
                         }
                     }
                 }
+                for assignment in props.proposed.iter() {
+                    span {
+                        class: "rounded-sm outline-dashed outline-1 outline-good opacity-80",
+                        title: "{proposed_label}",
+                        PersonChip {
+                            name: ImStr::from(format!("+ {}", assignment.sales_person_name)),
+                            color: Some(ImStr::from(assignment.background_color.as_ref())),
+                            bold: false,
+                        }
+                    }
+                }
+                for pending in props.pending.iter() {
+                    span {
+                        class: if pending.failed {
+                            "rounded-sm outline-dashed outline-1 outline-bad opacity-80"
+                        } else {
+                            "rounded-sm outline-dashed outline-1 outline-warn opacity-80"
+                        },
+                        title: if pending.failed { "{rejected_label}" } else { "{pending_label}" },
+                        PersonChip {
+                            name: ImStr::from(
+                                format!("{} {}", if pending.removal { "−" } else { "+" }, pending.name),
+                            ),
+                            color: Some(ImStr::from(pending.background_color.as_ref())),
+                            bold: false,
+                        }
+                    }
+                }
             }
             match cell_button {
                 CellButton::Add => rsx! {
@@ -1187,6 +1764,8 @@ pub fn WeekView(props: WeekViewProps) -> Element {
         .iter()
         .any(|slot| slot.day_of_week == Weekday::Sunday);
     let mut zoom = use_signal(|| Zoom::Full);
+    let mut focus = use_signal(|| None::<GridFocus>);
+    use_context_provider(|| BookingDrag(Signal::new(None)));
     let zoom_class = match *zoom.read() {
         Zoom::Full => "scale-down-100",
         Zoom::Half => "scale-down-50",
@@ -1228,8 +1807,32 @@ pub fn WeekView(props: WeekViewProps) -> Element {
     );
     let day_col_style = format!("height: {}px;", body_height);
 
+    let on_grid_command = props.on_grid_command;
+    let keyboard_enabled = on_grid_command.is_some();
+    let keyboard_slots = props.shiftplan_data.slots.clone();
+    let keyboard_days = visible_days.clone();
+
     rsx! {
-        div { class: "bg-surface border border-border rounded-lg overflow-auto print:overflow-visible",
+        div {
+            class: "bg-surface border border-border rounded-lg overflow-auto print:overflow-visible focus:outline-none focus-visible:ring-2 focus-visible:ring-accent",
+            tabindex: if keyboard_enabled { "0" } else { "-1" },
+            onkeydown: move |evt: KeyboardEvent| {
+                let Some(handler) = on_grid_command else {
+                    return;
+                };
+                if evt.modifiers().ctrl() || evt.modifiers().meta() || evt.modifiers().alt() {
+                    return;
+                }
+                let Some(key) = GridKey::parse(&evt.key().to_string()) else {
+                    return;
+                };
+                evt.prevent_default();
+                let current = *focus.read();
+                match grid_command(key, current) {
+                    Some(command) => handler.call(command),
+                    None => focus.set(move_focus(&keyboard_slots, &keyboard_days, current, key)),
+                }
+            },
             div { class: "fixed bottom-4 left-4 z-50 border border-border bg-surface p-2 rounded-md shadow-lg 2xl:hidden print:hidden",
                 label { class: "text-small font-normal text-ink-muted", "Zoom: " }
                 select {
@@ -1316,11 +1919,53 @@ pub fn WeekView(props: WeekViewProps) -> Element {
                                             highlight_item_id: props.highlight_item_id,
                                             add_event: props.add_event,
                                             remove_event: props.remove_event,
+                                            move_event: props.move_event,
                                             item_clicked: props.item_clicked,
                                             discourage,
                                             button_types: props.button_types.clone(),
                                             dropdown_entries: props.dropdown_entries.clone(),
                                             is_shiftplanner: props.is_shiftplanner,
+                                            proposed: props
+                                                .proposed_bookings
+                                                .iter()
+                                                .filter(|assignment| assignment.key.slot_id == slot.id)
+                                                .cloned()
+                                                .collect::<Rc<[AutoFillAssignment]>>(),
+                                            recurring: props
+                                                .recurring_bookings
+                                                .iter()
+                                                .filter(|key| key.slot_id == slot.id)
+                                                .map(|key| key.sales_person_id)
+                                                .collect::<Rc<[Uuid]>>(),
+                                            conflicts: props
+                                                .conflicts
+                                                .iter()
+                                                .filter(|conflict| conflict.slot_id == slot.id)
+                                                .map(|conflict| conflict.sales_person_id)
+                                                .collect::<Rc<[Uuid]>>(),
+                                            diff: props
+                                                .diff_marks
+                                                .iter()
+                                                .filter(|mark| mark.slot_id == slot.id)
+                                                .map(|mark| (mark.sales_person_id, mark.kind))
+                                                .collect::<Rc<[(Uuid, DraftDiffKind)]>>(),
+                                            week_published: props.week_published,
+                                            live_bookings: slot
+                                                .bookings
+                                                .iter()
+                                                .map(|booking| booking.id)
+                                                .filter(|id| props.live_changes.bookings.contains(id))
+                                                .collect::<Rc<[Uuid]>>(),
+                                            live_slot: props.live_changes.slots.contains(&slot.id),
+                                            pending: props
+                                                .pending_bookings
+                                                .iter()
+                                                .filter(|pending| pending.key.slot_id == slot.id)
+                                                .cloned()
+                                                .collect::<Rc<[PendingBooking]>>(),
+                                            highlight_staffing: props.highlight_staffing,
+                                            focus: focus.read().filter(|focus| focus.slot_id == slot.id),
+                                            on_focus: keyboard_enabled.then_some(EventHandler::new(move |target: GridFocus| focus.set(Some(target)))),
                                         }
                                     }
                                 }
@@ -1438,6 +2083,235 @@ mod week_cell_slot_render_tests {
         );
     }
 
+    #[test]
+    fn proposed_bookings_render_as_dashed_chips() {
+        fn app() -> Element {
+            let slot = super::week_cell_slot_render_tests::make_slot(0, 1);
+            let proposed: Rc<[AutoFillAssignment]> = [AutoFillAssignment {
+                key: state::booking_history::BookingKey {
+                    sales_person_id: Uuid::from_u128(7),
+                    slot_id: slot.id,
+                    week: 17,
+                    year: 2026,
+                },
+                sales_person_name: "Anna".into(),
+                background_color: "#dbe0ff".into(),
+            }]
+            .into();
+            rsx! {
+                WeekCellSlot {
+                    slot,
+                    day_start: 9.0,
+                    highlight_item_id: None,
+                    add_event: None,
+                    remove_event: None,
+                    item_clicked: None,
+                    discourage: false,
+                    button_types: WeekViewButtonTypes::None,
+                    dropdown_entries: None,
+                    is_shiftplanner: true,
+                    proposed,
+                }
+            }
+        }
+        let html = render(app);
+        assert!(html.contains("+ Anna"), "{html}");
+        assert!(html.contains("outline-dashed"), "{html}");
+    }
+
+    #[test]
+    fn recurring_bookings_carry_a_marker() {
+        fn app() -> Element {
+            let slot = super::week_cell_slot_render_tests::make_slot(2, 2);
+            let recurring: Rc<[Uuid]> = [slot.bookings[1].sales_person_id].into();
+            rsx! {
+                WeekCellSlot {
+                    slot,
+                    day_start: 9.0,
+                    highlight_item_id: None,
+                    add_event: None,
+                    remove_event: None,
+                    item_clicked: None,
+                    discourage: false,
+                    button_types: WeekViewButtonTypes::None,
+                    dropdown_entries: None,
+                    is_shiftplanner: false,
+                    recurring,
+                }
+            }
+        }
+        let html = render_with_tooltip(app);
+        assert!(html.contains("P2 ↻"), "{html}");
+        assert!(!html.contains("P1 ↻"), "{html}");
+    }
+
+    #[test]
+    fn conflicting_bookings_carry_a_marker() {
+        fn app() -> Element {
+            let slot = super::week_cell_slot_render_tests::make_slot(2, 2);
+            let conflicts: Rc<[Uuid]> = [slot.bookings[0].sales_person_id].into();
+            rsx! {
+                WeekCellSlot {
+                    slot,
+                    day_start: 9.0,
+                    highlight_item_id: None,
+                    add_event: None,
+                    remove_event: None,
+                    item_clicked: None,
+                    discourage: false,
+                    button_types: WeekViewButtonTypes::None,
+                    dropdown_entries: None,
+                    is_shiftplanner: false,
+                    conflicts,
+                }
+            }
+        }
+        let html = render_with_tooltip(app);
+        assert!(html.contains("⚠ P1"), "{html}");
+        assert!(!html.contains("⚠ P2"), "{html}");
+        assert!(html.contains("ring-bad"), "{html}");
+    }
+
+    #[test]
+    fn draft_diff_marks_added_and_removed_bookings() {
+        fn app() -> Element {
+            let slot = super::week_cell_slot_render_tests::make_slot(2, 3);
+            let diff: Rc<[(Uuid, DraftDiffKind)]> = [
+                (slot.bookings[0].sales_person_id, DraftDiffKind::Added),
+                (slot.bookings[1].sales_person_id, DraftDiffKind::Removed),
+            ]
+            .into();
+            rsx! {
+                WeekCellSlot {
+                    slot,
+                    day_start: 9.0,
+                    highlight_item_id: None,
+                    add_event: None,
+                    remove_event: None,
+                    item_clicked: None,
+                    discourage: false,
+                    button_types: WeekViewButtonTypes::None,
+                    dropdown_entries: None,
+                    is_shiftplanner: false,
+                    diff,
+                }
+            }
+        }
+        let html = render_with_tooltip(app);
+        assert!(html.contains("+ P1"), "{html}");
+        assert!(html.contains("− P2"), "{html}");
+        assert!(!html.contains("+ P3") && !html.contains("− P3"), "{html}");
+        assert!(html.contains("ring-good"), "{html}");
+        assert!(html.contains("ring-warn"), "{html}");
+    }
+
+    #[test]
+    fn pending_changes_replace_the_booking_chip() {
+        fn app() -> Element {
+            let slot = super::week_cell_slot_render_tests::make_slot(2, 2);
+            let pending: Rc<[PendingBooking]> = [
+                PendingBooking {
+                    key: BookingKey::from(&slot.bookings[0]),
+                    name: "P1".into(),
+                    background_color: "#ffffff".into(),
+                    removal: true,
+                    failed: false,
+                },
+                PendingBooking {
+                    key: BookingKey {
+                        sales_person_id: Uuid::from_u128(7),
+                        ..BookingKey::from(&slot.bookings[0])
+                    },
+                    name: "Anna".into(),
+                    background_color: "#dbe0ff".into(),
+                    removal: false,
+                    failed: true,
+                },
+            ]
+            .into();
+            rsx! {
+                WeekCellSlot {
+                    slot,
+                    day_start: 9.0,
+                    highlight_item_id: None,
+                    add_event: None,
+                    remove_event: None,
+                    item_clicked: None,
+                    discourage: false,
+                    button_types: WeekViewButtonTypes::None,
+                    dropdown_entries: None,
+                    is_shiftplanner: false,
+                    pending,
+                }
+            }
+        }
+        let html = render_with_tooltip(app);
+        assert!(html.contains("− P1"), "{html}");
+        assert!(html.contains("+ Anna"), "{html}");
+        assert!(
+            html.contains("outline-warn") && html.contains("outline-bad"),
+            "{html}"
+        );
+        assert_eq!(html.matches(">P1<").count(), 0, "{html}");
+    }
+
+    #[test]
+    fn live_changes_pulse_the_booking_and_the_slot() {
+        fn app() -> Element {
+            let slot = super::week_cell_slot_render_tests::make_slot(2, 3);
+            let live_bookings: Rc<[Uuid]> = [slot.bookings[1].id].into();
+            rsx! {
+                WeekCellSlot {
+                    slot,
+                    day_start: 9.0,
+                    highlight_item_id: None,
+                    add_event: None,
+                    remove_event: None,
+                    item_clicked: None,
+                    discourage: false,
+                    button_types: WeekViewButtonTypes::None,
+                    dropdown_entries: None,
+                    is_shiftplanner: false,
+                    live_bookings,
+                    live_slot: true,
+                }
+            }
+        }
+        let html = render_with_tooltip(app);
+        assert_eq!(html.matches("animate-pulse").count(), 2, "{html}");
+        assert!(
+            html.contains("ring-inset ring-accent animate-pulse"),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn conflict_markers_and_tints_are_hidden_when_highlighting_is_off() {
+        fn app() -> Element {
+            let slot = super::week_cell_slot_render_tests::make_slot(3, 2);
+            let conflicts: Rc<[Uuid]> = [slot.bookings[0].sales_person_id].into();
+            rsx! {
+                WeekCellSlot {
+                    slot,
+                    day_start: 9.0,
+                    highlight_item_id: None,
+                    add_event: None,
+                    remove_event: None,
+                    item_clicked: None,
+                    discourage: false,
+                    button_types: WeekViewButtonTypes::None,
+                    dropdown_entries: None,
+                    is_shiftplanner: false,
+                    conflicts,
+                    highlight_staffing: false,
+                }
+            }
+        }
+        let html = render_with_tooltip(app);
+        assert!(!html.contains("⚠"), "{html}");
+        assert!(!html.contains("bg-accent-soft"), "{html}");
+    }
+
     #[test]
     fn min_resources_span_is_absolutely_positioned_with_pointer_events_none() {
         fn app() -> Element {
@@ -1534,6 +2408,60 @@ mod week_cell_slot_render_tests {
             chip_area_close_idx,
         );
     }
+
+    #[test]
+    fn chips_are_draggable_when_moves_are_enabled() {
+        fn app() -> Element {
+            use_context_provider(|| BookingDrag(Signal::new(None)));
+            let slot = super::week_cell_slot_render_tests::make_slot(2, 2);
+            rsx! {
+                WeekCellSlot {
+                    slot,
+                    day_start: 9.0,
+                    highlight_item_id: None,
+                    add_event: None,
+                    remove_event: None,
+                    move_event: Some(EventHandler::new(|_: BookingMove| {})),
+                    item_clicked: None,
+                    discourage: false,
+                    button_types: WeekViewButtonTypes::AddRemove,
+                    dropdown_entries: None,
+                    is_shiftplanner: true,
+                }
+            }
+        }
+        let html = render_with_tooltip(app);
+        assert_eq!(
+            html.matches("draggable=true").count(),
+            2,
+            "expected two draggable chips: {html}"
+        );
+    }
+
+    #[test]
+    fn chips_are_not_draggable_in_read_only_mode() {
+        fn app() -> Element {
+            use_context_provider(|| BookingDrag(Signal::new(None)));
+            let slot = super::week_cell_slot_render_tests::make_slot(2, 2);
+            rsx! {
+                WeekCellSlot {
+                    slot,
+                    day_start: 9.0,
+                    highlight_item_id: None,
+                    add_event: None,
+                    remove_event: None,
+                    move_event: Some(EventHandler::new(|_: BookingMove| {})),
+                    item_clicked: None,
+                    discourage: false,
+                    button_types: WeekViewButtonTypes::None,
+                    dropdown_entries: None,
+                    is_shiftplanner: true,
+                }
+            }
+        }
+        let html = render_with_tooltip(app);
+        assert!(!html.contains("draggable=true"), "{html}");
+    }
 }
 
 #[cfg(test)]
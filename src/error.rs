@@ -1,6 +1,34 @@
 use reqwest::StatusCode;
+use rest_types::{
+    CustomExtraHoursTO, EmployeeWorkDetailsTO, ExtraHoursTO, SalesPersonTO, ShiftplanTO,
+    TextTemplateTO, WeekMessageTO,
+};
 use thiserror::Error;
 
+use crate::service::error::{ErrorStore, ERROR_STORE};
+
+/// An entity which is saved with a versioned PUT.
+#[derive(Clone, Debug)]
+pub enum ConflictEntity {
+    SalesPerson(SalesPersonTO),
+    EmployeeWorkDetails(EmployeeWorkDetailsTO),
+    ExtraHours(ExtraHoursTO),
+    CustomExtraHours(CustomExtraHoursTO),
+    TextTemplate(TextTemplateTO),
+    WeekMessage(WeekMessageTO),
+    Shiftplan(ShiftplanTO),
+}
+
+/// A versioned PUT was rejected because someone else saved the entity in
+/// the meantime.
+#[derive(Clone, Debug)]
+pub struct VersionConflict {
+    /// What the user tried to save.
+    pub yours: ConflictEntity,
+    /// What the server has now, including its current version.
+    pub theirs: ConflictEntity,
+}
+
 #[derive(Error, Debug)]
 pub enum ShiftyError {
     #[error("reqwest error: {0}")]
@@ -13,6 +41,47 @@ pub enum ShiftyError {
     /// The wrapped string is the user-facing message (already translated).
     #[error("{0}")]
     Conflict(String),
+
+    /// HTTP 409 on a versioned PUT with both sides of the conflict, so the
+    /// user can decide which one to keep.
+    #[error("The entry was changed by someone else in the meantime")]
+    VersionConflict(Box<VersionConflict>),
+
+    /// Failure reported by a non-HTTP backend, e.g. an unknown id in the
+    /// in-memory store.
+    #[error("backend error: {0}")]
+    Backend(String),
+
+    /// A week range covers more weeks than one operation may touch.
+    #[error("The range covers {weeks} weeks, at most {max} are allowed")]
+    WeekRangeTooLong { weeks: usize, max: usize },
+}
+
+impl ShiftyError {
+    /// The request did not reach the backend or a gateway in front of it
+    /// gave up, so sending it again later may succeed.
+    pub fn is_connectivity(&self) -> bool {
+        match self {
+            ShiftyError::Reqwest(e) => match e.status() {
+                Some(status) => is_gateway_failure(status),
+                None => is_transport_failure(e),
+            },
+            _ => false,
+        }
+    }
+}
+
+/// A gateway in front of the backend gave up on the request.
+pub fn is_gateway_failure(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// The request never got an answer, it failed on the way or timed out.
+pub fn is_transport_failure(e: &reqwest::Error) -> bool {
+    e.is_request() || e.is_timeout()
 }
 
 pub fn error_handler(e: ShiftyError) {
@@ -29,6 +98,19 @@ pub fn error_handler(e: ShiftyError) {
         ShiftyError::Conflict(msg) => {
             eprintln!("Conflict: {}", msg);
         }
+        ShiftyError::VersionConflict(conflict) => {
+            eprintln!("Version conflict: {:?}", conflict.theirs);
+            // Picked up by the conflict dialog.
+            *ERROR_STORE.write() = ErrorStore {
+                error: Some(ShiftyError::VersionConflict(conflict)),
+            };
+        }
+        ShiftyError::Backend(msg) => {
+            eprintln!("Error: {}", msg);
+        }
+        ShiftyError::WeekRangeTooLong { .. } => {
+            eprintln!("Error: {}", e);
+        }
     }
 }
 
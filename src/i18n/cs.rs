@@ -22,6 +22,11 @@ pub fn add_i18n_cs(i18n: &mut I18n<Key, Locale>) {
     i18n.add_text(Locale::Cs, Key::Logout, "Odhlásit");
     i18n.add_text(Locale::Cs, Key::TopBarYouAreLabel, "Jsi");
     i18n.add_text(Locale::Cs, Key::TopBarAdminGroupLabel, "Správa");
+    i18n.add_text(
+        Locale::Cs,
+        Key::TopBarResetDemoData,
+        "Obnovit ukázková data",
+    );
 
     // Shiftplan
     i18n.add_text(
@@ -81,6 +86,536 @@ pub fn add_i18n_cs(i18n: &mut I18n<Key, Locale>) {
         "Opravdu chcete smazat směnný plán {name}? Tuto akci nelze vrátit zpět.",
     );
     i18n.add_text(Locale::Cs, Key::ShiftplanIsPlanningLabel, "Pouze plánování");
+    i18n.add_text(Locale::Cs, Key::ShiftplanHistory, "Historie");
+    i18n.add_text(Locale::Cs, Key::ShiftplanHistoryEmpty, "Zatím žádné změny");
+    i18n.add_text(Locale::Cs, Key::ShiftplanUndo, "Zpět");
+    i18n.add_text(Locale::Cs, Key::ShiftplanRedo, "Znovu");
+    i18n.add_text(Locale::Cs, Key::ShiftplanHistoryAdded, "Přidáno");
+    i18n.add_text(Locale::Cs, Key::ShiftplanHistoryRemoved, "Odebráno");
+    i18n.add_text(Locale::Cs, Key::ShiftplanHistoryMoved, "Přesunuto");
+    i18n.add_text(
+        Locale::Cs,
+        Key::ShiftplanHistoryCopiedWeek,
+        "Týden zkopírován",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::ShiftplanHistoryBookingCount,
+        "{count} rezervací",
+    );
+    i18n.add_text(Locale::Cs, Key::CopyWeekOpen, "Kopírovat týden…");
+    i18n.add_text(Locale::Cs, Key::CopyWeekTitle, "Kopírovat týden");
+    i18n.add_text(Locale::Cs, Key::CopyWeekSource, "Zdrojový týden");
+    i18n.add_text(Locale::Cs, Key::CopyWeekTargetFrom, "První cílový týden");
+    i18n.add_text(
+        Locale::Cs,
+        Key::CopyWeekTargetUntil,
+        "Poslední cílový týden",
+    );
+    i18n.add_text(Locale::Cs, Key::CopyWeekShiftplan, "Plán směn");
+    i18n.add_text(Locale::Cs, Key::CopyWeekAllShiftplans, "Všechny plány směn");
+    i18n.add_text(
+        Locale::Cs,
+        Key::CopyWeekSkipUnavailable,
+        "Přeskočit osoby označené jako nedostupné",
+    );
+    i18n.add_text(Locale::Cs, Key::CopyWeekPreview, "Náhled");
+    i18n.add_text(
+        Locale::Cs,
+        Key::CopyWeekConfirm,
+        "Kopírovat {count} rezervací",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::CopyWeekInvalidRange,
+        "Zvolte platný zdrojový týden a cílový rozsah nejvýše {max} týdnů.",
+    );
+    i18n.add_text(Locale::Cs, Key::CopyWeekWeekHeading, "Týden {week}/{year}");
+    i18n.add_text(Locale::Cs, Key::CopyWeekStatusCreate, "Nové");
+    i18n.add_text(Locale::Cs, Key::CopyWeekStatusConflict, "Konflikt");
+    i18n.add_text(
+        Locale::Cs,
+        Key::CopyWeekStatusSkipped,
+        "Přeskočeno (nedostupné)",
+    );
+    i18n.add_text(Locale::Cs, Key::CopyWeekStatusExisting, "Již rezervováno");
+    i18n.add_text(
+        Locale::Cs,
+        Key::ShiftplanHistoryAutoFilled,
+        "Automaticky doplněno",
+    );
+    i18n.add_text(Locale::Cs, Key::AutoFillOpen, "Automaticky doplnit");
+    i18n.add_text(Locale::Cs, Key::AutoFillProposed, "Navržená rezervace");
+    i18n.add_text(
+        Locale::Cs,
+        Key::AutoFillSummary,
+        "Navrženo rezervací: {count}",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::AutoFillUnfilled,
+        "Neobsazených pozic: {count}",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::AutoFillNothing,
+        "Pro volné směny nebyla nalezena vhodná osoba",
+    );
+    i18n.add_text(Locale::Cs, Key::AutoFillApply, "Použít");
+    i18n.add_text(Locale::Cs, Key::AutoFillDiscard, "Zahodit");
+    i18n.add_text(Locale::Cs, Key::BookingPatterns, "Opakované směny");
+    i18n.add_text(Locale::Cs, Key::BookingPatternNew, "Nová opakovaná směna");
+    i18n.add_text(Locale::Cs, Key::BookingPatternPerson, "Osoba");
+    i18n.add_text(Locale::Cs, Key::BookingPatternShiftplan, "Plán směn");
+    i18n.add_text(Locale::Cs, Key::BookingPatternSlot, "Směna");
+    i18n.add_text(Locale::Cs, Key::BookingPatternInterval, "Každých … týdnů");
+    i18n.add_text(Locale::Cs, Key::BookingPatternFrom, "První týden");
+    i18n.add_text(Locale::Cs, Key::BookingPatternUntil, "Poslední týden");
+    i18n.add_text(Locale::Cs, Key::BookingPatternAdd, "Přidat");
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingPatternEmpty,
+        "Zatím nejsou definovány žádné opakované směny.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingPatternEvery,
+        "každých {weeks} týdnů",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingPatternHorizon,
+        "Vytvořit rezervace do",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingPatternMaterialize,
+        "Vytvořit rezervace",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingPatternMaterializeAll,
+        "Vytvořit rezervace pro všechny",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingPatternResult,
+        "Vytvořeno rezervací: {created}, již existovalo: {existing}, přeskočeno: {skipped}",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingPatternFailed,
+        "Rezervací, které se nepodařilo vytvořit: {failed}. Zkuste to později znovu.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingPatternLocalOnly,
+        "Opakované směny se ukládají pouze v tomto prohlížeči. Ostatní plánovači a zařízení nevidí vzory ani jejich značky v plánu směn; rezervace z nich vytvořené vidí všichni.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingPatternUnknownSlot,
+        "Směna již neexistuje",
+    );
+    i18n.add_text(Locale::Cs, Key::ViewModeMultiWeek, "Týdny");
+    i18n.add_text(Locale::Cs, Key::MultiWeekFourWeeks, "4 týdny");
+    i18n.add_text(Locale::Cs, Key::MultiWeekMonth, "Měsíc");
+    i18n.add_text(Locale::Cs, Key::CoverageWeekLabel, "{week}. týden");
+    i18n.add_text(
+        Locale::Cs,
+        Key::CoverageDayTitle,
+        "{understaffed} z {slots} směn nedostatečně obsazeno",
+    );
+    i18n.add_text(Locale::Cs, Key::CoverageCovered, "Plně obsazeno");
+    i18n.add_text(Locale::Cs, Key::CoveragePartial, "Částečně neobsazeno");
+    i18n.add_text(Locale::Cs, Key::CoverageUncovered, "Převážně neobsazeno");
+    i18n.add_text(Locale::Cs, Key::CoverageNoSlots, "Žádné směny");
+    i18n.add_text(
+        Locale::Cs,
+        Key::CoverageHint,
+        "Kliknutím na týden jej otevřete.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::StaffingHighlight,
+        "Zvýraznit obsazení a konflikty",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::StaffingLegendUnder,
+        "Nedostatečně obsazeno",
+    );
+    i18n.add_text(Locale::Cs, Key::StaffingLegendOver, "Přeobsazeno");
+    i18n.add_text(
+        Locale::Cs,
+        Key::StaffingLegendUnavailable,
+        "Nejste k dispozici",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::StaffingLegendConflict,
+        "Konflikt rezervace",
+    );
+    i18n.add_text(Locale::Cs, Key::PersonPickerTitle, "Zapsat osobu");
+    i18n.add_text(
+        Locale::Cs,
+        Key::PersonPickerFilter,
+        "Filtrovat podle jména…",
+    );
+    i18n.add_text(Locale::Cs, Key::PersonPickerEmpty, "Nikdo neodpovídá.");
+    i18n.add_text(Locale::Cs, Key::ShortcutsTitle, "Klávesové zkratky");
+    i18n.add_text(
+        Locale::Cs,
+        Key::ShortcutsHint,
+        "Klikněte do týdenního plánu a používejte je.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::ShortcutMove,
+        "Přesun mezi směnami, rezervacemi a dny",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::ShortcutPick,
+        "Zapsat osobu do vybrané směny",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::ShortcutRecent,
+        "Zapsat naposledy použitou osobu",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::ShortcutRemove,
+        "Odstranit vybranou rezervaci",
+    );
+    i18n.add_text(Locale::Cs, Key::ShortcutWeek, "Předchozí / další týden");
+    i18n.add_text(Locale::Cs, Key::ShortcutUndo, "Zpět");
+    i18n.add_text(Locale::Cs, Key::ShortcutRedo, "Znovu");
+    i18n.add_text(Locale::Cs, Key::ShortcutHelp, "Zobrazit tento přehled");
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureTitle,
+        "Upravit strukturu směn",
+    );
+    i18n.add_text(Locale::Cs, Key::SlotStructureLoading, "Načítání směn…");
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureEmpty,
+        "Tento plán směn nemá v tomto týdnu žádné směny.",
+    );
+    i18n.add_text(Locale::Cs, Key::SlotStructureValidFrom, "Platné od");
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureValidFromHint,
+        "Všechny změny platí od tohoto týdne. Dřívější týdny si ponechají současnou strukturu.",
+    );
+    i18n.add_text(Locale::Cs, Key::SlotStructureSelected, "Vybráno: {count}");
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureShiftOutOfDay,
+        "Vybrané směny nelze posunout přes půlnoc.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureApplyMinResources,
+        "Nastavit minimum",
+    );
+    i18n.add_text(Locale::Cs, Key::SlotStructureMinResources, "min. {count}");
+    i18n.add_text(Locale::Cs, Key::SlotStructureRemove, "Odebrat");
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureDuplicateSource,
+        "Kopírovat rozvržení z",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureDuplicateShiftplan,
+        "Do plánu směn",
+    );
+    i18n.add_text(Locale::Cs, Key::SlotStructureDuplicateTargets, "Na");
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureDuplicateReplace,
+        "Nahradit existující směny v těchto dnech",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureDuplicate,
+        "Kopírovat rozvržení",
+    );
+    i18n.add_text(Locale::Cs, Key::SlotStructureNew, "nová");
+    i18n.add_text(Locale::Cs, Key::SlotStructureChanged, "změněná");
+    i18n.add_text(Locale::Cs, Key::SlotStructureRemoved, "odebraná");
+    i18n.add_text(Locale::Cs, Key::SlotStructureSave, "Uložit změny ({count})");
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureRejected,
+        "{count} nových směn se překrývá s existujícími a nebyly vytvořeny:",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotStructureFailed,
+        "Uloženo změn: {saved}, nezdařilo se: {count}:",
+    );
+    i18n.add_text(Locale::Cs, Key::SlotTimelineTitle, "Historie směn");
+    i18n.add_text(Locale::Cs, Key::SlotTimelineShiftplan, "Plán směn");
+    i18n.add_text(Locale::Cs, Key::SlotTimelineFromYear, "Od roku");
+    i18n.add_text(Locale::Cs, Key::SlotTimelineUntilYear, "Do roku");
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotTimelineInvalidRange,
+        "Zvolte rozsah nejvýše {max} let.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotTimelineLoading,
+        "Načítání historie směn…",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotTimelineEmpty,
+        "V tomto rozsahu nebyly platné žádné směny.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotTimelineValidRange,
+        "Platná {from} – {to}",
+    );
+    i18n.add_text(Locale::Cs, Key::SlotTimelineValidSince, "Platná od {from}");
+    i18n.add_text(Locale::Cs, Key::SlotTimelineReopen, "Znovu otevřít");
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotTimelineReopenBlocked,
+        "Pozdější směna pokrývá stejný čas, proto tuto směnu nelze znovu otevřít.",
+    );
+    i18n.add_text(Locale::Cs, Key::SlotTimelineEndFrom, "Ukončit před týdnem");
+    i18n.add_text(Locale::Cs, Key::SlotTimelineEnd, "Ukončit směnu");
+    i18n.add_text(Locale::Cs, Key::SlotTimelineChanges, "Změny struktury");
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotTimelineNoChanges,
+        "Struktura se v tomto rozsahu nezměnila.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::SlotTimelineChangeSummary,
+        "KT {week}/{year}: {started} začalo, {ended} skončilo",
+    );
+    i18n.add_text(Locale::Cs, Key::DraftCreateTitle, "Vytvořit koncept");
+    i18n.add_text(Locale::Cs, Key::DraftName, "Název");
+    i18n.add_text(Locale::Cs, Key::DraftNameDefault, "{name} (koncept)");
+    i18n.add_text(
+        Locale::Cs,
+        Key::DraftCreateHint,
+        "Směny a rezervace vybraných týdnů se zkopírují do nového plánovacího rozpisu.",
+    );
+    i18n.add_text(Locale::Cs, Key::DraftFromWeek, "Od týdne");
+    i18n.add_text(Locale::Cs, Key::DraftUntilWeek, "Do týdne");
+    i18n.add_text(
+        Locale::Cs,
+        Key::DraftInvalidRange,
+        "Zvolte platný rozsah týdnů.",
+    );
+    i18n.add_text(Locale::Cs, Key::DraftCreate, "Vytvořit koncept");
+    i18n.add_text(Locale::Cs, Key::DraftCompareWith, "Koncept pro");
+    i18n.add_text(Locale::Cs, Key::DraftNotLinked, "Nepropojeno");
+    i18n.add_text(Locale::Cs, Key::DraftShowDiff, "Porovnat s živým plánem");
+    i18n.add_text(Locale::Cs, Key::DraftLivePlan, "Živý plán");
+    i18n.add_text(Locale::Cs, Key::DraftPlan, "Koncept");
+    i18n.add_text(
+        Locale::Cs,
+        Key::DraftDiffSummary,
+        "{added} přidáno, {removed} odebráno",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::DraftSlotsOnlyInDraft,
+        "{count} směn pouze v konceptu nebude zveřejněno",
+    );
+    i18n.add_text(Locale::Cs, Key::DraftPublishOpen, "Zveřejnit…");
+    i18n.add_text(Locale::Cs, Key::DraftPublishTitle, "Zveřejnit koncept");
+    i18n.add_text(
+        Locale::Cs,
+        Key::DraftPublishHint,
+        "Rezervace směn, které existují v obou plánech, se zapíší do živého plánu. Struktura směn živého plánu se nemění.",
+    );
+    i18n.add_text(Locale::Cs, Key::DraftPublish, "Zveřejnit");
+    i18n.add_text(
+        Locale::Cs,
+        Key::DraftPublishReport,
+        "Zveřejněno týdnů: {weeks}: {added} přidáno, {removed} odebráno, {skipped} směn přeskočeno",
+    );
+    i18n.add_text(Locale::Cs, Key::WeekPublish, "Zveřejnit týden");
+    i18n.add_text(Locale::Cs, Key::WeekUnpublish, "Zrušit zveřejnění");
+    i18n.add_text(Locale::Cs, Key::WeekPublished, "Zveřejněno");
+    i18n.add_text(
+        Locale::Cs,
+        Key::WeekPublishedBy,
+        "Zveřejněno {date} {time} uživatelem {user}",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::WeekPublishedLocked,
+        "Tento týden je zveřejněn. Pro změny kontaktujte plánování směn.",
+    );
+    i18n.add_text(Locale::Cs, Key::BookingChangesOpen, "Změny od…");
+    i18n.add_text(Locale::Cs, Key::BookingChangesHide, "Skrýt změny");
+    i18n.add_text(Locale::Cs, Key::BookingChangesTitle, "Změny od");
+    i18n.add_text(Locale::Cs, Key::BookingChangesSince, "Od");
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingChangesSummary,
+        "{added} přidáno, {removed} odebráno",
+    );
+    i18n.add_text(Locale::Cs, Key::BookingChangesNone, "Žádné změny.");
+    i18n.add_text(Locale::Cs, Key::BookingChangesCopy, "Kopírovat jako text");
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingChangesCopied,
+        "Zkopírováno do schránky",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingChangesTextHeader,
+        "Změny v týdnu {week}/{year} od {date} {time}:",
+    );
+    i18n.add_text(Locale::Cs, Key::TimeTravelOpen, "Cesta časem");
+    i18n.add_text(Locale::Cs, Key::TimeTravelClose, "Zpět na současnost");
+    i18n.add_text(Locale::Cs, Key::TimeTravelAt, "Plán k {date} {time}");
+    i18n.add_text(
+        Locale::Cs,
+        Key::TimeTravelHint,
+        "Pouze pro čtení. Rezervace jsou rekonstruovány z protokolu rezervací.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::TimeTravelNoLog,
+        "Pro tento týden nejsou zaznamenány žádné rezervace.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingProvenanceTitle,
+        "Podrobnosti rezervace",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingProvenanceBookedBy,
+        "Zarezervoval(a) {user} dne {date} v {time}",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingProvenanceSelfAdded,
+        "Přidáno samotnou osobou",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingProvenanceAddedByOther,
+        "Přidal(a) někdo jiný",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingProvenanceSlotHistory,
+        "Historie této směny v týdnu",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingProvenanceNoHistory,
+        "Žádné zaznamenané změny.",
+    );
+    i18n.add_text(Locale::Cs, Key::BookingLogWeek, "Týden");
+    i18n.add_text(Locale::Cs, Key::BookingLogAction, "Akce");
+    i18n.add_text(Locale::Cs, Key::BookingLogAt, "Kdy");
+    i18n.add_text(Locale::Cs, Key::BookingLogBy, "Kdo");
+    i18n.add_text(Locale::Cs, Key::BookingLogActionCreated, "Zarezervováno");
+    i18n.add_text(Locale::Cs, Key::BookingLogActionDeleted, "Odebráno");
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingLogFilterAction,
+        "Filtrovat podle akce",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingLogFilterActor,
+        "Filtrovat podle toho, kdo změnu provedl",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingLogFilterShiftplan,
+        "Filtrovat podle plánu směn",
+    );
+    i18n.add_text(Locale::Cs, Key::BookingLogFromWeek, "Od týdne");
+    i18n.add_text(Locale::Cs, Key::BookingLogUntilWeek, "Do týdne");
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingLogInvalidRange,
+        "Vyberte nejvýše {max} týdnů, počínaje dřívějším týdnem.",
+    );
+    i18n.add_text(Locale::Cs, Key::BookingLogDownloadCsv, "Stáhnout CSV");
+    i18n.add_text(Locale::Cs, Key::BookingLogEntryCount, "{count} záznamů");
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingLogEmpty,
+        "Filtrům neodpovídají žádné záznamy.",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::BookingLogOpenPage,
+        "Úplný protokol rezervací",
+    );
+    i18n.add_text(Locale::Cs, Key::LiveUpdateViewing, "Také prohlíží: {names}");
+    i18n.add_text(Locale::Cs, Key::LiveUpdateFeed, "Živě");
+    i18n.add_text(Locale::Cs, Key::LiveUpdatePolling, "Automatická obnova");
+    i18n.add_text(
+        Locale::Cs,
+        Key::LiveUpdateChangedBy,
+        "Naposledy změnil(a) {user} v {time}",
+    );
+    i18n.add_text(Locale::Cs, Key::VersionConflictTitle, "Mezitím změněno");
+    i18n.add_text(
+        Locale::Cs,
+        Key::VersionConflictHint,
+        "Někdo jiný tento záznam uložil, zatímco jste jej upravovali. U každého pole zvolte hodnotu, která má zůstat.",
+    );
+    i18n.add_text(Locale::Cs, Key::VersionConflictField, "Pole");
+    i18n.add_text(Locale::Cs, Key::VersionConflictYours, "Vaše");
+    i18n.add_text(Locale::Cs, Key::VersionConflictTheirs, "Uložené");
+    i18n.add_text(
+        Locale::Cs,
+        Key::VersionConflictReload,
+        "Zahodit moje a načíst znovu",
+    );
+    i18n.add_text(Locale::Cs, Key::VersionConflictOverwrite, "Přepsat");
+    i18n.add_text(Locale::Cs, Key::VersionConflictMerge, "Uložit výběr");
+    i18n.add_text(
+        Locale::Cs,
+        Key::OfflineQueuePending,
+        "{count} změn zatím neodesláno",
+    );
+    i18n.add_text(Locale::Cs, Key::OfflineQueueSending, "Odesílá se…");
+    i18n.add_text(Locale::Cs, Key::OfflineQueueSendNow, "Odeslat nyní");
+    i18n.add_text(Locale::Cs, Key::OfflineQueueDiscard, "Zahodit");
+    i18n.add_text(Locale::Cs, Key::OfflineQueueRejected, "Odmítnuto: {reason}");
+    i18n.add_text(
+        Locale::Cs,
+        Key::OfflineQueuePendingChip,
+        "Zatím neodesláno, čeká se na připojení",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::OfflineQueueRejectedChip,
+        "Odmítnuto serverem",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::OfflineQueueUnavailable,
+        "{name} · {day} nedostupný",
+    );
+    i18n.add_text(
+        Locale::Cs,
+        Key::OfflineQueueAvailable,
+        "{name} · {day} opět dostupný",
+    );
 
     // Booking log
     i18n.add_text(Locale::Cs, Key::BookingLogTitle, "Protokol rezervací");
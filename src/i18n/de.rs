@@ -22,6 +22,11 @@ pub fn add_i18n_de(i18n: &mut I18n<Key, Locale>) {
     i18n.add_text(Locale::De, Key::Logout, "Logout");
     i18n.add_text(Locale::De, Key::TopBarYouAreLabel, "Du bist");
     i18n.add_text(Locale::De, Key::TopBarAdminGroupLabel, "Verwaltung");
+    i18n.add_text(
+        Locale::De,
+        Key::TopBarResetDemoData,
+        "Demodaten zurücksetzen",
+    );
 
     // Shiftplan
     i18n.add_text(
@@ -85,6 +90,548 @@ pub fn add_i18n_de(i18n: &mut I18n<Key, Locale>) {
         "Soll Schichtplan {name} wirklich gelöscht werden? Dieser Vorgang kann nicht rückgängig gemacht werden.",
     );
     i18n.add_text(Locale::De, Key::ShiftplanIsPlanningLabel, "Nur Planung");
+    i18n.add_text(Locale::De, Key::ShiftplanHistory, "Verlauf");
+    i18n.add_text(
+        Locale::De,
+        Key::ShiftplanHistoryEmpty,
+        "Noch keine Änderungen",
+    );
+    i18n.add_text(Locale::De, Key::ShiftplanUndo, "Rückgängig");
+    i18n.add_text(Locale::De, Key::ShiftplanRedo, "Wiederholen");
+    i18n.add_text(Locale::De, Key::ShiftplanHistoryAdded, "Hinzugefügt");
+    i18n.add_text(Locale::De, Key::ShiftplanHistoryRemoved, "Entfernt");
+    i18n.add_text(Locale::De, Key::ShiftplanHistoryMoved, "Verschoben");
+    i18n.add_text(Locale::De, Key::ShiftplanHistoryCopiedWeek, "Woche kopiert");
+    i18n.add_text(
+        Locale::De,
+        Key::ShiftplanHistoryBookingCount,
+        "{count} Buchungen",
+    );
+    i18n.add_text(Locale::De, Key::CopyWeekOpen, "Woche kopieren…");
+    i18n.add_text(Locale::De, Key::CopyWeekTitle, "Woche kopieren");
+    i18n.add_text(Locale::De, Key::CopyWeekSource, "Quellwoche");
+    i18n.add_text(Locale::De, Key::CopyWeekTargetFrom, "Erste Zielwoche");
+    i18n.add_text(Locale::De, Key::CopyWeekTargetUntil, "Letzte Zielwoche");
+    i18n.add_text(Locale::De, Key::CopyWeekShiftplan, "Schichtplan");
+    i18n.add_text(Locale::De, Key::CopyWeekAllShiftplans, "Alle Schichtpläne");
+    i18n.add_text(
+        Locale::De,
+        Key::CopyWeekSkipUnavailable,
+        "Als nicht verfügbar markierte Personen überspringen",
+    );
+    i18n.add_text(Locale::De, Key::CopyWeekPreview, "Vorschau");
+    i18n.add_text(
+        Locale::De,
+        Key::CopyWeekConfirm,
+        "{count} Buchungen kopieren",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::CopyWeekInvalidRange,
+        "Bitte eine gültige Quellwoche und einen Zielbereich von höchstens {max} Wochen wählen.",
+    );
+    i18n.add_text(Locale::De, Key::CopyWeekWeekHeading, "KW {week}/{year}");
+    i18n.add_text(Locale::De, Key::CopyWeekStatusCreate, "Neu");
+    i18n.add_text(Locale::De, Key::CopyWeekStatusConflict, "Konflikt");
+    i18n.add_text(
+        Locale::De,
+        Key::CopyWeekStatusSkipped,
+        "Übersprungen (nicht verfügbar)",
+    );
+    i18n.add_text(Locale::De, Key::CopyWeekStatusExisting, "Bereits gebucht");
+    i18n.add_text(
+        Locale::De,
+        Key::ShiftplanHistoryAutoFilled,
+        "Automatisch befüllt",
+    );
+    i18n.add_text(Locale::De, Key::AutoFillOpen, "Automatisch befüllen");
+    i18n.add_text(Locale::De, Key::AutoFillProposed, "Vorgeschlagene Buchung");
+    i18n.add_text(
+        Locale::De,
+        Key::AutoFillSummary,
+        "{count} Buchungen vorgeschlagen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::AutoFillUnfilled,
+        "{count} Positionen bleiben offen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::AutoFillNothing,
+        "Keine passende Person für die offenen Schichten gefunden",
+    );
+    i18n.add_text(Locale::De, Key::AutoFillApply, "Übernehmen");
+    i18n.add_text(Locale::De, Key::AutoFillDiscard, "Verwerfen");
+    i18n.add_text(Locale::De, Key::BookingPatterns, "Wiederkehrende Schichten");
+    i18n.add_text(
+        Locale::De,
+        Key::BookingPatternNew,
+        "Neue wiederkehrende Schicht",
+    );
+    i18n.add_text(Locale::De, Key::BookingPatternPerson, "Person");
+    i18n.add_text(Locale::De, Key::BookingPatternShiftplan, "Schichtplan");
+    i18n.add_text(Locale::De, Key::BookingPatternSlot, "Schicht");
+    i18n.add_text(Locale::De, Key::BookingPatternInterval, "Alle … Wochen");
+    i18n.add_text(Locale::De, Key::BookingPatternFrom, "Erste Woche");
+    i18n.add_text(Locale::De, Key::BookingPatternUntil, "Letzte Woche");
+    i18n.add_text(Locale::De, Key::BookingPatternAdd, "Hinzufügen");
+    i18n.add_text(
+        Locale::De,
+        Key::BookingPatternEmpty,
+        "Noch keine wiederkehrenden Schichten angelegt.",
+    );
+    i18n.add_text(Locale::De, Key::BookingPatternEvery, "alle {weeks} Wochen");
+    i18n.add_text(
+        Locale::De,
+        Key::BookingPatternHorizon,
+        "Buchungen anlegen bis",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingPatternMaterialize,
+        "Buchungen anlegen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingPatternMaterializeAll,
+        "Buchungen für alle anlegen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingPatternResult,
+        "{created} Buchungen angelegt, {existing} bereits vorhanden, {skipped} übersprungen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingPatternFailed,
+        "{failed} Buchungen konnten nicht angelegt werden, bitte später erneut versuchen.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingPatternLocalOnly,
+        "Wiederkehrende Schichten werden nur in diesem Browser gespeichert. Andere Planer und Geräte sehen weder die Muster noch ihre Markierungen im Schichtplan; die daraus angelegten Buchungen sehen alle.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingPatternUnknownSlot,
+        "Schicht existiert nicht mehr",
+    );
+    i18n.add_text(Locale::De, Key::ViewModeMultiWeek, "Wochen");
+    i18n.add_text(Locale::De, Key::MultiWeekFourWeeks, "4 Wochen");
+    i18n.add_text(Locale::De, Key::MultiWeekMonth, "Monat");
+    i18n.add_text(Locale::De, Key::CoverageWeekLabel, "KW {week}");
+    i18n.add_text(
+        Locale::De,
+        Key::CoverageDayTitle,
+        "{understaffed} von {slots} Schichten unterbesetzt",
+    );
+    i18n.add_text(Locale::De, Key::CoverageCovered, "Voll besetzt");
+    i18n.add_text(Locale::De, Key::CoveragePartial, "Einzelne Lücken");
+    i18n.add_text(
+        Locale::De,
+        Key::CoverageUncovered,
+        "Überwiegend unterbesetzt",
+    );
+    i18n.add_text(Locale::De, Key::CoverageNoSlots, "Keine Schichten");
+    i18n.add_text(
+        Locale::De,
+        Key::CoverageHint,
+        "Klicke auf eine Woche, um sie zu öffnen.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::StaffingHighlight,
+        "Besetzung und Konflikte hervorheben",
+    );
+    i18n.add_text(Locale::De, Key::StaffingLegendUnder, "Unterbesetzt");
+    i18n.add_text(Locale::De, Key::StaffingLegendOver, "Überbesetzt");
+    i18n.add_text(
+        Locale::De,
+        Key::StaffingLegendUnavailable,
+        "Du bist nicht verfügbar",
+    );
+    i18n.add_text(Locale::De, Key::StaffingLegendConflict, "Buchungskonflikt");
+    i18n.add_text(Locale::De, Key::PersonPickerTitle, "Person einbuchen");
+    i18n.add_text(Locale::De, Key::PersonPickerFilter, "Nach Namen filtern…");
+    i18n.add_text(Locale::De, Key::PersonPickerEmpty, "Niemand gefunden.");
+    i18n.add_text(Locale::De, Key::ShortcutsTitle, "Tastenkürzel");
+    i18n.add_text(
+        Locale::De,
+        Key::ShortcutsHint,
+        "Klicke in den Wochenplan, um sie zu nutzen.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::ShortcutMove,
+        "Zwischen Schichten, Buchungen und Tagen wechseln",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::ShortcutPick,
+        "Person in die gewählte Schicht einbuchen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::ShortcutRecent,
+        "Zuletzt verwendete Person einbuchen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::ShortcutRemove,
+        "Gewählte Buchung entfernen",
+    );
+    i18n.add_text(Locale::De, Key::ShortcutWeek, "Vorherige / nächste Woche");
+    i18n.add_text(Locale::De, Key::ShortcutUndo, "Rückgängig");
+    i18n.add_text(Locale::De, Key::ShortcutRedo, "Wiederholen");
+    i18n.add_text(Locale::De, Key::ShortcutHelp, "Diese Übersicht anzeigen");
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureTitle,
+        "Schichtstruktur bearbeiten",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureLoading,
+        "Schichten werden geladen…",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureEmpty,
+        "Dieser Schichtplan hat in dieser Woche keine Schichten.",
+    );
+    i18n.add_text(Locale::De, Key::SlotStructureValidFrom, "Gültig ab");
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureValidFromHint,
+        "Alle Änderungen gelten ab dieser Woche. Frühere Wochen behalten die bisherige Struktur.",
+    );
+    i18n.add_text(Locale::De, Key::SlotStructureSelected, "{count} ausgewählt");
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureShiftOutOfDay,
+        "Die ausgewählten Schichten können nicht über Mitternacht verschoben werden.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureApplyMinResources,
+        "Minimum setzen",
+    );
+    i18n.add_text(Locale::De, Key::SlotStructureMinResources, "min. {count}");
+    i18n.add_text(Locale::De, Key::SlotStructureRemove, "Entfernen");
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureDuplicateSource,
+        "Aufteilung kopieren von",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureDuplicateShiftplan,
+        "In Schichtplan",
+    );
+    i18n.add_text(Locale::De, Key::SlotStructureDuplicateTargets, "Auf");
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureDuplicateReplace,
+        "Bestehende Schichten an diesen Tagen ersetzen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureDuplicate,
+        "Aufteilung kopieren",
+    );
+    i18n.add_text(Locale::De, Key::SlotStructureNew, "neu");
+    i18n.add_text(Locale::De, Key::SlotStructureChanged, "geändert");
+    i18n.add_text(Locale::De, Key::SlotStructureRemoved, "entfernt");
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureSave,
+        "{count} Änderungen speichern",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureRejected,
+        "{count} neue Schichten überschneiden sich mit bestehenden und wurden nicht angelegt:",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotStructureFailed,
+        "{saved} Änderungen wurden gespeichert, {count} sind fehlgeschlagen:",
+    );
+    i18n.add_text(Locale::De, Key::SlotTimelineTitle, "Schichtverlauf");
+    i18n.add_text(Locale::De, Key::SlotTimelineShiftplan, "Schichtplan");
+    i18n.add_text(Locale::De, Key::SlotTimelineFromYear, "Von Jahr");
+    i18n.add_text(Locale::De, Key::SlotTimelineUntilYear, "Bis Jahr");
+    i18n.add_text(
+        Locale::De,
+        Key::SlotTimelineInvalidRange,
+        "Wähle einen Zeitraum von höchstens {max} Jahren.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotTimelineLoading,
+        "Schichtverlauf wird geladen…",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotTimelineEmpty,
+        "In diesem Zeitraum waren keine Schichten gültig.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotTimelineValidRange,
+        "Gültig {from} – {to}",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotTimelineValidSince,
+        "Gültig seit {from}",
+    );
+    i18n.add_text(Locale::De, Key::SlotTimelineReopen, "Wieder öffnen");
+    i18n.add_text(
+        Locale::De,
+        Key::SlotTimelineReopenBlocked,
+        "Eine spätere Schicht belegt dieselbe Zeit, daher kann diese Schicht nicht wieder geöffnet werden.",
+    );
+    i18n.add_text(Locale::De, Key::SlotTimelineEndFrom, "Beenden vor Woche");
+    i18n.add_text(Locale::De, Key::SlotTimelineEnd, "Schicht beenden");
+    i18n.add_text(Locale::De, Key::SlotTimelineChanges, "Strukturänderungen");
+    i18n.add_text(
+        Locale::De,
+        Key::SlotTimelineNoChanges,
+        "Die Struktur hat sich in diesem Zeitraum nicht geändert.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::SlotTimelineChangeSummary,
+        "KW {week}/{year}: {started} begonnen, {ended} beendet",
+    );
+    i18n.add_text(Locale::De, Key::DraftCreateTitle, "Entwurf erstellen");
+    i18n.add_text(Locale::De, Key::DraftName, "Name");
+    i18n.add_text(Locale::De, Key::DraftNameDefault, "{name} (Entwurf)");
+    i18n.add_text(
+        Locale::De,
+        Key::DraftCreateHint,
+        "Schichten und Buchungen der gewählten Wochen werden in einen neuen Planungs-Schichtplan kopiert.",
+    );
+    i18n.add_text(Locale::De, Key::DraftFromWeek, "Ab Woche");
+    i18n.add_text(Locale::De, Key::DraftUntilWeek, "Bis Woche");
+    i18n.add_text(
+        Locale::De,
+        Key::DraftInvalidRange,
+        "Wähle einen gültigen Wochenbereich.",
+    );
+    i18n.add_text(Locale::De, Key::DraftCreate, "Entwurf erstellen");
+    i18n.add_text(Locale::De, Key::DraftCompareWith, "Entwurf von");
+    i18n.add_text(Locale::De, Key::DraftNotLinked, "Nicht verknüpft");
+    i18n.add_text(Locale::De, Key::DraftShowDiff, "Mit Live-Plan vergleichen");
+    i18n.add_text(Locale::De, Key::DraftLivePlan, "Live-Plan");
+    i18n.add_text(Locale::De, Key::DraftPlan, "Entwurf");
+    i18n.add_text(
+        Locale::De,
+        Key::DraftDiffSummary,
+        "{added} hinzugefügt, {removed} entfernt",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::DraftSlotsOnlyInDraft,
+        "{count} Schichten nur im Entwurf werden nicht veröffentlicht",
+    );
+    i18n.add_text(Locale::De, Key::DraftPublishOpen, "Veröffentlichen…");
+    i18n.add_text(
+        Locale::De,
+        Key::DraftPublishTitle,
+        "Entwurf veröffentlichen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::DraftPublishHint,
+        "Buchungen von Schichten, die es in beiden Plänen gibt, werden in den Live-Plan übernommen. Die Schichtstruktur des Live-Plans bleibt unverändert.",
+    );
+    i18n.add_text(Locale::De, Key::DraftPublish, "Veröffentlichen");
+    i18n.add_text(
+        Locale::De,
+        Key::DraftPublishReport,
+        "{weeks} Wochen veröffentlicht: {added} hinzugefügt, {removed} entfernt, {skipped} Schichten übersprungen",
+    );
+    i18n.add_text(Locale::De, Key::WeekPublish, "Woche veröffentlichen");
+    i18n.add_text(Locale::De, Key::WeekUnpublish, "Veröffentlichung aufheben");
+    i18n.add_text(Locale::De, Key::WeekPublished, "Veröffentlicht");
+    i18n.add_text(
+        Locale::De,
+        Key::WeekPublishedBy,
+        "Veröffentlicht am {date} {time} von {user}",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::WeekPublishedLocked,
+        "Diese Woche ist veröffentlicht. Für Änderungen wende dich an die Schichtplanung.",
+    );
+    i18n.add_text(Locale::De, Key::BookingChangesOpen, "Änderungen seit…");
+    i18n.add_text(Locale::De, Key::BookingChangesHide, "Änderungen ausblenden");
+    i18n.add_text(Locale::De, Key::BookingChangesTitle, "Änderungen seit");
+    i18n.add_text(Locale::De, Key::BookingChangesSince, "Seit");
+    i18n.add_text(
+        Locale::De,
+        Key::BookingChangesSummary,
+        "{added} hinzugefügt, {removed} entfernt",
+    );
+    i18n.add_text(Locale::De, Key::BookingChangesNone, "Keine Änderungen.");
+    i18n.add_text(Locale::De, Key::BookingChangesCopy, "Als Text kopieren");
+    i18n.add_text(
+        Locale::De,
+        Key::BookingChangesCopied,
+        "In die Zwischenablage kopiert",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingChangesTextHeader,
+        "Änderungen in KW {week}/{year} seit {date} {time}:",
+    );
+    i18n.add_text(Locale::De, Key::TimeTravelOpen, "Zeitreise");
+    i18n.add_text(Locale::De, Key::TimeTravelClose, "Zurück zu jetzt");
+    i18n.add_text(Locale::De, Key::TimeTravelAt, "Plan am {date} {time}");
+    i18n.add_text(
+        Locale::De,
+        Key::TimeTravelHint,
+        "Nur lesend. Die Buchungen werden aus dem Buchungsprotokoll rekonstruiert.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::TimeTravelNoLog,
+        "Für diese Woche sind keine Buchungen protokolliert.",
+    );
+    i18n.add_text(Locale::De, Key::BookingProvenanceTitle, "Buchungsdetails");
+    i18n.add_text(
+        Locale::De,
+        Key::BookingProvenanceBookedBy,
+        "Gebucht von {user} am {date} um {time}",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingProvenanceSelfAdded,
+        "Von der Person selbst eingetragen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingProvenanceAddedByOther,
+        "Von jemand anderem eingetragen",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingProvenanceSlotHistory,
+        "Verlauf dieser Schicht in der Woche",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingProvenanceNoHistory,
+        "Keine Änderungen protokolliert.",
+    );
+    i18n.add_text(Locale::De, Key::BookingLogWeek, "Woche");
+    i18n.add_text(Locale::De, Key::BookingLogAction, "Aktion");
+    i18n.add_text(Locale::De, Key::BookingLogAt, "Wann");
+    i18n.add_text(Locale::De, Key::BookingLogBy, "Von");
+    i18n.add_text(Locale::De, Key::BookingLogActionCreated, "Gebucht");
+    i18n.add_text(Locale::De, Key::BookingLogActionDeleted, "Entfernt");
+    i18n.add_text(
+        Locale::De,
+        Key::BookingLogFilterAction,
+        "Nach Aktion filtern",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingLogFilterActor,
+        "Nach Bearbeiter filtern",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingLogFilterShiftplan,
+        "Nach Schichtplan filtern",
+    );
+    i18n.add_text(Locale::De, Key::BookingLogFromWeek, "Von Woche");
+    i18n.add_text(Locale::De, Key::BookingLogUntilWeek, "Bis Woche");
+    i18n.add_text(
+        Locale::De,
+        Key::BookingLogInvalidRange,
+        "Höchstens {max} Wochen wählen, beginnend mit der früheren Woche.",
+    );
+    i18n.add_text(Locale::De, Key::BookingLogDownloadCsv, "CSV herunterladen");
+    i18n.add_text(Locale::De, Key::BookingLogEntryCount, "{count} Einträge");
+    i18n.add_text(
+        Locale::De,
+        Key::BookingLogEmpty,
+        "Keine Einträge passen zu den Filtern.",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::BookingLogOpenPage,
+        "Vollständiges Buchungsprotokoll",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::LiveUpdateViewing,
+        "Ebenfalls geöffnet von: {names}",
+    );
+    i18n.add_text(Locale::De, Key::LiveUpdateFeed, "Live");
+    i18n.add_text(
+        Locale::De,
+        Key::LiveUpdatePolling,
+        "Automatisch aktualisiert",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::LiveUpdateChangedBy,
+        "Zuletzt geändert von {user} um {time}",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::VersionConflictTitle,
+        "Zwischenzeitlich geändert",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::VersionConflictHint,
+        "Jemand anderes hat diesen Eintrag gespeichert, während du ihn bearbeitet hast. Wähle für jedes Feld den Wert, der bleiben soll.",
+    );
+    i18n.add_text(Locale::De, Key::VersionConflictField, "Feld");
+    i18n.add_text(Locale::De, Key::VersionConflictYours, "Deine");
+    i18n.add_text(Locale::De, Key::VersionConflictTheirs, "Gespeichert");
+    i18n.add_text(
+        Locale::De,
+        Key::VersionConflictReload,
+        "Meine verwerfen und neu laden",
+    );
+    i18n.add_text(Locale::De, Key::VersionConflictOverwrite, "Überschreiben");
+    i18n.add_text(Locale::De, Key::VersionConflictMerge, "Auswahl speichern");
+    i18n.add_text(
+        Locale::De,
+        Key::OfflineQueuePending,
+        "{count} Änderungen noch nicht gesendet",
+    );
+    i18n.add_text(Locale::De, Key::OfflineQueueSending, "Wird gesendet…");
+    i18n.add_text(Locale::De, Key::OfflineQueueSendNow, "Jetzt senden");
+    i18n.add_text(Locale::De, Key::OfflineQueueDiscard, "Verwerfen");
+    i18n.add_text(Locale::De, Key::OfflineQueueRejected, "Abgelehnt: {reason}");
+    i18n.add_text(
+        Locale::De,
+        Key::OfflineQueuePendingChip,
+        "Noch nicht gesendet, wartet auf Verbindung",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::OfflineQueueRejectedChip,
+        "Vom Server abgelehnt",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::OfflineQueueUnavailable,
+        "{name} · {day} nicht verfügbar",
+    );
+    i18n.add_text(
+        Locale::De,
+        Key::OfflineQueueAvailable,
+        "{name} · {day} wieder verfügbar",
+    );
 
     // Booking log
     i18n.add_text(Locale::De, Key::BookingLogTitle, "Buchungsprotokoll");
@@ -22,6 +22,7 @@ pub fn add_i18n_en(i18n: &mut I18n<Key, Locale>) {
     i18n.add_text(Locale::En, Key::Logout, "Logout");
     i18n.add_text(Locale::En, Key::TopBarYouAreLabel, "You are");
     i18n.add_text(Locale::En, Key::TopBarAdminGroupLabel, "Administration");
+    i18n.add_text(Locale::En, Key::TopBarResetDemoData, "Reset demo data");
 
     // Shiftplan
     i18n.add_text(
@@ -73,6 +74,484 @@ pub fn add_i18n_en(i18n: &mut I18n<Key, Locale>) {
         "Are you sure you want to delete shiftplan {name}? This cannot be undone.",
     );
     i18n.add_text(Locale::En, Key::ShiftplanIsPlanningLabel, "Planning only");
+    i18n.add_text(Locale::En, Key::ShiftplanHistory, "History");
+    i18n.add_text(Locale::En, Key::ShiftplanHistoryEmpty, "No changes yet");
+    i18n.add_text(Locale::En, Key::ShiftplanUndo, "Undo");
+    i18n.add_text(Locale::En, Key::ShiftplanRedo, "Redo");
+    i18n.add_text(Locale::En, Key::ShiftplanHistoryAdded, "Added");
+    i18n.add_text(Locale::En, Key::ShiftplanHistoryRemoved, "Removed");
+    i18n.add_text(Locale::En, Key::ShiftplanHistoryMoved, "Moved");
+    i18n.add_text(Locale::En, Key::ShiftplanHistoryCopiedWeek, "Copied week");
+    i18n.add_text(
+        Locale::En,
+        Key::ShiftplanHistoryBookingCount,
+        "{count} bookings",
+    );
+    i18n.add_text(Locale::En, Key::CopyWeekOpen, "Copy week…");
+    i18n.add_text(Locale::En, Key::CopyWeekTitle, "Copy week");
+    i18n.add_text(Locale::En, Key::CopyWeekSource, "Source week");
+    i18n.add_text(Locale::En, Key::CopyWeekTargetFrom, "First target week");
+    i18n.add_text(Locale::En, Key::CopyWeekTargetUntil, "Last target week");
+    i18n.add_text(Locale::En, Key::CopyWeekShiftplan, "Shift plan");
+    i18n.add_text(Locale::En, Key::CopyWeekAllShiftplans, "All shift plans");
+    i18n.add_text(
+        Locale::En,
+        Key::CopyWeekSkipUnavailable,
+        "Skip people marked unavailable",
+    );
+    i18n.add_text(Locale::En, Key::CopyWeekPreview, "Preview");
+    i18n.add_text(Locale::En, Key::CopyWeekConfirm, "Copy {count} bookings");
+    i18n.add_text(
+        Locale::En,
+        Key::CopyWeekInvalidRange,
+        "Choose a valid source week and a target range of at most {max} weeks.",
+    );
+    i18n.add_text(Locale::En, Key::CopyWeekWeekHeading, "Week {week}/{year}");
+    i18n.add_text(Locale::En, Key::CopyWeekStatusCreate, "New");
+    i18n.add_text(Locale::En, Key::CopyWeekStatusConflict, "Conflict");
+    i18n.add_text(
+        Locale::En,
+        Key::CopyWeekStatusSkipped,
+        "Skipped (unavailable)",
+    );
+    i18n.add_text(Locale::En, Key::CopyWeekStatusExisting, "Already booked");
+    i18n.add_text(Locale::En, Key::ShiftplanHistoryAutoFilled, "Auto-filled");
+    i18n.add_text(Locale::En, Key::AutoFillOpen, "Auto-fill");
+    i18n.add_text(Locale::En, Key::AutoFillProposed, "Proposed booking");
+    i18n.add_text(
+        Locale::En,
+        Key::AutoFillSummary,
+        "{count} bookings proposed",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::AutoFillUnfilled,
+        "{count} positions stay open",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::AutoFillNothing,
+        "No suitable person found for the open slots",
+    );
+    i18n.add_text(Locale::En, Key::AutoFillApply, "Apply");
+    i18n.add_text(Locale::En, Key::AutoFillDiscard, "Discard");
+    i18n.add_text(Locale::En, Key::BookingPatterns, "Recurring shifts");
+    i18n.add_text(Locale::En, Key::BookingPatternNew, "New recurring shift");
+    i18n.add_text(Locale::En, Key::BookingPatternPerson, "Person");
+    i18n.add_text(Locale::En, Key::BookingPatternShiftplan, "Shift plan");
+    i18n.add_text(Locale::En, Key::BookingPatternSlot, "Slot");
+    i18n.add_text(Locale::En, Key::BookingPatternInterval, "Every … weeks");
+    i18n.add_text(Locale::En, Key::BookingPatternFrom, "First week");
+    i18n.add_text(Locale::En, Key::BookingPatternUntil, "Last week");
+    i18n.add_text(Locale::En, Key::BookingPatternAdd, "Add");
+    i18n.add_text(
+        Locale::En,
+        Key::BookingPatternEmpty,
+        "No recurring shifts defined yet.",
+    );
+    i18n.add_text(Locale::En, Key::BookingPatternEvery, "every {weeks} weeks");
+    i18n.add_text(
+        Locale::En,
+        Key::BookingPatternHorizon,
+        "Create bookings until",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingPatternMaterialize,
+        "Create bookings",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingPatternMaterializeAll,
+        "Create bookings for all",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingPatternResult,
+        "{created} bookings created, {existing} already existed, {skipped} skipped",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingPatternFailed,
+        "{failed} bookings could not be created, try again later.",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingPatternLocalOnly,
+        "Recurring shifts are saved in this browser only. Other planners and devices see neither the patterns nor their markers in the shift plan; the bookings created from them are visible to everyone.",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingPatternUnknownSlot,
+        "Slot no longer exists",
+    );
+    i18n.add_text(Locale::En, Key::ViewModeMultiWeek, "Weeks");
+    i18n.add_text(Locale::En, Key::MultiWeekFourWeeks, "4 weeks");
+    i18n.add_text(Locale::En, Key::MultiWeekMonth, "Month");
+    i18n.add_text(Locale::En, Key::CoverageWeekLabel, "CW {week}");
+    i18n.add_text(
+        Locale::En,
+        Key::CoverageDayTitle,
+        "{understaffed} of {slots} shifts under-staffed",
+    );
+    i18n.add_text(Locale::En, Key::CoverageCovered, "Fully staffed");
+    i18n.add_text(Locale::En, Key::CoveragePartial, "Some gaps");
+    i18n.add_text(Locale::En, Key::CoverageUncovered, "Mostly under-staffed");
+    i18n.add_text(Locale::En, Key::CoverageNoSlots, "No shifts");
+    i18n.add_text(Locale::En, Key::CoverageHint, "Click a week to open it.");
+    i18n.add_text(
+        Locale::En,
+        Key::StaffingHighlight,
+        "Highlight staffing and conflicts",
+    );
+    i18n.add_text(Locale::En, Key::StaffingLegendUnder, "Under-staffed");
+    i18n.add_text(Locale::En, Key::StaffingLegendOver, "Over-staffed");
+    i18n.add_text(
+        Locale::En,
+        Key::StaffingLegendUnavailable,
+        "You are unavailable",
+    );
+    i18n.add_text(Locale::En, Key::StaffingLegendConflict, "Booking conflict");
+    i18n.add_text(Locale::En, Key::PersonPickerTitle, "Book a person");
+    i18n.add_text(Locale::En, Key::PersonPickerFilter, "Filter by name…");
+    i18n.add_text(Locale::En, Key::PersonPickerEmpty, "Nobody matches.");
+    i18n.add_text(Locale::En, Key::ShortcutsTitle, "Keyboard shortcuts");
+    i18n.add_text(
+        Locale::En,
+        Key::ShortcutsHint,
+        "Click into the week grid to use them.",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::ShortcutMove,
+        "Move between shifts, bookings and days",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::ShortcutPick,
+        "Book a person into the selected shift",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::ShortcutRecent,
+        "Book a recently used person",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::ShortcutRemove,
+        "Remove the selected booking",
+    );
+    i18n.add_text(Locale::En, Key::ShortcutWeek, "Previous / next week");
+    i18n.add_text(Locale::En, Key::ShortcutUndo, "Undo");
+    i18n.add_text(Locale::En, Key::ShortcutRedo, "Redo");
+    i18n.add_text(Locale::En, Key::ShortcutHelp, "Show this overview");
+    i18n.add_text(Locale::En, Key::SlotStructureTitle, "Edit slot structure");
+    i18n.add_text(Locale::En, Key::SlotStructureLoading, "Loading slots…");
+    i18n.add_text(
+        Locale::En,
+        Key::SlotStructureEmpty,
+        "This shift plan has no slots in this week.",
+    );
+    i18n.add_text(Locale::En, Key::SlotStructureValidFrom, "Valid from");
+    i18n.add_text(
+        Locale::En,
+        Key::SlotStructureValidFromHint,
+        "All changes take effect in this week. Earlier weeks keep the current structure.",
+    );
+    i18n.add_text(Locale::En, Key::SlotStructureSelected, "{count} selected");
+    i18n.add_text(
+        Locale::En,
+        Key::SlotStructureShiftOutOfDay,
+        "The selected slots cannot be moved past midnight.",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SlotStructureApplyMinResources,
+        "Set minimum",
+    );
+    i18n.add_text(Locale::En, Key::SlotStructureMinResources, "min. {count}");
+    i18n.add_text(Locale::En, Key::SlotStructureRemove, "Remove");
+    i18n.add_text(
+        Locale::En,
+        Key::SlotStructureDuplicateSource,
+        "Copy layout of",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SlotStructureDuplicateShiftplan,
+        "Into shift plan",
+    );
+    i18n.add_text(Locale::En, Key::SlotStructureDuplicateTargets, "Onto");
+    i18n.add_text(
+        Locale::En,
+        Key::SlotStructureDuplicateReplace,
+        "Replace existing slots on these days",
+    );
+    i18n.add_text(Locale::En, Key::SlotStructureDuplicate, "Copy layout");
+    i18n.add_text(Locale::En, Key::SlotStructureNew, "new");
+    i18n.add_text(Locale::En, Key::SlotStructureChanged, "changed");
+    i18n.add_text(Locale::En, Key::SlotStructureRemoved, "removed");
+    i18n.add_text(Locale::En, Key::SlotStructureSave, "Save {count} changes");
+    i18n.add_text(
+        Locale::En,
+        Key::SlotStructureRejected,
+        "{count} new slots overlap existing ones and were not created:",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SlotStructureFailed,
+        "{saved} changes were saved, {count} failed:",
+    );
+    i18n.add_text(Locale::En, Key::SlotTimelineTitle, "Slot history");
+    i18n.add_text(Locale::En, Key::SlotTimelineShiftplan, "Shift plan");
+    i18n.add_text(Locale::En, Key::SlotTimelineFromYear, "From year");
+    i18n.add_text(Locale::En, Key::SlotTimelineUntilYear, "Until year");
+    i18n.add_text(
+        Locale::En,
+        Key::SlotTimelineInvalidRange,
+        "Choose a range of at most {max} years.",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SlotTimelineLoading,
+        "Loading slot history…",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SlotTimelineEmpty,
+        "No slots were valid in this range.",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SlotTimelineValidRange,
+        "Valid {from} – {to}",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SlotTimelineValidSince,
+        "Valid since {from}",
+    );
+    i18n.add_text(Locale::En, Key::SlotTimelineReopen, "Reopen");
+    i18n.add_text(
+        Locale::En,
+        Key::SlotTimelineReopenBlocked,
+        "A later slot covers the same time, so this slot cannot be reopened.",
+    );
+    i18n.add_text(Locale::En, Key::SlotTimelineEndFrom, "End before week");
+    i18n.add_text(Locale::En, Key::SlotTimelineEnd, "End slot");
+    i18n.add_text(Locale::En, Key::SlotTimelineChanges, "Structure changes");
+    i18n.add_text(
+        Locale::En,
+        Key::SlotTimelineNoChanges,
+        "The structure did not change in this range.",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::SlotTimelineChangeSummary,
+        "CW {week}/{year}: {started} started, {ended} ended",
+    );
+    i18n.add_text(Locale::En, Key::DraftCreateTitle, "Create draft");
+    i18n.add_text(Locale::En, Key::DraftName, "Name");
+    i18n.add_text(Locale::En, Key::DraftNameDefault, "{name} (draft)");
+    i18n.add_text(
+        Locale::En,
+        Key::DraftCreateHint,
+        "Slots and bookings of the selected weeks are copied into a new planning shiftplan.",
+    );
+    i18n.add_text(Locale::En, Key::DraftFromWeek, "From week");
+    i18n.add_text(Locale::En, Key::DraftUntilWeek, "Until week");
+    i18n.add_text(
+        Locale::En,
+        Key::DraftInvalidRange,
+        "Choose a valid week range.",
+    );
+    i18n.add_text(Locale::En, Key::DraftCreate, "Create draft");
+    i18n.add_text(Locale::En, Key::DraftCompareWith, "Draft of");
+    i18n.add_text(Locale::En, Key::DraftNotLinked, "Not linked");
+    i18n.add_text(Locale::En, Key::DraftShowDiff, "Compare with live plan");
+    i18n.add_text(Locale::En, Key::DraftLivePlan, "Live plan");
+    i18n.add_text(Locale::En, Key::DraftPlan, "Draft");
+    i18n.add_text(
+        Locale::En,
+        Key::DraftDiffSummary,
+        "{added} added, {removed} removed",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::DraftSlotsOnlyInDraft,
+        "{count} slots only in the draft are not published",
+    );
+    i18n.add_text(Locale::En, Key::DraftPublishOpen, "Publish…");
+    i18n.add_text(Locale::En, Key::DraftPublishTitle, "Publish draft");
+    i18n.add_text(
+        Locale::En,
+        Key::DraftPublishHint,
+        "Bookings of slots present in both plans are written into the live plan. The slot structure of the live plan is not changed.",
+    );
+    i18n.add_text(Locale::En, Key::DraftPublish, "Publish");
+    i18n.add_text(
+        Locale::En,
+        Key::DraftPublishReport,
+        "{weeks} weeks published: {added} added, {removed} removed, {skipped} slots skipped",
+    );
+    i18n.add_text(Locale::En, Key::WeekPublish, "Publish week");
+    i18n.add_text(Locale::En, Key::WeekUnpublish, "Unpublish week");
+    i18n.add_text(Locale::En, Key::WeekPublished, "Published");
+    i18n.add_text(
+        Locale::En,
+        Key::WeekPublishedBy,
+        "Published {date} {time} by {user}",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::WeekPublishedLocked,
+        "This week is published. Please contact shift planning for changes.",
+    );
+    i18n.add_text(Locale::En, Key::BookingChangesOpen, "Changes since…");
+    i18n.add_text(Locale::En, Key::BookingChangesHide, "Hide changes");
+    i18n.add_text(Locale::En, Key::BookingChangesTitle, "Changes since");
+    i18n.add_text(Locale::En, Key::BookingChangesSince, "Since");
+    i18n.add_text(
+        Locale::En,
+        Key::BookingChangesSummary,
+        "{added} added, {removed} removed",
+    );
+    i18n.add_text(Locale::En, Key::BookingChangesNone, "No changes.");
+    i18n.add_text(Locale::En, Key::BookingChangesCopy, "Copy as text");
+    i18n.add_text(Locale::En, Key::BookingChangesCopied, "Copied to clipboard");
+    i18n.add_text(
+        Locale::En,
+        Key::BookingChangesTextHeader,
+        "Changes to CW {week}/{year} since {date} {time}:",
+    );
+    i18n.add_text(Locale::En, Key::TimeTravelOpen, "Time travel");
+    i18n.add_text(Locale::En, Key::TimeTravelClose, "Back to now");
+    i18n.add_text(Locale::En, Key::TimeTravelAt, "Plan as of {date} {time}");
+    i18n.add_text(
+        Locale::En,
+        Key::TimeTravelHint,
+        "Read-only. Bookings are rebuilt from the booking log.",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::TimeTravelNoLog,
+        "No bookings logged for this week.",
+    );
+    i18n.add_text(Locale::En, Key::BookingProvenanceTitle, "Booking details");
+    i18n.add_text(
+        Locale::En,
+        Key::BookingProvenanceBookedBy,
+        "Booked by {user} on {date} at {time}",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingProvenanceSelfAdded,
+        "Added by the person themselves",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingProvenanceAddedByOther,
+        "Added by someone else",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingProvenanceSlotHistory,
+        "History of this slot in the week",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingProvenanceNoHistory,
+        "No changes logged.",
+    );
+    i18n.add_text(Locale::En, Key::BookingLogWeek, "Week");
+    i18n.add_text(Locale::En, Key::BookingLogAction, "Action");
+    i18n.add_text(Locale::En, Key::BookingLogAt, "When");
+    i18n.add_text(Locale::En, Key::BookingLogBy, "By");
+    i18n.add_text(Locale::En, Key::BookingLogActionCreated, "Booked");
+    i18n.add_text(Locale::En, Key::BookingLogActionDeleted, "Removed");
+    i18n.add_text(Locale::En, Key::BookingLogFilterAction, "Filter by action");
+    i18n.add_text(
+        Locale::En,
+        Key::BookingLogFilterActor,
+        "Filter by who acted",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::BookingLogFilterShiftplan,
+        "Filter by shiftplan",
+    );
+    i18n.add_text(Locale::En, Key::BookingLogFromWeek, "From week");
+    i18n.add_text(Locale::En, Key::BookingLogUntilWeek, "Until week");
+    i18n.add_text(
+        Locale::En,
+        Key::BookingLogInvalidRange,
+        "Choose at most {max} weeks, starting with the earlier week.",
+    );
+    i18n.add_text(Locale::En, Key::BookingLogDownloadCsv, "Download CSV");
+    i18n.add_text(Locale::En, Key::BookingLogEntryCount, "{count} entries");
+    i18n.add_text(
+        Locale::En,
+        Key::BookingLogEmpty,
+        "No entries match the filters.",
+    );
+    i18n.add_text(Locale::En, Key::BookingLogOpenPage, "Full booking log");
+    i18n.add_text(Locale::En, Key::LiveUpdateViewing, "Also viewing: {names}");
+    i18n.add_text(Locale::En, Key::LiveUpdateFeed, "Live");
+    i18n.add_text(Locale::En, Key::LiveUpdatePolling, "Auto-refresh");
+    i18n.add_text(
+        Locale::En,
+        Key::LiveUpdateChangedBy,
+        "Last changed by {user} at {time}",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::VersionConflictTitle,
+        "Changed in the meantime",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::VersionConflictHint,
+        "Someone else saved this entry while you were editing it. Pick the value to keep for every field.",
+    );
+    i18n.add_text(Locale::En, Key::VersionConflictField, "Field");
+    i18n.add_text(Locale::En, Key::VersionConflictYours, "Yours");
+    i18n.add_text(Locale::En, Key::VersionConflictTheirs, "Saved");
+    i18n.add_text(
+        Locale::En,
+        Key::VersionConflictReload,
+        "Discard mine and reload",
+    );
+    i18n.add_text(Locale::En, Key::VersionConflictOverwrite, "Overwrite");
+    i18n.add_text(Locale::En, Key::VersionConflictMerge, "Save selection");
+    i18n.add_text(
+        Locale::En,
+        Key::OfflineQueuePending,
+        "{count} changes not sent yet",
+    );
+    i18n.add_text(Locale::En, Key::OfflineQueueSending, "Sending…");
+    i18n.add_text(Locale::En, Key::OfflineQueueSendNow, "Send now");
+    i18n.add_text(Locale::En, Key::OfflineQueueDiscard, "Discard");
+    i18n.add_text(Locale::En, Key::OfflineQueueRejected, "Rejected: {reason}");
+    i18n.add_text(
+        Locale::En,
+        Key::OfflineQueuePendingChip,
+        "Not sent yet, waiting for a connection",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::OfflineQueueRejectedChip,
+        "Rejected by the server",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::OfflineQueueUnavailable,
+        "{name} · {day} unavailable",
+    );
+    i18n.add_text(
+        Locale::En,
+        Key::OfflineQueueAvailable,
+        "{name} · {day} available again",
+    );
 
     // Booking log
     i18n.add_text(Locale::En, Key::BookingLogTitle, "Booking Log");
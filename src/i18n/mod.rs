@@ -73,6 +73,7 @@ pub enum Key {
     Logout,
     TopBarYouAreLabel,
     TopBarAdminGroupLabel,
+    TopBarResetDemoData,
 
     // Shiftplan
     ShiftplanCalendarWeek,
@@ -92,6 +93,204 @@ pub enum Key {
     ShiftplanDeleteConfirmTitle,
     ShiftplanDeleteConfirmBody,
     ShiftplanIsPlanningLabel,
+    ShiftplanHistory,
+    ShiftplanHistoryEmpty,
+    ShiftplanUndo,
+    ShiftplanRedo,
+    ShiftplanHistoryAdded,
+    ShiftplanHistoryRemoved,
+    ShiftplanHistoryMoved,
+    ShiftplanHistoryCopiedWeek,
+    ShiftplanHistoryBookingCount,
+    CopyWeekOpen,
+    CopyWeekTitle,
+    CopyWeekSource,
+    CopyWeekTargetFrom,
+    CopyWeekTargetUntil,
+    CopyWeekShiftplan,
+    CopyWeekAllShiftplans,
+    CopyWeekSkipUnavailable,
+    CopyWeekPreview,
+    CopyWeekConfirm,
+    CopyWeekInvalidRange,
+    CopyWeekWeekHeading,
+    CopyWeekStatusCreate,
+    CopyWeekStatusConflict,
+    CopyWeekStatusSkipped,
+    CopyWeekStatusExisting,
+    ShiftplanHistoryAutoFilled,
+    AutoFillOpen,
+    AutoFillProposed,
+    AutoFillSummary,
+    AutoFillUnfilled,
+    AutoFillNothing,
+    AutoFillApply,
+    AutoFillDiscard,
+    BookingPatterns,
+    BookingPatternNew,
+    BookingPatternPerson,
+    BookingPatternShiftplan,
+    BookingPatternSlot,
+    BookingPatternInterval,
+    BookingPatternFrom,
+    BookingPatternUntil,
+    BookingPatternAdd,
+    BookingPatternEmpty,
+    BookingPatternEvery,
+    BookingPatternHorizon,
+    BookingPatternMaterialize,
+    BookingPatternMaterializeAll,
+    BookingPatternResult,
+    BookingPatternFailed,
+    BookingPatternLocalOnly,
+    BookingPatternUnknownSlot,
+    ViewModeMultiWeek,
+    MultiWeekFourWeeks,
+    MultiWeekMonth,
+    CoverageWeekLabel,
+    CoverageDayTitle,
+    CoverageCovered,
+    CoveragePartial,
+    CoverageUncovered,
+    CoverageNoSlots,
+    CoverageHint,
+    StaffingHighlight,
+    StaffingLegendUnder,
+    StaffingLegendOver,
+    StaffingLegendUnavailable,
+    StaffingLegendConflict,
+    PersonPickerTitle,
+    PersonPickerFilter,
+    PersonPickerEmpty,
+    ShortcutsTitle,
+    ShortcutsHint,
+    ShortcutMove,
+    ShortcutPick,
+    ShortcutRecent,
+    ShortcutRemove,
+    ShortcutWeek,
+    ShortcutUndo,
+    ShortcutRedo,
+    ShortcutHelp,
+    SlotStructureTitle,
+    SlotStructureLoading,
+    SlotStructureEmpty,
+    SlotStructureValidFrom,
+    SlotStructureValidFromHint,
+    SlotStructureSelected,
+    SlotStructureShiftOutOfDay,
+    SlotStructureApplyMinResources,
+    SlotStructureMinResources,
+    SlotStructureRemove,
+    SlotStructureDuplicateSource,
+    SlotStructureDuplicateShiftplan,
+    SlotStructureDuplicateTargets,
+    SlotStructureDuplicateReplace,
+    SlotStructureDuplicate,
+    SlotStructureNew,
+    SlotStructureChanged,
+    SlotStructureRemoved,
+    SlotStructureSave,
+    SlotStructureRejected,
+    SlotStructureFailed,
+    SlotTimelineTitle,
+    SlotTimelineShiftplan,
+    SlotTimelineFromYear,
+    SlotTimelineUntilYear,
+    SlotTimelineInvalidRange,
+    SlotTimelineLoading,
+    SlotTimelineEmpty,
+    SlotTimelineValidRange,
+    SlotTimelineValidSince,
+    SlotTimelineReopen,
+    SlotTimelineReopenBlocked,
+    SlotTimelineEndFrom,
+    SlotTimelineEnd,
+    SlotTimelineChanges,
+    SlotTimelineNoChanges,
+    SlotTimelineChangeSummary,
+    DraftCreateTitle,
+    DraftName,
+    DraftNameDefault,
+    DraftCreateHint,
+    DraftFromWeek,
+    DraftUntilWeek,
+    DraftInvalidRange,
+    DraftCreate,
+    DraftCompareWith,
+    DraftNotLinked,
+    DraftShowDiff,
+    DraftLivePlan,
+    DraftPlan,
+    DraftDiffSummary,
+    DraftSlotsOnlyInDraft,
+    DraftPublishOpen,
+    DraftPublishTitle,
+    DraftPublishHint,
+    DraftPublish,
+    DraftPublishReport,
+    WeekPublish,
+    WeekUnpublish,
+    WeekPublished,
+    WeekPublishedBy,
+    WeekPublishedLocked,
+    BookingChangesOpen,
+    BookingChangesHide,
+    BookingChangesTitle,
+    BookingChangesSince,
+    BookingChangesSummary,
+    BookingChangesNone,
+    BookingChangesCopy,
+    BookingChangesCopied,
+    BookingChangesTextHeader,
+    TimeTravelOpen,
+    TimeTravelClose,
+    TimeTravelAt,
+    TimeTravelHint,
+    TimeTravelNoLog,
+    BookingProvenanceTitle,
+    BookingProvenanceBookedBy,
+    BookingProvenanceSelfAdded,
+    BookingProvenanceAddedByOther,
+    BookingProvenanceSlotHistory,
+    BookingProvenanceNoHistory,
+    BookingLogWeek,
+    BookingLogAction,
+    BookingLogAt,
+    BookingLogBy,
+    BookingLogActionCreated,
+    BookingLogActionDeleted,
+    BookingLogFilterAction,
+    BookingLogFilterActor,
+    BookingLogFilterShiftplan,
+    BookingLogFromWeek,
+    BookingLogUntilWeek,
+    BookingLogInvalidRange,
+    BookingLogDownloadCsv,
+    BookingLogEntryCount,
+    BookingLogEmpty,
+    BookingLogOpenPage,
+    LiveUpdateViewing,
+    LiveUpdateFeed,
+    LiveUpdatePolling,
+    LiveUpdateChangedBy,
+    VersionConflictTitle,
+    VersionConflictHint,
+    VersionConflictField,
+    VersionConflictYours,
+    VersionConflictTheirs,
+    VersionConflictReload,
+    VersionConflictOverwrite,
+    VersionConflictMerge,
+    OfflineQueuePending,
+    OfflineQueueSending,
+    OfflineQueueSendNow,
+    OfflineQueueDiscard,
+    OfflineQueueRejected,
+    OfflineQueuePendingChip,
+    OfflineQueueRejectedChip,
+    OfflineQueueUnavailable,
+    OfflineQueueAvailable,
 
     // Booking log
     BookingLogTitle,
@@ -510,6 +709,312 @@ mod tests {
         }
     }
 
+    #[test]
+    fn i18n_history_keys_present_in_all_locales() {
+        for locale in [Locale::En, Locale::De, Locale::Cs] {
+            let i18n = generate(locale);
+            for key in [
+                Key::ShiftplanHistory,
+                Key::ShiftplanHistoryEmpty,
+                Key::ShiftplanUndo,
+                Key::ShiftplanRedo,
+                Key::ShiftplanHistoryAdded,
+                Key::ShiftplanHistoryRemoved,
+                Key::ShiftplanHistoryMoved,
+                Key::ShiftplanHistoryCopiedWeek,
+                Key::ShiftplanHistoryBookingCount,
+            ] {
+                let value = i18n.t(key);
+                assert!(
+                    !value.is_empty() && value.as_ref() != "??",
+                    "missing translation for {:?} in {:?}: got `{}`",
+                    key,
+                    locale,
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn i18n_copy_week_and_auto_fill_keys_present_in_all_locales() {
+        for locale in [Locale::En, Locale::De, Locale::Cs] {
+            let i18n = generate(locale);
+            for key in [
+                Key::CopyWeekOpen,
+                Key::CopyWeekTitle,
+                Key::CopyWeekSource,
+                Key::CopyWeekTargetFrom,
+                Key::CopyWeekTargetUntil,
+                Key::CopyWeekShiftplan,
+                Key::CopyWeekAllShiftplans,
+                Key::CopyWeekSkipUnavailable,
+                Key::CopyWeekPreview,
+                Key::CopyWeekConfirm,
+                Key::CopyWeekInvalidRange,
+                Key::CopyWeekWeekHeading,
+                Key::CopyWeekStatusCreate,
+                Key::CopyWeekStatusConflict,
+                Key::CopyWeekStatusSkipped,
+                Key::CopyWeekStatusExisting,
+                Key::ShiftplanHistoryAutoFilled,
+                Key::AutoFillOpen,
+                Key::AutoFillProposed,
+                Key::AutoFillSummary,
+                Key::AutoFillUnfilled,
+                Key::AutoFillNothing,
+                Key::AutoFillApply,
+                Key::AutoFillDiscard,
+            ] {
+                let value = i18n.t(key);
+                assert!(
+                    !value.is_empty() && value.as_ref() != "??",
+                    "missing translation for {:?} in {:?}: got `{}`",
+                    key,
+                    locale,
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn i18n_booking_pattern_keys_present_in_all_locales() {
+        for locale in [Locale::En, Locale::De, Locale::Cs] {
+            let i18n = generate(locale);
+            for key in [
+                Key::BookingPatterns,
+                Key::BookingPatternNew,
+                Key::BookingPatternPerson,
+                Key::BookingPatternShiftplan,
+                Key::BookingPatternSlot,
+                Key::BookingPatternInterval,
+                Key::BookingPatternFrom,
+                Key::BookingPatternUntil,
+                Key::BookingPatternAdd,
+                Key::BookingPatternEmpty,
+                Key::BookingPatternEvery,
+                Key::BookingPatternHorizon,
+                Key::BookingPatternMaterialize,
+                Key::BookingPatternMaterializeAll,
+                Key::BookingPatternResult,
+                Key::BookingPatternFailed,
+                Key::BookingPatternLocalOnly,
+                Key::BookingPatternUnknownSlot,
+            ] {
+                let value = i18n.t(key);
+                assert!(
+                    !value.is_empty() && value.as_ref() != "??",
+                    "missing translation for {:?} in {:?}: got `{}`",
+                    key,
+                    locale,
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn i18n_multi_week_staffing_and_keyboard_keys_present_in_all_locales() {
+        for locale in [Locale::En, Locale::De, Locale::Cs] {
+            let i18n = generate(locale);
+            for key in [
+                Key::ViewModeMultiWeek,
+                Key::MultiWeekFourWeeks,
+                Key::MultiWeekMonth,
+                Key::CoverageWeekLabel,
+                Key::CoverageDayTitle,
+                Key::CoverageCovered,
+                Key::CoveragePartial,
+                Key::CoverageUncovered,
+                Key::CoverageNoSlots,
+                Key::CoverageHint,
+                Key::StaffingHighlight,
+                Key::StaffingLegendUnder,
+                Key::StaffingLegendOver,
+                Key::StaffingLegendUnavailable,
+                Key::StaffingLegendConflict,
+                Key::PersonPickerTitle,
+                Key::PersonPickerFilter,
+                Key::PersonPickerEmpty,
+                Key::ShortcutsTitle,
+                Key::ShortcutsHint,
+                Key::ShortcutMove,
+                Key::ShortcutPick,
+                Key::ShortcutRecent,
+                Key::ShortcutRemove,
+                Key::ShortcutWeek,
+                Key::ShortcutUndo,
+                Key::ShortcutRedo,
+                Key::ShortcutHelp,
+            ] {
+                let value = i18n.t(key);
+                assert!(
+                    !value.is_empty() && value.as_ref() != "??",
+                    "missing translation for {:?} in {:?}: got `{}`",
+                    key,
+                    locale,
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn i18n_slot_structure_and_timeline_keys_present_in_all_locales() {
+        for locale in [Locale::En, Locale::De, Locale::Cs] {
+            let i18n = generate(locale);
+            for key in [
+                Key::SlotStructureTitle,
+                Key::SlotStructureLoading,
+                Key::SlotStructureEmpty,
+                Key::SlotStructureValidFrom,
+                Key::SlotStructureValidFromHint,
+                Key::SlotStructureSelected,
+                Key::SlotStructureShiftOutOfDay,
+                Key::SlotStructureApplyMinResources,
+                Key::SlotStructureMinResources,
+                Key::SlotStructureRemove,
+                Key::SlotStructureDuplicateSource,
+                Key::SlotStructureDuplicateShiftplan,
+                Key::SlotStructureDuplicateTargets,
+                Key::SlotStructureDuplicateReplace,
+                Key::SlotStructureDuplicate,
+                Key::SlotStructureNew,
+                Key::SlotStructureChanged,
+                Key::SlotStructureRemoved,
+                Key::SlotStructureSave,
+                Key::SlotStructureRejected,
+                Key::SlotStructureFailed,
+                Key::SlotTimelineTitle,
+                Key::SlotTimelineShiftplan,
+                Key::SlotTimelineFromYear,
+                Key::SlotTimelineUntilYear,
+                Key::SlotTimelineInvalidRange,
+                Key::SlotTimelineLoading,
+                Key::SlotTimelineEmpty,
+                Key::SlotTimelineValidRange,
+                Key::SlotTimelineValidSince,
+                Key::SlotTimelineReopen,
+                Key::SlotTimelineReopenBlocked,
+                Key::SlotTimelineEndFrom,
+                Key::SlotTimelineEnd,
+                Key::SlotTimelineChanges,
+                Key::SlotTimelineNoChanges,
+                Key::SlotTimelineChangeSummary,
+            ] {
+                let value = i18n.t(key);
+                assert!(
+                    !value.is_empty() && value.as_ref() != "??",
+                    "missing translation for {:?} in {:?}: got `{}`",
+                    key,
+                    locale,
+                    value
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn i18n_shiftplan_draft_and_publication_keys_present_in_all_locales() {
+        for locale in [Locale::En, Locale::De, Locale::Cs] {
+            let i18n = generate(locale);
+            for key in [
+                Key::DraftCreateTitle,
+                Key::DraftName,
+                Key::DraftNameDefault,
+                Key::DraftCreateHint,
+                Key::DraftFromWeek,
+                Key::DraftUntilWeek,
+                Key::DraftInvalidRange,
+                Key::DraftCreate,
+                Key::DraftCompareWith,
+                Key::DraftNotLinked,
+                Key::DraftShowDiff,
+                Key::DraftLivePlan,
+                Key::DraftPlan,
+                Key::DraftDiffSummary,
+                Key::DraftSlotsOnlyInDraft,
+                Key::DraftPublishOpen,
+                Key::DraftPublishTitle,
+                Key::DraftPublishHint,
+                Key::DraftPublish,
+                Key::DraftPublishReport,
+                Key::WeekPublish,
+                Key::WeekUnpublish,
+                Key::WeekPublished,
+                Key::WeekPublishedBy,
+                Key::WeekPublishedLocked,
+                Key::BookingChangesOpen,
+                Key::BookingChangesHide,
+                Key::BookingChangesTitle,
+                Key::BookingChangesSince,
+                Key::BookingChangesSummary,
+                Key::BookingChangesNone,
+                Key::BookingChangesCopy,
+                Key::BookingChangesCopied,
+                Key::BookingChangesTextHeader,
+                Key::TimeTravelOpen,
+                Key::TimeTravelClose,
+                Key::TimeTravelAt,
+                Key::TimeTravelHint,
+                Key::TimeTravelNoLog,
+                Key::BookingProvenanceTitle,
+                Key::BookingProvenanceBookedBy,
+                Key::BookingProvenanceSelfAdded,
+                Key::BookingProvenanceAddedByOther,
+                Key::BookingProvenanceSlotHistory,
+                Key::BookingProvenanceNoHistory,
+                Key::BookingLogWeek,
+                Key::BookingLogAction,
+                Key::BookingLogAt,
+                Key::BookingLogBy,
+                Key::BookingLogActionCreated,
+                Key::BookingLogActionDeleted,
+                Key::BookingLogFilterAction,
+                Key::BookingLogFilterActor,
+                Key::BookingLogFilterShiftplan,
+                Key::BookingLogFromWeek,
+                Key::BookingLogUntilWeek,
+                Key::BookingLogInvalidRange,
+                Key::BookingLogDownloadCsv,
+                Key::BookingLogEntryCount,
+                Key::BookingLogEmpty,
+                Key::BookingLogOpenPage,
+                Key::LiveUpdateViewing,
+                Key::LiveUpdateFeed,
+                Key::LiveUpdatePolling,
+                Key::LiveUpdateChangedBy,
+                Key::VersionConflictTitle,
+                Key::VersionConflictHint,
+                Key::VersionConflictField,
+                Key::VersionConflictYours,
+                Key::VersionConflictTheirs,
+                Key::VersionConflictReload,
+                Key::VersionConflictOverwrite,
+                Key::VersionConflictMerge,
+                Key::OfflineQueuePending,
+                Key::OfflineQueueSending,
+                Key::OfflineQueueSendNow,
+                Key::OfflineQueueDiscard,
+                Key::OfflineQueueRejected,
+                Key::OfflineQueuePendingChip,
+                Key::OfflineQueueRejectedChip,
+                Key::OfflineQueueUnavailable,
+                Key::OfflineQueueAvailable,
+            ] {
+                let value = i18n.t(key);
+                assert!(
+                    !value.is_empty() && value.as_ref() != "??",
+                    "missing translation for {:?} in {:?}: got `{}`",
+                    key,
+                    locale,
+                    value
+                );
+            }
+        }
+    }
+
     #[test]
     fn shiftplan_filled_of_need_substitutes_placeholders() {
         for locale in [Locale::En, Locale::De, Locale::Cs] {
@@ -1,32 +1,52 @@
 use rest_types::{
-    BlockTO, ExtraHoursTO, GenerateInvitationRequest, InvitationResponse, SalesPersonTO,
-    SpecialDayTypeTO, UserRole, UserTO, WeekMessageTO,
+    BlockTO, BookingTO, ExtraHoursTO, GenerateInvitationRequest, InvitationResponse, SalesPersonTO,
+    UpdateTextTemplateRequestTO, UserRole, UserTO, WeekMessageTO, WeekPublicationTO,
 };
-use std::collections::HashMap;
+use std::collections::{hash_map::Entry, HashMap};
 use std::rc::Rc;
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use crate::{
-    api,
+    backend,
     base_types::ImStr,
-    error::ShiftyError,
+    error::{ConflictEntity, ShiftyError},
     state::{
+        auto_fill::{propose_auto_fill, AutoFillProposal},
+        booking_history::{BookingEdit, BookingKey},
         booking_log::BookingLog,
+        booking_log_filter::SlotTimes,
+        booking_pattern::{BookingPattern, PatternMaterialization},
+        coverage::WeekCoverage,
         employee::{Employee, ExtraHours},
         employee_work_details::{EmployeeWorkDetails, WorkingHoursMini},
+        offline_queue::QueuedMutation,
         sales_person_available::SalesPersonUnavailable,
-        shiftplan::{Booking, BookingConflict, SalesPerson},
+        shiftplan::{Booking, BookingConflict, BookingMove, BookingMoveFailure, SalesPerson},
+        shiftplan_draft::{
+            matching_slot, DraftComparison, DraftDiffKind, DraftPublishReport, DraftWeekDiff,
+            ShiftplanDraft,
+        },
         slot_edit::SlotEditItem,
+        slot_structure::{SlotStructureChange, SlotStructureReport},
+        slot_timeline::SlotTimeline,
         text_template::TextTemplate,
         week::Week,
+        week_copy::{plan_week_copy, WeekCopyPreview, WeekCopyPreviewWeek, WeekCopyRequest},
+        week_publication::WeekPublication,
         weekly_overview::WeeklySummary,
         Config, Shiftplan, Slot, User, Weekday,
     },
 };
 
+pub mod cache;
+
+use cache::{CacheQuery, InvalidateOnDrop, Invalidation, Remembered};
+
 pub async fn load_sales_persons(config: Config) -> Result<Rc<[SalesPerson]>, ShiftyError> {
-    let sales_person_tos = api::get_sales_persons(config).await?;
+    let backend = backend::from_config(&config);
+    let sales_person_tos =
+        cache::entities(CacheQuery::SalesPersons, backend.get_sales_persons()).await?;
     let mut sales_persons: Vec<SalesPerson> =
         sales_person_tos.iter().map(SalesPerson::from).collect();
     sales_persons.sort_by_key(|sales_person| sales_person.name.clone());
@@ -39,7 +59,12 @@ pub async fn load_bookable_sales_persons(
     config: Config,
     shiftplan_id: Uuid,
 ) -> Result<Rc<[SalesPerson]>, ShiftyError> {
-    let sales_person_tos = api::get_bookable_sales_persons(config, shiftplan_id).await?;
+    let backend = backend::from_config(&config);
+    let sales_person_tos = cache::entities(
+        CacheQuery::BookableSalesPersons { shiftplan_id },
+        backend.get_bookable_sales_persons(shiftplan_id),
+    )
+    .await?;
     let mut sales_persons: Vec<SalesPerson> =
         sales_person_tos.iter().map(SalesPerson::from).collect();
     sales_persons.sort_by_key(|sales_person| sales_person.name.clone());
@@ -50,7 +75,8 @@ pub async fn load_user_for_sales_person(
     config: Config,
     sales_person_id: Uuid,
 ) -> Result<Option<ImStr>, ShiftyError> {
-    let user = api::get_user_for_sales_person(config, sales_person_id)
+    let user = backend::from_config(&config)
+        .get_user_for_sales_person(sales_person_id)
         .await?
         .map(|user| user.into());
     Ok(user)
@@ -61,7 +87,9 @@ pub async fn save_user_for_sales_person(
     sales_person_id: Uuid,
     user_id: ImStr,
 ) -> Result<(), ShiftyError> {
-    api::post_user_to_sales_person(config, sales_person_id, user_id).await?;
+    backend::from_config(&config)
+        .post_user_to_sales_person(sales_person_id, user_id)
+        .await?;
     Ok(())
 }
 
@@ -69,7 +97,9 @@ pub async fn remove_user_from_sales_person(
     config: Config,
     sales_person_id: Uuid,
 ) -> Result<(), ShiftyError> {
-    api::delete_user_from_sales_person(config, sales_person_id).await?;
+    backend::from_config(&config)
+        .delete_user_from_sales_person(sales_person_id)
+        .await?;
     Ok(())
 }
 
@@ -79,7 +109,12 @@ pub async fn load_bookings(
     week: u8,
     year: u32,
 ) -> Result<Rc<[Booking]>, ShiftyError> {
-    let booking_tos = api::get_bookings_for_week(config, week, year).await?;
+    let backend = backend::from_config(&config);
+    let booking_tos = cache::entities(
+        CacheQuery::Bookings { year, week },
+        backend.get_bookings_for_week(week, year),
+    )
+    .await?;
     let bookings: Rc<[Booking]> = booking_tos
         .iter()
         .map(|booking_to| booking_to.into())
@@ -101,50 +136,10 @@ pub async fn load_bookings(
     Ok(bookings)
 }
 
-pub async fn load_slots(
-    config: Config,
-    year: u32,
-    week: u8,
-    shiftplan_id: Uuid,
-    bookings: Rc<[Booking]>,
-) -> Result<Rc<[Slot]>, ShiftyError> {
-    let slot_tos = api::get_slots(config.clone(), year, week, shiftplan_id).await?;
-    let special_days = api::get_special_days_for_week(config.clone(), year, week).await?;
-    let slots: Rc<[Slot]> = slot_tos
-        .iter()
-        .filter(|slot_to| {
-            if slot_to.shiftplan_id.is_none() {
-                tracing::warn!("Slot {} has no shiftplan_id, ignoring", slot_to.id);
-                return false;
-            }
-            true
-        })
-        .filter(|slot_to| {
-            !special_days.iter().any(|special_day| {
-                special_day.day_of_week == slot_to.day_of_week
-                    && (special_day.day_type == SpecialDayTypeTO::Holiday
-                        || special_day.day_type == SpecialDayTypeTO::ShortDay
-                            && special_day.time_of_day.is_some()
-                            && slot_to.to > special_day.time_of_day.unwrap())
-            })
-        })
-        .map(|slot_to| slot_to.into())
-        .map(|slot: Slot| Slot {
-            bookings: bookings
-                .iter()
-                .filter(|booking| booking.slot_id == slot.id)
-                .map(|booking| booking.clone())
-                .collect(),
-            ..slot
-        })
-        .collect();
-    Ok(slots)
-}
-
 pub async fn load_shiftplan_catalog(
     config: Config,
 ) -> Result<Rc<[rest_types::ShiftplanTO]>, ShiftyError> {
-    let shiftplans = api::get_all_shiftplans(config).await?;
+    let shiftplans = backend::from_config(&config).get_all_shiftplans().await?;
     Ok(shiftplans)
 }
 
@@ -154,7 +149,53 @@ pub async fn load_shift_plan(
     week: u8,
     year: u32,
 ) -> Result<Shiftplan, ShiftyError> {
-    let shiftplan_week = api::get_shiftplan_week(config, shiftplan_id, year, week).await?;
+    let shiftplan = cache::value(
+        CacheQuery::ShiftplanWeek {
+            shiftplan_id,
+            year,
+            week,
+        },
+        fetch_shift_plan(config, shiftplan_id, week, year),
+        |shiftplan: &Shiftplan| {
+            shiftplan
+                .slots
+                .iter()
+                .flat_map(|slot| {
+                    std::iter::once(slot.id).chain(
+                        slot.bookings
+                            .iter()
+                            .flat_map(|booking| [booking.id, booking.sales_person_id]),
+                    )
+                })
+                .collect()
+        },
+    )
+    .await?;
+    Ok(shiftplan.as_ref().clone())
+}
+
+/// The plan of the week from memory, even if expired, e.g. prefetched.
+pub fn remembered_shift_plan(
+    shiftplan_id: Uuid,
+    week: u8,
+    year: u32,
+) -> Option<Remembered<Shiftplan>> {
+    cache::remembered(&CacheQuery::ShiftplanWeek {
+        shiftplan_id,
+        year,
+        week,
+    })
+}
+
+async fn fetch_shift_plan(
+    config: Config,
+    shiftplan_id: Uuid,
+    week: u8,
+    year: u32,
+) -> Result<Shiftplan, ShiftyError> {
+    let shiftplan_week = backend::from_config(&config)
+        .get_shiftplan_week(shiftplan_id, year, week)
+        .await?;
     let slots = shiftplan_week
         .days
         .iter()
@@ -191,6 +232,49 @@ pub async fn load_shift_plan(
     Ok(Shiftplan { week, year, slots })
 }
 
+/// Loads the plan, working hours and, if wanted, the booking conflicts of
+/// the weeks after and before `week` into the cache, so navigating there is
+/// served from memory.
+pub async fn prefetch_neighbour_weeks(
+    config: Config,
+    shiftplan_id: Uuid,
+    week: Week,
+    with_balance: bool,
+    with_conflicts: bool,
+) -> Result<(), ShiftyError> {
+    for neighbour in [week.next()?, week.previous()?] {
+        load_shift_plan(config.clone(), shiftplan_id, neighbour.week, neighbour.year).await?;
+        load_working_hours_minified_for_week(
+            config.clone(),
+            neighbour.year,
+            neighbour.week,
+            with_balance,
+        )
+        .await?;
+        if with_conflicts {
+            load_bookings_conflicts_for_week(config.clone(), neighbour.year, neighbour.week)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Loads the plan `shiftplan_id` for each of `weeks` and summarizes the
+/// staffing of every day.
+pub async fn load_shiftplan_coverage(
+    config: Config,
+    shiftplan_id: Uuid,
+    weeks: &[Week],
+) -> Result<Rc<[WeekCoverage]>, ShiftyError> {
+    info!("Load shiftplan coverage");
+    let mut coverage = Vec::with_capacity(weeks.len());
+    for week in weeks {
+        let shiftplan = load_shift_plan(config.clone(), shiftplan_id, week.week, week.year).await?;
+        coverage.push(WeekCoverage::from_shiftplan(&shiftplan)?);
+    }
+    Ok(coverage.into())
+}
+
 pub async fn load_day_aggregate(
     config: Config,
     year: u32,
@@ -200,7 +284,9 @@ pub async fn load_day_aggregate(
     use crate::state::shiftplan::DayPlanColumn;
 
     let day_of_week_to: rest_types::DayOfWeekTO = (&day_of_week).into();
-    let aggregate = api::get_shiftplan_day(config, year, week, day_of_week_to).await?;
+    let aggregate = backend::from_config(&config)
+        .get_shiftplan_day(year, week, day_of_week_to)
+        .await?;
     let plans: Rc<[DayPlanColumn]> = aggregate
         .plans
         .iter()
@@ -249,7 +335,9 @@ pub async fn load_day_aggregate(
 }
 
 pub async fn load_current_sales_person(config: Config) -> Result<Option<SalesPerson>, ShiftyError> {
-    let sales_person_to = api::get_current_sales_person(config).await?;
+    let sales_person_to = backend::from_config(&config)
+        .get_current_sales_person()
+        .await?;
     let sales_person = sales_person_to.as_ref().map(SalesPerson::from);
     Ok(sales_person)
 }
@@ -258,7 +346,9 @@ pub async fn load_sales_person(
     config: Config,
     sales_person_id: Uuid,
 ) -> Result<SalesPerson, ShiftyError> {
-    let sales_person_to = api::get_sales_person(config, sales_person_id).await?;
+    let sales_person_to = backend::from_config(&config)
+        .get_sales_person(sales_person_id)
+        .await?;
     let sales_person = SalesPerson::from(&sales_person_to);
     Ok(sales_person)
 }
@@ -267,11 +357,16 @@ pub async fn save_sales_person(
     config: Config,
     sales_person: SalesPerson,
 ) -> Result<Uuid, ShiftyError> {
+    let _invalidate = InvalidateOnDrop(Invalidation::SalesPersons);
     if sales_person.id.is_nil() {
-        let created = api::post_sales_person(config, SalesPersonTO::from(&sales_person)).await?;
+        let created = backend::from_config(&config)
+            .post_sales_person(SalesPersonTO::from(&sales_person))
+            .await?;
         Ok(created.id)
     } else {
-        api::put_sales_person(config, SalesPersonTO::from(&sales_person)).await?;
+        backend::from_config(&config)
+            .put_sales_person(SalesPersonTO::from(&sales_person))
+            .await?;
         Ok(sales_person.id)
     }
 }
@@ -284,7 +379,10 @@ pub async fn register_user_to_slot(
     year: u32,
 ) -> Result<(), ShiftyError> {
     info!("Add booking");
-    api::add_booking(config, user_id, slot_id, week, year).await?;
+    let _invalidate = InvalidateOnDrop(Invalidation::Week { year, week });
+    backend::from_config(&config)
+        .add_booking(user_id, slot_id, week, year)
+        .await?;
     Ok(())
 }
 
@@ -295,6 +393,10 @@ pub async fn remove_user_from_slot(
     shiftplan: Shiftplan,
 ) -> Result<(), ShiftyError> {
     info!("Remove booking");
+    let _invalidate = InvalidateOnDrop(Invalidation::Week {
+        year: shiftplan.year,
+        week: shiftplan.week,
+    });
     let slot = shiftplan.slots.iter().find(|slot| slot.id == slot_id);
     if let Some(slot) = slot {
         let booking = slot
@@ -302,19 +404,447 @@ pub async fn remove_user_from_slot(
             .iter()
             .find(|booking| booking.sales_person_id == user_id);
         if let Some(booking) = booking {
-            api::remove_booking(config, booking.id).await?;
+            backend::from_config(&config)
+                .remove_booking(booking.id)
+                .await?;
         }
     }
     Ok(())
 }
 
+/// Removes a booking of the given week by its id, e.g. one picked in the day
+/// view which shows the bookings of several shiftplans.
+pub async fn remove_booking(
+    config: Config,
+    booking_id: Uuid,
+    year: u32,
+    week: u8,
+) -> Result<(), ShiftyError> {
+    info!("Remove booking {booking_id}");
+    let _invalidate = InvalidateOnDrop(Invalidation::Week { year, week });
+    backend::from_config(&config)
+        .remove_booking(booking_id)
+        .await?;
+    Ok(())
+}
+
+/// Sends a booking or unavailability change, e.g. one replayed from the
+/// offline queue.
+pub async fn replay_queued_mutation(
+    config: Config,
+    mutation: &QueuedMutation,
+) -> Result<(), ShiftyError> {
+    match mutation {
+        // Changes are queued on timeouts too, so the original request may
+        // have reached the backend already. Like `apply_booking_edit`, the
+        // replay looks at the week first and neither books twice nor
+        // removes a booking which is gone.
+        QueuedMutation::AddBooking(key) => {
+            let bookings = backend::from_config(&config)
+                .get_bookings_for_week(key.week, key.year)
+                .await?;
+            if bookings
+                .iter()
+                .any(|booking| booking.deleted.is_none() && booking_key(booking) == *key)
+            {
+                cache::invalidate(Invalidation::Week {
+                    year: key.year,
+                    week: key.week,
+                });
+                return Ok(());
+            }
+            register_user_to_slot(config, key.slot_id, key.sales_person_id, key.week, key.year)
+                .await
+        }
+        QueuedMutation::RemoveBooking { booking_id, key } => {
+            let bookings = backend::from_config(&config)
+                .get_bookings_for_week(key.week, key.year)
+                .await?;
+            if !bookings
+                .iter()
+                .any(|booking| booking.deleted.is_none() && booking.id == *booking_id)
+            {
+                cache::invalidate(Invalidation::Week {
+                    year: key.year,
+                    week: key.week,
+                });
+                return Ok(());
+            }
+            remove_booking(config, *booking_id, key.year, key.week).await
+        }
+        QueuedMutation::AddUnavailableDay {
+            sales_person_id,
+            year,
+            week,
+            day_of_week,
+        } => {
+            create_unavailable_sales_person_day(
+                config,
+                *sales_person_id,
+                *year,
+                *week,
+                (*day_of_week).into(),
+            )
+            .await
+        }
+        QueuedMutation::RemoveUnavailableDay { id, .. } => {
+            delete_unavailable_sales_person_day(config, *id).await
+        }
+    }
+}
+
+/// Moves a booking to another slot of the same week.
+///
+/// The booking is removed first and then re-added to the target slot. If the
+/// second call fails, the original booking is restored before the error is
+/// returned, so the person never silently disappears from the plan.
+pub async fn move_user_between_slots(
+    config: Config,
+    booking_move: BookingMove,
+    shiftplan: Shiftplan,
+) -> Result<(), BookingMoveFailure> {
+    info!("Move booking");
+    let Some(booking) = shiftplan
+        .slots
+        .iter()
+        .find(|slot| slot.id == booking_move.from_slot_id)
+        .and_then(|slot| {
+            slot.bookings
+                .iter()
+                .find(|booking| booking.sales_person_id == booking_move.sales_person_id)
+        })
+    else {
+        return Ok(());
+    };
+    let _invalidate = InvalidateOnDrop(Invalidation::Week {
+        year: shiftplan.year,
+        week: shiftplan.week,
+    });
+    let backend = backend::from_config(&config);
+    backend
+        .remove_booking(booking.id)
+        .await
+        .map_err(BookingMoveFailure::NothingApplied)?;
+    let Err(err) = backend
+        .add_booking(
+            booking_move.sales_person_id,
+            booking_move.to_slot_id,
+            shiftplan.week,
+            shiftplan.year,
+        )
+        .await
+    else {
+        return Ok(());
+    };
+    match backend
+        .add_booking(
+            booking_move.sales_person_id,
+            booking_move.from_slot_id,
+            shiftplan.week,
+            shiftplan.year,
+        )
+        .await
+    {
+        Ok(()) => Err(BookingMoveFailure::RolledBack(err)),
+        Err(rollback) => Err(BookingMoveFailure::RollbackFailed {
+            error: err,
+            rollback,
+        }),
+    }
+}
+
+fn booking_key(booking: &BookingTO) -> BookingKey {
+    BookingKey {
+        sales_person_id: booking.sales_person_id,
+        slot_id: booking.slot_id,
+        week: booking.calendar_week as u8,
+        year: booking.year,
+    }
+}
+
+/// Copies the bookings of the previous week and returns the ones which were
+/// actually created, so the copy can be undone.
 pub async fn copy_from_previous_week(
     config: Config,
     week: u8,
     year: u32,
-) -> Result<(), ShiftyError> {
+) -> Result<Rc<[BookingKey]>, ShiftyError> {
     info!("Copy from previous week");
-    api::copy_week(config, week - 1, year, week, year).await?;
+    let _invalidate = InvalidateOnDrop(Invalidation::Week { year, week });
+    let backend = backend::from_config(&config);
+    let previous = Week { year, week }.previous()?;
+    let before = backend.get_bookings_for_week(week, year).await?;
+    backend
+        .copy_week(previous.week, previous.year, week, year)
+        .await?;
+    let after = backend.get_bookings_for_week(week, year).await?;
+    Ok(after
+        .iter()
+        .filter(|booking| before.iter().all(|existing| existing.id != booking.id))
+        .map(booking_key)
+        .collect())
+}
+
+/// Dry run of a [`WeekCopyRequest`]: which bookings of the source week would
+/// be created in each target week, per shiftplan.
+pub async fn preview_week_copy(
+    config: Config,
+    request: WeekCopyRequest,
+) -> Result<WeekCopyPreview, ShiftyError> {
+    info!("Preview week copy");
+    let target_weeks = request.target_weeks()?;
+    let shiftplan_ids: Vec<Uuid> = match request.shiftplan_id {
+        Some(shiftplan_id) => vec![shiftplan_id],
+        None => load_shiftplan_catalog(config.clone())
+            .await?
+            .iter()
+            .filter(|shiftplan| shiftplan.deleted.is_none())
+            .map(|shiftplan| shiftplan.id)
+            .collect(),
+    };
+    let mut sources = Vec::new();
+    for shiftplan_id in shiftplan_ids.iter() {
+        sources.push(
+            load_shift_plan(
+                config.clone(),
+                *shiftplan_id,
+                request.source.week,
+                request.source.year,
+            )
+            .await?,
+        );
+    }
+    let mut sales_person_ids: Vec<Uuid> = sources
+        .iter()
+        .flat_map(|source| source.slots.iter())
+        .flat_map(|slot| slot.bookings.iter())
+        .map(|booking| booking.sales_person_id)
+        .collect();
+    sales_person_ids.sort();
+    sales_person_ids.dedup();
+
+    let mut weeks = Vec::new();
+    for target_week in target_weeks {
+        let mut unavailable = Vec::new();
+        for sales_person_id in sales_person_ids.iter() {
+            unavailable.extend(
+                backend::from_config(&config)
+                    .get_unavailable_sales_person_days_for_week(
+                        *sales_person_id,
+                        target_week.year,
+                        target_week.week,
+                    )
+                    .await?
+                    .iter()
+                    .map(SalesPersonUnavailable::from),
+            );
+        }
+        let mut bookings = Vec::new();
+        for (shiftplan_id, source) in shiftplan_ids.iter().zip(sources.iter()) {
+            let target = load_shift_plan(
+                config.clone(),
+                *shiftplan_id,
+                target_week.week,
+                target_week.year,
+            )
+            .await?;
+            bookings.extend(plan_week_copy(
+                source,
+                &target,
+                &unavailable,
+                request.skip_unavailable,
+            ));
+        }
+        weeks.push(WeekCopyPreviewWeek {
+            week: target_week,
+            bookings,
+        });
+    }
+    Ok(WeekCopyPreview { weeks })
+}
+
+/// Creates the bookings of `patterns` for every occurrence from `from`
+/// through `until`.
+///
+/// Occurrences whose slot does not exist in that week, or whose person is
+/// unavailable that day, are skipped. Existing bookings are left alone. A
+/// failing request counts its occurrence as failed and the rest go on, so
+/// the result still lists every booking which was created.
+pub async fn materialize_booking_patterns(
+    config: Config,
+    patterns: &[BookingPattern],
+    from: Week,
+    until: Week,
+) -> Result<PatternMaterialization, ShiftyError> {
+    info!("Materialize booking patterns");
+    let _invalidate = InvalidateOnDrop(Invalidation::Bookings);
+    let backend = backend::from_config(&config);
+    let mut shiftplans: HashMap<(Uuid, Week), Shiftplan> = HashMap::new();
+    let mut unavailable: HashMap<(Uuid, Week), Rc<[SalesPersonUnavailable]>> = HashMap::new();
+    let mut result = PatternMaterialization::default();
+    for pattern in patterns {
+        for week in pattern.occurrences(from, until) {
+            let shiftplan = match shiftplans.entry((pattern.shiftplan_id, week)) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    match load_shift_plan(
+                        config.clone(),
+                        pattern.shiftplan_id,
+                        week.week,
+                        week.year,
+                    )
+                    .await
+                    {
+                        Ok(shiftplan) => entry.insert(shiftplan),
+                        Err(err) => {
+                            warn!("Loading week {}/{} failed: {err}", week.week, week.year);
+                            result.failed += 1;
+                            continue;
+                        }
+                    }
+                }
+            };
+            let Some(slot) = shiftplan
+                .slots
+                .iter()
+                .find(|slot| slot.id == pattern.slot_id)
+                .cloned()
+            else {
+                result.skipped += 1;
+                continue;
+            };
+            if slot
+                .bookings
+                .iter()
+                .any(|booking| booking.sales_person_id == pattern.sales_person_id)
+            {
+                result.existing += 1;
+                continue;
+            }
+            let days = match unavailable.entry((pattern.sales_person_id, week)) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    match load_unavailable_sales_person_days_for_week(
+                        config.clone(),
+                        pattern.sales_person_id,
+                        week.year,
+                        week.week,
+                    )
+                    .await
+                    {
+                        Ok(days) => entry.insert(days),
+                        Err(err) => {
+                            warn!("Loading unavailable days failed: {err}");
+                            result.failed += 1;
+                            continue;
+                        }
+                    }
+                }
+            };
+            if days.iter().any(|day| day.day_of_week == slot.day_of_week) {
+                result.skipped += 1;
+                continue;
+            }
+            if let Err(err) = backend
+                .add_booking(pattern.sales_person_id, slot.id, week.week, week.year)
+                .await
+            {
+                warn!("Booking a recurring shift failed: {err}");
+                result.failed += 1;
+                continue;
+            }
+            result.created.push(BookingKey {
+                sales_person_id: pattern.sales_person_id,
+                slot_id: slot.id,
+                week: week.week,
+                year: week.year,
+            });
+        }
+    }
+    Ok(result)
+}
+
+/// Proposes bookings for the under-staffed slots of `shiftplan`, the plan
+/// `shiftplan_id` as currently shown for its week.
+pub async fn propose_shiftplan_auto_fill(
+    config: Config,
+    shiftplan_id: Uuid,
+    shiftplan: Shiftplan,
+) -> Result<AutoFillProposal, ShiftyError> {
+    info!("Propose auto-fill");
+    let bookable = load_bookable_sales_persons(config.clone(), shiftplan_id).await?;
+    let working_hours =
+        load_working_hours_minified_for_week(config.clone(), shiftplan.year, shiftplan.week, false)
+            .await?;
+    let mut unavailable = Vec::new();
+    for sales_person in bookable.iter() {
+        unavailable.extend(
+            backend::from_config(&config)
+                .get_unavailable_sales_person_days_for_week(
+                    sales_person.id,
+                    shiftplan.year,
+                    shiftplan.week,
+                )
+                .await?
+                .iter()
+                .map(SalesPersonUnavailable::from),
+        );
+    }
+    Ok(propose_auto_fill(
+        &shiftplan,
+        &bookable,
+        &working_hours,
+        &unavailable,
+    ))
+}
+
+/// Applies a recorded [`BookingEdit`]: removes its `removed` bookings, then
+/// adds its `added` ones.
+///
+/// Bookings are looked up by person, slot and week since their ids change
+/// whenever an edit is undone. Bookings which are already gone are not
+/// removed again and existing ones are not added twice, so replaying an edit
+/// after someone else changed the week does not fail half way.
+pub async fn apply_booking_edit(config: Config, edit: &BookingEdit) -> Result<(), ShiftyError> {
+    info!("Apply booking edit");
+    let _invalidate = InvalidateOnDrop(Invalidation::Bookings);
+    let backend = backend::from_config(&config);
+    let mut weeks: Vec<(u8, u32)> = edit
+        .removed
+        .iter()
+        .chain(edit.added.iter())
+        .map(|key| (key.week, key.year))
+        .collect();
+    weeks.sort();
+    weeks.dedup();
+    // Like `replay_queued_mutation`, bookings removed already are neither
+    // removed again nor count as existing.
+    let mut bookings: Vec<BookingTO> = Vec::new();
+    for (week, year) in weeks {
+        bookings.extend(
+            backend
+                .get_bookings_for_week(week, year)
+                .await?
+                .iter()
+                .filter(|booking| booking.deleted.is_none())
+                .cloned(),
+        );
+    }
+
+    for key in edit.removed.iter() {
+        if let Some(booking) = bookings.iter().find(|booking| booking_key(booking) == *key) {
+            backend.remove_booking(booking.id).await?;
+        }
+    }
+    for key in edit.added.iter() {
+        let removed_now = edit.removed.contains(key);
+        let exists = bookings.iter().any(|booking| booking_key(booking) == *key);
+        if removed_now || !exists {
+            backend
+                .add_booking(key.sales_person_id, key.slot_id, key.week, key.year)
+                .await?;
+        }
+    }
     Ok(())
 }
 
@@ -323,7 +853,9 @@ pub async fn load_employees(
     year: u32,
     week_until: u8,
 ) -> Result<Rc<[Employee]>, ShiftyError> {
-    let report_tos = api::get_short_reports(config, year, week_until).await?;
+    let report_tos = backend::from_config(&config)
+        .get_short_reports(year, week_until)
+        .await?;
     Ok(report_tos.iter().map(Employee::from).collect())
 }
 
@@ -333,7 +865,9 @@ pub async fn load_employee_details(
     week_until: u8,
     employee_id: uuid::Uuid,
 ) -> Result<Employee, ShiftyError> {
-    let report = api::get_employee_reports(config, employee_id, year, week_until).await?;
+    let report = backend::from_config(&config)
+        .get_employee_reports(employee_id, year, week_until)
+        .await?;
     Ok(Employee::from(report.as_ref()))
 }
 
@@ -342,12 +876,12 @@ pub async fn load_extra_hours_per_year(
     year: u32,
     employee_id: uuid::Uuid,
 ) -> Result<Rc<[ExtraHours]>, ShiftyError> {
-    let mut extra_hours: Vec<ExtraHoursTO> =
-        api::get_extra_hours_for_year(config, employee_id, year, 53)
-            .await?
-            .iter()
-            .cloned()
-            .collect();
+    let mut extra_hours: Vec<ExtraHoursTO> = backend::from_config(&config)
+        .get_extra_hours_for_year(employee_id, year, 53)
+        .await?
+        .iter()
+        .cloned()
+        .collect();
     extra_hours.sort_by_key(|extra_hours| extra_hours.date_time.clone());
     Ok(extra_hours.iter().map(ExtraHours::from).collect())
 }
@@ -363,9 +897,9 @@ pub async fn load_unavailable_sales_person_days_for_week(
     year: u32,
     week: u8,
 ) -> Result<Rc<[SalesPersonUnavailable]>, ShiftyError> {
-    let unavailable_days =
-        api::get_unavailable_sales_person_days_for_week(config, sales_person_id, year, week)
-            .await?;
+    let unavailable_days = backend::from_config(&config)
+        .get_unavailable_sales_person_days_for_week(sales_person_id, year, week)
+        .await?;
     let weeks: Rc<[SalesPersonUnavailable]> = unavailable_days
         .iter()
         .map(SalesPersonUnavailable::from)
@@ -380,7 +914,9 @@ pub async fn create_unavailable_sales_person_day(
     week: u8,
     day: Weekday,
 ) -> Result<(), ShiftyError> {
-    api::create_unavailable_sales_person_day(config, sales_person_id, year, week, (&day).into())
+    let _invalidate = InvalidateOnDrop(Invalidation::Week { year, week });
+    backend::from_config(&config)
+        .create_unavailable_sales_person_day(sales_person_id, year, week, (&day).into())
         .await?;
     Ok(())
 }
@@ -389,7 +925,10 @@ pub async fn delete_unavailable_sales_person_day(
     config: Config,
     unavailable_id: Uuid,
 ) -> Result<(), ShiftyError> {
-    api::delete_unavailable_sales_person_day(config, unavailable_id).await?;
+    let _invalidate = InvalidateOnDrop(Invalidation::Bookings);
+    backend::from_config(&config)
+        .delete_unavailable_sales_person_day(unavailable_id)
+        .await?;
     Ok(())
 }
 
@@ -399,10 +938,50 @@ pub async fn load_working_hours_minified_for_week(
     week: u8,
     fetch_balance: bool,
 ) -> Result<Rc<[WorkingHoursMini]>, ShiftyError> {
-    let week_reports = api::get_working_hours_for_week(config.clone(), year, week).await?;
+    let working_hours = cache::value(
+        CacheQuery::WorkingHoursMini {
+            year,
+            week,
+            with_balance: fetch_balance,
+        },
+        fetch_working_hours_minified_for_week(config, year, week, fetch_balance),
+        |working_hours| {
+            working_hours
+                .iter()
+                .map(|working_hours| working_hours.sales_person_id)
+                .collect()
+        },
+    )
+    .await?;
+    Ok(working_hours.as_ref().clone())
+}
+
+pub fn remembered_working_hours_minified_for_week(
+    year: u32,
+    week: u8,
+    fetch_balance: bool,
+) -> Option<Remembered<Rc<[WorkingHoursMini]>>> {
+    cache::remembered(&CacheQuery::WorkingHoursMini {
+        year,
+        week,
+        with_balance: fetch_balance,
+    })
+}
+
+async fn fetch_working_hours_minified_for_week(
+    config: Config,
+    year: u32,
+    week: u8,
+    fetch_balance: bool,
+) -> Result<Rc<[WorkingHoursMini]>, ShiftyError> {
+    let week_reports = backend::from_config(&config)
+        .get_working_hours_for_week(year, week)
+        .await?;
 
     let balance_map: HashMap<Uuid, f32> = if fetch_balance {
-        let balance_reports = api::get_balance_until_week(config, year, week).await?;
+        let balance_reports = backend::from_config(&config)
+            .get_balance_until_week(year, week)
+            .await?;
         balance_reports
             .iter()
             .map(|r| (r.sales_person.id, r.balance_hours))
@@ -505,12 +1084,12 @@ mod working_hours_mini_loader_tests {
 }
 
 pub async fn load_all_users(config: Config) -> Result<Rc<[User]>, ShiftyError> {
-    let users = api::get_all_users(config).await?;
+    let users = backend::from_config(&config).get_all_users().await?;
     Ok(users.iter().map(User::from).collect())
 }
 
 pub async fn load_all_roles(config: Config) -> Result<Rc<[ImStr]>, ShiftyError> {
-    let roles = api::get_all_roles(config).await?;
+    let roles = backend::from_config(&config).get_all_roles().await?;
     Ok(roles.iter().map(|role| role.name.clone().into()).collect())
 }
 
@@ -518,7 +1097,9 @@ pub async fn load_roles_from_user(
     config: Config,
     user_id: ImStr,
 ) -> Result<Rc<[ImStr]>, ShiftyError> {
-    let roles = api::get_roles_from_user(config, user_id).await?;
+    let roles = backend::from_config(&config)
+        .get_roles_from_user(user_id)
+        .await?;
     Ok(roles.iter().map(|role| role.name.clone().into()).collect())
 }
 
@@ -527,14 +1108,12 @@ pub async fn add_user_to_role(
     user_id: ImStr,
     role: ImStr,
 ) -> Result<(), ShiftyError> {
-    api::add_role_to_user(
-        config,
-        UserRole {
+    backend::from_config(&config)
+        .add_role_to_user(UserRole {
             user: user_id.to_string(),
             role: role.to_string(),
-        },
-    )
-    .await?;
+        })
+        .await?;
     Ok(())
 }
 
@@ -543,30 +1122,26 @@ pub async fn remove_user_from_role(
     user_id: ImStr,
     role: ImStr,
 ) -> Result<(), ShiftyError> {
-    api::remove_role_from_user(
-        config,
-        UserRole {
+    backend::from_config(&config)
+        .remove_role_from_user(UserRole {
             user: user_id.to_string(),
             role: role.to_string(),
-        },
-    )
-    .await?;
+        })
+        .await?;
     Ok(())
 }
 
 pub async fn add_user(config: Config, user: ImStr) -> Result<(), ShiftyError> {
-    api::add_user(
-        config,
-        UserTO {
+    backend::from_config(&config)
+        .add_user(UserTO {
             name: user.to_string(),
-        },
-    )
-    .await?;
+        })
+        .await?;
     Ok(())
 }
 
 pub async fn delete_user(config: Config, user: ImStr) -> Result<(), ShiftyError> {
-    api::delete_user(config, user).await?;
+    backend::from_config(&config).delete_user(user).await?;
     Ok(())
 }
 
@@ -575,11 +1150,33 @@ pub async fn load_bookings_conflicts_for_week(
     year: u32,
     week: u8,
 ) -> Result<Rc<[BookingConflict]>, ShiftyError> {
-    Ok(api::get_booking_conflicts_for_week(config, year, week)
-        .await?
-        .iter()
-        .map(|booking_conflict_to| BookingConflict::from(booking_conflict_to))
-        .collect())
+    let backend = backend::from_config(&config);
+    let conflicts = cache::value(
+        CacheQuery::BookingConflicts { year, week },
+        async {
+            Ok(backend
+                .get_booking_conflicts_for_week(year, week)
+                .await?
+                .iter()
+                .map(|booking_conflict_to| BookingConflict::from(booking_conflict_to))
+                .collect::<Rc<[BookingConflict]>>())
+        },
+        |conflicts| {
+            conflicts
+                .iter()
+                .flat_map(|conflict| [conflict.booking_id, conflict.sales_person_id])
+                .collect()
+        },
+    )
+    .await?;
+    Ok(conflicts.as_ref().clone())
+}
+
+pub fn remembered_bookings_conflicts_for_week(
+    year: u32,
+    week: u8,
+) -> Option<Remembered<Rc<[BookingConflict]>>> {
+    cache::remembered(&CacheQuery::BookingConflicts { year, week })
 }
 
 pub async fn load_booking_log(
@@ -587,18 +1184,66 @@ pub async fn load_booking_log(
     year: u32,
     week: u8,
 ) -> Result<Rc<[BookingLog]>, ShiftyError> {
-    Ok(api::get_booking_log(config, year, week)
+    Ok(backend::from_config(&config)
+        .get_booking_log(year, week)
         .await?
         .iter()
         .map(|booking_log_to| BookingLog::from(booking_log_to))
         .collect())
 }
 
+/// The booking log of each of `weeks`; the backend serves one week at a time.
+pub async fn load_booking_log_range(
+    config: Config,
+    weeks: &[Week],
+) -> Result<Rc<[BookingLog]>, ShiftyError> {
+    info!("Load booking log over {} weeks", weeks.len());
+    let backend = backend::from_config(&config);
+    let mut log: Vec<BookingLog> = Vec::new();
+    for week in weeks.iter() {
+        log.extend(
+            backend
+                .get_booking_log(week.year, week.week)
+                .await?
+                .iter()
+                .map(BookingLog::from),
+        );
+    }
+    Ok(log.into())
+}
+
+/// Weekday and times of the slots of `shiftplan_id` valid in each of `weeks`.
+pub async fn load_shiftplan_slot_times(
+    config: Config,
+    shiftplan_id: Uuid,
+    weeks: &[Week],
+) -> Result<Rc<[SlotTimes]>, ShiftyError> {
+    let backend = backend::from_config(&config);
+    let mut slot_times: Vec<SlotTimes> = Vec::new();
+    for week in weeks.iter() {
+        slot_times.extend(
+            backend
+                .get_slots(week.year, week.week, shiftplan_id)
+                .await?
+                .iter()
+                .map(|slot| SlotTimes {
+                    week: *week,
+                    day_of_week: slot.day_of_week.into(),
+                    from: slot.from,
+                    to: slot.to,
+                }),
+        );
+    }
+    Ok(slot_times.into())
+}
+
 pub async fn load_weekly_summary_for_year(
     config: Config,
     year: u32,
 ) -> Result<Rc<[WeeklySummary]>, ShiftyError> {
-    let extra_hours_to = api::get_weekly_overview(config, year).await?;
+    let extra_hours_to = backend::from_config(&config)
+        .get_weekly_overview(year)
+        .await?;
     let mut extra_hours_to: Vec<WeeklySummary> =
         extra_hours_to.iter().map(WeeklySummary::from).collect();
     extra_hours_to.sort_by_key(|extra_hours| (extra_hours.year, extra_hours.week));
@@ -611,7 +1256,9 @@ pub async fn load_summary_for_week(
     year: u32,
     week: u8,
 ) -> Result<WeeklySummary, ShiftyError> {
-    let yearly_summaries = api::get_weekly_overview(config, year).await?;
+    let yearly_summaries = backend::from_config(&config)
+        .get_weekly_overview(year)
+        .await?;
     if let Some(summary) = yearly_summaries.iter().find(|s| s.week == week) {
         Ok(WeeklySummary::from(summary))
     } else {
@@ -639,12 +1286,12 @@ pub async fn load_employee_work_details(
     config: Config,
     employee_id: Uuid,
 ) -> Result<Rc<[EmployeeWorkDetails]>, ShiftyError> {
-    let mut employee_work_details_to: Vec<EmployeeWorkDetails> =
-        api::get_employee_work_details_for_sales_person(config, employee_id)
-            .await?
-            .iter()
-            .flat_map(EmployeeWorkDetails::try_from)
-            .collect();
+    let mut employee_work_details_to: Vec<EmployeeWorkDetails> = backend::from_config(&config)
+        .get_employee_work_details_for_sales_person(employee_id)
+        .await?
+        .iter()
+        .flat_map(EmployeeWorkDetails::try_from)
+        .collect();
     employee_work_details_to.sort_by_key(|details| details.from);
     Ok(employee_work_details_to.into())
 }
@@ -653,7 +1300,9 @@ pub async fn save_new_employee_work_details(
     config: Config,
     employee_work_details: EmployeeWorkDetails,
 ) -> Result<(), ShiftyError> {
-    api::post_employee_work_details(config, (&employee_work_details).try_into()?).await?;
+    backend::from_config(&config)
+        .post_employee_work_details((&employee_work_details).try_into()?)
+        .await?;
     Ok(())
 }
 
@@ -661,12 +1310,14 @@ pub async fn update_employee_work_details(
     config: Config,
     employee_work_details: EmployeeWorkDetails,
 ) -> Result<(), ShiftyError> {
-    api::put_employee_work_details(config, (&employee_work_details).try_into()?).await?;
+    backend::from_config(&config)
+        .put_employee_work_details((&employee_work_details).try_into()?)
+        .await?;
     Ok(())
 }
 
 pub async fn load_slot(config: Config, slot_id: Uuid) -> Result<SlotEditItem, ShiftyError> {
-    let slot_to = api::get_slot(config, slot_id).await?;
+    let slot_to = backend::from_config(&config).get_slot(slot_id).await?;
     Ok((&slot_to).into())
 }
 
@@ -676,33 +1327,357 @@ pub async fn save_slot(
     year: u32,
     week: u8,
 ) -> Result<(), ShiftyError> {
-    api::update_slot(config, slot.as_ref().into(), year, week).await?;
+    let _invalidate = InvalidateOnDrop(Invalidation::Slots);
+    backend::from_config(&config)
+        .update_slot(slot.as_ref().into(), year, week)
+        .await?;
     Ok(())
 }
 
 pub async fn create_slot(config: Config, slot: Rc<SlotEditItem>) -> Result<bool, ShiftyError> {
-    Ok(api::post_slot(config, slot.as_ref().into()).await?)
+    let _invalidate = InvalidateOnDrop(Invalidation::Slots);
+    backend::from_config(&config)
+        .post_slot(slot.as_ref().into())
+        .await
 }
 
-pub async fn load_week_message(
+/// Slots of `shiftplan_id` active in the given week, as editable items.
+pub async fn load_slot_structure(
     config: Config,
     year: u32,
     week: u8,
-) -> Result<Option<String>, ShiftyError> {
-    match api::get_week_message(config, year, week).await? {
-        Some(week_message) => Ok(Some(week_message.message.to_string())),
-        None => Ok(None),
-    }
-}
-
-pub async fn save_week_message(
-    config: Config,
-    year: u32,
+    shiftplan_id: Uuid,
+) -> Result<Rc<[SlotEditItem]>, ShiftyError> {
+    let backend = backend::from_config(&config);
+    let slot_tos = cache::entities(
+        CacheQuery::Slots {
+            year,
+            week,
+            shiftplan_id,
+        },
+        backend.get_slots(year, week, shiftplan_id),
+    )
+    .await?;
+    Ok(slot_tos.iter().map(SlotEditItem::from).collect())
+}
+
+/// Creates a planning shiftplan named `name` as a copy of `live_id`: every
+/// slot valid between `from` and `until` and the bookings of those weeks.
+pub async fn create_shiftplan_draft(
+    config: Config,
+    live_id: Uuid,
+    name: &str,
+    from: Week,
+    until: Week,
+) -> Result<ShiftplanDraft, ShiftyError> {
+    info!("Create draft of shiftplan {live_id}");
+    let _invalidate = InvalidateOnDrop(Invalidation::All);
+    let backend = backend::from_config(&config);
+    let weeks = from.through(until)?;
+    let draft_id = backend.create_shiftplan(name, true).await?.id;
+    // A half copied draft would stay in the tab bar as a planning plan.
+    if let Err(err) = copy_into_draft(&config, live_id, draft_id, &weeks).await {
+        if let Err(cleanup) = backend.delete_shiftplan(draft_id).await {
+            warn!("Removing the incomplete draft {draft_id} failed: {cleanup}");
+        }
+        return Err(err);
+    }
+    Ok(ShiftplanDraft { draft_id, live_id })
+}
+
+/// Copies the slots of `live_id` in `weeks` into the draft, then the
+/// bookings onto the copied slots.
+async fn copy_into_draft(
+    config: &Config,
+    live_id: Uuid,
+    draft_id: Uuid,
+    weeks: &[Week],
+) -> Result<(), ShiftyError> {
+    let backend = backend::from_config(config);
+    let mut slot_ids: Vec<Uuid> = Vec::new();
+    for week in weeks.iter() {
+        for slot in backend
+            .get_slots(week.year, week.week, live_id)
+            .await?
+            .iter()
+        {
+            if slot_ids.contains(&slot.id) {
+                continue;
+            }
+            slot_ids.push(slot.id);
+            backend
+                .post_slot(rest_types::SlotTO {
+                    id: Uuid::nil(),
+                    version: Uuid::nil(),
+                    shiftplan_id: Some(draft_id),
+                    ..slot.clone()
+                })
+                .await?;
+        }
+    }
+    for week in weeks {
+        let live = load_shift_plan(config.clone(), live_id, week.week, week.year).await?;
+        let draft = load_shift_plan(config.clone(), draft_id, week.week, week.year).await?;
+        for live_slot in live.slots.iter() {
+            let Some(draft_slot) = matching_slot(&draft.slots, live_slot) else {
+                continue;
+            };
+            for booking in live_slot.bookings.iter() {
+                backend
+                    .add_booking(booking.sales_person_id, draft_slot.id, week.week, week.year)
+                    .await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The live plan of `week` and how the draft differs from it.
+pub async fn load_draft_comparison(
+    config: Config,
+    draft: ShiftplanDraft,
+    week: Week,
+) -> Result<DraftComparison, ShiftyError> {
+    let live = load_shift_plan(config.clone(), draft.live_id, week.week, week.year).await?;
+    let draft = load_shift_plan(config, draft.draft_id, week.week, week.year).await?;
+    let diff = DraftWeekDiff::new(&live, &draft);
+    Ok(DraftComparison { live, diff })
+}
+
+/// Writes the booking differences of the draft into the live plan for every
+/// week from `from` through `until`.
+pub async fn publish_shiftplan_draft(
+    config: Config,
+    draft: ShiftplanDraft,
+    from: Week,
+    until: Week,
+) -> Result<DraftPublishReport, ShiftyError> {
+    info!("Publish draft {} into {}", draft.draft_id, draft.live_id);
+    let _invalidate = InvalidateOnDrop(Invalidation::Bookings);
+    let backend = backend::from_config(&config);
+    let mut report = DraftPublishReport::default();
+    for week in from.through(until)? {
+        let comparison = load_draft_comparison(config.clone(), draft, week).await?;
+        for booking in comparison.diff.bookings.iter() {
+            match (booking.kind, booking.live_booking_id) {
+                (DraftDiffKind::Added, _) => {
+                    backend
+                        .add_booking(
+                            booking.sales_person_id,
+                            booking.live_slot_id,
+                            week.week,
+                            week.year,
+                        )
+                        .await?;
+                    report.added += 1;
+                }
+                (DraftDiffKind::Removed, Some(booking_id)) => {
+                    backend.remove_booking(booking_id).await?;
+                    report.removed += 1;
+                }
+                (DraftDiffKind::Removed, None) => {}
+            }
+        }
+        report.weeks += 1;
+        report.skipped_slots += comparison.diff.slots_only_in_draft;
+    }
+    Ok(report)
+}
+
+/// Every slot of `shiftplan_id` valid in at least one of `weeks`.
+pub async fn load_slot_timeline(
+    config: Config,
+    shiftplan_id: Uuid,
+    weeks: Vec<Week>,
+) -> Result<SlotTimeline, ShiftyError> {
+    info!("Load slot timeline over {} weeks", weeks.len());
+    let backend = backend::from_config(&config);
+    let mut slots: Vec<SlotEditItem> = Vec::new();
+    for week in weeks.iter() {
+        for slot in cache::entities(
+            CacheQuery::Slots {
+                year: week.year,
+                week: week.week,
+                shiftplan_id,
+            },
+            backend.get_slots(week.year, week.week, shiftplan_id),
+        )
+        .await?
+        .iter()
+        {
+            if !slots.iter().any(|known| known.id == slot.id) {
+                slots.push(slot.into());
+            }
+        }
+    }
+    SlotTimeline::build(weeks, &slots)
+}
+
+/// Removes the end of an ended slot so it is valid again from its
+/// `valid_from` on.
+pub async fn reopen_slot(config: Config, slot: SlotEditItem) -> Result<(), ShiftyError> {
+    let week = Week::of_date(slot.valid_from);
+    let _invalidate = InvalidateOnDrop(Invalidation::Slots);
+    backend::from_config(&config)
+        .update_slot(
+            (&SlotEditItem {
+                valid_to: None,
+                ..slot
+            })
+                .into(),
+            week.year,
+            week.week,
+        )
+        .await?;
+    Ok(())
+}
+
+/// Ends the slot before `week`.
+pub async fn end_slot_from(config: Config, slot_id: Uuid, week: Week) -> Result<(), ShiftyError> {
+    let _invalidate = InvalidateOnDrop(Invalidation::Slots);
+    backend::from_config(&config)
+        .delete_slot_from(slot_id, week.year, week.week)
+        .await?;
+    Ok(())
+}
+
+/// Writes the bulk edits of the structure editor, all of them taking effect
+/// in `valid_from`. Updates and deletes split existing slots at that week;
+/// new slots start on its Monday.
+pub async fn save_slot_structure(
+    config: Config,
+    changes: &[SlotStructureChange],
+    valid_from: Week,
+) -> Result<SlotStructureReport, ShiftyError> {
+    info!("Save {} slot structure changes", changes.len());
+    let _invalidate = InvalidateOnDrop(Invalidation::Slots);
+    let backend = backend::from_config(&config);
+    let monday = valid_from.monday()?;
+    let mut report = SlotStructureReport::default();
+    for change in changes {
+        let result = match change {
+            SlotStructureChange::Delete(slot_id) => {
+                backend
+                    .delete_slot_from(*slot_id, valid_from.year, valid_from.week)
+                    .await
+            }
+            SlotStructureChange::Update(slot) => {
+                backend
+                    .update_slot(slot.into(), valid_from.year, valid_from.week)
+                    .await
+            }
+            SlotStructureChange::Create(slot) => {
+                let slot = SlotEditItem {
+                    valid_from: monday,
+                    ..slot.clone()
+                };
+                match backend.post_slot((&slot).into()).await {
+                    Ok(true) => Ok(()),
+                    Ok(false) => {
+                        report.rejected.push(slot);
+                        continue;
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+        };
+        // Earlier changes are saved already, so a failing one is reported
+        // next to them instead of hiding what was applied.
+        match result {
+            Ok(()) => report.saved += 1,
+            Err(err) => {
+                warn!("Saving a slot structure change failed: {err}");
+                report.failed.push((change.clone(), err.to_string().into()));
+            }
+        }
+    }
+    Ok(report)
+}
+
+pub async fn load_week_message(
+    config: Config,
+    year: u32,
+    week: u8,
+) -> Result<Option<String>, ShiftyError> {
+    match backend::from_config(&config)
+        .get_week_message(year, week)
+        .await?
+    {
+        Some(week_message) => Ok(Some(week_message.message.to_string())),
+        None => Ok(None),
+    }
+}
+
+pub async fn load_week_publication(
+    config: Config,
+    year: u32,
+    week: u8,
+) -> Result<Option<WeekPublication>, ShiftyError> {
+    Ok(backend::from_config(&config)
+        .get_week_publication(year, week)
+        .await?
+        .as_ref()
+        .map(WeekPublication::from))
+}
+
+pub async fn publish_week(
+    config: Config,
+    year: u32,
+    week: u8,
+) -> Result<WeekPublication, ShiftyError> {
+    info!("Publish week {week}/{year}");
+    let publication = backend::from_config(&config)
+        .post_week_publication(WeekPublicationTO {
+            id: Uuid::nil(),
+            year,
+            calendar_week: week,
+            published_at: None,
+            published_by: None,
+            deleted: None,
+            version: Uuid::nil(),
+        })
+        .await?;
+    Ok((&publication).into())
+}
+
+pub async fn unpublish_week(config: Config, publication_id: Uuid) -> Result<(), ShiftyError> {
+    info!("Unpublish week {publication_id}");
+    backend::from_config(&config)
+        .delete_week_publication(publication_id)
+        .await
+}
+
+/// Announces that the current user has the week open and returns the other
+/// users who do, sorted by name.
+pub async fn load_shiftplan_viewers(
+    config: Config,
+    shiftplan_id: Uuid,
+    year: u32,
+    week: u8,
+) -> Result<Rc<[Rc<str>]>, ShiftyError> {
+    let viewers = backend::from_config(&config)
+        .put_shiftplan_presence(shiftplan_id, year, week)
+        .await?;
+    let mut users: Vec<Rc<str>> = viewers
+        .iter()
+        .map(|viewer| viewer.user.as_ref().into())
+        .collect();
+    users.sort();
+    users.dedup();
+    Ok(users.into())
+}
+
+pub async fn save_week_message(
+    config: Config,
+    year: u32,
     week: u8,
     message: String,
 ) -> Result<(), ShiftyError> {
     // First check if a week message already exists
-    match api::get_week_message(config.clone(), year, week).await? {
+    match backend::from_config(&config)
+        .get_week_message(year, week)
+        .await?
+    {
         Some(existing_message) => {
             // Update existing message using PUT
             let week_message = WeekMessageTO {
@@ -714,7 +1689,9 @@ pub async fn save_week_message(
                 deleted: None,
                 version: existing_message.version,
             };
-            api::put_week_message(config, week_message).await?;
+            backend::from_config(&config)
+                .put_week_message(week_message)
+                .await?;
         }
         None => {
             // Create new message using POST
@@ -727,7 +1704,9 @@ pub async fn save_week_message(
                 deleted: None,
                 version: uuid::Uuid::nil(),
             };
-            api::post_week_message(config, week_message).await?;
+            backend::from_config(&config)
+                .post_week_message(week_message)
+                .await?;
         }
     }
     Ok(())
@@ -737,13 +1716,15 @@ pub async fn load_sales_person_by_user(
     config: Config,
     username: ImStr,
 ) -> Result<Option<SalesPerson>, ShiftyError> {
-    let sales_person_to = api::get_sales_person_by_user(config, username).await?;
+    let sales_person_to = backend::from_config(&config)
+        .get_sales_person_by_user(username)
+        .await?;
     let sales_person = sales_person_to.as_ref().map(SalesPerson::from);
     Ok(sales_person)
 }
 
 pub async fn load_text_templates(config: Config) -> Result<Rc<[TextTemplate]>, ShiftyError> {
-    let template_tos = api::get_text_templates(config).await?;
+    let template_tos = backend::from_config(&config).get_text_templates().await?;
     let templates: Vec<TextTemplate> = template_tos.iter().map(TextTemplate::from).collect();
     Ok(templates.into())
 }
@@ -752,7 +1733,9 @@ pub async fn load_text_templates_by_type(
     config: Config,
     template_type: &str,
 ) -> Result<Rc<[TextTemplate]>, ShiftyError> {
-    let template_tos = api::get_text_templates_by_type(config, template_type).await?;
+    let template_tos = backend::from_config(&config)
+        .get_text_templates_by_type(template_type)
+        .await?;
     let templates: Vec<TextTemplate> = template_tos.iter().map(TextTemplate::from).collect();
     Ok(templates.into())
 }
@@ -761,7 +1744,9 @@ pub async fn load_text_template(
     config: Config,
     template_id: Uuid,
 ) -> Result<TextTemplate, ShiftyError> {
-    let template_to = api::get_text_template(config, template_id).await?;
+    let template_to = backend::from_config(&config)
+        .get_text_template(template_id)
+        .await?;
     Ok(TextTemplate::from(&template_to))
 }
 
@@ -770,7 +1755,9 @@ pub async fn save_text_template(
     template: &TextTemplate,
 ) -> Result<TextTemplate, ShiftyError> {
     let request = template.to_create_request();
-    let result_to = api::create_text_template(config, request).await?;
+    let result_to = backend::from_config(&config)
+        .create_text_template(request)
+        .await?;
     Ok(TextTemplate::from(&result_to))
 }
 
@@ -780,12 +1767,60 @@ pub async fn update_text_template(
     template: &TextTemplate,
 ) -> Result<TextTemplate, ShiftyError> {
     let request = template.to_update_request();
-    let result_to = api::update_text_template(config, template_id, request).await?;
+    let result_to = backend::from_config(&config)
+        .update_text_template(template_id, request)
+        .await?;
     Ok(TextTemplate::from(&result_to))
 }
 
+/// Saves the side of a version conflict the user settled on.
+pub async fn save_conflict_entity(
+    config: Config,
+    entity: ConflictEntity,
+) -> Result<(), ShiftyError> {
+    let backend = backend::from_config(&config);
+    match entity {
+        ConflictEntity::SalesPerson(sales_person) => {
+            let _invalidate = InvalidateOnDrop(Invalidation::SalesPersons);
+            backend.put_sales_person(sales_person).await
+        }
+        ConflictEntity::EmployeeWorkDetails(work_details) => {
+            backend.put_employee_work_details(work_details).await
+        }
+        ConflictEntity::ExtraHours(extra_hours) => {
+            backend.update_extra_hour(extra_hours).await?;
+            Ok(())
+        }
+        ConflictEntity::CustomExtraHours(custom_extra_hours) => {
+            backend.put_custom_extra_hours(custom_extra_hours).await
+        }
+        ConflictEntity::TextTemplate(template) => {
+            backend
+                .update_text_template(
+                    template.id,
+                    UpdateTextTemplateRequestTO {
+                        name: template.name,
+                        template_type: template.template_type,
+                        template_text: template.template_text,
+                        template_engine: template.template_engine,
+                        version: Some(template.version),
+                    },
+                )
+                .await?;
+            Ok(())
+        }
+        ConflictEntity::WeekMessage(week_message) => backend.put_week_message(week_message).await,
+        ConflictEntity::Shiftplan(shiftplan) => {
+            backend.update_shiftplan(shiftplan).await?;
+            Ok(())
+        }
+    }
+}
+
 pub async fn delete_text_template(config: Config, template_id: Uuid) -> Result<(), ShiftyError> {
-    api::delete_text_template(config, template_id).await?;
+    backend::from_config(&config)
+        .delete_text_template(template_id)
+        .await?;
     Ok(())
 }
 
@@ -794,7 +1829,9 @@ pub async fn generate_custom_report(
     billing_period_id: Uuid,
     template_id: Uuid,
 ) -> Result<String, ShiftyError> {
-    let report = api::generate_custom_report(config, billing_period_id, template_id).await?;
+    let report = backend::from_config(&config)
+        .generate_custom_report(billing_period_id, template_id)
+        .await?;
     Ok(report)
 }
 
@@ -802,7 +1839,9 @@ pub async fn generate_block_report(
     config: Config,
     template_id: Uuid,
 ) -> Result<String, ShiftyError> {
-    let report = api::generate_block_report(config, template_id).await?;
+    let report = backend::from_config(&config)
+        .generate_block_report(template_id)
+        .await?;
     Ok(report)
 }
 
@@ -823,7 +1862,9 @@ pub async fn generate_invitation(
         username: username.to_string(),
         expiration_hours,
     };
-    let invitation = api::generate_invitation(config.clone(), request).await?;
+    let invitation = backend::from_config(&config)
+        .generate_invitation(request)
+        .await?;
     let fixed_invitation = fix_invitation_link(invitation, &config.backend);
     Ok(fixed_invitation)
 }
@@ -832,7 +1873,9 @@ pub async fn load_user_invitations(
     config: Config,
     username: ImStr,
 ) -> Result<Rc<[InvitationResponse]>, ShiftyError> {
-    let invitations = api::list_user_invitations(config.clone(), username).await?;
+    let invitations = backend::from_config(&config)
+        .list_user_invitations(username)
+        .await?;
     let fixed_invitations: Vec<InvitationResponse> = invitations
         .iter()
         .map(|inv| fix_invitation_link(inv.clone(), &config.backend))
@@ -841,7 +1884,9 @@ pub async fn load_user_invitations(
 }
 
 pub async fn revoke_invitation(config: Config, invitation_id: Uuid) -> Result<(), ShiftyError> {
-    api::revoke_invitation(config, invitation_id).await?;
+    backend::from_config(&config)
+        .revoke_invitation(invitation_id)
+        .await?;
     Ok(())
 }
 
@@ -849,7 +1894,9 @@ pub async fn revoke_invitation_session(
     config: Config,
     invitation_id: Uuid,
 ) -> Result<(), ShiftyError> {
-    api::revoke_session_for_invitation(config, invitation_id).await?;
+    backend::from_config(&config)
+        .revoke_session_for_invitation(invitation_id)
+        .await?;
     Ok(())
 }
 
@@ -860,6 +1907,1059 @@ pub async fn load_blocks(
     to_year: u32,
     to_week: u8,
 ) -> Result<Rc<[BlockTO]>, ShiftyError> {
-    let blocks = api::get_blocks(config, from_year, from_week, to_year, to_week).await?;
+    let blocks = backend::from_config(&config)
+        .get_blocks(from_year, from_week, to_year, to_week)
+        .await?;
     Ok(blocks)
 }
+
+#[cfg(test)]
+mod in_memory_backend_loader_tests {
+    use std::rc::Rc;
+
+    use futures::executor::block_on;
+    use time::macros::date;
+
+    use super::*;
+    use crate::backend::{self, FixtureStore, InMemoryBackend};
+    use crate::state::booking_history::BookingEditKind;
+    use crate::state::booking_log;
+    use crate::state::booking_log_filter::BookingLogFilter;
+    use crate::state::slot_structure::SlotStructureDraft;
+    use crate::state::week_copy::WeekCopyStatus;
+
+    fn install_demo() -> InMemoryBackend {
+        let in_memory = InMemoryBackend::new(FixtureStore::demo(date!(2025 - 06 - 04)));
+        backend::install(Some(Rc::new(in_memory.clone())));
+        in_memory
+    }
+
+    #[test]
+    fn load_shift_plan_reads_from_installed_backend() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let shiftplan = block_on(load_shift_plan(Config::default(), shiftplan_id, 23, 2025));
+        backend::install(None);
+
+        let shiftplan = shiftplan.unwrap();
+        assert_eq!(shiftplan.slots.len(), 18);
+        let booking = shiftplan
+            .slots
+            .iter()
+            .flat_map(|slot| slot.bookings.iter())
+            .next()
+            .expect("fixture bookings");
+        assert_eq!(booking.created_by.as_deref(), Some("demo"));
+        assert!(booking.created.is_some());
+    }
+
+    #[test]
+    fn cached_week_is_refreshed_by_loader_mutations() {
+        let in_memory = install_demo();
+        let store = in_memory.snapshot();
+        let shiftplan_id = store.shiftplans[0].id;
+        let config = Config::default();
+        let result = block_on(async {
+            let before = load_shift_plan(config.clone(), shiftplan_id, 23, 2025).await?;
+            let slot = before.slots[0].clone();
+            let mut unbooked = store
+                .sales_persons
+                .iter()
+                .map(|sales_person| sales_person.id)
+                .filter(|id| {
+                    slot.bookings
+                        .iter()
+                        .all(|booking| booking.sales_person_id != *id)
+                });
+            let (outside, through_loader) = (unbooked.next().unwrap(), unbooked.next().unwrap());
+
+            // A write past the loader is not seen until the week expires.
+            backend::from_config(&config)
+                .add_booking(outside, slot.id, 23, 2025)
+                .await?;
+            let cached = load_shift_plan(config.clone(), shiftplan_id, 23, 2025).await?;
+            register_user_to_slot(config.clone(), slot.id, through_loader, 23, 2025).await?;
+            let after = load_shift_plan(config.clone(), shiftplan_id, 23, 2025).await?;
+            Ok::<_, ShiftyError>((before, cached, after, slot.id, outside, through_loader))
+        });
+        backend::install(None);
+
+        let (before, cached, after, slot_id, outside, through_loader) = result.unwrap();
+        assert_eq!(cached, before);
+        assert!(booked_slot_ids(&after, outside).contains(&slot_id));
+        assert!(booked_slot_ids(&after, through_loader).contains(&slot_id));
+    }
+
+    #[test]
+    fn prefetch_remembers_the_neighbouring_weeks() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let result = block_on(prefetch_neighbour_weeks(
+            Config::default(),
+            shiftplan_id,
+            Week {
+                year: 2025,
+                week: 1,
+            },
+            true,
+            true,
+        ));
+        let previous = remembered_shift_plan(shiftplan_id, 52, 2024);
+        let next = remembered_shift_plan(shiftplan_id, 2, 2025);
+        let shown = remembered_shift_plan(shiftplan_id, 1, 2025);
+        let conflicts = remembered_bookings_conflicts_for_week(2025, 2);
+        let working_hours = remembered_working_hours_minified_for_week(2024, 52, true);
+        backend::install(None);
+
+        result.unwrap();
+        let previous = previous.expect("previous week prefetched");
+        assert!(previous.fresh);
+        assert_eq!((previous.value.year, previous.value.week), (2024, 52));
+        assert!(next.is_some_and(|next| next.value.is_week(2025, 2)));
+        assert!(shown.is_none());
+        assert!(conflicts.is_some() && working_hours.is_some());
+    }
+
+    #[test]
+    fn load_bookings_carries_provenance() {
+        let in_memory = install_demo();
+        let result = block_on(async {
+            let sales_persons = load_sales_persons(Config::default()).await?;
+            load_bookings(Config::default(), sales_persons, 23, 2025).await
+        });
+        backend::install(None);
+
+        let bookings = result.unwrap();
+        let booking = bookings.first().expect("fixture bookings");
+        let sales_person = in_memory
+            .snapshot()
+            .sales_persons
+            .into_iter()
+            .find(|sales_person| sales_person.id == booking.sales_person_id)
+            .unwrap();
+        assert_eq!(booking.label.as_ref(), sales_person.name.as_ref());
+        assert_eq!(booking.created_by.as_deref(), Some("demo"));
+        assert!(booking.created.is_some());
+    }
+
+    #[test]
+    fn register_user_to_slot_is_visible_to_following_loads() {
+        let in_memory = install_demo();
+        let store = in_memory.snapshot();
+        let slot_id = store.slots[0].id;
+        let sales_person_id = store.sales_persons[4].id;
+        let result = block_on(async {
+            register_user_to_slot(Config::default(), slot_id, sales_person_id, 40, 2025).await?;
+            load_bookings_conflicts_for_week(Config::default(), 2025, 40).await?;
+            load_sales_persons(Config::default()).await
+        });
+        backend::install(None);
+
+        assert_eq!(result.unwrap().len(), 5);
+        let bookings = in_memory.snapshot().bookings;
+        assert!(bookings.iter().any(
+            |booking| booking.calendar_week == 40 && booking.sales_person_id == sales_person_id
+        ));
+    }
+
+    fn booked_slot_ids(shiftplan: &Shiftplan, sales_person_id: Uuid) -> Vec<Uuid> {
+        shiftplan
+            .slots
+            .iter()
+            .filter(|slot| {
+                slot.bookings
+                    .iter()
+                    .any(|booking| booking.sales_person_id == sales_person_id)
+            })
+            .map(|slot| slot.id)
+            .collect()
+    }
+
+    #[test]
+    fn apply_booking_edit_reverts_and_replays_an_add() {
+        let in_memory = install_demo();
+        let store = in_memory.snapshot();
+        let key = BookingKey {
+            sales_person_id: store.sales_persons[4].id,
+            slot_id: store.slots[0].id,
+            week: 40,
+            year: 2025,
+        };
+        let edit = BookingEdit {
+            kind: BookingEditKind::Add,
+            summary: "Eva".into(),
+            added: [key].into(),
+            removed: [].into(),
+        };
+        let count = |in_memory: &InMemoryBackend| {
+            in_memory
+                .snapshot()
+                .bookings
+                .iter()
+                .filter(|booking| booking.deleted.is_none() && booking_key(booking) == key)
+                .count()
+        };
+        let result = block_on(async {
+            register_user_to_slot(
+                Config::default(),
+                key.slot_id,
+                key.sales_person_id,
+                40,
+                2025,
+            )
+            .await?;
+            apply_booking_edit(Config::default(), &edit.inverse()).await?;
+            let after_undo = count(&in_memory);
+            apply_booking_edit(Config::default(), &edit).await?;
+            apply_booking_edit(Config::default(), &edit).await?;
+            Ok::<_, ShiftyError>(after_undo)
+        });
+        backend::install(None);
+
+        assert_eq!(result.unwrap(), 0);
+        assert_eq!(count(&in_memory), 1);
+    }
+
+    #[test]
+    fn replayed_bookings_which_already_went_through_are_skipped() {
+        let in_memory = install_demo();
+        let store = in_memory.snapshot();
+        let key = BookingKey {
+            sales_person_id: store.sales_persons[4].id,
+            slot_id: store.slots[0].id,
+            week: 41,
+            year: 2025,
+        };
+        let config = Config::default();
+        let active = |in_memory: &InMemoryBackend| -> Vec<Uuid> {
+            in_memory
+                .snapshot()
+                .bookings
+                .iter()
+                .filter(|booking| booking.deleted.is_none() && booking_key(booking) == key)
+                .map(|booking| booking.id)
+                .collect()
+        };
+        // The first attempt reached the backend before it timed out.
+        block_on(register_user_to_slot(
+            config.clone(),
+            key.slot_id,
+            key.sales_person_id,
+            key.week,
+            key.year,
+        ))
+        .unwrap();
+        let replayed = block_on(replay_queued_mutation(
+            config.clone(),
+            &QueuedMutation::AddBooking(key),
+        ));
+        let booked = active(&in_memory);
+        let booking_id = booked[0];
+        block_on(remove_booking(
+            config.clone(),
+            booking_id,
+            key.year,
+            key.week,
+        ))
+        .unwrap();
+        let removed_again = block_on(replay_queued_mutation(
+            config,
+            &QueuedMutation::RemoveBooking { booking_id, key },
+        ));
+        backend::install(None);
+
+        replayed.unwrap();
+        assert_eq!(booked.len(), 1);
+        removed_again.unwrap();
+        assert!(active(&in_memory).is_empty());
+    }
+
+    #[test]
+    fn replay_queued_mutations_adds_and_removes() {
+        let in_memory = install_demo();
+        let store = in_memory.snapshot();
+        let key = BookingKey {
+            sales_person_id: store.sales_persons[4].id,
+            slot_id: store.slots[0].id,
+            week: 40,
+            year: 2025,
+        };
+        let config = Config::default();
+        block_on(replay_queued_mutation(
+            config.clone(),
+            &QueuedMutation::AddBooking(key),
+        ))
+        .unwrap();
+        let booking_id = in_memory
+            .snapshot()
+            .bookings
+            .iter()
+            .find(|booking| booking.deleted.is_none() && booking_key(booking) == key)
+            .map(|booking| booking.id)
+            .unwrap();
+        block_on(replay_queued_mutation(
+            config.clone(),
+            &QueuedMutation::RemoveBooking { booking_id, key },
+        ))
+        .unwrap();
+        assert!(in_memory
+            .snapshot()
+            .bookings
+            .iter()
+            .all(|booking| booking.deleted.is_some() || booking_key(booking) != key));
+
+        block_on(replay_queued_mutation(
+            config.clone(),
+            &QueuedMutation::AddUnavailableDay {
+                sales_person_id: key.sales_person_id,
+                year: 2025,
+                week: 40,
+                day_of_week: rest_types::DayOfWeekTO::Friday,
+            },
+        ))
+        .unwrap();
+        let days = block_on(load_unavailable_sales_person_days_for_week(
+            config.clone(),
+            key.sales_person_id,
+            2025,
+            40,
+        ))
+        .unwrap();
+        assert_eq!(days.len(), 1);
+        block_on(replay_queued_mutation(
+            config.clone(),
+            &QueuedMutation::RemoveUnavailableDay {
+                id: days[0].id,
+                sales_person_id: key.sales_person_id,
+                year: 2025,
+                week: 40,
+                day_of_week: rest_types::DayOfWeekTO::Friday,
+            },
+        ))
+        .unwrap();
+        let days = block_on(load_unavailable_sales_person_days_for_week(
+            config,
+            key.sales_person_id,
+            2025,
+            40,
+        ))
+        .unwrap();
+        backend::install(None);
+        assert!(days.is_empty());
+    }
+
+    #[test]
+    fn copy_from_previous_week_returns_created_bookings() {
+        let in_memory = install_demo();
+        let created = block_on(copy_from_previous_week(Config::default(), 24, 2025));
+        backend::install(None);
+
+        let created = created.unwrap();
+        let previous_week = in_memory
+            .snapshot()
+            .bookings
+            .iter()
+            .filter(|booking| booking.deleted.is_none() && booking.calendar_week == 23)
+            .count();
+        assert!(!created.is_empty());
+        assert!(created.len() <= previous_week);
+        assert!(created.iter().all(|key| key.week == 24 && key.year == 2025));
+    }
+
+    #[test]
+    fn copy_from_previous_week_crosses_the_year() {
+        // The demo books the week of 2025-12-22, the last one of 2025.
+        let in_memory = InMemoryBackend::new(FixtureStore::demo(date!(2025 - 12 - 22)));
+        backend::install(Some(Rc::new(in_memory)));
+        let created = block_on(copy_from_previous_week(Config::default(), 1, 2026));
+        backend::install(None);
+
+        let created = created.unwrap();
+        assert!(!created.is_empty());
+        assert!(created.iter().all(|key| key.week == 1 && key.year == 2026));
+    }
+
+    #[test]
+    fn stale_sales_person_save_yields_conflict_which_merges() {
+        install_demo();
+        let config = Config::default();
+        let mut loaded = block_on(load_sales_persons(config.clone())).unwrap()[0].clone();
+        let mut other = loaded.clone();
+        other.background_color = "#123456".into();
+        block_on(save_sales_person(config.clone(), other)).unwrap();
+
+        loaded.name = "Renamed".into();
+        let conflict_id = loaded.id;
+        let conflict = match block_on(save_sales_person(config.clone(), loaded)) {
+            Err(ShiftyError::VersionConflict(conflict)) => conflict,
+            other => panic!("expected a version conflict, got {other:?}"),
+        };
+        let fields: Vec<_> = conflict
+            .diffs()
+            .unwrap()
+            .into_iter()
+            .map(|diff| diff.field)
+            .collect();
+        assert_eq!(fields, vec![Rc::from("background_color"), Rc::from("name")]);
+        let merged = conflict
+            .merged(&[Rc::from("name")].into_iter().collect())
+            .unwrap();
+        block_on(save_conflict_entity(config.clone(), merged)).unwrap();
+
+        let saved = block_on(load_sales_persons(config))
+            .unwrap()
+            .iter()
+            .find(|sales_person| sales_person.id == conflict_id)
+            .cloned()
+            .unwrap();
+        assert_eq!(saved.name.as_ref(), "Renamed");
+        assert_eq!(saved.background_color.as_ref(), "#123456");
+        backend::install(None);
+    }
+
+    #[test]
+    fn booking_log_range_spans_weeks_and_matches_shiftplan_slots() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let weeks = Week {
+            year: 2025,
+            week: 21,
+        }
+        .through(Week {
+            year: 2025,
+            week: 23,
+        })
+        .unwrap();
+        let result = block_on(async {
+            let log = load_booking_log_range(Config::default(), &weeks).await?;
+            let slot_times =
+                load_shiftplan_slot_times(Config::default(), shiftplan_id, &weeks).await?;
+            let single = load_booking_log(Config::default(), 2025, 23).await?;
+            Ok::<_, ShiftyError>((log, slot_times, single))
+        });
+        backend::install(None);
+
+        let (log, slot_times, single) = result.unwrap();
+        assert!(log.len() > single.len());
+        assert!(log.iter().any(|entry| entry.calendar_week == 22));
+        assert!(log.iter().any(|entry| entry.calendar_week == 23));
+        assert!(slot_times.iter().any(|slot| slot.week.week == 21));
+        let filter = BookingLogFilter {
+            slots: Some(slot_times),
+            ..Default::default()
+        };
+        assert!(booking_log::events(single.iter())
+            .iter()
+            .any(|event| filter.matches(event)));
+    }
+
+    #[test]
+    fn slot_timeline_shows_ended_slots_and_reopens_them() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let config = Config::default();
+        let weeks = Week {
+            year: 2025,
+            week: 20,
+        }
+        .through(Week {
+            year: 2025,
+            week: 30,
+        })
+        .unwrap();
+        let result = block_on(async {
+            let before = load_slot_timeline(config.clone(), shiftplan_id, weeks.clone()).await?;
+            let slot = before.rows[0].slot.clone();
+            end_slot_from(
+                config.clone(),
+                slot.id,
+                Week {
+                    year: 2025,
+                    week: 25,
+                },
+            )
+            .await?;
+            let ended = load_slot_timeline(config.clone(), shiftplan_id, weeks.clone()).await?;
+            reopen_slot(config.clone(), load_slot(config.clone(), slot.id).await?).await?;
+            let reopened = load_slot_timeline(config.clone(), shiftplan_id, weeks.clone()).await?;
+            Ok::<_, ShiftyError>((slot.id, before, ended, reopened))
+        });
+        backend::install(None);
+
+        let (slot_id, before, ended, reopened) = result.unwrap();
+        let row = |timeline: &SlotTimeline| {
+            timeline
+                .rows
+                .iter()
+                .find(|row| row.slot.id == slot_id)
+                .unwrap()
+                .active
+                .clone()
+        };
+        assert_eq!(row(&before), Some(0..=10));
+        assert!(before.changes.is_empty());
+        assert_eq!(row(&ended), Some(0..=4));
+        assert_eq!(ended.changes, vec![5]);
+        assert_eq!(row(&reopened), Some(0..=10));
+    }
+
+    #[test]
+    fn week_publication_round_trip() {
+        install_demo();
+        let config = Config::default();
+        let result = block_on(async {
+            let before = load_week_publication(config.clone(), 2025, 23).await?;
+            let published = publish_week(config.clone(), 2025, 23).await?;
+            let loaded = load_week_publication(config.clone(), 2025, 23).await?;
+            unpublish_week(config.clone(), published.id).await?;
+            let after = load_week_publication(config.clone(), 2025, 23).await?;
+            Ok::<_, ShiftyError>((before, published, loaded, after))
+        });
+        backend::install(None);
+
+        let (before, published, loaded, after) = result.unwrap();
+        assert!(before.is_none());
+        assert_eq!(published.published_by.as_deref(), Some("demo"));
+        assert_eq!(loaded, Some(published));
+        assert!(after.is_none());
+    }
+
+    #[test]
+    fn failed_draft_copy_removes_the_draft() {
+        // Without the shiftplanner privilege a published week takes no
+        // bookings, so copying them into the draft fails.
+        let mut store = FixtureStore::demo(date!(2025 - 06 - 04));
+        store
+            .privileges
+            .retain(|privilege| privilege != "shiftplanner");
+        let in_memory = InMemoryBackend::new(store);
+        backend::install(Some(Rc::new(in_memory.clone())));
+        let live_id = in_memory.snapshot().shiftplans[0].id;
+        let config = Config::default();
+        let week = Week {
+            year: 2025,
+            week: 23,
+        };
+        let result = block_on(async {
+            publish_week(config.clone(), week.year, week.week).await?;
+            create_shiftplan_draft(config.clone(), live_id, "Draft", week, week).await
+        });
+        backend::install(None);
+
+        assert!(result.is_err());
+        let drafts: Vec<_> = in_memory
+            .snapshot()
+            .shiftplans
+            .iter()
+            .filter(|shiftplan| shiftplan.name.as_ref() == "Draft")
+            .cloned()
+            .collect();
+        assert_eq!(drafts.len(), 1);
+        assert!(drafts[0].deleted.is_some());
+    }
+
+    #[test]
+    fn draft_is_cloned_compared_and_published() {
+        let in_memory = install_demo();
+        let live_id = in_memory.snapshot().shiftplans[0].id;
+        let config = Config::default();
+        let week = Week {
+            year: 2025,
+            week: 23,
+        };
+        let result = block_on(async {
+            let draft =
+                create_shiftplan_draft(config.clone(), live_id, "Draft", week, week).await?;
+            let cloned = load_draft_comparison(config.clone(), draft, week).await?;
+
+            // Move one booking of the draft into a neighbouring slot.
+            let draft_plan =
+                load_shift_plan(config.clone(), draft.draft_id, week.week, week.year).await?;
+            let booked = draft_plan
+                .slots
+                .iter()
+                .find(|slot| !slot.bookings.is_empty())
+                .unwrap();
+            let booking = booked.bookings[0].clone();
+            let target = draft_plan
+                .slots
+                .iter()
+                .find(|slot| {
+                    slot.bookings
+                        .iter()
+                        .all(|other| other.sales_person_id != booking.sales_person_id)
+                })
+                .unwrap();
+            let backend = backend::from_config(&config);
+            backend.remove_booking(booking.id).await?;
+            backend
+                .add_booking(booking.sales_person_id, target.id, week.week, week.year)
+                .await?;
+            cache::invalidate(Invalidation::Week {
+                year: week.year,
+                week: week.week,
+            });
+            let changed = load_draft_comparison(config.clone(), draft, week).await?;
+
+            let report = publish_shiftplan_draft(config.clone(), draft, week, week).await?;
+            let published = load_draft_comparison(config.clone(), draft, week).await?;
+            Ok::<_, ShiftyError>((cloned, changed, report, published))
+        });
+        backend::install(None);
+
+        let (cloned, changed, report, published) = result.unwrap();
+        assert!(cloned.diff.bookings.is_empty());
+        assert_eq!(cloned.diff.slots_only_in_draft, 0);
+        assert_eq!(changed.diff.count(DraftDiffKind::Added), 1);
+        assert_eq!(changed.diff.count(DraftDiffKind::Removed), 1);
+        assert_eq!(report.added, 1);
+        assert_eq!(report.removed, 1);
+        assert!(published.diff.bookings.is_empty());
+    }
+
+    #[test]
+    fn save_slot_structure_splits_at_valid_from_and_reports_overlaps() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let config = Config::default();
+        let result = block_on(async {
+            let slots = load_slot_structure(config.clone(), 2025, 23, shiftplan_id).await?;
+            let mut draft = SlotStructureDraft::new(slots);
+            draft.toggle(0);
+            draft.set_min_resources(4);
+            let monday_count = draft
+                .slots
+                .iter()
+                .filter(|slot| slot.item.day_of_week == Weekday::Monday)
+                .count();
+            // Copying Monday onto Tuesday without replacing overlaps every
+            // Tuesday slot.
+            draft.duplicate_day(Weekday::Monday, &[Weekday::Tuesday], None, false);
+            let report = save_slot_structure(
+                config.clone(),
+                &draft.changes(),
+                Week {
+                    year: 2025,
+                    week: 24,
+                },
+            )
+            .await?;
+            let week_23 = load_slot_structure(config.clone(), 2025, 23, shiftplan_id).await?;
+            let week_24 = load_slot_structure(config.clone(), 2025, 24, shiftplan_id).await?;
+            Ok::<_, ShiftyError>((monday_count, report, week_23, week_24))
+        });
+        backend::install(None);
+
+        let (monday_count, report, week_23, week_24) = result.unwrap();
+        assert_eq!(report.saved, 1);
+        assert_eq!(report.rejected.len(), monday_count);
+        assert!(week_23.iter().all(|slot| slot.min_resources != 4));
+        assert_eq!(
+            week_24
+                .iter()
+                .filter(|slot| slot.min_resources == 4)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn save_slot_structure_reports_failures_next_to_saved_changes() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let config = Config::default();
+        let missing = Uuid::new_v4();
+        let result = block_on(async {
+            let slots = load_slot_structure(config.clone(), 2025, 23, shiftplan_id).await?;
+            let update = |slot: &SlotEditItem| {
+                SlotStructureChange::Update(SlotEditItem {
+                    min_resources: 5,
+                    ..slot.clone()
+                })
+            };
+            let changes = [
+                update(&slots[0]),
+                SlotStructureChange::Delete(missing),
+                update(&slots[1]),
+            ];
+            let week = Week {
+                year: 2025,
+                week: 24,
+            };
+            save_slot_structure(config.clone(), &changes, week).await
+        });
+        backend::install(None);
+
+        let report = result.unwrap();
+        assert_eq!(report.saved, 2);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, SlotStructureChange::Delete(missing));
+    }
+
+    #[test]
+    fn preview_week_copy_covers_every_target_week() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let request = WeekCopyRequest {
+            source: Week {
+                year: 2025,
+                week: 22,
+            },
+            target_from: Week {
+                year: 2025,
+                week: 22,
+            },
+            target_until: Week {
+                year: 2025,
+                week: 25,
+            },
+            shiftplan_id: Some(shiftplan_id),
+            skip_unavailable: true,
+        };
+        let preview = block_on(preview_week_copy(Config::default(), request));
+        backend::install(None);
+
+        let preview = preview.unwrap();
+        let weeks: Vec<u8> = preview.weeks.iter().map(|week| week.week.week).collect();
+        assert_eq!(weeks, vec![23, 24, 25]);
+        assert!(preview.weeks[0].count(WeekCopyStatus::AlreadyBooked) > 0);
+        assert!(preview.weeks[2]
+            .bookings
+            .iter()
+            .all(|booking| booking.status == WeekCopyStatus::Create));
+    }
+
+    #[test]
+    fn preview_week_copy_rejects_too_many_target_weeks() {
+        let request = WeekCopyRequest {
+            source: Week {
+                year: 2025,
+                week: 1,
+            },
+            target_from: Week {
+                year: 2025,
+                week: 1,
+            },
+            target_until: Week {
+                year: 2025,
+                week: 52,
+            },
+            shiftplan_id: None,
+            skip_unavailable: true,
+        };
+        let preview = block_on(preview_week_copy(Config::default(), request));
+
+        assert!(matches!(
+            preview,
+            Err(ShiftyError::WeekRangeTooLong { weeks: 51, .. })
+        ));
+    }
+
+    #[test]
+    fn auto_fill_proposes_bookings_for_missing_capacity() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let result = block_on(async {
+            let shiftplan = load_shift_plan(Config::default(), shiftplan_id, 23, 2025).await?;
+            let proposal =
+                propose_shiftplan_auto_fill(Config::default(), shiftplan_id, shiftplan.clone())
+                    .await?;
+            Ok::<_, ShiftyError>((shiftplan, proposal))
+        });
+        backend::install(None);
+
+        let (shiftplan, proposal) = result.unwrap();
+        assert!(!proposal.is_empty());
+        for assignment in proposal.assignments.iter() {
+            let slot = shiftplan
+                .slots
+                .iter()
+                .find(|slot| slot.id == assignment.key.slot_id)
+                .unwrap();
+            assert!(slot.evaluation().too_less_resources);
+            assert!(!slot
+                .bookings
+                .iter()
+                .any(|booking| booking.sales_person_id == assignment.key.sales_person_id));
+        }
+    }
+
+    #[test]
+    fn load_shiftplan_coverage_summarizes_each_week() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let weeks = [
+            Week {
+                year: 2025,
+                week: 23,
+            },
+            Week {
+                year: 2025,
+                week: 24,
+            },
+        ];
+        let coverage = block_on(load_shiftplan_coverage(
+            Config::default(),
+            shiftplan_id,
+            &weeks,
+        ));
+        backend::install(None);
+
+        let coverage = coverage.unwrap();
+        assert_eq!(coverage.len(), 2);
+        assert_eq!(coverage[0].week, weeks[0]);
+        assert_eq!(coverage[0].days.len(), 7);
+        let slots: usize = coverage[0].days.iter().map(|day| day.slots).sum();
+        assert_eq!(slots, 18);
+        assert!(coverage[0].understaffed_days() > 0);
+    }
+
+    #[test]
+    fn materialize_booking_patterns_creates_missing_occurrences() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let eva = in_memory.snapshot().sales_persons[4].id;
+        let result = block_on(async {
+            let shiftplan = load_shift_plan(Config::default(), shiftplan_id, 24, 2025).await?;
+            let slot_id = shiftplan
+                .slots
+                .iter()
+                .find(|slot| {
+                    !slot
+                        .bookings
+                        .iter()
+                        .any(|booking| booking.sales_person_id == eva)
+                })
+                .unwrap()
+                .id;
+            let pattern = BookingPattern {
+                id: Uuid::new_v4(),
+                sales_person_id: eva,
+                shiftplan_id,
+                slot_id,
+                interval_weeks: 2,
+                valid_from: Week {
+                    year: 2025,
+                    week: 24,
+                },
+                valid_until: Week {
+                    year: 2025,
+                    week: 30,
+                },
+            };
+            let from = Week {
+                year: 2025,
+                week: 24,
+            };
+            let until = Week {
+                year: 2025,
+                week: 28,
+            };
+            let first = materialize_booking_patterns(
+                Config::default(),
+                std::slice::from_ref(&pattern),
+                from,
+                until,
+            )
+            .await?;
+            let second =
+                materialize_booking_patterns(Config::default(), &[pattern], from, until).await?;
+            Ok::<_, ShiftyError>((first, second))
+        });
+        backend::install(None);
+
+        let (first, second) = result.unwrap();
+        let weeks: Vec<u8> = first.created.iter().map(|key| key.week).collect();
+        assert_eq!(weeks, vec![24, 26, 28]);
+        assert!(second.created.is_empty());
+        assert_eq!(second.existing, 3);
+    }
+
+    #[test]
+    fn materialize_booking_patterns_counts_failures_and_keeps_going() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let eva = in_memory.snapshot().sales_persons[4].id;
+        let result = block_on(async {
+            let shiftplan = load_shift_plan(Config::default(), shiftplan_id, 24, 2025).await?;
+            let slot_id = shiftplan
+                .slots
+                .iter()
+                .find(|slot| {
+                    !slot
+                        .bookings
+                        .iter()
+                        .any(|booking| booking.sales_person_id == eva)
+                })
+                .unwrap()
+                .id;
+            let weeks = Week {
+                year: 2025,
+                week: 24,
+            };
+            let pattern = |sales_person_id| BookingPattern {
+                id: Uuid::new_v4(),
+                sales_person_id,
+                shiftplan_id,
+                slot_id,
+                interval_weeks: 1,
+                valid_from: weeks,
+                valid_until: weeks,
+            };
+            // The backend does not know the first person, so its booking
+            // fails while the second one is still created.
+            materialize_booking_patterns(
+                Config::default(),
+                &[pattern(Uuid::new_v4()), pattern(eva)],
+                weeks,
+                weeks,
+            )
+            .await
+        });
+        backend::install(None);
+
+        let result = result.unwrap();
+        assert_eq!(result.failed, 1);
+        assert_eq!(result.created.len(), 1);
+        assert_eq!(result.created[0].sales_person_id, eva);
+    }
+
+    #[test]
+    fn move_user_between_slots_moves_the_booking() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let result = block_on(async {
+            let shiftplan = load_shift_plan(Config::default(), shiftplan_id, 23, 2025).await?;
+            let from_slot = &shiftplan.slots[0];
+            let sales_person_id = from_slot.bookings[0].sales_person_id;
+            let to_slot = shiftplan
+                .slots
+                .iter()
+                .find(|slot| {
+                    slot.bookings
+                        .iter()
+                        .all(|booking| booking.sales_person_id != sales_person_id)
+                })
+                .expect("free target slot");
+            let booking_move = BookingMove {
+                sales_person_id,
+                from_slot_id: from_slot.id,
+                to_slot_id: to_slot.id,
+            };
+            let moved =
+                move_user_between_slots(Config::default(), booking_move, shiftplan.clone()).await;
+            let reloaded = load_shift_plan(Config::default(), shiftplan_id, 23, 2025).await?;
+            Ok::<_, ShiftyError>((booking_move, moved, reloaded))
+        });
+        backend::install(None);
+
+        let (booking_move, moved, reloaded) = result.unwrap();
+        moved.unwrap();
+        let booked = booked_slot_ids(&reloaded, booking_move.sales_person_id);
+        assert!(booked.contains(&booking_move.to_slot_id));
+        assert!(!booked.contains(&booking_move.from_slot_id));
+    }
+
+    #[test]
+    fn move_user_between_slots_rolls_back_when_adding_fails() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let result = block_on(async {
+            let shiftplan = load_shift_plan(Config::default(), shiftplan_id, 23, 2025).await?;
+            let from_slot = &shiftplan.slots[0];
+            let booking_move = BookingMove {
+                sales_person_id: from_slot.bookings[0].sales_person_id,
+                from_slot_id: from_slot.id,
+                to_slot_id: Uuid::nil(),
+            };
+            let moved =
+                move_user_between_slots(Config::default(), booking_move, shiftplan.clone()).await;
+            let reloaded = load_shift_plan(Config::default(), shiftplan_id, 23, 2025).await?;
+            Ok::<_, ShiftyError>((booking_move, moved, reloaded))
+        });
+        backend::install(None);
+
+        let (booking_move, moved, reloaded) = result.unwrap();
+        assert!(matches!(moved, Err(BookingMoveFailure::RolledBack(_))));
+        let booked = booked_slot_ids(&reloaded, booking_move.sales_person_id);
+        assert!(booked.contains(&booking_move.from_slot_id));
+    }
+
+    #[test]
+    fn move_user_between_slots_reports_nothing_applied_for_a_stale_booking() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let result = block_on(async {
+            let shiftplan = load_shift_plan(Config::default(), shiftplan_id, 23, 2025).await?;
+            let from_slot = &shiftplan.slots[0];
+            let booking = from_slot.bookings[0].clone();
+            let to_slot = shiftplan
+                .slots
+                .iter()
+                .find(|slot| {
+                    slot.bookings
+                        .iter()
+                        .all(|other| other.sales_person_id != booking.sales_person_id)
+                })
+                .expect("free target slot");
+            let booking_move = BookingMove {
+                sales_person_id: booking.sales_person_id,
+                from_slot_id: from_slot.id,
+                to_slot_id: to_slot.id,
+            };
+            // Someone else removed the booking after the plan was loaded.
+            remove_booking(Config::default(), booking.id, 2025, 23).await?;
+            let moved =
+                move_user_between_slots(Config::default(), booking_move, shiftplan.clone()).await;
+            let reloaded = load_shift_plan(Config::default(), shiftplan_id, 23, 2025).await?;
+            Ok::<_, ShiftyError>((booking_move, moved, reloaded))
+        });
+        backend::install(None);
+
+        let (booking_move, moved, reloaded) = result.unwrap();
+        assert!(matches!(moved, Err(BookingMoveFailure::NothingApplied(_))));
+        let booked = booked_slot_ids(&reloaded, booking_move.sales_person_id);
+        assert!(!booked.contains(&booking_move.from_slot_id));
+        assert!(!booked.contains(&booking_move.to_slot_id));
+    }
+
+    #[test]
+    fn move_user_between_slots_reports_a_failed_rollback() {
+        let in_memory = install_demo();
+        let shiftplan_id = in_memory.snapshot().shiftplans[0].id;
+        let result = block_on(async {
+            let loaded = load_shift_plan(Config::default(), shiftplan_id, 23, 2025).await?;
+            // The old slot is gone by the time the move is rolled back, so
+            // neither the new nor the old slot can be booked.
+            let from_slot = Slot {
+                id: Uuid::from_u128(1),
+                ..loaded.slots[0].clone()
+            };
+            let shiftplan = Shiftplan {
+                slots: [from_slot.clone()].into(),
+                ..loaded
+            };
+            let booking_move = BookingMove {
+                sales_person_id: from_slot.bookings[0].sales_person_id,
+                from_slot_id: from_slot.id,
+                to_slot_id: Uuid::nil(),
+            };
+            let moved = move_user_between_slots(Config::default(), booking_move, shiftplan).await;
+            let reloaded = load_shift_plan(Config::default(), shiftplan_id, 23, 2025).await?;
+            Ok::<_, ShiftyError>((from_slot, moved, reloaded))
+        });
+        backend::install(None);
+
+        let (from_slot, moved, reloaded) = result.unwrap();
+        assert!(matches!(
+            moved,
+            Err(BookingMoveFailure::RollbackFailed { .. })
+        ));
+        let booking_id = from_slot.bookings[0].id;
+        assert!(reloaded
+            .slots
+            .iter()
+            .flat_map(|slot| slot.bookings.iter())
+            .all(|booking| booking.id != booking_id));
+    }
+}
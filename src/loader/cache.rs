@@ -0,0 +1,570 @@
+//! Normalized client cache for the loader.
+//!
+//! Entities are stored once, keyed by their id. A query, e.g. the bookings
+//! of a week, remembers which ids it returned and when it was fetched. A
+//! query younger than its TTL is answered from memory and concurrent loads
+//! of the same query share one request. Mutations call [`invalidate`] so the
+//! next load fetches again. An expired query stays [`remembered`], so a page
+//! can show it right away while it is loaded again.
+
+use std::{any::Any, cell::RefCell, collections::HashMap, future::Future, rc::Rc};
+
+use futures::channel::oneshot;
+use rest_types::{BookingTO, SalesPersonTO, SlotTO};
+use uuid::Uuid;
+
+use crate::error::ShiftyError;
+
+/// TTL of data which rarely changes: people, slots and special days.
+const STABLE_TTL_MS: f64 = 5.0 * 60.0 * 1000.0;
+/// TTL of bookings, which other planners change all the time.
+const BOOKINGS_TTL_MS: f64 = 30.0 * 1000.0;
+/// TTL of working hours. They also change through extra hours and contracts,
+/// which do not invalidate the cache, so they are only kept briefly.
+const WORKING_HOURS_TTL_MS: f64 = 10.0 * 1000.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CacheQuery {
+    SalesPersons,
+    BookableSalesPersons {
+        shiftplan_id: Uuid,
+    },
+    Bookings {
+        year: u32,
+        week: u8,
+    },
+    Slots {
+        year: u32,
+        week: u8,
+        shiftplan_id: Uuid,
+    },
+    ShiftplanWeek {
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+    },
+    BookingConflicts {
+        year: u32,
+        week: u8,
+    },
+    WorkingHoursMini {
+        year: u32,
+        week: u8,
+        with_balance: bool,
+    },
+}
+
+impl CacheQuery {
+    fn ttl_ms(&self) -> f64 {
+        match self {
+            CacheQuery::Bookings { .. }
+            | CacheQuery::ShiftplanWeek { .. }
+            | CacheQuery::BookingConflicts { .. } => BOOKINGS_TTL_MS,
+            CacheQuery::WorkingHoursMini { .. } => WORKING_HOURS_TTL_MS,
+            CacheQuery::SalesPersons
+            | CacheQuery::BookableSalesPersons { .. }
+            | CacheQuery::Slots { .. } => STABLE_TTL_MS,
+        }
+    }
+
+    /// The week of a query derived from the bookings of that week.
+    fn booked_week(&self) -> Option<(u32, u8)> {
+        match *self {
+            CacheQuery::Bookings { year, week }
+            | CacheQuery::ShiftplanWeek { year, week, .. }
+            | CacheQuery::BookingConflicts { year, week }
+            | CacheQuery::WorkingHoursMini { year, week, .. } => Some((year, week)),
+            CacheQuery::SalesPersons
+            | CacheQuery::BookableSalesPersons { .. }
+            | CacheQuery::Slots { .. } => None,
+        }
+    }
+}
+
+/// What a mutation changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Invalidation {
+    /// One entity. Every query which returned it is dropped.
+    Entity(Uuid),
+    /// The bookings of one week.
+    Week {
+        year: u32,
+        week: u8,
+    },
+    /// Bookings of several weeks, e.g. after copying weeks.
+    Bookings,
+    /// Slots, which apply to many weeks.
+    Slots,
+    SalesPersons,
+    /// Which sales persons may book into which shiftplan.
+    ShiftplanAssignments,
+    All,
+}
+
+impl Invalidation {
+    fn drops(&self, query: &CacheQuery, refs: &[Uuid]) -> bool {
+        match self {
+            Invalidation::All => true,
+            Invalidation::Entity(id) => refs.contains(id),
+            Invalidation::Week { year, week } => query.booked_week() == Some((*year, *week)),
+            Invalidation::Bookings => query.booked_week().is_some(),
+            Invalidation::Slots => {
+                matches!(query, CacheQuery::Slots { .. }) || query.booked_week().is_some()
+            }
+            Invalidation::SalesPersons => {
+                matches!(
+                    query,
+                    CacheQuery::SalesPersons | CacheQuery::BookableSalesPersons { .. }
+                ) || query.booked_week().is_some()
+            }
+            Invalidation::ShiftplanAssignments => {
+                matches!(query, CacheQuery::BookableSalesPersons { .. })
+            }
+        }
+    }
+}
+
+/// An entity which is stored by id.
+pub trait CacheEntity: Clone + 'static {
+    fn cache_id(&self) -> Uuid;
+}
+
+impl CacheEntity for SalesPersonTO {
+    fn cache_id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl CacheEntity for BookingTO {
+    fn cache_id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl CacheEntity for SlotTO {
+    fn cache_id(&self) -> Uuid {
+        self.id
+    }
+}
+
+enum Stored {
+    /// A list of entities, in the order the backend returned them.
+    Entities(Rc<[Uuid]>),
+    /// A composed result which is not split into entities.
+    Value(Rc<dyn Any>),
+}
+
+struct QueryEntry {
+    stored: Stored,
+    /// Ids of all entities the result contains.
+    refs: Rc<[Uuid]>,
+    fetched_at: f64,
+}
+
+/// A cached value which may be older than its TTL.
+pub struct Remembered<T> {
+    pub value: Rc<T>,
+    /// Whether a load would return the value without fetching it again.
+    pub fresh: bool,
+}
+
+#[derive(Default)]
+pub struct LoaderCache {
+    entities: HashMap<Uuid, Rc<dyn Any>>,
+    queries: HashMap<CacheQuery, QueryEntry>,
+    /// Loads which are under way, with the loads waiting for them.
+    in_flight: HashMap<CacheQuery, Vec<oneshot::Sender<()>>>,
+    /// Bumped by every invalidation. A response to a request sent before
+    /// the last invalidation is returned but not stored.
+    generation: u64,
+}
+
+impl LoaderCache {
+    fn fresh(&self, query: &CacheQuery, now: f64) -> Option<&QueryEntry> {
+        self.queries
+            .get(query)
+            .filter(|entry| now - entry.fetched_at < query.ttl_ms())
+    }
+
+    pub fn get_entities<T: CacheEntity>(&self, query: &CacheQuery, now: f64) -> Option<Rc<[T]>> {
+        let Stored::Entities(ids) = &self.fresh(query, now)?.stored else {
+            return None;
+        };
+        ids.iter()
+            .map(|id| self.entities.get(id)?.downcast_ref::<T>().cloned())
+            .collect()
+    }
+
+    pub fn put_entities<T: CacheEntity>(&mut self, query: CacheQuery, items: &[T], now: f64) {
+        let ids: Rc<[Uuid]> = items.iter().map(CacheEntity::cache_id).collect();
+        for item in items {
+            self.entities.insert(item.cache_id(), Rc::new(item.clone()));
+        }
+        self.queries.insert(
+            query,
+            QueryEntry {
+                stored: Stored::Entities(ids.clone()),
+                refs: ids,
+                fetched_at: now,
+            },
+        );
+    }
+
+    pub fn get_value<T: 'static>(&self, query: &CacheQuery, now: f64) -> Option<Rc<T>> {
+        let Stored::Value(value) = &self.fresh(query, now)?.stored else {
+            return None;
+        };
+        value.clone().downcast::<T>().ok()
+    }
+
+    /// The value of `query` regardless of its TTL. Invalidated values are
+    /// gone.
+    pub fn get_remembered<T: 'static>(
+        &self,
+        query: &CacheQuery,
+        now: f64,
+    ) -> Option<Remembered<T>> {
+        let entry = self.queries.get(query)?;
+        let Stored::Value(value) = &entry.stored else {
+            return None;
+        };
+        Some(Remembered {
+            value: value.clone().downcast::<T>().ok()?,
+            fresh: now - entry.fetched_at < query.ttl_ms(),
+        })
+    }
+
+    pub fn put_value<T: 'static>(
+        &mut self,
+        query: CacheQuery,
+        value: Rc<T>,
+        refs: Rc<[Uuid]>,
+        now: f64,
+    ) {
+        self.queries.insert(
+            query,
+            QueryEntry {
+                stored: Stored::Value(value),
+                refs,
+                fetched_at: now,
+            },
+        );
+    }
+
+    pub fn invalidate(&mut self, invalidation: Invalidation) {
+        self.generation += 1;
+        self.queries
+            .retain(|query, entry| !invalidation.drops(query, &entry.refs));
+        match invalidation {
+            Invalidation::Entity(id) => {
+                self.entities.remove(&id);
+            }
+            Invalidation::All => self.entities.clear(),
+            _ => {}
+        }
+    }
+}
+
+thread_local! {
+    static CACHE: RefCell<LoaderCache> = RefCell::new(LoaderCache::default());
+}
+
+fn with_cache<R>(f: impl FnOnce(&mut LoaderCache) -> R) -> R {
+    CACHE.with(|cache| f(&mut cache.borrow_mut()))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn now_ms() -> f64 {
+    js_sys::Date::now()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now_ms() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64() * 1000.0)
+        .unwrap_or_default()
+}
+
+/// Forgets a load under way when it is dropped, e.g. cancelled, so the loads
+/// waiting for it fetch themselves.
+struct InFlight(CacheQuery);
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        let _ = CACHE.try_with(|cache| {
+            if let Ok(mut cache) = cache.try_borrow_mut() {
+                cache.in_flight.remove(&self.0);
+            }
+        });
+    }
+}
+
+/// Answers `query` from the cache or runs `fetch` and stores its result.
+async fn load<T>(
+    query: CacheQuery,
+    lookup: impl Fn(&LoaderCache, f64) -> Option<T>,
+    fetch: impl Future<Output = Result<T, ShiftyError>>,
+    store: impl FnOnce(&mut LoaderCache, &T, f64),
+) -> Result<T, ShiftyError> {
+    if let Some(hit) = with_cache(|cache| lookup(cache, now_ms())) {
+        return Ok(hit);
+    }
+    let waiting = with_cache(|cache| {
+        cache.in_flight.get_mut(&query).map(|waiters| {
+            let (sender, receiver) = oneshot::channel();
+            waiters.push(sender);
+            receiver
+        })
+    });
+    let in_flight = match waiting {
+        Some(receiver) => {
+            let _ = receiver.await;
+            if let Some(hit) = with_cache(|cache| lookup(cache, now_ms())) {
+                return Ok(hit);
+            }
+            // The other load failed or was invalidated, try once more.
+            None
+        }
+        None => {
+            with_cache(|cache| cache.in_flight.insert(query, Vec::new()));
+            Some(InFlight(query))
+        }
+    };
+    let generation = with_cache(|cache| cache.generation);
+    let result = fetch.await;
+    with_cache(|cache| {
+        if let Ok(value) = &result {
+            if cache.generation == generation {
+                store(cache, value, now_ms());
+            }
+        }
+        if in_flight.is_some() {
+            for waiter in cache.in_flight.remove(&query).unwrap_or_default() {
+                let _ = waiter.send(());
+            }
+        }
+    });
+    result
+}
+
+/// A list of entities, cached per query.
+pub async fn entities<T: CacheEntity>(
+    query: CacheQuery,
+    fetch: impl Future<Output = Result<Rc<[T]>, ShiftyError>>,
+) -> Result<Rc<[T]>, ShiftyError> {
+    load(
+        query,
+        |cache, now| cache.get_entities(&query, now),
+        fetch,
+        |cache, items, now| cache.put_entities(query, items, now),
+    )
+    .await
+}
+
+/// A composed result, cached as a whole. It is dropped with any of the
+/// entities `refs` lists.
+pub async fn value<T: 'static>(
+    query: CacheQuery,
+    fetch: impl Future<Output = Result<T, ShiftyError>>,
+    refs: impl FnOnce(&T) -> Rc<[Uuid]>,
+) -> Result<Rc<T>, ShiftyError> {
+    load(
+        query,
+        |cache, now| cache.get_value(&query, now),
+        async { fetch.await.map(Rc::new) },
+        |cache, value, now| cache.put_value(query, value.clone(), refs(value), now),
+    )
+    .await
+}
+
+/// The value of `query`, even if expired, e.g. a prefetched week.
+pub fn remembered<T: 'static>(query: &CacheQuery) -> Option<Remembered<T>> {
+    with_cache(|cache| cache.get_remembered(query, now_ms()))
+}
+
+/// Drops what `invalidation` describes. Loads which are under way are not
+/// stored anymore.
+pub fn invalidate(invalidation: Invalidation) {
+    with_cache(|cache| cache.invalidate(invalidation));
+}
+
+/// Forgets everything, e.g. when another backend is installed.
+pub fn clear() {
+    invalidate(Invalidation::All);
+}
+
+/// Invalidates when dropped, so a mutation of several requests invalidates
+/// on every way out, including failures half way.
+#[must_use]
+pub struct InvalidateOnDrop(pub Invalidation);
+
+impl Drop for InvalidateOnDrop {
+    fn drop(&mut self) {
+        let _ = CACHE.try_with(|cache| {
+            if let Ok(mut cache) = cache.try_borrow_mut() {
+                cache.invalidate(self.0);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use futures::{executor::block_on, join};
+
+    use super::*;
+
+    fn sales_person(id: u128, name: &str) -> SalesPersonTO {
+        SalesPersonTO {
+            id: Uuid::from_u128(id),
+            name: name.into(),
+            background_color: "#ffffff".into(),
+            is_paid: Some(true),
+            inactive: false,
+            deleted: None,
+            version: Uuid::nil(),
+        }
+    }
+
+    #[test]
+    fn queries_expire_after_their_ttl() {
+        let mut cache = LoaderCache::default();
+        let week = CacheQuery::Bookings {
+            year: 2025,
+            week: 23,
+        };
+        cache.put_entities(
+            CacheQuery::SalesPersons,
+            &[sales_person(1, "Anna")],
+            1_000.0,
+        );
+        cache.put_entities::<BookingTO>(week, &[], 1_000.0);
+
+        assert!(cache
+            .get_entities::<SalesPersonTO>(&CacheQuery::SalesPersons, 1_000.0 + BOOKINGS_TTL_MS)
+            .is_some());
+        assert!(cache
+            .get_entities::<BookingTO>(&week, 1_000.0 + BOOKINGS_TTL_MS)
+            .is_none());
+        assert!(cache
+            .get_entities::<SalesPersonTO>(&CacheQuery::SalesPersons, 1_000.0 + STABLE_TTL_MS)
+            .is_none());
+    }
+
+    #[test]
+    fn entities_are_shared_between_queries() {
+        let mut cache = LoaderCache::default();
+        let bookable = CacheQuery::BookableSalesPersons {
+            shiftplan_id: Uuid::from_u128(9),
+        };
+        cache.put_entities(
+            CacheQuery::SalesPersons,
+            &[sales_person(1, "Anna"), sales_person(2, "Ben")],
+            0.0,
+        );
+        cache.put_entities(bookable, &[sales_person(2, "Benjamin")], 0.0);
+
+        let all = cache
+            .get_entities::<SalesPersonTO>(&CacheQuery::SalesPersons, 1.0)
+            .unwrap();
+        assert_eq!(all[1].name.as_ref(), "Benjamin");
+
+        cache.invalidate(Invalidation::Entity(Uuid::from_u128(2)));
+        assert!(cache
+            .get_entities::<SalesPersonTO>(&CacheQuery::SalesPersons, 1.0)
+            .is_none());
+        assert!(cache
+            .get_entities::<SalesPersonTO>(&bookable, 1.0)
+            .is_none());
+    }
+
+    #[test]
+    fn week_invalidation_keeps_other_weeks() {
+        let mut cache = LoaderCache::default();
+        let week = |week| CacheQuery::Bookings { year: 2025, week };
+        cache.put_entities::<BookingTO>(week(23), &[], 0.0);
+        cache.put_entities::<BookingTO>(week(24), &[], 0.0);
+        cache.put_entities(CacheQuery::SalesPersons, &[sales_person(1, "Anna")], 0.0);
+
+        cache.invalidate(Invalidation::Week {
+            year: 2025,
+            week: 23,
+        });
+        assert!(cache.get_entities::<BookingTO>(&week(23), 1.0).is_none());
+        assert!(cache.get_entities::<BookingTO>(&week(24), 1.0).is_some());
+        assert!(cache
+            .get_entities::<SalesPersonTO>(&CacheQuery::SalesPersons, 1.0)
+            .is_some());
+    }
+
+    #[test]
+    fn expired_values_stay_remembered_until_invalidated() {
+        let mut cache = LoaderCache::default();
+        let conflicts = CacheQuery::BookingConflicts {
+            year: 2025,
+            week: 23,
+        };
+        cache.put_value(conflicts, Rc::new(7_u32), Rc::from([]), 0.0);
+
+        let remembered = cache
+            .get_remembered::<u32>(&conflicts, BOOKINGS_TTL_MS)
+            .unwrap();
+        assert_eq!(*remembered.value, 7);
+        assert!(!remembered.fresh);
+        assert!(cache
+            .get_value::<u32>(&conflicts, BOOKINGS_TTL_MS)
+            .is_none());
+
+        cache.invalidate(Invalidation::Slots);
+        assert!(cache.get_remembered::<u32>(&conflicts, 0.0).is_none());
+    }
+
+    #[test]
+    fn concurrent_loads_share_one_request() {
+        clear();
+        let fetches = Cell::new(0);
+        let (release, released) = oneshot::channel::<()>();
+        let fetch = |gate: Option<oneshot::Receiver<()>>| {
+            let fetches = &fetches;
+            async move {
+                fetches.set(fetches.get() + 1);
+                if let Some(gate) = gate {
+                    let _ = gate.await;
+                }
+                Ok::<Rc<[SalesPersonTO]>, ShiftyError>(Rc::from([sales_person(1, "Anna")]))
+            }
+        };
+        let (first, second, _) = block_on(async {
+            join!(
+                entities(CacheQuery::SalesPersons, fetch(Some(released))),
+                entities(CacheQuery::SalesPersons, fetch(None)),
+                async {
+                    let _ = release.send(());
+                }
+            )
+        });
+        assert_eq!(fetches.get(), 1);
+        assert_eq!(first.unwrap()[0].name.as_ref(), "Anna");
+        assert_eq!(second.unwrap()[0].name.as_ref(), "Anna");
+
+        // Responses to requests sent before an invalidation are not stored.
+        clear();
+        let (release, released) = oneshot::channel::<()>();
+        block_on(async {
+            join!(
+                entities(CacheQuery::SalesPersons, fetch(Some(released))),
+                async {
+                    invalidate(Invalidation::SalesPersons);
+                    let _ = release.send(());
+                }
+            )
+        })
+        .0
+        .unwrap();
+        block_on(entities(CacheQuery::SalesPersons, fetch(None))).unwrap();
+        assert_eq!(fetches.get(), 3);
+    }
+}
@@ -8,6 +8,7 @@ use tracing::Level;
 mod api;
 mod app;
 mod auth;
+mod backend;
 mod base_types;
 mod component;
 mod error;
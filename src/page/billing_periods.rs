@@ -1,5 +1,5 @@
 use crate::{
-    api,
+    backend,
     base_types::ImStr,
     component::{
         atoms::{Btn, BtnVariant},
@@ -85,7 +85,10 @@ pub fn BillingPeriods() -> Element {
                         let bp_id = *delete_billing_period_id.read();
                         if let Some(id) = bp_id {
                             let config = CONFIG.read().clone();
-                            match api::delete_billing_period(config, id).await {
+                            match backend::from_config(&config)
+                                .delete_billing_period(id)
+                                .await
+                            {
                                 Ok(()) => {
                                     show_delete_dialog.set(false);
                                     delete_billing_period_id.set(None);
@@ -0,0 +1,354 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    base_types::ImStr,
+    component::{
+        atoms::{btn, BtnVariant},
+        form::{Field, SelectInput, TextInput},
+        shiftplan_draft_dialog::parse_week_range,
+        TopBar,
+    },
+    i18n::Key,
+    js, loader,
+    service::{auth::AUTH, config::CONFIG, i18n::I18N},
+    state::{
+        booking_changes::BookingChangeKind,
+        booking_log::{self, BookingLogEvent},
+        booking_log_filter::{
+            action_label, actors, csv_data_url, sort_events, to_csv, BookingLogColumn,
+            BookingLogFilter,
+        },
+        week::Week,
+    },
+};
+
+/// Most weeks the page loads at once; every week is a request.
+const MAX_WEEKS: usize = 53;
+
+/// The weeks between the two `YYYY-Www` inputs, `None` if the range is
+/// invalid or longer than [`MAX_WEEKS`].
+pub(crate) fn parse_weeks(from: &str, until: &str) -> Option<Vec<Week>> {
+    let (from, until) = parse_week_range(from, until)?;
+    from.through(until)
+        .ok()
+        .filter(|weeks| weeks.len() <= MAX_WEEKS)
+}
+
+fn format_time(time: time::Time) -> String {
+    format!("{:02}:{:02}", time.hour(), time.minute())
+}
+
+const HEADER_CLASSES: &str =
+    "px-3 py-2 text-left text-micro text-ink-muted uppercase whitespace-nowrap cursor-pointer select-none hover:text-ink";
+
+#[component]
+pub fn BookingLogPage() -> Element {
+    let i18n = I18N.read().clone();
+    let config = CONFIG.read().clone();
+    let is_shiftplanner = AUTH
+        .read()
+        .auth_info
+        .as_ref()
+        .map(|auth_info| auth_info.has_privilege("shiftplanner"))
+        .unwrap_or(false);
+
+    let current_week = Week {
+        year: js::get_current_year(),
+        week: js::get_current_week(),
+    };
+    let mut from = use_signal(move || {
+        (0..3)
+            .try_fold(current_week, |week, _| week.previous())
+            .unwrap_or(current_week)
+            .to_iso_string()
+    });
+    let mut until = use_signal(move || current_week.to_iso_string());
+    let mut person = use_signal(String::new);
+    let mut action = use_signal(|| None::<BookingChangeKind>);
+    let mut actor = use_signal(|| None::<Rc<str>>);
+    let mut shiftplan_id = use_signal(|| None::<Uuid>);
+    let mut sort_column = use_signal(|| BookingLogColumn::At);
+    let mut ascending = use_signal(|| false);
+
+    let catalog = {
+        let config = config.clone();
+        use_resource(move || loader::load_shiftplan_catalog(config.to_owned()))
+    };
+    let log = {
+        let config = config.clone();
+        use_resource(move || {
+            let config = config.to_owned();
+            let weeks = parse_weeks(&from.read(), &until.read());
+            async move {
+                match weeks {
+                    Some(weeks) => loader::load_booking_log_range(config, &weeks)
+                        .await
+                        .map(Some),
+                    None => Ok(None),
+                }
+            }
+        })
+    };
+    let slot_times = {
+        let config = config.clone();
+        use_resource(move || {
+            let config = config.to_owned();
+            let weeks = parse_weeks(&from.read(), &until.read());
+            let shiftplan_id = *shiftplan_id.read();
+            async move {
+                match (weeks, shiftplan_id) {
+                    (Some(weeks), Some(shiftplan_id)) => {
+                        loader::load_shiftplan_slot_times(config, shiftplan_id, &weeks)
+                            .await
+                            .map(Some)
+                    }
+                    _ => Ok(None),
+                }
+            }
+        })
+    };
+
+    if !is_shiftplanner {
+        return rsx! {
+            TopBar {}
+        };
+    }
+
+    let range_valid = parse_weeks(&from.read(), &until.read()).is_some();
+    let catalog_list = match &*catalog.read_unchecked() {
+        Some(Ok(catalog)) => catalog.clone(),
+        _ => Rc::from([]),
+    };
+    let all_events: Option<Result<Vec<BookingLogEvent>, ImStr>> = match &*log.read_unchecked() {
+        Some(Ok(Some(log))) => Some(Ok(booking_log::events(log.iter()))),
+        Some(Err(err)) => Some(Err(ImStr::from(err.to_string()))),
+        Some(Ok(None)) | None => None,
+    };
+    let slot_error = match &*slot_times.read_unchecked() {
+        Some(Err(err)) => Some(ImStr::from(err.to_string())),
+        _ => None,
+    };
+    let slot_filter = match &*slot_times.read_unchecked() {
+        Some(Ok(Some(slot_times))) => Some(slot_times.clone()),
+        // Until the shiftplan's slots are known nothing matches.
+        _ if shiftplan_id.read().is_some() => Some(Rc::from([])),
+        _ => None,
+    };
+    let filter = BookingLogFilter {
+        person: person.read().clone(),
+        action: *action.read(),
+        actor: actor.read().clone(),
+        slots: slot_filter,
+    };
+    let actor_list = match &all_events {
+        Some(Ok(events)) => actors(events),
+        _ => Vec::new(),
+    };
+    let shown: Option<Result<Vec<BookingLogEvent>, ImStr>> = all_events.map(|events| {
+        events.map(|events| {
+            let mut events: Vec<BookingLogEvent> = events
+                .into_iter()
+                .filter(|event| filter.matches(event))
+                .collect();
+            sort_events(&mut events, *sort_column.read(), *ascending.read());
+            events
+        })
+    });
+    let csv_href = match &shown {
+        Some(Ok(events)) => csv_data_url(&to_csv(events, &i18n)),
+        _ => String::new(),
+    };
+    let csv_file_name = format!("booking-log-{}-{}.csv", from.read(), until.read());
+
+    let header = move |column: BookingLogColumn, title: Rc<str>| {
+        let marker = match (*sort_column.read() == column, *ascending.read()) {
+            (true, true) => " ▲",
+            (true, false) => " ▼",
+            (false, _) => "",
+        };
+        rsx! {
+            th {
+                class: HEADER_CLASSES,
+                onclick: move |_| {
+                    if *sort_column.read() == column {
+                        let was_ascending = *ascending.read();
+                        ascending.set(!was_ascending);
+                    } else {
+                        sort_column.set(column);
+                        ascending.set(true);
+                    }
+                },
+                "{title}{marker}"
+            }
+        }
+    };
+
+    rsx! {
+        TopBar {}
+        main { class: "mx-auto w-full px-4 py-6 md:py-8 space-y-6",
+            h1 { class: "text-h1 text-ink", "{i18n.t(Key::BookingLogTitle)}" }
+            section { class: "grid grid-cols-3 gap-3 max-w-[960px] max-md:grid-cols-1",
+                Field { label: ImStr::from(i18n.t(Key::BookingLogFromWeek).as_ref()),
+                    TextInput {
+                        value: ImStr::from(from.read().as_str()),
+                        input_type: ImStr::from("week"),
+                        on_change: move |value: ImStr| from.set(value.to_string()),
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::BookingLogUntilWeek).as_ref()),
+                    TextInput {
+                        value: ImStr::from(until.read().as_str()),
+                        input_type: ImStr::from("week"),
+                        on_change: move |value: ImStr| until.set(value.to_string()),
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::BookingLogFilterShiftplan).as_ref()),
+                    SelectInput {
+                        on_change: move |value: ImStr| shiftplan_id.set(value.as_str().parse::<Uuid>().ok()),
+                        option { value: "", selected: shiftplan_id.read().is_none(), "{i18n.t(Key::BookingLogFilterAll)}" }
+                        for shiftplan in catalog_list.iter().filter(|shiftplan| shiftplan.deleted.is_none()) {
+                            option {
+                                value: "{shiftplan.id}",
+                                selected: Some(shiftplan.id) == *shiftplan_id.read(),
+                                "{shiftplan.name}"
+                            }
+                        }
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::BookingLogFilterName).as_ref()),
+                    TextInput {
+                        value: ImStr::from(person.read().as_str()),
+                        on_change: move |value: ImStr| person.set(value.to_string()),
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::BookingLogFilterAction).as_ref()),
+                    SelectInput {
+                        on_change: move |value: ImStr| {
+                            action
+                                .set(
+                                    match value.as_str() {
+                                        "created" => Some(BookingChangeKind::Added),
+                                        "deleted" => Some(BookingChangeKind::Removed),
+                                        _ => None,
+                                    },
+                                )
+                        },
+                        option { value: "all", selected: action.read().is_none(), "{i18n.t(Key::BookingLogFilterAll)}" }
+                        option {
+                            value: "created",
+                            selected: *action.read() == Some(BookingChangeKind::Added),
+                            "{i18n.t(Key::BookingLogActionCreated)}"
+                        }
+                        option {
+                            value: "deleted",
+                            selected: *action.read() == Some(BookingChangeKind::Removed),
+                            "{i18n.t(Key::BookingLogActionDeleted)}"
+                        }
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::BookingLogFilterActor).as_ref()),
+                    SelectInput {
+                        on_change: move |value: ImStr| {
+                            actor.set((!value.as_str().is_empty()).then(|| Rc::from(value.as_str())))
+                        },
+                        option { value: "", selected: actor.read().is_none(), "{i18n.t(Key::BookingLogFilterAll)}" }
+                        for name in actor_list.iter() {
+                            option {
+                                value: "{name}",
+                                selected: actor.read().as_ref() == Some(name),
+                                "{name}"
+                            }
+                        }
+                    }
+                }
+            }
+            if !range_valid {
+                div { class: "text-small text-bad",
+                    {i18n.t_m(Key::BookingLogInvalidRange, [("max", MAX_WEEKS.to_string().as_str())].into())}
+                }
+            }
+            if let Some(err) = slot_error {
+                div { class: "text-small text-bad", "{err}" }
+            }
+            match shown {
+                None if range_valid => rsx! {
+                    div { class: "text-small text-ink-muted", "{i18n.t(Key::BookingLogLoading)}" }
+                },
+                None => rsx! {},
+                Some(Err(err)) => rsx! {
+                    div { class: "text-small text-bad", "{err}" }
+                },
+                Some(Ok(events)) => rsx! {
+                    div { class: "flex flex-wrap items-center gap-3",
+                        span { class: "text-small text-ink-muted flex-1",
+                            {i18n.t_m(Key::BookingLogEntryCount, [("count", events.len().to_string().as_str())].into())}
+                        }
+                        a {
+                            class: btn::build_class(BtnVariant::Secondary, events.is_empty()),
+                            href: "{csv_href}",
+                            download: "{csv_file_name}",
+                            "{i18n.t(Key::BookingLogDownloadCsv)}"
+                        }
+                    }
+                    if events.is_empty() {
+                        div { class: "text-small text-ink-muted italic", "{i18n.t(Key::BookingLogEmpty)}" }
+                    } else {
+                        section { class: "bg-surface border border-border rounded-lg overflow-x-auto",
+                            table { class: "w-full text-small font-normal border-collapse",
+                                thead {
+                                    tr { class: "bg-surface-alt text-left",
+                                        {header(BookingLogColumn::Week, i18n.t(Key::BookingLogWeek))}
+                                        {header(BookingLogColumn::Day, i18n.t(Key::BookingLogDay))}
+                                        {header(BookingLogColumn::Time, i18n.t(Key::BookingLogTime))}
+                                        {header(BookingLogColumn::Name, i18n.t(Key::BookingLogName))}
+                                        {header(BookingLogColumn::Action, i18n.t(Key::BookingLogAction))}
+                                        {header(BookingLogColumn::At, i18n.t(Key::BookingLogAt))}
+                                        {header(BookingLogColumn::By, i18n.t(Key::BookingLogBy))}
+                                    }
+                                }
+                                tbody {
+                                    for event in events.iter() {
+                                        tr { class: "border-t border-border",
+                                            td { class: "px-3 py-2 font-mono whitespace-nowrap", "{event.week.week}/{event.week.year}" }
+                                            td { class: "px-3 py-2", "{event.day_of_week.i18n_string(&i18n)}" }
+                                            td { class: "px-3 py-2 font-mono whitespace-nowrap",
+                                                "{format_time(event.time_from)}–{format_time(event.time_to)}"
+                                            }
+                                            td { class: "px-3 py-2 text-ink", "{event.sales_person_name}" }
+                                            td {
+                                                class: if event.kind == BookingChangeKind::Removed { "px-3 py-2 text-bad" } else { "px-3 py-2 text-good" },
+                                                "{action_label(&i18n, event.kind)}"
+                                            }
+                                            td { class: "px-3 py-2 text-ink-muted whitespace-nowrap",
+                                                "{i18n.format_date(&event.at.date())} {format_time(event.at.time())}"
+                                            }
+                                            td { class: "px-3 py-2 text-ink-muted", {event.by.as_deref().unwrap_or("—")} }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn week_range_is_bounded() {
+        assert_eq!(
+            parse_weeks("2025-W20", "2025-W23").map(|weeks| weeks.len()),
+            Some(4)
+        );
+        assert_eq!(parse_weeks("2025-W23", "2025-W20"), None);
+        assert_eq!(parse_weeks("2023-W01", "2025-W01"), None);
+    }
+}
@@ -0,0 +1,409 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    base_types::ImStr,
+    component::{
+        atoms::{Btn, BtnVariant},
+        form::{Field, SelectInput, TextInput},
+        PersonChip, TopBar,
+    },
+    i18n::{I18n, Key, Locale},
+    js, loader,
+    service::{
+        auth::AUTH,
+        booking_pattern::{BookingPatternAction, BOOKING_PATTERNS, BOOKING_PATTERN_RESULT},
+        config::CONFIG,
+        i18n::I18N,
+    },
+    state::{booking_pattern::BookingPattern, shiftplan::Weekday, week::Week},
+};
+
+/// Weeks ahead the bookings are created for unless the planner picks
+/// another horizon.
+const DEFAULT_HORIZON_WEEKS: i64 = 8;
+
+fn slot_label(
+    i18n: &I18n<Key, Locale>,
+    day_of_week: Weekday,
+    from: time::Time,
+    to: time::Time,
+) -> String {
+    format!(
+        "{} {:02}:{:02}–{:02}:{:02}",
+        day_of_week.i18n_string(i18n),
+        from.hour(),
+        from.minute(),
+        to.hour(),
+        to.minute(),
+    )
+}
+
+/// Builds a pattern from the form inputs, `None` while a field is missing
+/// or invalid.
+pub(crate) fn build_pattern(
+    sales_person_id: Option<Uuid>,
+    shiftplan_id: Option<Uuid>,
+    slot_id: Option<Uuid>,
+    interval_weeks: &str,
+    valid_from: &str,
+    valid_until: &str,
+) -> Option<BookingPattern> {
+    let interval_weeks: u8 = interval_weeks.trim().parse().ok()?;
+    let pattern = BookingPattern {
+        id: Uuid::new_v4(),
+        sales_person_id: sales_person_id?,
+        shiftplan_id: shiftplan_id?,
+        slot_id: slot_id?,
+        interval_weeks,
+        valid_from: Week::parse_iso(valid_from)?,
+        valid_until: Week::parse_iso(valid_until)?,
+    };
+    (pattern.interval_weeks > 0 && pattern.valid_from <= pattern.valid_until).then_some(pattern)
+}
+
+#[component]
+pub fn BookingPatterns() -> Element {
+    let i18n = I18N.read().clone();
+    let config = CONFIG.read().clone();
+    let pattern_service = use_coroutine_handle::<BookingPatternAction>();
+    let patterns = BOOKING_PATTERNS.read().clone();
+    let is_shiftplanner = AUTH
+        .read()
+        .auth_info
+        .as_ref()
+        .map(|auth_info| auth_info.has_privilege("shiftplanner"))
+        .unwrap_or(false);
+
+    let today = Week::of_date(js::current_datetime().date());
+    let mut form_sales_person = use_signal(|| None::<Uuid>);
+    let mut form_shiftplan = use_signal(|| None::<Uuid>);
+    let mut form_slot = use_signal(|| None::<Uuid>);
+    let mut form_interval = use_signal(|| "1".to_string());
+    let mut form_from = use_signal(move || today.to_iso_string());
+    let mut form_until = use_signal(move || {
+        today
+            .monday()
+            .map(|monday| Week::of_date(monday + time::Duration::weeks(52)).to_iso_string())
+            .unwrap_or_default()
+    });
+    let mut horizon = use_signal(move || {
+        today
+            .monday()
+            .map(|monday| {
+                Week::of_date(monday + time::Duration::weeks(DEFAULT_HORIZON_WEEKS)).to_iso_string()
+            })
+            .unwrap_or_default()
+    });
+
+    let sales_persons = {
+        let config = config.clone();
+        use_resource(move || loader::load_sales_persons(config.to_owned()))
+    };
+    let catalog = {
+        let config = config.clone();
+        use_resource(move || loader::load_shiftplan_catalog(config.to_owned()))
+    };
+    // Slots of the chosen plan as they exist in the first week of the pattern.
+    let form_slots = {
+        let config = config.clone();
+        use_resource(move || {
+            let config = config.to_owned();
+            let shiftplan_id = *form_shiftplan.read();
+            let week = Week::parse_iso(&form_from.read()).unwrap_or(today);
+            async move {
+                match shiftplan_id {
+                    Some(shiftplan_id) => {
+                        loader::load_shift_plan(config, shiftplan_id, week.week, week.year)
+                            .await
+                            .map(|shiftplan| shiftplan.slots)
+                    }
+                    None => Ok(Rc::from([])),
+                }
+            }
+        })
+    };
+    // Slot details of the listed patterns, keyed by slot id.
+    let pattern_slots = {
+        let config = config.clone();
+        use_resource(move || {
+            let config = config.to_owned();
+            let mut slot_ids: Vec<Uuid> = BOOKING_PATTERNS
+                .read()
+                .iter()
+                .map(|pattern| pattern.slot_id)
+                .collect();
+            slot_ids.sort();
+            slot_ids.dedup();
+            async move {
+                let mut slots = HashMap::new();
+                for slot_id in slot_ids {
+                    if let Ok(slot) = loader::load_slot(config.clone(), slot_id).await {
+                        slots.insert(slot_id, slot);
+                    }
+                }
+                slots
+            }
+        })
+    };
+
+    let sales_person_list = match &*sales_persons.read_unchecked() {
+        Some(Ok(sales_persons)) => sales_persons.clone(),
+        _ => Rc::from([]),
+    };
+    let catalog_list = match &*catalog.read_unchecked() {
+        Some(Ok(catalog)) => catalog.clone(),
+        _ => Rc::from([]),
+    };
+    let slot_list = match &*form_slots.read_unchecked() {
+        Some(Ok(slots)) => slots.clone(),
+        _ => Rc::from([]),
+    };
+    let slot_details = pattern_slots.read_unchecked().clone().unwrap_or_default();
+
+    let new_pattern = build_pattern(
+        *form_sales_person.read(),
+        *form_shiftplan.read(),
+        *form_slot.read(),
+        &form_interval.read(),
+        &form_from.read(),
+        &form_until.read(),
+    );
+    let horizon_week = Week::parse_iso(&horizon.read());
+    let all_ids: Rc<[Uuid]> = patterns.iter().map(|pattern| pattern.id).collect();
+    let result_text = BOOKING_PATTERN_RESULT.read().as_ref().map(|result| {
+        i18n.t_m(
+            Key::BookingPatternResult,
+            [
+                ("created", result.created.len().to_string().as_str()),
+                ("existing", result.existing.to_string().as_str()),
+                ("skipped", result.skipped.to_string().as_str()),
+            ]
+            .into(),
+        )
+    });
+
+    let failed_text = BOOKING_PATTERN_RESULT
+        .read()
+        .as_ref()
+        .filter(|result| result.failed > 0)
+        .map(|result| {
+            i18n.t_m(
+                Key::BookingPatternFailed,
+                [("failed", result.failed.to_string().as_str())].into(),
+            )
+        });
+
+    if !is_shiftplanner {
+        return rsx! {
+            TopBar {}
+        };
+    }
+
+    rsx! {
+        TopBar {}
+        main { class: "mx-auto max-w-[960px] w-full px-4 py-6 md:py-8 space-y-6",
+            h1 { class: "text-h1 text-ink", "{i18n.t(Key::BookingPatterns)}" }
+            p { class: "text-small text-ink-muted", "{i18n.t(Key::BookingPatternLocalOnly)}" }
+
+            section { class: "bg-surface border border-border rounded-lg p-4 space-y-3",
+                h2 { class: "text-h2 text-ink", "{i18n.t(Key::BookingPatternNew)}" }
+                div { class: "grid grid-cols-3 gap-3 max-md:grid-cols-1",
+                    Field { label: ImStr::from(i18n.t(Key::BookingPatternPerson).as_ref()),
+                        SelectInput {
+                            on_change: move |value: ImStr| form_sales_person.set(value.as_str().parse().ok()),
+                            option { value: "", selected: form_sales_person.read().is_none(), "—" }
+                            for sales_person in sales_person_list.iter().filter(|sales_person| !sales_person.inactive) {
+                                option {
+                                    value: "{sales_person.id}",
+                                    selected: *form_sales_person.read() == Some(sales_person.id),
+                                    "{sales_person.name}"
+                                }
+                            }
+                        }
+                    }
+                    Field { label: ImStr::from(i18n.t(Key::BookingPatternShiftplan).as_ref()),
+                        SelectInput {
+                            on_change: move |value: ImStr| {
+                                form_shiftplan.set(value.as_str().parse().ok());
+                                form_slot.set(None);
+                            },
+                            option { value: "", selected: form_shiftplan.read().is_none(), "—" }
+                            for shiftplan in catalog_list.iter().filter(|shiftplan| shiftplan.deleted.is_none()) {
+                                option {
+                                    value: "{shiftplan.id}",
+                                    selected: *form_shiftplan.read() == Some(shiftplan.id),
+                                    "{shiftplan.name}"
+                                }
+                            }
+                        }
+                    }
+                    Field { label: ImStr::from(i18n.t(Key::BookingPatternSlot).as_ref()),
+                        SelectInput {
+                            disabled: form_shiftplan.read().is_none(),
+                            on_change: move |value: ImStr| form_slot.set(value.as_str().parse().ok()),
+                            option { value: "", selected: form_slot.read().is_none(), "—" }
+                            for slot in slot_list.iter() {
+                                option {
+                                    value: "{slot.id}",
+                                    selected: *form_slot.read() == Some(slot.id),
+                                    {slot_label(&i18n, slot.day_of_week, slot.from, slot.to)}
+                                }
+                            }
+                        }
+                    }
+                    Field { label: ImStr::from(i18n.t(Key::BookingPatternInterval).as_ref()),
+                        TextInput {
+                            value: ImStr::from(form_interval.read().as_str()),
+                            input_type: ImStr::from("number"),
+                            on_change: move |value: ImStr| form_interval.set(value.to_string()),
+                        }
+                    }
+                    Field { label: ImStr::from(i18n.t(Key::BookingPatternFrom).as_ref()),
+                        TextInput {
+                            value: ImStr::from(form_from.read().as_str()),
+                            input_type: ImStr::from("week"),
+                            on_change: move |value: ImStr| form_from.set(value.to_string()),
+                        }
+                    }
+                    Field { label: ImStr::from(i18n.t(Key::BookingPatternUntil).as_ref()),
+                        TextInput {
+                            value: ImStr::from(form_until.read().as_str()),
+                            input_type: ImStr::from("week"),
+                            on_change: move |value: ImStr| form_until.set(value.to_string()),
+                        }
+                    }
+                }
+                div { class: "flex justify-end",
+                    Btn {
+                        variant: BtnVariant::Primary,
+                        disabled: new_pattern.is_none(),
+                        on_click: move |_| {
+                            if let Some(pattern) = new_pattern.clone() {
+                                pattern_service.send(BookingPatternAction::Save(pattern));
+                                form_slot.set(None);
+                            }
+                        },
+                        "{i18n.t(Key::BookingPatternAdd)}"
+                    }
+                }
+            }
+
+            section { class: "bg-surface border border-border rounded-lg p-4 space-y-3",
+                div { class: "flex flex-wrap items-end gap-3",
+                    Field { label: ImStr::from(i18n.t(Key::BookingPatternHorizon).as_ref()),
+                        TextInput {
+                            value: ImStr::from(horizon.read().as_str()),
+                            input_type: ImStr::from("week"),
+                            on_change: move |value: ImStr| horizon.set(value.to_string()),
+                        }
+                    }
+                    Btn {
+                        variant: BtnVariant::Secondary,
+                        disabled: horizon_week.is_none() || patterns.is_empty(),
+                        on_click: move |_| {
+                            if let Some(until) = horizon_week {
+                                pattern_service.send(BookingPatternAction::Materialize {
+                                    pattern_ids: all_ids.clone(),
+                                    from: today,
+                                    until,
+                                });
+                            }
+                        },
+                        "{i18n.t(Key::BookingPatternMaterializeAll)}"
+                    }
+                }
+                if let Some(result_text) = result_text {
+                    div { class: "text-small text-good", "{result_text}" }
+                }
+                if let Some(failed_text) = failed_text {
+                    div { class: "text-small text-bad", "{failed_text}" }
+                }
+                if patterns.is_empty() {
+                    div { class: "text-ink-muted italic", "{i18n.t(Key::BookingPatternEmpty)}" }
+                }
+                ul { class: "divide-y divide-border",
+                    for pattern in patterns.iter() {
+                        {
+                            let sales_person = sales_person_list
+                                .iter()
+                                .find(|sales_person| sales_person.id == pattern.sales_person_id);
+                            let shiftplan_name = catalog_list
+                                .iter()
+                                .find(|shiftplan| shiftplan.id == pattern.shiftplan_id)
+                                .map(|shiftplan| shiftplan.name.to_string())
+                                .unwrap_or_default();
+                            let slot_text = match slot_details.get(&pattern.slot_id) {
+                                Some(slot) => slot_label(&i18n, slot.day_of_week, slot.from, slot.to),
+                                None => i18n.t(Key::BookingPatternUnknownSlot).to_string(),
+                            };
+                            let every = i18n.t_m(
+                                Key::BookingPatternEvery,
+                                [("weeks", pattern.interval_weeks.to_string().as_str())].into(),
+                            );
+                            let pattern_id = pattern.id;
+                            rsx! {
+                                li { key: "{pattern.id}", class: "flex flex-wrap items-center gap-3 py-2",
+                                    if let Some(sales_person) = sales_person {
+                                        PersonChip {
+                                            name: ImStr::from(sales_person.name.as_ref()),
+                                            color: Some(ImStr::from(sales_person.background_color.as_ref())),
+                                        }
+                                    }
+                                    span { class: "text-body text-ink", "{shiftplan_name} · {slot_text}" }
+                                    span { class: "text-small text-ink-muted",
+                                        "{every}, {pattern.valid_from.to_iso_string()} – {pattern.valid_until.to_iso_string()}"
+                                    }
+                                    span { class: "flex-1" }
+                                    Btn {
+                                        variant: BtnVariant::Secondary,
+                                        disabled: horizon_week.is_none(),
+                                        on_click: move |_| {
+                                            if let Some(until) = horizon_week {
+                                                pattern_service.send(BookingPatternAction::Materialize {
+                                                    pattern_ids: Rc::from([pattern_id]),
+                                                    from: today,
+                                                    until,
+                                                });
+                                            }
+                                        },
+                                        "{i18n.t(Key::BookingPatternMaterialize)}"
+                                    }
+                                    Btn {
+                                        variant: BtnVariant::Danger,
+                                        on_click: move |_| pattern_service.send(BookingPatternAction::Delete(pattern_id)),
+                                        "{i18n.t(Key::Delete)}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_pattern_requires_every_field() {
+        let id = Some(Uuid::from_u128(1));
+        assert!(build_pattern(id, id, id, "2", "2025-W10", "2025-W20").is_some());
+        assert!(build_pattern(None, id, id, "2", "2025-W10", "2025-W20").is_none());
+        assert!(build_pattern(id, id, None, "2", "2025-W10", "2025-W20").is_none());
+        assert!(build_pattern(id, id, id, "", "2025-W10", "2025-W20").is_none());
+    }
+
+    #[test]
+    fn build_pattern_rejects_zero_interval_and_reversed_range() {
+        let id = Some(Uuid::from_u128(1));
+        assert!(build_pattern(id, id, id, "0", "2025-W10", "2025-W20").is_none());
+        assert!(build_pattern(id, id, id, "1", "2025-W20", "2025-W10").is_none());
+    }
+}
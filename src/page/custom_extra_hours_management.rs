@@ -5,7 +5,7 @@ use tracing::info;
 use uuid::Uuid;
 
 use crate::{
-    api,
+    backend,
     error::result_handler,
     i18n::Key,
     service::{config::CONFIG, i18n::I18N},
@@ -28,6 +28,7 @@ pub enum CustomExtraHoursManagementAction {
         description: Option<String>,
         modifies_balance: bool,
         assigned_sales_person_ids: Vec<Uuid>,
+        version: Uuid,
     },
     DeleteCustomExtraHours(Uuid),
 }
@@ -65,15 +66,14 @@ pub fn CustomExtraHoursManagement() -> Element {
                             // For now, we'll load for the current user's sales person
                             // In a real implementation, you might want to load all custom extra hours
                             // or filter by the current user's permissions
-                            if let Ok(current_sales_person) =
-                                api::get_current_sales_person(config.clone()).await
+                            if let Ok(current_sales_person) = backend::from_config(&config)
+                                .get_current_sales_person()
+                                .await
                             {
                                 if let Some(sales_person) = current_sales_person {
-                                    match api::get_custom_extra_hours_by_sales_person(
-                                        config.clone(),
-                                        sales_person.id,
-                                    )
-                                    .await
+                                    match backend::from_config(&config)
+                                        .get_custom_extra_hours_by_sales_person(sales_person.id)
+                                        .await
                                     {
                                         Ok(hours) => {
                                             let definitions: Rc<[CustomExtraHoursDefinition]> =
@@ -104,10 +104,10 @@ pub fn CustomExtraHoursManagement() -> Element {
                                 version: Uuid::nil(),
                             };
 
-                            let result =
-                                api::post_custom_extra_hours(config.clone(), custom_extra_hours_to)
-                                    .await;
-                            result_handler(result.map_err(|e| crate::error::ShiftyError::from(e)));
+                            let result = backend::from_config(&config)
+                                .post_custom_extra_hours(custom_extra_hours_to)
+                                .await;
+                            result_handler(result);
                             // Note: We don't reload here to avoid infinite loops
                         }
                         CustomExtraHoursManagementAction::UpdateCustomExtraHours {
@@ -116,6 +116,7 @@ pub fn CustomExtraHoursManagement() -> Element {
                             description,
                             modifies_balance,
                             assigned_sales_person_ids,
+                            version,
                         } => {
                             let custom_extra_hours_to = CustomExtraHoursTO {
                                 id,
@@ -125,18 +126,20 @@ pub fn CustomExtraHoursManagement() -> Element {
                                 assigned_sales_person_ids: assigned_sales_person_ids.into(),
                                 created: None,
                                 deleted: None,
-                                version: Uuid::nil(),
+                                version,
                             };
 
-                            let result =
-                                api::put_custom_extra_hours(config.clone(), custom_extra_hours_to)
-                                    .await;
-                            result_handler(result.map_err(|e| crate::error::ShiftyError::from(e)));
+                            let result = backend::from_config(&config)
+                                .put_custom_extra_hours(custom_extra_hours_to)
+                                .await;
+                            result_handler(result);
                             // Note: We don't reload here to avoid infinite loops
                         }
                         CustomExtraHoursManagementAction::DeleteCustomExtraHours(id) => {
-                            let result = api::delete_custom_extra_hours(config.clone(), id).await;
-                            result_handler(result.map_err(|e| crate::error::ShiftyError::from(e)));
+                            let result = backend::from_config(&config)
+                                .delete_custom_extra_hours(id)
+                                .await;
+                            result_handler(result);
                             // Note: We don't reload here to avoid infinite loops
                         }
                     }
@@ -188,6 +191,12 @@ pub fn CustomExtraHoursManagement() -> Element {
                 description,
                 modifies_balance,
                 assigned_sales_person_ids: vec![], // For now, empty - would need sales person selection UI
+                version: custom_extra_hours
+                    .read()
+                    .iter()
+                    .find(|custom_hour| custom_hour.id == id)
+                    .map(|custom_hour| custom_hour.version)
+                    .unwrap_or_default(),
             });
         } else {
             action_coroutine.send(CustomExtraHoursManagementAction::CreateCustomExtraHours {
@@ -1,5 +1,7 @@
 pub mod billing_period_details;
 pub mod billing_periods;
+pub mod booking_log;
+pub mod booking_patterns;
 pub mod custom_extra_hours_management;
 pub mod employee_details;
 pub mod employees;
@@ -9,6 +11,7 @@ pub mod my_shifts;
 pub mod not_authenticated;
 pub mod sales_person_details;
 pub mod shiftplan;
+pub mod slot_timeline;
 pub mod text_template_management;
 pub mod user_details;
 pub mod user_management;
@@ -16,6 +19,8 @@ pub mod weekly_overview;
 
 pub use billing_period_details::BillingPeriodDetails;
 pub use billing_periods::BillingPeriods;
+pub use booking_log::BookingLogPage;
+pub use booking_patterns::BookingPatterns;
 pub use custom_extra_hours_management::CustomExtraHoursManagement;
 pub use employee_details::EmployeeDetails;
 pub use employees::Employees;
@@ -26,6 +31,7 @@ pub use not_authenticated::NotAuthenticated;
 pub use sales_person_details::SalesPersonDetails;
 pub use shiftplan::ShiftPlan;
 pub use shiftplan::ShiftPlanDeep;
+pub use slot_timeline::SlotTimelinePage;
 pub use text_template_management::TextTemplateManagement;
 pub use user_details::UserDetails;
 pub use user_management::UserManagementPage;
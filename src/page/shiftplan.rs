@@ -2,17 +2,29 @@ use std::rc::Rc;
 
 use dioxus::prelude::*;
 use futures_util::StreamExt;
+use rest_types::DayOfWeekTO;
 use tracing::info;
 use uuid::Uuid;
 
 use crate::base_types::ImStr;
 use crate::component::atoms::{Btn, BtnVariant, PersonChip};
+use crate::component::booking_changes_panel::BookingChangesPanel;
+use crate::component::booking_history_popover::BookingHistoryPopover;
 use crate::component::booking_log_table::BookingLogTable;
+use crate::component::coverage_heatmap::CoverageHeatmap;
 use crate::component::day_aggregate_view::{DayAggregateView, DayButtonBar};
 use crate::component::dropdown_base::DropdownTrigger;
+use crate::component::form::FormCheckbox;
+use crate::component::keyboard_shortcuts_dialog::KeyboardShortcutsDialog;
+use crate::component::offline_queue_panel::OfflineQueuePanel;
+use crate::component::person_picker_dialog::PersonPickerDialog;
+use crate::component::shiftplan_draft_dialog::{CreateDraftDialog, PublishDraftDialog};
 use crate::component::shiftplan_tab_bar::ShiftplanTabBar;
 use crate::component::slot_edit::SlotEdit;
-use crate::component::week_view::WeekViewButtonTypes;
+use crate::component::slot_structure_dialog::SlotStructureDialog;
+use crate::component::time_travel_bar::TimeTravelBar;
+use crate::component::week_copy_dialog::WeekCopyDialog;
+use crate::component::week_view::{StaffingLegend, WeekViewButtonTypes};
 use crate::component::working_hours_mini_overview::WorkingHoursMiniOverview;
 use crate::component::working_hours_overview_layout_toggle::WorkingHoursOverviewLayoutToggle;
 use crate::component::TopBar;
@@ -21,14 +33,19 @@ use crate::error::result_handler;
 use crate::i18n::Key;
 use crate::js;
 use crate::loader;
+use crate::router::Route;
 use crate::service::auth::AUTH;
 use crate::service::booking_conflict::BookingConflictAction;
 use crate::service::booking_conflict::BOOKING_CONFLICTS_STORE;
 use crate::service::booking_log::{BookingLogAction, BOOKING_LOG_STORE};
+use crate::service::booking_pattern::BOOKING_PATTERNS;
 use crate::service::config::CONFIG;
 use crate::service::i18n::I18N;
+use crate::service::live_update::{LiveMode, LiveUpdateAction, LIVE_UPDATE};
+use crate::service::offline_queue::{OfflineQueueAction, OFFLINE_QUEUE};
+use crate::service::shiftplan_draft::{live_plan_of, ShiftplanDraftAction};
 use crate::service::slot_edit::SlotEditAction;
-use crate::service::slot_edit::SHIFTPLAN_REFRESH;
+use crate::service::slot_edit::{trigger_shiftplan_refresh, SHIFTPLAN_REFRESH};
 use crate::service::text_template::{
     handle_text_template_action, TextTemplateAction, TEXT_TEMPLATE_STORE,
 };
@@ -39,12 +56,27 @@ use crate::service::weekly_summary::WEEKLY_SUMMARY_STORE;
 use crate::service::working_hours_mini::WorkingHoursMiniAction;
 use crate::service::working_hours_mini::WORKING_HOURS_MINI;
 use crate::state;
+use crate::state::auto_fill::AutoFillProposal;
+use crate::state::booking_history::{BookingEdit, BookingEditKind, BookingHistory, BookingKey};
+use crate::state::coverage::MultiWeekRange;
 use crate::state::dropdown::DropdownEntry;
+use crate::state::grid_focus::{remember_person, GridCommand};
+use crate::state::offline_queue::QueuedMutation;
 use crate::state::sales_person_available::SalesPersonUnavailable;
 use crate::state::shiftplan::SalesPerson;
+use crate::state::shiftplan::{BookingMove, BookingMoveFailure};
+use crate::state::shiftplan_draft::{DraftDiffKind, ShiftplanDraft};
+use crate::state::time_travel::{change_moments, plan_at};
+use crate::state::week::Week;
+use crate::state::week_copy::WeekCopyPreview;
+use crate::state::week_publication::WeekPublication;
 use crate::state::Config;
 use crate::state::Weekday;
 
+/// Wait after the shown week arrived before the neighbouring weeks are
+/// prefetched, so quick navigation does not compete with them.
+const PREFETCH_DELAY_MS: u32 = 300;
+
 pub enum ShiftPlanAction {
     AddUserToSlot {
         slot_id: Uuid,
@@ -60,17 +92,87 @@ pub enum ShiftPlanAction {
         slot_id: Uuid,
         sales_person_id: Uuid,
     },
+    MoveUserBetweenSlots(BookingMove),
+    Undo,
+    Redo,
     NextWeek,
     PreviousWeek,
+    /// Jumps to the week and shows it in the week grid.
+    OpenWeek(Week),
     UpdateSalesPerson(Uuid),
     CopyFromPreviousWeek,
+    ApplyWeekCopy(WeekCopyPreview),
+    ProposeAutoFill,
+    ApplyAutoFill,
     ToggleAvailability(Weekday),
     ToggleChangeStructureMode,
     LoadWeekMessage,
     SaveWeekMessage(String),
+    PublishWeek,
+    UnpublishWeek(Uuid),
     LoadDayAggregate,
 }
 
+/// Bookings of `shift_plan` which one of the recurring booking patterns
+/// produces.
+fn recurring_bookings(shift_plan: &state::Shiftplan) -> Rc<[BookingKey]> {
+    let patterns = BOOKING_PATTERNS.read();
+    shift_plan
+        .slots
+        .iter()
+        .flat_map(|slot| slot.bookings.iter())
+        .map(BookingKey::from)
+        .filter(|key| patterns.iter().any(|pattern| pattern.covers(key)))
+        .collect()
+}
+
+/// Weekday and time range of a slot, e.g. `Monday 09:00–12:00`.
+fn slot_label(i18n: &crate::i18n::I18n<Key, crate::i18n::Locale>, slot: &state::Slot) -> String {
+    format!(
+        "{} {:02}:{:02}–{:02}:{:02}",
+        slot.day_of_week.i18n_string(i18n),
+        slot.from.hour(),
+        slot.from.minute(),
+        slot.to.hour(),
+        slot.to.minute(),
+    )
+}
+
+/// Describes a booking for the undo history, e.g. `Anna · Monday 09:00–12:00`.
+fn describe_booking(
+    i18n: &crate::i18n::I18n<Key, crate::i18n::Locale>,
+    slots: &[state::Slot],
+    sales_persons: &[SalesPerson],
+    key: &BookingKey,
+) -> String {
+    let name = sales_persons
+        .iter()
+        .find(|sales_person| sales_person.id == key.sales_person_id)
+        .map(|sales_person| sales_person.name.to_string())
+        .unwrap_or_default();
+    match slots.iter().find(|slot| slot.id == key.slot_id) {
+        Some(slot) => format!("{} · {}", name, slot_label(i18n, slot)),
+        None => name,
+    }
+}
+
+/// Describes a queued unavailability change, e.g. `Anna · Tuesday unavailable`.
+fn i18n_day_summary(
+    i18n: &crate::i18n::I18n<Key, crate::i18n::Locale>,
+    name: &str,
+    weekday: Weekday,
+    unavailable: bool,
+) -> String {
+    let key = if unavailable {
+        Key::OfflineQueueUnavailable
+    } else {
+        Key::OfflineQueueAvailable
+    };
+    let day = weekday.i18n_string(i18n);
+    i18n.t_m(key, [("name", name), ("day", day.as_ref())].into())
+        .to_string()
+}
+
 #[derive(Clone, PartialEq, Props)]
 pub struct ShiftPlanProps {
     year: Option<u32>,
@@ -96,6 +198,11 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
     let weekly_summary_service = use_coroutine_handle::<WeeklySummaryAction>();
     let weekly_summary = WEEKLY_SUMMARY_STORE.read().clone();
     let slot_edit_service = use_coroutine_handle::<SlotEditAction>();
+    let shiftplan_draft_service = use_coroutine_handle::<ShiftplanDraftAction>();
+    let live_update_service = use_coroutine_handle::<LiveUpdateAction>();
+    let live_update = LIVE_UPDATE.read().clone();
+    let offline_queue_service = use_coroutine_handle::<OfflineQueueAction>();
+    let offline_queue = OFFLINE_QUEUE.read().clone();
     let is_shiftplanner = auth_info
         .as_ref()
         .map(|auth_info| auth_info.has_privilege("shiftplanner"))
@@ -159,13 +266,32 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
             async move {
                 match shiftplan_id {
                     Some(id) => {
-                        loader::load_shift_plan(
-                            config,
-                            id,
-                            *week.to_owned().read(),
-                            *year.to_owned().read(),
-                        )
-                        .await
+                        let plan_week = *week.to_owned().read();
+                        let plan_year = *year.to_owned().read();
+                        // A prefetched week shows up right away. An expired
+                        // one is reloaded meanwhile and replaced if it changed.
+                        if let Some(remembered) =
+                            loader::remembered_shift_plan(id, plan_week, plan_year)
+                        {
+                            if !remembered.fresh {
+                                let shown = remembered.value.clone();
+                                spawn(async move {
+                                    if let Ok(reloaded) =
+                                        loader::load_shift_plan(config, id, plan_week, plan_year)
+                                            .await
+                                    {
+                                        if reloaded != *shown
+                                            && *week.peek() == plan_week
+                                            && *year.peek() == plan_year
+                                        {
+                                            trigger_shiftplan_refresh();
+                                        }
+                                    }
+                                });
+                            }
+                            return Ok(remembered.value.as_ref().clone());
+                        }
+                        loader::load_shift_plan(config, id, plan_week, plan_year).await
                     }
                     None => Ok(crate::state::Shiftplan {
                         week: *week.read(),
@@ -196,6 +322,15 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
     let mut change_structure_mode: Signal<bool> = use_signal(|| false);
     let week_message = use_signal(|| String::new());
     let mut week_message_draft = use_signal(|| String::new());
+    let booking_history: Signal<BookingHistory> = use_signal(BookingHistory::default);
+    let mut show_copy_dialog = use_signal(|| false);
+    let mut show_structure_dialog = use_signal(|| false);
+    let mut auto_fill_proposal: Signal<Option<AutoFillProposal>> = use_signal(|| None);
+
+    // Keyboard planning state
+    let mut recent_persons: Signal<Rc<[Uuid]>> = use_signal(|| Rc::from([]));
+    let mut picker_slot: Signal<Option<Uuid>> = use_signal(|| None);
+    let mut show_shortcuts = use_signal(|| false);
 
     // Day view state
     let mut view_mode = use_signal(|| state::ViewMode::Week);
@@ -203,6 +338,76 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
     let day_aggregate: Signal<Option<state::DayAggregate>> = use_signal(|| None);
     let show_sunday = use_signal(|| false);
 
+    // Draft workflow state
+    let mut show_create_draft = use_signal(|| false);
+    let mut show_publish_draft = use_signal(|| false);
+    let mut show_draft_diff = use_signal(|| true);
+    let selected_plan = match &*shiftplan_catalog.read_unchecked() {
+        Some(Ok(catalog)) => catalog
+            .iter()
+            .find(|shiftplan| Some(shiftplan.id) == *selected_shiftplan_id.read())
+            .cloned(),
+        _ => None,
+    };
+    let is_draft_plan = selected_plan
+        .as_ref()
+        .is_some_and(|shiftplan| shiftplan.is_planning);
+    let draft_link = selected_shiftplan_id.read().and_then(live_plan_of);
+    let draft_comparison = {
+        let config = config.clone();
+        use_resource(move || {
+            let config = config.to_owned();
+            let draft = selected_shiftplan_id.read().and_then(live_plan_of);
+            let enabled = *show_draft_diff.read();
+            let current = Week {
+                year: *year.read(),
+                week: *week.read(),
+            };
+            let _refresh = *SHIFTPLAN_REFRESH.read();
+            async move {
+                match draft {
+                    Some(draft) if enabled => loader::load_draft_comparison(config, draft, current)
+                        .await
+                        .map(Some),
+                    _ => Ok(None),
+                }
+            }
+        })
+    };
+    // Empty while the comparison of the previously shown week is replaced.
+    let current_comparison = match &*draft_comparison.read() {
+        Some(Ok(Some(comparison))) if comparison.live.is_week(*year.read(), *week.read()) => {
+            Some(comparison.clone())
+        }
+        _ => None,
+    };
+    let comparison_shown = is_draft_plan && current_comparison.is_some();
+
+    // Multi-week view state
+    let mut multi_week_range = use_signal(|| MultiWeekRange::FourWeeks);
+    let coverage_resource = {
+        let config = config.clone();
+        use_resource(move || {
+            let config = config.to_owned();
+            let shiftplan_id = *selected_shiftplan_id.read();
+            let is_multi_week = *view_mode.read() == state::ViewMode::MultiWeek;
+            let anchor = Week {
+                year: *year.read(),
+                week: *week.read(),
+            };
+            let range = *multi_week_range.read();
+            let _refresh = *SHIFTPLAN_REFRESH.read();
+            async move {
+                match shiftplan_id {
+                    Some(id) if is_multi_week => {
+                        loader::load_shiftplan_coverage(config, id, &range.weeks(anchor)?).await
+                    }
+                    _ => Ok(Rc::from([])),
+                }
+            }
+        })
+    };
+
     // Shiftplan report state
     let mut selected_template_id = use_signal(|| None::<Uuid>);
     let mut shiftplan_report_result = use_signal(|| None::<String>);
@@ -212,20 +417,82 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
     // Working-hours overview layout (cards / table) — persisted per browser
     let mut working_hours_layout = use_signal(ui_prefs::get_working_hours_layout);
 
+    // Staffing tints and conflict markers in the week grid — persisted per browser
+    let mut highlight_staffing = use_signal(ui_prefs::get_staffing_highlight);
+
     // Booking log state
     let mut show_booking_log = use_signal(|| false);
+    let mut show_booking_changes = use_signal(|| false);
+    // Moment the week is shown at, `None` for the current plan
+    let mut time_travel = use_signal(|| None::<time::PrimitiveDateTime>);
+    let time_travel_at = *time_travel.read();
     let mut booking_log_name_filter = use_signal(|| String::new());
     let mut booking_log_day_filter = use_signal(|| None::<Weekday>);
     let mut booking_log_status_filter = use_signal(|| "all".to_string());
     let mut booking_log_created_by_filter = use_signal(|| "all".to_string());
 
-    let button_mode = if *change_structure_mode.read() {
+    let mut week_publication_resource = {
+        let config = config.clone();
+        use_resource(move || {
+            let config = config.to_owned();
+            let year = *year.read();
+            let week = *week.read();
+            async move { loader::load_week_publication(config, year, week).await }
+        })
+    };
+    let week_publication: Option<WeekPublication> =
+        match &*week_publication_resource.read_unchecked() {
+            Some(Ok(Some(publication)))
+                if publication.year == *year.read() && publication.week == *week.read() =>
+            {
+                Some(publication.clone())
+            }
+            _ => None,
+        };
+    let week_locked = week_publication.is_some() && !is_shiftplanner;
+    let week_published_str = week_publication.as_ref().map(|publication| {
+        i18n.t_m(
+            Key::WeekPublishedBy,
+            [
+                (
+                    "date",
+                    publication
+                        .published_at
+                        .map(|published_at| i18n.format_date(&published_at.date()))
+                        .unwrap_or_default()
+                        .as_ref(),
+                ),
+                (
+                    "time",
+                    publication
+                        .published_at
+                        .map(|published_at| {
+                            format!("{:02}:{:02}", published_at.hour(), published_at.minute())
+                        })
+                        .unwrap_or_default()
+                        .as_str(),
+                ),
+                (
+                    "user",
+                    publication.published_by.as_deref().unwrap_or_default(),
+                ),
+            ]
+            .into(),
+        )
+    });
+
+    let button_mode = if time_travel_at.is_some() {
+        WeekViewButtonTypes::None
+    } else if *change_structure_mode.read() {
         WeekViewButtonTypes::Dropdown
     } else if js::current_datetime().date() - date > time::Duration::weeks(2) && !is_hr {
         WeekViewButtonTypes::None
     } else {
         WeekViewButtonTypes::AddRemove
     };
+    let can_auto_fill =
+        button_mode == WeekViewButtonTypes::AddRemove && selected_shiftplan_id.read().is_some();
+    let keyboard_editable = button_mode == WeekViewButtonTypes::AddRemove && !week_locked;
 
     // Load shiftplan-report templates for report generation
     use_effect(move || {
@@ -239,11 +506,77 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
         }
     });
 
+    // An auto-fill proposal only applies to the week and plan it was made for
+    use_effect(move || {
+        let _ = week.read();
+        let _ = year.read();
+        let _ = selected_shiftplan_id.read();
+        auto_fill_proposal.set(None);
+    });
+
+    // Follow what other planners do in the shown week
+    use_effect(move || {
+        let week = *week.read();
+        let year = *year.read();
+        if let Some(shiftplan_id) = *selected_shiftplan_id.read() {
+            live_update_service.send(LiveUpdateAction::Watch {
+                shiftplan_id,
+                year,
+                week,
+            });
+        }
+    });
+    use_effect(move || {
+        if let Some(Ok(shift_plan)) = &*shift_plan_context.read() {
+            live_update_service.send(LiveUpdateAction::Baseline(shift_plan.clone()));
+        }
+    });
+    use_drop(move || live_update_service.send(LiveUpdateAction::Stop));
+
+    // Prefetch the neighbouring weeks once the shown one is there
+    let mut prefetch: Signal<Option<Task>> = use_signal(|| None);
+    use_effect({
+        let config = config.clone();
+        move || {
+            if let Some(previous) = prefetch.write().take() {
+                previous.cancel();
+            }
+            let shown = match &*shift_plan_context.read() {
+                Some(Ok(shift_plan)) if shift_plan.is_week(*year.read(), *week.read()) => Week {
+                    year: shift_plan.year,
+                    week: shift_plan.week,
+                },
+                _ => return,
+            };
+            let Some(shiftplan_id) = *selected_shiftplan_id.read() else {
+                return;
+            };
+            let config = config.clone();
+            let task = spawn(async move {
+                gloo_timers::future::TimeoutFuture::new(PREFETCH_DELAY_MS).await;
+                if let Err(err) = loader::prefetch_neighbour_weeks(
+                    config,
+                    shiftplan_id,
+                    shown,
+                    is_hr,
+                    is_shiftplanner,
+                )
+                .await
+                {
+                    tracing::warn!("Prefetching the neighbouring weeks failed: {err}");
+                }
+            });
+            prefetch.set(Some(task));
+        }
+    });
+
     // Collapse booking log and reset filters when week or year changes
     use_effect(move || {
         let _ = week.read();
         let _ = year.read();
         show_booking_log.set(false);
+        show_booking_changes.set(false);
+        time_travel.set(None);
         booking_log_name_filter.set(String::new());
         booking_log_day_filter.set(None);
         booking_log_status_filter.set("all".to_string());
@@ -277,9 +610,76 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                 view_mode,
                 selected_day,
                 day_aggregate,
-                show_sunday
+                show_sunday,
+                booking_history,
+                auto_fill_proposal
             ];
             async move {
+                // The loaded plan, unless it still is the one of the week
+                // shown before navigating.
+                let current_shift_plan = move || -> Option<state::Shiftplan> {
+                    match &*shift_plan_context.read_unchecked() {
+                        Some(Ok(shift_plan)) if shift_plan.is_week(*year.read(), *week.read()) => {
+                            Some(shift_plan.clone())
+                        }
+                        _ => None,
+                    }
+                };
+                // Viewers may not change the bookings of a published week,
+                // even if a stale view still offers the buttons.
+                let week_locked = move |year: u32, week: u8| -> bool {
+                    let is_shiftplanner = AUTH
+                        .read()
+                        .auth_info
+                        .as_ref()
+                        .is_some_and(|auth_info| auth_info.has_privilege("shiftplanner"));
+                    !is_shiftplanner
+                        && matches!(
+                            &*week_publication_resource.read_unchecked(),
+                            Some(Ok(Some(publication)))
+                                if publication.year == year && publication.week == week
+                        )
+                };
+                // Slots and sales persons currently shown, used to describe
+                // the recorded edits in the history popover.
+                let known_slots = move || -> Vec<state::Slot> {
+                    let mut slots: Vec<state::Slot> = Vec::new();
+                    if let Some(shift_plan) = current_shift_plan() {
+                        slots.extend(shift_plan.slots.iter().cloned());
+                    }
+                    if let Some(agg) = &*day_aggregate.read() {
+                        for plan in agg.plans.iter() {
+                            slots.extend(plan.slots.iter().cloned());
+                        }
+                    }
+                    slots
+                };
+                let describe = move |key: &BookingKey| -> String {
+                    let sales_persons = match &*sales_persons_resource.read_unchecked() {
+                        Some(Ok(sales_persons)) => sales_persons.clone(),
+                        _ => Rc::new([]),
+                    };
+                    describe_booking(&I18N.read(), &known_slots(), &sales_persons, key)
+                };
+                let mut record = move |kind: BookingEditKind,
+                                       summary: String,
+                                       added: Rc<[BookingKey]>,
+                                       removed: Rc<[BookingKey]>| {
+                    booking_history.write().record(BookingEdit {
+                        kind,
+                        summary: summary.into(),
+                        added,
+                        removed,
+                    });
+                };
+
+                let enqueue = move |mutation: QueuedMutation, summary: String| {
+                    offline_queue_service.send(OfflineQueueAction::Enqueue {
+                        mutation,
+                        summary: summary.into(),
+                    });
+                };
+
                 let mut update_shiftplan = {
                     to_owned![
                         config,
@@ -316,7 +716,13 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                                 )
                                 .await
                                 {
-                                    day_aggregate.set(Some(loaded));
+                                    // The user may have moved on meanwhile.
+                                    if loaded.year == *year.read()
+                                        && loaded.week == *week.read()
+                                        && loaded.day_of_week == *selected_day.read()
+                                    {
+                                        day_aggregate.set(Some(loaded));
+                                    }
                                 }
                             });
                         }
@@ -400,23 +806,56 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                             week,
                             year,
                         } => {
+                            if week_locked(year, week) {
+                                tracing::warn!(
+                                    "Week {week}/{year} is published, not adding the booking"
+                                );
+                                continue;
+                            }
                             info!("Registering user to slot");
-                            match loader::register_user_to_slot(
-                                config.to_owned(),
-                                slot_id,
+                            let key = BookingKey {
                                 sales_person_id,
+                                slot_id,
                                 week,
                                 year,
-                            )
-                            .await
-                            {
-                                Ok(_) => {}
-                                Err(crate::error::ShiftyError::Reqwest(ref e))
+                            };
+                            // Changes made while older ones are still queued
+                            // are queued as well, so they reach the backend
+                            // in order.
+                            let result = if OFFLINE_QUEUE.read().queue.has_pending() {
+                                Err(None)
+                            } else {
+                                loader::register_user_to_slot(
+                                    config.to_owned(),
+                                    slot_id,
+                                    sales_person_id,
+                                    week,
+                                    year,
+                                )
+                                .await
+                                .map_err(Some)
+                            };
+                            match result {
+                                Ok(_) => {
+                                    record(
+                                        BookingEditKind::Add,
+                                        describe(&key),
+                                        [key].into(),
+                                        [].into(),
+                                    );
+                                }
+                                Err(None) => {
+                                    enqueue(QueuedMutation::AddBooking(key), describe(&key))
+                                }
+                                Err(Some(e)) if e.is_connectivity() => {
+                                    enqueue(QueuedMutation::AddBooking(key), describe(&key))
+                                }
+                                Err(Some(crate::error::ShiftyError::Reqwest(ref e)))
                                     if e.status() == Some(reqwest::StatusCode::FORBIDDEN) =>
                                 {
                                     // Silently ignore forbidden booking errors
                                 }
-                                Err(e) => {
+                                Err(Some(e)) => {
                                     crate::error::error_handler(e);
                                 }
                             }
@@ -426,18 +865,153 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                             slot_id,
                             sales_person_id,
                         } => {
+                            if week_locked(*year.read(), *week.read()) {
+                                tracing::warn!("The week is published, not removing the booking");
+                                continue;
+                            }
                             info!("Removing user from slot");
-                            if let Some(Ok(shift_plan)) = &*shift_plan_context.read_unchecked() {
-                                result_handler(
+                            let shift_plan = current_shift_plan();
+                            if let Some(shift_plan) = shift_plan {
+                                let booking = shift_plan
+                                    .slots
+                                    .iter()
+                                    .filter(|slot| slot.id == slot_id)
+                                    .flat_map(|slot| slot.bookings.iter())
+                                    .find(|booking| booking.sales_person_id == sales_person_id)
+                                    .map(|booking| (booking.id, BookingKey::from(booking)));
+                                let key = booking.map(|(_, key)| key);
+                                let summary = key.as_ref().map(describe);
+                                let result = if OFFLINE_QUEUE.read().queue.has_pending() {
+                                    Err(None)
+                                } else {
                                     loader::remove_user_from_slot(
                                         config.to_owned(),
                                         slot_id,
                                         sales_person_id,
-                                        shift_plan.clone(),
+                                        shift_plan,
                                     )
-                                    .await,
+                                    .await
+                                    .map_err(Some)
+                                };
+                                let removed = match result {
+                                    Ok(()) => Some(()),
+                                    Err(Some(e)) if !e.is_connectivity() => result_handler(Err(e)),
+                                    Err(_) => {
+                                        if let (Some((booking_id, key)), Some(summary)) =
+                                            (booking, summary.clone())
+                                        {
+                                            enqueue(
+                                                QueuedMutation::RemoveBooking { booking_id, key },
+                                                summary,
+                                            );
+                                        }
+                                        None
+                                    }
+                                };
+                                if let (Some(()), Some(key), Some(summary)) =
+                                    (removed, key, summary)
+                                {
+                                    record(
+                                        BookingEditKind::Remove,
+                                        summary,
+                                        [].into(),
+                                        [key].into(),
+                                    );
+                                }
+                            }
+                            update_shiftplan();
+                        }
+                        ShiftPlanAction::MoveUserBetweenSlots(booking_move) => {
+                            if week_locked(*year.read(), *week.read()) {
+                                tracing::warn!("The week is published, not moving the booking");
+                                continue;
+                            }
+                            info!("Moving user between slots");
+                            let shift_plan = current_shift_plan();
+                            if let Some(shift_plan) = shift_plan {
+                                let from = BookingKey {
+                                    sales_person_id: booking_move.sales_person_id,
+                                    slot_id: booking_move.from_slot_id,
+                                    week: shift_plan.week,
+                                    year: shift_plan.year,
+                                };
+                                let to = BookingKey {
+                                    slot_id: booking_move.to_slot_id,
+                                    ..from
+                                };
+                                let summary = format!(
+                                    "{} → {}",
+                                    describe(&from),
+                                    shift_plan
+                                        .slots
+                                        .iter()
+                                        .find(|slot| slot.id == to.slot_id)
+                                        .map(|slot| slot_label(&I18N.read(), slot))
+                                        .unwrap_or_default(),
                                 );
+                                let booking_id = shift_plan
+                                    .slots
+                                    .iter()
+                                    .filter(|slot| slot.id == from.slot_id)
+                                    .flat_map(|slot| slot.bookings.iter())
+                                    .find(|booking| booking.sales_person_id == from.sales_person_id)
+                                    .map(|booking| booking.id);
+                                let result = if OFFLINE_QUEUE.read().queue.has_pending() {
+                                    Err(None)
+                                } else {
+                                    loader::move_user_between_slots(
+                                        config.to_owned(),
+                                        booking_move,
+                                        shift_plan,
+                                    )
+                                    .await
+                                    .map_err(Some)
+                                };
+                                match result {
+                                    Ok(()) => record(
+                                        BookingEditKind::Move,
+                                        summary,
+                                        [to].into(),
+                                        [from].into(),
+                                    ),
+                                    Err(Some(BookingMoveFailure::NothingApplied(e)))
+                                        if !e.is_connectivity() =>
+                                    {
+                                        crate::error::error_handler(e)
+                                    }
+                                    // The backend changed already, queueing the
+                                    // move again would book the person twice.
+                                    Err(Some(BookingMoveFailure::RolledBack(e))) => {
+                                        crate::error::error_handler(e)
+                                    }
+                                    Err(Some(BookingMoveFailure::RollbackFailed {
+                                        error,
+                                        rollback,
+                                    })) => {
+                                        tracing::warn!(
+                                            "Booking the old slot again failed: {rollback}"
+                                        );
+                                        crate::error::error_handler(error)
+                                    }
+                                    Err(Some(BookingMoveFailure::NothingApplied(_)))
+                                    | Err(None) => {
+                                        // A queued move is its removal
+                                        // followed by the new booking.
+                                        if let Some(booking_id) = booking_id {
+                                            enqueue(
+                                                QueuedMutation::RemoveBooking {
+                                                    booking_id,
+                                                    key: from,
+                                                },
+                                                describe(&from),
+                                            );
+                                            enqueue(QueuedMutation::AddBooking(to), summary);
+                                        }
+                                    }
+                                }
                             }
+                            // Reloads the week and, for shiftplanners, the
+                            // booking conflicts of both affected days.
                             update_shiftplan();
                         }
                         ShiftPlanAction::NextWeek => {
@@ -502,6 +1076,29 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                                 week_message_draft.set(String::new());
                             }
                         }
+                        ShiftPlanAction::OpenWeek(target) => {
+                            info!("Open week");
+                            year.set(target.year);
+                            week.set(target.week);
+                            view_mode.set(state::ViewMode::Week);
+                            update_shiftplan();
+                            reload_unavailable_days(config.clone()).await;
+
+                            // Load week message for new week
+                            if let Ok(Some(message)) = loader::load_week_message(
+                                config.clone(),
+                                *year.read(),
+                                *week.read(),
+                            )
+                            .await
+                            {
+                                week_message.set(message.clone());
+                                week_message_draft.set(message);
+                            } else {
+                                week_message.set(String::new());
+                                week_message_draft.set(String::new());
+                            }
+                        }
                         ShiftPlanAction::UpdateSalesPerson(uuid) => {
                             info!("Update sales person");
                             if let Some(Ok(sales_persons)) =
@@ -516,41 +1113,177 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                             reload_unavailable_days(config.clone()).await;
                         }
                         ShiftPlanAction::CopyFromPreviousWeek => {
-                            result_handler(
+                            if let Some(created) = result_handler(
                                 loader::copy_from_previous_week(
                                     config.to_owned(),
                                     *week.read(),
                                     *year.read(),
                                 )
                                 .await,
-                            );
+                            ) {
+                                let summary = I18N.read().t_m(
+                                    Key::ShiftplanHistoryBookingCount,
+                                    [("count", created.len().to_string().as_str())].into(),
+                                );
+                                record(
+                                    BookingEditKind::CopyWeek,
+                                    summary.to_string(),
+                                    created,
+                                    [].into(),
+                                );
+                            }
                             update_shiftplan();
                         }
-                        ShiftPlanAction::ToggleAvailability(weekday) => {
-                            if let Some(available_day) = unavailable_days
-                                .read()
-                                .iter()
-                                .find(|unavailable_day| unavailable_day.day_of_week == weekday)
+                        ShiftPlanAction::ApplyWeekCopy(preview) => {
+                            let created = preview.to_create();
+                            let summary = I18N.read().t_m(
+                                Key::ShiftplanHistoryBookingCount,
+                                [("count", created.len().to_string().as_str())].into(),
+                            );
+                            let edit = BookingEdit {
+                                kind: BookingEditKind::CopyWeek,
+                                summary: summary.as_ref().into(),
+                                added: created,
+                                removed: [].into(),
+                            };
+                            if result_handler(
+                                loader::apply_booking_edit(config.to_owned(), &edit).await,
+                            )
+                            .is_some()
                             {
-                                result_handler(
-                                    loader::delete_unavailable_sales_person_day(
-                                        config.to_owned(),
-                                        available_day.id,
-                                    )
-                                    .await,
-                                );
-                            } else if let Some(sales_person) = current_sales_person.read().as_ref()
+                                booking_history.write().record(edit);
+                            }
+                            update_shiftplan();
+                        }
+                        ShiftPlanAction::ProposeAutoFill => {
+                            let shiftplan = current_shift_plan();
+                            let shiftplan_id = *selected_shiftplan_id.read();
+                            if let (Some(shiftplan_id), Some(shiftplan)) = (shiftplan_id, shiftplan)
                             {
-                                result_handler(
-                                    loader::create_unavailable_sales_person_day(
+                                if let Some(proposal) = result_handler(
+                                    loader::propose_shiftplan_auto_fill(
                                         config.to_owned(),
-                                        sales_person.id,
-                                        *year.read(),
-                                        *week.read(),
-                                        weekday,
+                                        shiftplan_id,
+                                        shiftplan,
                                     )
                                     .await,
+                                ) {
+                                    auto_fill_proposal.set(Some(proposal));
+                                }
+                            }
+                        }
+                        ShiftPlanAction::ApplyAutoFill => {
+                            if let Some(proposal) = auto_fill_proposal.take() {
+                                let created = proposal.keys();
+                                let summary = I18N.read().t_m(
+                                    Key::ShiftplanHistoryBookingCount,
+                                    [("count", created.len().to_string().as_str())].into(),
                                 );
+                                let edit = BookingEdit {
+                                    kind: BookingEditKind::AutoFill,
+                                    summary: summary.as_ref().into(),
+                                    added: created,
+                                    removed: [].into(),
+                                };
+                                if result_handler(
+                                    loader::apply_booking_edit(config.to_owned(), &edit).await,
+                                )
+                                .is_some()
+                                {
+                                    booking_history.write().record(edit);
+                                }
+                                update_shiftplan();
+                            }
+                        }
+                        ShiftPlanAction::Undo => {
+                            let edit = booking_history.read().next_undo().cloned();
+                            if let Some(edit) = edit {
+                                info!("Undo booking edit");
+                                if result_handler(
+                                    loader::apply_booking_edit(config.to_owned(), &edit.inverse())
+                                        .await,
+                                )
+                                .is_some()
+                                {
+                                    booking_history.write().undone();
+                                }
+                                update_shiftplan();
+                            }
+                        }
+                        ShiftPlanAction::Redo => {
+                            let edit = booking_history.read().next_redo().cloned();
+                            if let Some(edit) = edit {
+                                info!("Redo booking edit");
+                                if result_handler(
+                                    loader::apply_booking_edit(config.to_owned(), &edit).await,
+                                )
+                                .is_some()
+                                {
+                                    booking_history.write().redone();
+                                }
+                                update_shiftplan();
+                            }
+                        }
+                        ShiftPlanAction::ToggleAvailability(weekday) => {
+                            let Some(sales_person) = current_sales_person.read().clone() else {
+                                continue;
+                            };
+                            let (year, week) = (*year.read(), *week.read());
+                            let existing = unavailable_days
+                                .read()
+                                .iter()
+                                .find(|unavailable_day| unavailable_day.day_of_week == weekday)
+                                .map(|unavailable_day| unavailable_day.id);
+                            let queued = OFFLINE_QUEUE
+                                .read()
+                                .queue
+                                .unavailable_day_change(sales_person.id, year, week, weekday)
+                                .cloned();
+                            let day_of_week = DayOfWeekTO::from(&weekday);
+                            // A queued change decides what the day shows, so
+                            // toggling again undoes it.
+                            let mutation = match (queued, existing) {
+                                (Some(QueuedMutation::AddUnavailableDay { .. }), _) => {
+                                    QueuedMutation::RemoveUnavailableDay {
+                                        id: Uuid::nil(),
+                                        sales_person_id: sales_person.id,
+                                        year,
+                                        week,
+                                        day_of_week,
+                                    }
+                                }
+                                (Some(_), _) | (None, None) => QueuedMutation::AddUnavailableDay {
+                                    sales_person_id: sales_person.id,
+                                    year,
+                                    week,
+                                    day_of_week,
+                                },
+                                (None, Some(id)) => QueuedMutation::RemoveUnavailableDay {
+                                    id,
+                                    sales_person_id: sales_person.id,
+                                    year,
+                                    week,
+                                    day_of_week,
+                                },
+                            };
+                            let summary = i18n_day_summary(
+                                &I18N.read(),
+                                &sales_person.name,
+                                weekday,
+                                matches!(mutation, QueuedMutation::AddUnavailableDay { .. }),
+                            );
+                            let result = if OFFLINE_QUEUE.read().queue.has_pending() {
+                                Err(None)
+                            } else {
+                                loader::replay_queued_mutation(config.to_owned(), &mutation)
+                                    .await
+                                    .map_err(Some)
+                            };
+                            match result {
+                                Ok(()) => {}
+                                Err(None) => enqueue(mutation, summary),
+                                Err(Some(e)) if e.is_connectivity() => enqueue(mutation, summary),
+                                Err(Some(e)) => crate::error::error_handler(e),
                             }
                             update_shiftplan();
                             reload_unavailable_days(config.clone()).await;
@@ -582,40 +1315,76 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                             .await
                             {
                                 tracing::error!("Failed to save week message: {:?}", e);
+                                crate::error::error_handler(e);
                             } else {
                                 week_message.set(message.clone());
                                 week_message_draft.set(message);
                             }
                         }
+                        ShiftPlanAction::PublishWeek => {
+                            if let Err(err) =
+                                loader::publish_week(config.clone(), *year.read(), *week.read())
+                                    .await
+                            {
+                                crate::error::error_handler(err);
+                            }
+                            week_publication_resource.restart();
+                        }
+                        ShiftPlanAction::UnpublishWeek(publication_id) => {
+                            if let Err(err) =
+                                loader::unpublish_week(config.clone(), publication_id).await
+                            {
+                                crate::error::error_handler(err);
+                            }
+                            week_publication_resource.restart();
+                        }
                         ShiftPlanAction::RemoveUserFromSlotDay {
                             slot_id,
                             sales_person_id,
                         } => {
+                            if week_locked(*year.read(), *week.read()) {
+                                tracing::warn!("The week is published, not removing the booking");
+                                continue;
+                            }
                             info!("Removing user from slot (day view)");
-                            if let Some(ref agg) = *day_aggregate.read() {
-                                // Find the booking across all plans
-                                for plan in agg.plans.iter() {
-                                    if let Some(slot) = plan.slots.iter().find(|s| s.id == slot_id)
-                                    {
-                                        if let Some(booking) = slot
-                                            .bookings
-                                            .iter()
-                                            .find(|b| b.sales_person_id == sales_person_id)
-                                        {
-                                            if let Err(e) = crate::api::remove_booking(
-                                                config.to_owned(),
-                                                booking.id,
-                                            )
-                                            .await
-                                            {
-                                                tracing::error!(
-                                                    "Failed to remove booking: {:?}",
-                                                    e
-                                                );
-                                            }
-                                            break;
-                                        }
+                            // Find the booking across all plans
+                            let booking = day_aggregate.read().as_ref().and_then(|agg| {
+                                agg.plans
+                                    .iter()
+                                    .flat_map(|plan| plan.slots.iter())
+                                    .filter(|slot| slot.id == slot_id)
+                                    .flat_map(|slot| slot.bookings.iter())
+                                    .find(|booking| booking.sales_person_id == sales_person_id)
+                                    .map(|booking| (booking.id, BookingKey::from(booking)))
+                            });
+                            if let Some((booking_id, key)) = booking {
+                                let summary = describe(&key);
+                                let result = if OFFLINE_QUEUE.read().queue.has_pending() {
+                                    Err(None)
+                                } else {
+                                    loader::remove_booking(
+                                        config.clone(),
+                                        booking_id,
+                                        key.year,
+                                        key.week,
+                                    )
+                                    .await
+                                    .map_err(Some)
+                                };
+                                match result {
+                                    Ok(()) => record(
+                                        BookingEditKind::Remove,
+                                        summary,
+                                        [].into(),
+                                        [key].into(),
+                                    ),
+                                    Err(Some(e)) if !e.is_connectivity() => {
+                                        tracing::error!("Failed to remove booking: {:?}", e)
                                     }
+                                    Err(_) => enqueue(
+                                        QueuedMutation::RemoveBooking { booking_id, key },
+                                        summary,
+                                    ),
                                 }
                             }
                             update_shiftplan();
@@ -629,7 +1398,12 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                             )
                             .await
                             {
-                                day_aggregate.set(Some(loaded));
+                                if loaded.year == *year.read()
+                                    && loaded.week == *week.read()
+                                    && loaded.day_of_week == *selected_day.read()
+                                {
+                                    day_aggregate.set(Some(loaded));
+                                }
                             }
                         }
                     }
@@ -671,8 +1445,34 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
     ]
     .into();
 
+    let live_mode_str = match live_update.mode {
+        LiveMode::Off => None,
+        LiveMode::Feed => Some(i18n.t(Key::LiveUpdateFeed)),
+        LiveMode::Polling => Some(i18n.t(Key::LiveUpdatePolling)),
+    };
+    let live_changed_str = live_update.last_change.as_ref().map(|(at, by)| {
+        i18n.t_m(
+            Key::LiveUpdateChangedBy,
+            [
+                ("user", by.as_deref().unwrap_or_default()),
+                (
+                    "time",
+                    format!("{:02}:{:02}", at.hour(), at.minute()).as_str(),
+                ),
+            ]
+            .into(),
+        )
+    });
+    let live_viewers_str = (!live_update.viewers.is_empty()).then(|| {
+        i18n.t_m(
+            Key::LiveUpdateViewing,
+            [("names", live_update.viewers.join(", ").as_str())].into(),
+        )
+    });
+
     let view_week_str = i18n.t(Key::ViewModeWeek);
     let view_day_str = i18n.t(Key::ViewModeDay);
+    let view_multi_week_str = i18n.t(Key::ViewModeMultiWeek);
 
     let toggle_active_class =
         "px-3 py-1 text-body font-medium rounded-[4px] bg-surface text-ink shadow-sm";
@@ -697,6 +1497,11 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                     style: "font-variant-numeric: tabular-nums;",
                     "{calendar_week_str}"
                 }
+                if let Some(published) = week_published_str.as_ref() {
+                    span { class: "px-2 py-0.5 rounded-sm text-small bg-good-soft text-good",
+                        "{published}"
+                    }
+                }
                 button {
                     class: nav_btn_class,
                     "aria-label": "Nächste Woche",
@@ -725,6 +1530,39 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                         },
                         "{view_day_str}"
                     }
+                    button {
+                        class: if *view_mode.read() == state::ViewMode::MultiWeek { toggle_active_class } else { toggle_inactive_class },
+                        onclick: move |_| {
+                            view_mode.set(state::ViewMode::MultiWeek);
+                        },
+                        "{view_multi_week_str}"
+                    }
+                }
+                span { class: "w-px h-5 bg-border mx-1.5" }
+                BookingHistoryPopover {
+                    history: booking_history.read().clone(),
+                    on_undo: move |_| cr.send(ShiftPlanAction::Undo),
+                    on_redo: move |_| cr.send(ShiftPlanAction::Redo),
+                }
+                button {
+                    class: nav_btn_class,
+                    title: "{i18n.t(Key::ShortcutsTitle)}",
+                    "aria-label": "{i18n.t(Key::ShortcutsTitle)}",
+                    onclick: move |_| show_shortcuts.set(true),
+                    "?"
+                }
+                if let Some(mode) = live_mode_str.as_ref() {
+                    span {
+                        class: "inline-flex items-center gap-1.5 px-2 py-0.5 rounded-sm text-small bg-surface-alt text-ink-muted",
+                        title: live_changed_str.as_deref().unwrap_or_default(),
+                        span {
+                            class: if live_update.mode == LiveMode::Feed { "w-2 h-2 rounded-full bg-good" } else { "w-2 h-2 rounded-full bg-warn" },
+                        }
+                        "{mode}"
+                    }
+                }
+                if let Some(viewers) = live_viewers_str.as_ref() {
+                    span { class: "text-small text-ink-muted", "{viewers}" }
                 }
                 span { class: "flex-1 min-w-0" }
                 {
@@ -763,6 +1601,69 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                             {i18n.t(Key::BookingLogTitle)}
                         }
                     }
+                    Btn {
+                        variant: BtnVariant::Secondary,
+                        on_click: move |_| {
+                            let should_show = !*show_booking_changes.read();
+                            if should_show {
+                                booking_log_service.send(BookingLogAction::LoadBookingLog(
+                                    *year.read(),
+                                    *week.read(),
+                                ));
+                            }
+                            show_booking_changes.set(should_show);
+                        },
+                        if *show_booking_changes.read() {
+                            {i18n.t(Key::BookingChangesHide)}
+                        } else {
+                            {i18n.t(Key::BookingChangesOpen)}
+                        }
+                    }
+                    Btn {
+                        variant: BtnVariant::Secondary,
+                        on_click: move |_| {
+                            if time_travel_at.is_some() {
+                                time_travel.set(None);
+                            } else {
+                                booking_log_service.send(BookingLogAction::LoadBookingLog(
+                                    *year.read(),
+                                    *week.read(),
+                                ));
+                                time_travel.set(Some(js::current_datetime()));
+                            }
+                        },
+                        if time_travel_at.is_some() {
+                            {i18n.t(Key::TimeTravelClose)}
+                        } else {
+                            {i18n.t(Key::TimeTravelOpen)}
+                        }
+                    }
+                }
+                if is_shiftplanner {
+                    if let Some(publication_id) = week_publication.as_ref().map(|publication| publication.id) {
+                        Btn {
+                            variant: BtnVariant::Secondary,
+                            on_click: move |_| cr.send(ShiftPlanAction::UnpublishWeek(publication_id)),
+                            {i18n.t(Key::WeekUnpublish)}
+                        }
+                    } else {
+                        Btn {
+                            variant: BtnVariant::Secondary,
+                            on_click: move |_| cr.send(ShiftPlanAction::PublishWeek),
+                            {i18n.t(Key::WeekPublish)}
+                        }
+                    }
+                    Btn {
+                        variant: BtnVariant::Secondary,
+                        on_click: move |_| show_copy_dialog.set(true),
+                        {i18n.t(Key::CopyWeekOpen)}
+                    }
+                    Btn {
+                        variant: BtnVariant::Secondary,
+                        disabled: !can_auto_fill,
+                        on_click: move |_| cr.send(ShiftPlanAction::ProposeAutoFill),
+                        {i18n.t(Key::AutoFillOpen)}
+                    }
                 }
                 if is_shiftplanner {
                     DropdownTrigger {
@@ -782,6 +1683,38 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                                 !*change_structure_mode.read() || !is_shift_editor,
                             )
                                 .into(),
+                            (
+                                "Slot history",
+                                Box::new(move |_| {
+                                    if let Some(shiftplan_id) = *selected_shiftplan_id.read() {
+                                        navigator()
+                                            .push(Route::SlotTimelinePage {
+                                                shiftplan_id: shiftplan_id.to_string(),
+                                            });
+                                    }
+                                }),
+                                selected_shiftplan_id.read().is_none(),
+                            )
+                                .into(),
+                            (
+                                "Bulk edit structure",
+                                Box::new(move |_| {
+                                    let mut show_structure_dialog = show_structure_dialog;
+                                    show_structure_dialog.set(true);
+                                }),
+                                !*change_structure_mode.read() || !is_shift_editor
+                                    || selected_shiftplan_id.read().is_none(),
+                            )
+                                .into(),
+                            (
+                                "Create draft",
+                                Box::new(move |_| {
+                                    let mut show_create_draft = show_create_draft;
+                                    show_create_draft.set(true);
+                                }),
+                                selected_plan.is_none() || is_draft_plan,
+                            )
+                                .into(),
                         ]
                             .into(),
                         button {
@@ -882,7 +1815,7 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
         }
 
         {
-            if *view_mode.read() == state::ViewMode::Week {
+            if *view_mode.read() != state::ViewMode::Day {
                 if let Some(Ok(catalog)) = &*shiftplan_catalog.read_unchecked() {
                     let catalog = catalog.clone();
                     rsx! {
@@ -988,103 +1921,399 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                                     }
                                 },
                                 is_shiftplanner,
+                                week_published: week_publication.is_some(),
                             }
                         } else {
                             div { class: "text-ink-muted italic", "Loading..." }
                         }
                     }
                 }
+            } else if *view_mode.read() == state::ViewMode::MultiWeek {
+                rsx! {
+                    div { class: "m-4",
+                        div { class: "mb-3 flex flex-wrap items-center gap-3",
+                            div { class: "inline-flex bg-surface-alt rounded-md p-0.5 gap-0.5",
+                                for (range , label) in [
+                                    (MultiWeekRange::FourWeeks, i18n.t(Key::MultiWeekFourWeeks)),
+                                    (MultiWeekRange::Month, i18n.t(Key::MultiWeekMonth)),
+                                ]
+                                {
+                                    button {
+                                        class: if *multi_week_range.read() == range { toggle_active_class } else { toggle_inactive_class },
+                                        onclick: move |_| multi_week_range.set(range),
+                                        "{label}"
+                                    }
+                                }
+                            }
+                            span { class: "text-small text-ink-muted", "{i18n.t(Key::CoverageHint)}" }
+                        }
+                        match &*coverage_resource.read_unchecked() {
+                            Some(Ok(weeks)) => rsx! {
+                                CoverageHeatmap {
+                                    weeks: weeks.clone(),
+                                    current_week: Week {
+                                        year: *year.read(),
+                                        week: *week.read(),
+                                    },
+                                    on_select_week: move |target: Week| cr.send(ShiftPlanAction::OpenWeek(target)),
+                                }
+                            },
+                            Some(Err(err)) => rsx! {
+                                div { class: "text-bad", "{err}" }
+                            },
+                            None => rsx! {
+                                div { class: "text-ink-muted italic", "Loading..." }
+                            },
+                        }
+                    }
+                }
             } else {
                 // Week view (existing)
                 match &*shift_plan_context.read_unchecked() {
-                    Some(Ok(shift_plan)) => {
+                    Some(Ok(shift_plan)) if shift_plan.is_week(*year.read(), *week.read()) => {
                         to_owned![current_sales_person, unavailable_days];
                         rsx! {
                             div { class: "m-4",
                                 SlotEdit {}
-                                WeekView {
-                                    shiftplan_data: shift_plan.clone(),
-                                    date_of_monday: date,
-                                    highlight_item_id: current_sales_person.read().as_ref().map(|sp| sp.id),
-                                    discourage_weekdays: unavailable_days
-                                        .read()
-                                        .iter()
-                                        .map(|unavailable_day| unavailable_day.day_of_week)
-                                        .collect(),
-                                    button_types: button_mode,
-                                    dropdown_entries: field_dropdown_entries,
-                                    weekday_headers: if weekly_summary.data_loaded && weekly_summary.weekly_summary.len() > 0 { vec![
-                                        (
-                                            Weekday::Monday,
-                                            format!("{:.1}h", weekly_summary.weekly_summary[0].monday_available_hours)
-                                                .into(),
-                                        ),
-                                        (
-                                            Weekday::Tuesday,
-                                            format!("{:.1}h", weekly_summary.weekly_summary[0].tuesday_available_hours)
-                                                .into(),
-                                        ),
-                                        (
-                                            Weekday::Wednesday,
-                                            format!("{:.1}h", weekly_summary.weekly_summary[0].wednesday_available_hours)
-                                                .into(),
-                                        ),
-                                        (
-                                            Weekday::Thursday,
-                                            format!("{:.1}h", weekly_summary.weekly_summary[0].thursday_available_hours)
-                                                .into(),
-                                        ),
-                                        (
-                                            Weekday::Friday,
-                                            format!("{:.1}h", weekly_summary.weekly_summary[0].friday_available_hours)
-                                                .into(),
-                                        ),
-                                        (
-                                            Weekday::Saturday,
-                                            format!("{:.1}h", weekly_summary.weekly_summary[0].saturday_available_hours)
-                                                .into(),
-                                        ),
-                                        (
-                                            Weekday::Sunday,
-                                            format!("{:.1}h", weekly_summary.weekly_summary[0].sunday_available_hours)
-                                                .into(),
-                                        ),
-                                    ] } else { vec![] },
-                                    add_event: move |slot: state::Slot| {
-                                        to_owned![current_sales_person];
-                                        info!("Register to slot");
-                                        if let Some(ref current_sales_person) = *current_sales_person.read() {
-                                            cr.send(ShiftPlanAction::AddUserToSlot {
-                                                slot_id: slot.id,
-                                                sales_person_id: current_sales_person.id,
-                                                week: *week.read(),
-                                                year: *year.read(),
-                                            });
+                                if let Some(proposal) = auto_fill_proposal.read().as_ref() {
+                                    div { class: "mb-3 flex flex-wrap items-center gap-3 px-3 py-2 rounded-md border border-border bg-good-soft text-small text-ink print:hidden",
+                                        if proposal.is_empty() {
+                                            span { "{i18n.t(Key::AutoFillNothing)}" }
+                                        } else {
+                                            span { class: "font-semibold",
+                                                {
+                                                    i18n.t_m(
+                                                        Key::AutoFillSummary,
+                                                        [("count", proposal.assignments.len().to_string().as_str())].into(),
+                                                    )
+                                                }
+                                            }
                                         }
-                                        info!("Done");
-                                    },
-                                    remove_event: move |slot: state::Slot| {
-                                        to_owned![current_sales_person];
-                                        info!("Register to slot");
-                                        if let Some(ref current_sales_person) = *current_sales_person.read() {
-                                            cr.send(ShiftPlanAction::RemoveUserFromSlot {
-                                                slot_id: slot.id,
-                                                sales_person_id: current_sales_person.id,
-                                            });
+                                        if proposal.missing_count() > 0 {
+                                            span { class: "text-warn",
+                                                {
+                                                    i18n.t_m(
+                                                        Key::AutoFillUnfilled,
+                                                        [("count", proposal.missing_count().to_string().as_str())].into(),
+                                                    )
+                                                }
+                                            }
                                         }
-                                        info!("Done");
-                                    },
-                                    item_clicked: move |sales_person_id: Uuid| {
-                                        if is_shiftplanner {
-                                            cr.send(ShiftPlanAction::UpdateSalesPerson(sales_person_id));
+                                        span { class: "flex-1" }
+                                        Btn {
+                                            variant: BtnVariant::Secondary,
+                                            on_click: move |_| auto_fill_proposal.set(None),
+                                            {i18n.t(Key::AutoFillDiscard)}
                                         }
-                                    },
-                                    title_double_clicked: move |weekday: Weekday| {
-                                        if is_shiftplanner {
-                                            cr.send(ShiftPlanAction::ToggleAvailability(weekday));
+                                        Btn {
+                                            variant: BtnVariant::Primary,
+                                            disabled: proposal.is_empty(),
+                                            on_click: move |_| cr.send(ShiftPlanAction::ApplyAutoFill),
+                                            {i18n.t(Key::AutoFillApply)}
                                         }
-                                    },
-                                    is_shiftplanner,
+                                    }
+                                }
+                                div { class: "mb-2 flex flex-wrap items-center gap-x-4 gap-y-2 print:hidden",
+                                    FormCheckbox {
+                                        value: highlight_staffing(),
+                                        on_change: move |enabled: bool| {
+                                            ui_prefs::set_staffing_highlight(enabled);
+                                            highlight_staffing.set(enabled);
+                                        },
+                                        label: rsx! { "{i18n.t(Key::StaffingHighlight)}" },
+                                    }
+                                    if highlight_staffing() {
+                                        StaffingLegend {}
+                                    }
+                                }
+                                if is_shiftplanner && is_draft_plan {
+                                    div { class: "mb-3 flex flex-wrap items-center gap-3 px-3 py-2 rounded-md border border-border bg-surface-alt text-small print:hidden",
+                                        span { class: "text-ink-muted", "{i18n.t(Key::DraftCompareWith)}" }
+                                        select {
+                                            class: "h-[30px] px-2 border border-border-strong rounded-md bg-surface text-ink text-small form-input",
+                                            onchange: move |event| {
+                                                let Some(draft_id) = *selected_shiftplan_id.read() else {
+                                                    return;
+                                                };
+                                                match event.data.value().parse::<Uuid>() {
+                                                    Ok(live_id) => shiftplan_draft_service
+                                                        .send(ShiftplanDraftAction::Link(ShiftplanDraft { draft_id, live_id })),
+                                                    Err(_) => shiftplan_draft_service.send(ShiftplanDraftAction::Unlink(draft_id)),
+                                                }
+                                            },
+                                            option { value: "", selected: draft_link.is_none(), "{i18n.t(Key::DraftNotLinked)}" }
+                                            if let Some(Ok(catalog)) = &*shiftplan_catalog.read_unchecked() {
+                                                for shiftplan in catalog
+                                                    .iter()
+                                                    .filter(|shiftplan| !shiftplan.is_planning && shiftplan.deleted.is_none())
+                                                {
+                                                    option {
+                                                        value: "{shiftplan.id}",
+                                                        selected: draft_link.is_some_and(|draft| draft.live_id == shiftplan.id),
+                                                        "{shiftplan.name}"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        FormCheckbox {
+                                            value: show_draft_diff(),
+                                            on_change: move |enabled: bool| show_draft_diff.set(enabled),
+                                            label: rsx! { "{i18n.t(Key::DraftShowDiff)}" },
+                                        }
+                                        if let Some(comparison) = current_comparison.as_ref() {
+                                            span { class: "text-ink",
+                                                {
+                                                    i18n.t_m(
+                                                        Key::DraftDiffSummary,
+                                                        [
+                                                            ("added", comparison.diff.count(DraftDiffKind::Added).to_string().as_str()),
+                                                            ("removed", comparison.diff.count(DraftDiffKind::Removed).to_string().as_str()),
+                                                        ]
+                                                        .into(),
+                                                    )
+                                                }
+                                            }
+                                            if comparison.diff.slots_only_in_draft > 0 {
+                                                span { class: "text-warn",
+                                                    {
+                                                        i18n.t_m(
+                                                            Key::DraftSlotsOnlyInDraft,
+                                                            [("count", comparison.diff.slots_only_in_draft.to_string().as_str())].into(),
+                                                        )
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        span { class: "flex-1" }
+                                        Btn {
+                                            variant: BtnVariant::Secondary,
+                                            disabled: draft_link.is_none(),
+                                            on_click: move |_| show_publish_draft.set(true),
+                                            {i18n.t(Key::DraftPublishOpen)}
+                                        }
+                                    }
+                                }
+                                if week_locked {
+                                    div { class: "mb-3 px-3 py-2 rounded-md border border-border bg-surface-alt text-small text-ink-muted print:hidden",
+                                        "{i18n.t(Key::WeekPublishedLocked)}"
+                                    }
+                                }
+                                if let Some(moment) = time_travel_at {
+                                    TimeTravelBar {
+                                        moments: Rc::from(change_moments(&BOOKING_LOG_STORE.read())),
+                                        moment,
+                                        on_change: move |moment| time_travel.set(Some(moment)),
+                                        on_close: move |_| time_travel.set(None),
+                                    }
+                                }
+                                OfflineQueuePanel {
+                                    queue: offline_queue.queue.clone(),
+                                    replaying: offline_queue.replaying,
+                                    on_retry: move |_| offline_queue_service.send(OfflineQueueAction::Retry),
+                                    on_discard: move |id| offline_queue_service.send(OfflineQueueAction::Discard(id)),
+                                }
+                                div {
+                                    class: if comparison_shown { "grid grid-cols-2 gap-4 max-lg:grid-cols-1" } else { "" },
+                                    if let Some(Some(comparison)) = draft_comparison
+                                        .read()
+                                        .as_ref()
+                                        .and_then(|result| result.as_ref().ok())
+                                        .filter(|_| is_draft_plan)
+                                    {
+                                        div { class: "min-w-0",
+                                            h3 { class: "mb-1 text-small font-semibold text-ink-muted", "{i18n.t(Key::DraftLivePlan)}" }
+                                            WeekView {
+                                                shiftplan_data: comparison.live.clone(),
+                                                date_of_monday: date,
+                                                highlight_item_id: current_sales_person.read().as_ref().map(|sp| sp.id),
+                                                button_types: WeekViewButtonTypes::None,
+                                                dropdown_entries: None,
+                                                add_event: None,
+                                                remove_event: None,
+                                                move_event: None,
+                                                item_clicked: None,
+                                                title_double_clicked: None,
+                                                is_shiftplanner,
+                                                highlight_staffing: false,
+                                                diff_marks: comparison.diff.marks(true),
+                                            }
+                                        }
+                                    }
+                                    div { class: "min-w-0",
+                                        if comparison_shown {
+                                            h3 { class: "mb-1 text-small font-semibold text-ink-muted", "{i18n.t(Key::DraftPlan)}" }
+                                        }
+                                        WeekView {
+                                            week_published: week_publication.is_some(),
+                                            pending_bookings: offline_queue.queue.pending_bookings(
+                                                shift_plan.year,
+                                                shift_plan.week,
+                                                match &*sales_persons_resource.read_unchecked() {
+                                                    Some(Ok(sales_persons)) => sales_persons,
+                                                    _ => &[],
+                                                },
+                                            ),
+                                            live_changes: if time_travel_at.is_none() {
+                                                live_update.changes.clone()
+                                            } else {
+                                                Default::default()
+                                            },
+                                            diff_marks: match current_comparison.as_ref() {
+                                                Some(comparison) if is_draft_plan => comparison.diff.marks(false),
+                                                _ => Rc::from([]),
+                                            },
+                                            shiftplan_data: match time_travel_at {
+                                                Some(moment) => plan_at(
+                                                    shift_plan,
+                                                    &BOOKING_LOG_STORE.read(),
+                                                    match &*sales_persons_resource.read_unchecked() {
+                                                        Some(Ok(sales_persons)) => sales_persons,
+                                                        _ => &[],
+                                                    },
+                                                    moment,
+                                                ),
+                                                None => shift_plan.clone(),
+                                            },
+                                            date_of_monday: date,
+                                            highlight_item_id: current_sales_person.read().as_ref().map(|sp| sp.id),
+                                            discourage_weekdays: {
+                                                let days: Vec<Weekday> = unavailable_days
+                                                    .read()
+                                                    .iter()
+                                                    .map(|unavailable_day| unavailable_day.day_of_week)
+                                                    .collect();
+                                                match current_sales_person.read().as_ref() {
+                                                    Some(sales_person) => offline_queue.queue.apply_unavailable_days(
+                                                        sales_person.id,
+                                                        shift_plan.year,
+                                                        shift_plan.week,
+                                                        &days,
+                                                    ),
+                                                    None => days.into(),
+                                                }
+                                            },
+                                            button_types: button_mode,
+                                            dropdown_entries: field_dropdown_entries,
+                                            weekday_headers: if weekly_summary.data_loaded && weekly_summary.weekly_summary.len() > 0 { vec![
+                                                (
+                                                    Weekday::Monday,
+                                                    format!("{:.1}h", weekly_summary.weekly_summary[0].monday_available_hours)
+                                                        .into(),
+                                                ),
+                                                (
+                                                    Weekday::Tuesday,
+                                                    format!("{:.1}h", weekly_summary.weekly_summary[0].tuesday_available_hours)
+                                                        .into(),
+                                                ),
+                                                (
+                                                    Weekday::Wednesday,
+                                                    format!("{:.1}h", weekly_summary.weekly_summary[0].wednesday_available_hours)
+                                                        .into(),
+                                                ),
+                                                (
+                                                    Weekday::Thursday,
+                                                    format!("{:.1}h", weekly_summary.weekly_summary[0].thursday_available_hours)
+                                                        .into(),
+                                                ),
+                                                (
+                                                    Weekday::Friday,
+                                                    format!("{:.1}h", weekly_summary.weekly_summary[0].friday_available_hours)
+                                                        .into(),
+                                                ),
+                                                (
+                                                    Weekday::Saturday,
+                                                    format!("{:.1}h", weekly_summary.weekly_summary[0].saturday_available_hours)
+                                                        .into(),
+                                                ),
+                                                (
+                                                    Weekday::Sunday,
+                                                    format!("{:.1}h", weekly_summary.weekly_summary[0].sunday_available_hours)
+                                                        .into(),
+                                                ),
+                                            ] } else { vec![] },
+                                            add_event: move |slot: state::Slot| {
+                                                to_owned![current_sales_person];
+                                                info!("Register to slot");
+                                                if let Some(ref current_sales_person) = *current_sales_person.read() {
+                                                    cr.send(ShiftPlanAction::AddUserToSlot {
+                                                        slot_id: slot.id,
+                                                        sales_person_id: current_sales_person.id,
+                                                        week: *week.read(),
+                                                        year: *year.read(),
+                                                    });
+                                                }
+                                                info!("Done");
+                                            },
+                                            remove_event: move |slot: state::Slot| {
+                                                to_owned![current_sales_person];
+                                                info!("Register to slot");
+                                                if let Some(ref current_sales_person) = *current_sales_person.read() {
+                                                    cr.send(ShiftPlanAction::RemoveUserFromSlot {
+                                                        slot_id: slot.id,
+                                                        sales_person_id: current_sales_person.id,
+                                                    });
+                                                }
+                                                info!("Done");
+                                            },
+                                            move_event: if is_shiftplanner && time_travel_at.is_none() {
+                                                Some(EventHandler::new(move |booking_move: BookingMove| {
+                                                    cr.send(ShiftPlanAction::MoveUserBetweenSlots(booking_move));
+                                                }))
+                                            } else {
+                                                None
+                                            },
+                                            item_clicked: move |sales_person_id: Uuid| {
+                                                if is_shiftplanner {
+                                                    cr.send(ShiftPlanAction::UpdateSalesPerson(sales_person_id));
+                                                }
+                                            },
+                                            title_double_clicked: move |weekday: Weekday| {
+                                                if is_shiftplanner {
+                                                    cr.send(ShiftPlanAction::ToggleAvailability(weekday));
+                                                }
+                                            },
+                                            on_grid_command: move |command: GridCommand| match command {
+                                                GridCommand::PreviousWeek => cr.send(ShiftPlanAction::PreviousWeek),
+                                                GridCommand::NextWeek => cr.send(ShiftPlanAction::NextWeek),
+                                                GridCommand::ShowHelp => show_shortcuts.set(true),
+                                                GridCommand::OpenPicker(slot_id) if keyboard_editable => {
+                                                    picker_slot.set(Some(slot_id));
+                                                }
+                                                GridCommand::PickRecent { slot_id, index } if keyboard_editable && is_shiftplanner => {
+                                                    if let Some(sales_person_id) = recent_persons.read().get(index).copied() {
+                                                        cr.send(ShiftPlanAction::AddUserToSlot {
+                                                            slot_id,
+                                                            sales_person_id,
+                                                            week: *week.read(),
+                                                            year: *year.read(),
+                                                        });
+                                                    }
+                                                }
+                                                GridCommand::RemoveBooking { slot_id, sales_person_id } if keyboard_editable => {
+                                                    let own = current_sales_person
+                                                        .read()
+                                                        .as_ref()
+                                                        .is_some_and(|sales_person| sales_person.id == sales_person_id);
+                                                    if is_shiftplanner || own {
+                                                        cr.send(ShiftPlanAction::RemoveUserFromSlot {
+                                                            slot_id,
+                                                            sales_person_id,
+                                                        });
+                                                    }
+                                                }
+                                                _ => {}
+                                            },
+                                            is_shiftplanner,
+                                            recurring_bookings: recurring_bookings(shift_plan),
+                                            conflicts: booking_conflicts.clone(),
+                                            highlight_staffing: highlight_staffing(),
+                                            proposed_bookings: auto_fill_proposal
+                                                .read()
+                                                .as_ref()
+                                                .map(|proposal| proposal.assignments.clone())
+                                                .unwrap_or_else(|| Rc::from([])),
+                                        }
+                                    }
                                 }
 
                             div { class: "mt-4 print:hidden flex flex-col gap-2",
@@ -1272,6 +2501,13 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                             // Booking log table — only visible for shiftplanner role AND when expanded via toolbar toggle
                             if is_shiftplanner && *show_booking_log.read() {
                                 div { class: "mt-6 mx-4 print:hidden",
+                                    div { class: "mb-2 text-right",
+                                        Link {
+                                            class: "text-small text-accent hover:underline",
+                                            to: Route::BookingLogPage {},
+                                            "{i18n.t(Key::BookingLogOpenPage)} →"
+                                        }
+                                    }
                                     BookingLogTable {
                                         bookings: BOOKING_LOG_STORE.read().clone(),
                                         name_filter: booking_log_name_filter.read().clone(),
@@ -1299,6 +2535,107 @@ pub fn ShiftPlan(props: ShiftPlanProps) -> Element {
                                     }
                                 }
                             }
+
+                            if is_shiftplanner && *show_booking_changes.read() {
+                                div { class: "mt-6 mx-4 print:hidden",
+                                    BookingChangesPanel {
+                                        bookings: BOOKING_LOG_STORE.read().clone(),
+                                        week: Week { year: *year.read(), week: *week.read() },
+                                        default_since: week_publication
+                                            .as_ref()
+                                            .and_then(|publication| publication.published_at)
+                                            .unwrap_or_else(|| js::current_datetime() - time::Duration::days(1)),
+                                    }
+                                }
+                            }
+
+                            if let Some(slot_id) = picker_slot() {
+                                PersonPickerDialog {
+                                    sales_persons: if is_shiftplanner {
+                                        match &*sales_persons_resource.read_unchecked() {
+                                            Some(Ok(sales_persons)) => sales_persons.clone(),
+                                            _ => Rc::from([]),
+                                        }
+                                    } else {
+                                        current_sales_person.read().iter().cloned().collect()
+                                    },
+                                    recent: recent_persons(),
+                                    exclude: shift_plan
+                                        .slots
+                                        .iter()
+                                        .filter(|slot| slot.id == slot_id)
+                                        .flat_map(|slot| slot.bookings.iter())
+                                        .map(|booking| booking.sales_person_id)
+                                        .collect::<Rc<[Uuid]>>(),
+                                    on_close: move |_| picker_slot.set(None),
+                                    on_pick: move |sales_person_id: Uuid| {
+                                        picker_slot.set(None);
+                                        let recent = remember_person(&recent_persons.read(), sales_person_id);
+                                        recent_persons.set(recent);
+                                        cr.send(ShiftPlanAction::AddUserToSlot {
+                                            slot_id,
+                                            sales_person_id,
+                                            week: *week.read(),
+                                            year: *year.read(),
+                                        });
+                                    },
+                                }
+                            }
+                            if *show_shortcuts.read() {
+                                KeyboardShortcutsDialog { on_close: move |_| show_shortcuts.set(false) }
+                            }
+                            if let (true, Some(shiftplan_id)) = (
+                                is_shift_editor && *show_structure_dialog.read(),
+                                *selected_shiftplan_id.read(),
+                            ) {
+                                SlotStructureDialog {
+                                    current_week: Week { year: *year.read(), week: *week.read() },
+                                    shiftplan_id,
+                                    shiftplans: match &*shiftplan_catalog.read_unchecked() {
+                                        Some(Ok(catalog)) => catalog.clone(),
+                                        _ => Rc::from([]),
+                                    },
+                                    on_close: move |_| show_structure_dialog.set(false),
+                                }
+                            }
+                            if let (true, Some(live)) = (
+                                is_shiftplanner && *show_create_draft.read(),
+                                selected_plan.clone().filter(|shiftplan| !shiftplan.is_planning),
+                            ) {
+                                CreateDraftDialog {
+                                    live,
+                                    current_week: Week { year: *year.read(), week: *week.read() },
+                                    on_close: move |_| show_create_draft.set(false),
+                                    on_created: move |draft: ShiftplanDraft| {
+                                        show_create_draft.set(false);
+                                        shiftplan_draft_service.send(ShiftplanDraftAction::Link(draft));
+                                        shiftplan_catalog.restart();
+                                        selected_shiftplan_id.set(Some(draft.draft_id));
+                                    },
+                                }
+                            }
+                            if let (true, Some(draft)) = (is_shiftplanner && *show_publish_draft.read(), draft_link) {
+                                PublishDraftDialog {
+                                    draft,
+                                    current_week: Week { year: *year.read(), week: *week.read() },
+                                    on_close: move |_| show_publish_draft.set(false),
+                                }
+                            }
+                            if is_shiftplanner && *show_copy_dialog.read() {
+                                WeekCopyDialog {
+                                    current_week: Week { year: *year.read(), week: *week.read() },
+                                    shiftplans: match &*shiftplan_catalog.read_unchecked() {
+                                        Some(Ok(catalog)) => catalog.clone(),
+                                        _ => Rc::from([]),
+                                    },
+                                    selected_shiftplan_id: *selected_shiftplan_id.read(),
+                                    on_close: move |_| show_copy_dialog.set(false),
+                                    on_confirm: move |preview: WeekCopyPreview| {
+                                        show_copy_dialog.set(false);
+                                        cr.send(ShiftPlanAction::ApplyWeekCopy(preview));
+                                    },
+                                }
+                            }
                         }
                     }
                 }
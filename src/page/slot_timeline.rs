@@ -0,0 +1,371 @@
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    base_types::ImStr,
+    component::{
+        atoms::{Btn, BtnVariant},
+        form::{Field, SelectInput, TextInput},
+        TopBar,
+    },
+    i18n::{I18n, Key, Locale},
+    js, loader,
+    router::Route,
+    service::{auth::AUTH, config::CONFIG, i18n::I18N},
+    state::{
+        slot_edit::SlotEditItem,
+        slot_timeline::{weeks_of_years, SlotTimeline},
+        week::Week,
+    },
+};
+
+/// Most years the timeline loads at once; every week is a request.
+const MAX_YEARS: u32 = 3;
+
+fn slot_label(i18n: &I18n<Key, Locale>, slot: &SlotEditItem) -> String {
+    format!(
+        "{} {:02}:{:02}–{:02}:{:02}",
+        slot.day_of_week.i18n_short_string(i18n),
+        slot.from.hour(),
+        slot.from.minute(),
+        slot.to.hour(),
+        slot.to.minute(),
+    )
+}
+
+fn validity_label(i18n: &I18n<Key, Locale>, slot: &SlotEditItem) -> Rc<str> {
+    match slot.valid_to {
+        Some(valid_to) => i18n.t_m(
+            Key::SlotTimelineValidRange,
+            [
+                ("from", i18n.format_date(&slot.valid_from).as_ref()),
+                ("to", i18n.format_date(&valid_to).as_ref()),
+            ]
+            .into(),
+        ),
+        None => i18n.t_m(
+            Key::SlotTimelineValidSince,
+            [("from", i18n.format_date(&slot.valid_from).as_ref())].into(),
+        ),
+    }
+}
+
+pub(crate) fn cell_class(active: bool, change: bool, selected: bool) -> &'static str {
+    match (active, change, selected) {
+        (true, _, true) => "h-5 bg-accent",
+        (true, true, false) => "h-5 bg-accent-soft border-l-2 border-warn",
+        (true, false, false) => "h-5 bg-accent-soft",
+        (false, true, _) => "h-5 bg-warn-soft",
+        (false, false, _) => "h-5",
+    }
+}
+
+/// Parses the year range inputs, `None` if reversed or longer than
+/// [`MAX_YEARS`].
+pub(crate) fn parse_year_range(from: &str, until: &str) -> Option<(u32, u32)> {
+    let from: u32 = from.trim().parse().ok()?;
+    let until: u32 = until.trim().parse().ok()?;
+    (from <= until && until - from < MAX_YEARS).then_some((from, until))
+}
+
+#[derive(Clone, PartialEq, Props)]
+pub struct SlotTimelinePageProps {
+    pub shiftplan_id: String,
+}
+
+#[component]
+pub fn SlotTimelinePage(props: SlotTimelinePageProps) -> Element {
+    let shiftplan_id = match Uuid::parse_str(&props.shiftplan_id) {
+        Ok(shiftplan_id) => shiftplan_id,
+        Err(err) => {
+            return rsx! { "Invalid shiftplan id: {err}" };
+        }
+    };
+    let i18n = I18N.read().clone();
+    let config = CONFIG.read().clone();
+    let auth_info = AUTH.read().auth_info.clone();
+    let is_shiftplanner = auth_info
+        .as_ref()
+        .map(|auth_info| auth_info.has_privilege("shiftplanner"))
+        .unwrap_or(false);
+    let is_shift_editor = auth_info
+        .as_ref()
+        .map(|auth_info| auth_info.has_privilege("shiftplan.edit"))
+        .unwrap_or(false);
+
+    let current_year = js::get_current_year();
+    let mut from_year = use_signal(move || current_year.to_string());
+    let mut until_year = use_signal(move || current_year.to_string());
+    let mut selected_row = use_signal(|| None::<Uuid>);
+    let mut end_week = use_signal(String::new);
+    let mut reload = use_signal(|| 0u64);
+    let mut error = use_signal(|| None::<ImStr>);
+    let mut shiftplan_signal = use_signal(move || shiftplan_id);
+    if *shiftplan_signal.peek() != shiftplan_id {
+        shiftplan_signal.set(shiftplan_id);
+    }
+
+    let catalog = {
+        let config = config.clone();
+        use_resource(move || loader::load_shiftplan_catalog(config.to_owned()))
+    };
+    let timeline = {
+        let config = config.clone();
+        use_resource(move || {
+            let config = config.to_owned();
+            let _reload = *reload.read();
+            let shiftplan_id = *shiftplan_signal.read();
+            let range = parse_year_range(&from_year.read(), &until_year.read());
+            async move {
+                match range {
+                    Some((from, until)) => {
+                        let weeks = weeks_of_years(from, until)?;
+                        loader::load_slot_timeline(config, shiftplan_id, weeks)
+                            .await
+                            .map(Some)
+                    }
+                    None => Ok(None),
+                }
+            }
+        })
+    };
+
+    let catalog_list = match &*catalog.read_unchecked() {
+        Some(Ok(catalog)) => catalog.clone(),
+        _ => Rc::from([]),
+    };
+    let range_valid = parse_year_range(&from_year.read(), &until_year.read()).is_some();
+
+    if !is_shiftplanner {
+        return rsx! {
+            TopBar {}
+        };
+    }
+
+    rsx! {
+        TopBar {}
+        main { class: "mx-auto w-full px-4 py-6 md:py-8 space-y-6",
+            h1 { class: "text-h1 text-ink", "{i18n.t(Key::SlotTimelineTitle)}" }
+            section { class: "grid grid-cols-3 gap-3 max-w-[720px] max-md:grid-cols-1",
+                Field { label: ImStr::from(i18n.t(Key::SlotTimelineShiftplan).as_ref()),
+                    SelectInput {
+                        on_change: move |value: ImStr| {
+                            if let Ok(id) = value.as_str().parse::<Uuid>() {
+                                selected_row.set(None);
+                                navigator()
+                                    .push(Route::SlotTimelinePage {
+                                        shiftplan_id: id.to_string(),
+                                    });
+                            }
+                        },
+                        for shiftplan in catalog_list.iter().filter(|shiftplan| shiftplan.deleted.is_none()) {
+                            option {
+                                value: "{shiftplan.id}",
+                                selected: shiftplan.id == shiftplan_id,
+                                "{shiftplan.name}"
+                            }
+                        }
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::SlotTimelineFromYear).as_ref()),
+                    TextInput {
+                        value: ImStr::from(from_year.read().as_str()),
+                        input_type: ImStr::from("number"),
+                        on_change: move |value: ImStr| from_year.set(value.to_string()),
+                    }
+                }
+                Field { label: ImStr::from(i18n.t(Key::SlotTimelineUntilYear).as_ref()),
+                    TextInput {
+                        value: ImStr::from(until_year.read().as_str()),
+                        input_type: ImStr::from("number"),
+                        on_change: move |value: ImStr| until_year.set(value.to_string()),
+                    }
+                }
+            }
+            if !range_valid {
+                div { class: "text-small text-bad",
+                    {i18n.t_m(Key::SlotTimelineInvalidRange, [("max", MAX_YEARS.to_string().as_str())].into())}
+                }
+            }
+            if let Some(error) = error.read().as_ref() {
+                div { class: "text-small text-bad", "{error}" }
+            }
+            match &*timeline.read_unchecked() {
+                None => rsx! {
+                    div { class: "text-small text-ink-muted", "{i18n.t(Key::SlotTimelineLoading)}" }
+                },
+                Some(Err(err)) => rsx! {
+                    div { class: "text-small text-bad", "{err}" }
+                },
+                Some(Ok(None)) => rsx! {},
+                Some(Ok(Some(timeline))) if timeline.rows.is_empty() => rsx! {
+                    div { class: "text-small text-ink-muted italic", "{i18n.t(Key::SlotTimelineEmpty)}" }
+                },
+                Some(Ok(Some(timeline))) => {
+                    let timeline: SlotTimeline = timeline.clone();
+                    let grid_style = format!(
+                        "display: grid; grid-template-columns: 220px repeat({}, 12px);",
+                        timeline.weeks.len()
+                    );
+                    let selected = selected_row();
+                    let selected_index = timeline
+                        .rows
+                        .iter()
+                        .position(|row| Some(row.slot.id) == selected);
+                    rsx! {
+                        section { class: "bg-surface border border-border rounded-lg overflow-auto",
+                            div { style: "{grid_style}",
+                                div { class: "bg-surface-alt border-b border-border" }
+                                for (index , week) in timeline.weeks.iter().enumerate() {
+                                    div {
+                                        class: if timeline.is_change(index) { "bg-warn-soft border-b border-border text-micro text-warn text-center" } else { "bg-surface-alt border-b border-border text-micro text-ink-faint text-center" },
+                                        title: "{week.week}/{week.year}",
+                                        if week.week % 4 == 1 {
+                                            "{week.week}"
+                                        }
+                                    }
+                                }
+                                for row in timeline.rows.iter() {
+                                    {
+                                        let slot_id = row.slot.id;
+                                        let is_selected = selected == Some(slot_id);
+                                        rsx! {
+                                            button {
+                                                class: if is_selected { "px-2 py-0.5 text-left text-small font-mono text-accent bg-accent-soft truncate" } else { "px-2 py-0.5 text-left text-small font-mono text-ink hover:bg-surface-alt truncate" },
+                                                title: "{validity_label(&i18n, &row.slot)}",
+                                                onclick: move |_| {
+                                                    error.set(None);
+                                                    end_week.set(String::new());
+                                                    selected_row.set((!is_selected).then_some(slot_id));
+                                                },
+                                                "{slot_label(&i18n, &row.slot)}"
+                                            }
+                                            for index in 0..timeline.weeks.len() {
+                                                div { class: cell_class(row.is_active(index), timeline.is_change(index), is_selected) }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if let Some(index) = selected_index {
+                            {
+                                let slot = timeline.rows[index].slot.clone();
+                                let slot_id = slot.id;
+                                let can_reopen = is_shift_editor && timeline.can_reopen(index);
+                                let end = Week::parse_iso(&end_week.read())
+                                    .filter(|week| *week > Week::of_date(slot.valid_from));
+                                let slot_for_reopen = slot.clone();
+                                rsx! {
+                                    section { class: "bg-surface border border-border rounded-lg p-4 space-y-3 max-w-[720px]",
+                                        h2 { class: "text-h2 text-ink", "{slot_label(&i18n, &slot)}" }
+                                        div { class: "text-small text-ink-muted",
+                                            "{validity_label(&i18n, &slot)} · "
+                                            {i18n.t_m(Key::SlotStructureMinResources, [("count", slot.min_resources.to_string().as_str())].into())}
+                                        }
+                                        if is_shift_editor {
+                                            div { class: "flex flex-wrap items-end gap-3",
+                                                Btn {
+                                                    variant: BtnVariant::Secondary,
+                                                    disabled: !can_reopen,
+                                                    on_click: move |_| {
+                                                        let slot = slot_for_reopen.clone();
+                                                        spawn(async move {
+                                                            match loader::reopen_slot(CONFIG.read().clone(), slot).await {
+                                                                Ok(()) => *reload.write() += 1,
+                                                                Err(err) => error.set(Some(ImStr::from(err.to_string()))),
+                                                            }
+                                                        });
+                                                    },
+                                                    "{i18n.t(Key::SlotTimelineReopen)}"
+                                                }
+                                                Field { label: ImStr::from(i18n.t(Key::SlotTimelineEndFrom).as_ref()),
+                                                    TextInput {
+                                                        value: ImStr::from(end_week.read().as_str()),
+                                                        input_type: ImStr::from("week"),
+                                                        on_change: move |value: ImStr| end_week.set(value.to_string()),
+                                                    }
+                                                }
+                                                Btn {
+                                                    variant: BtnVariant::Danger,
+                                                    disabled: end.is_none(),
+                                                    on_click: move |_| {
+                                                        let Some(end) = end else {
+                                                            return;
+                                                        };
+                                                        spawn(async move {
+                                                            match loader::end_slot_from(CONFIG.read().clone(), slot_id, end).await {
+                                                                Ok(()) => *reload.write() += 1,
+                                                                Err(err) => error.set(Some(ImStr::from(err.to_string()))),
+                                                            }
+                                                        });
+                                                    },
+                                                    "{i18n.t(Key::SlotTimelineEnd)}"
+                                                }
+                                            }
+                                            if slot.valid_to.is_some() && !can_reopen {
+                                                div { class: "text-small text-ink-muted", "{i18n.t(Key::SlotTimelineReopenBlocked)}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        section { class: "space-y-1 max-w-[720px]",
+                            h2 { class: "text-h2 text-ink", "{i18n.t(Key::SlotTimelineChanges)}" }
+                            if timeline.changes.is_empty() {
+                                div { class: "text-small text-ink-muted italic", "{i18n.t(Key::SlotTimelineNoChanges)}" }
+                            }
+                            ul { class: "text-small text-ink space-y-0.5",
+                                for index in timeline.changes.iter().copied() {
+                                    {
+                                        let week = timeline.weeks[index];
+                                        let (started, ended) = timeline.change_summary(index);
+                                        rsx! {
+                                            li {
+                                                {
+                                                    i18n.t_m(
+                                                        Key::SlotTimelineChangeSummary,
+                                                        [
+                                                            ("week", week.week.to_string().as_str()),
+                                                            ("year", week.year.to_string().as_str()),
+                                                            ("started", started.to_string().as_str()),
+                                                            ("ended", ended.to_string().as_str()),
+                                                        ]
+                                                        .into(),
+                                                    )
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn year_range_is_bounded() {
+        assert_eq!(parse_year_range("2024", "2025"), Some((2024, 2025)));
+        assert_eq!(parse_year_range("2025", "2024"), None);
+        assert_eq!(parse_year_range("2020", "2025"), None);
+        assert_eq!(parse_year_range("x", "2025"), None);
+    }
+
+    #[test]
+    fn change_weeks_are_tinted_even_without_the_slot() {
+        assert!(cell_class(false, true, false).contains("bg-warn-soft"));
+        assert!(cell_class(true, true, false).contains("border-warn"));
+        assert_eq!(cell_class(true, false, true), "h-5 bg-accent");
+    }
+}
@@ -20,6 +20,7 @@ use crate::{
 pub fn TextTemplateManagement() -> Element {
     let mut show_form = use_signal(|| false);
     let mut editing_id = use_signal(|| None::<Uuid>);
+    let mut editing_version = use_signal(Uuid::nil);
     let mut form_name = use_signal(|| "".to_string());
     let mut form_template_type = use_signal(|| "billing-period".to_string());
     let mut form_template_text = use_signal(|| "".to_string());
@@ -89,6 +90,7 @@ pub fn TextTemplateManagement() -> Element {
                 template_engine,
                 created_at: None,
                 created_by: None,
+                version: *editing_version.read(),
             };
             action_coroutine.send(TextTemplateAction::UpdateTemplate(id, template));
         } else {
@@ -101,6 +103,7 @@ pub fn TextTemplateManagement() -> Element {
                 template_engine,
                 created_at: None,
                 created_by: None,
+                version: Uuid::nil(),
             };
             action_coroutine.send(TextTemplateAction::SaveTemplate(template));
         }
@@ -119,6 +122,7 @@ pub fn TextTemplateManagement() -> Element {
         form_template_text.set(template.template_text.to_string());
         form_template_engine.set(template.template_engine.clone());
         editing_id.set(Some(template.id));
+        editing_version.set(template.version);
         show_form.set(true);
     };
 
@@ -2,6 +2,8 @@ use dioxus::prelude::*;
 
 pub use crate::page::BillingPeriodDetails;
 pub use crate::page::BillingPeriods;
+pub use crate::page::BookingLogPage;
+pub use crate::page::BookingPatterns;
 pub use crate::page::CustomExtraHoursManagement;
 pub use crate::page::EmployeeDetails;
 pub use crate::page::Employees;
@@ -11,6 +13,7 @@ pub use crate::page::MyShifts;
 pub use crate::page::SalesPersonDetails;
 pub use crate::page::ShiftPlan;
 pub use crate::page::ShiftPlanDeep;
+pub use crate::page::SlotTimelinePage;
 pub use crate::page::TextTemplateManagement;
 pub use crate::page::UserDetails;
 pub use crate::page::UserManagementPage;
@@ -48,4 +51,10 @@ pub enum Route {
     BillingPeriodDetails { billing_period_id: String },
     #[route("/my-shifts/")]
     MyShifts {},
+    #[route("/booking_patterns/")]
+    BookingPatterns {},
+    #[route("/slot_timeline/:shiftplan_id/")]
+    SlotTimelinePage { shiftplan_id: String },
+    #[route("/booking_log/")]
+    BookingLogPage {},
 }
@@ -1,4 +1,4 @@
-use crate::{api, state::AuthInfo};
+use crate::{backend, state::AuthInfo};
 use dioxus::prelude::*;
 
 use super::{
@@ -7,14 +7,15 @@ use super::{
 };
 
 pub async fn load_auth_info() {
-    if CONFIG.read().backend.is_empty() {
+    if !CONFIG.read().is_loaded() {
         *AUTH.write() = AuthStore {
             auth_info: None,
             loading_done: true,
         };
         return;
     }
-    let auth_info = api::fetch_auth_info(CONFIG.read().backend.clone()).await;
+    let backend = backend::from_config(&CONFIG.read());
+    let auth_info = backend.fetch_auth_info().await;
 
     match auth_info {
         Ok(Some(auth_info)) => {
@@ -30,9 +31,7 @@ pub async fn load_auth_info() {
             };
         }
         Err(err) => {
-            *ERROR_STORE.write() = ErrorStore {
-                error: Some(err.into()),
-            };
+            *ERROR_STORE.write() = ErrorStore { error: Some(err) };
             *AUTH.write() = AuthStore {
                 auth_info: None,
                 loading_done: true,
@@ -5,7 +5,7 @@ use futures_util::StreamExt;
 use tracing::info;
 use uuid::Uuid;
 
-use crate::{api, error::ShiftyError};
+use crate::{backend, error::ShiftyError};
 
 use rest_types::BillingPeriodTO;
 
@@ -42,7 +42,9 @@ pub enum BillingPeriodAction {
 
 pub async fn load_billing_periods() -> Result<(), ShiftyError> {
     info!("Loading billing periods");
-    let billing_periods = api::get_billing_periods(CONFIG.read().clone()).await?;
+    let billing_periods = backend::from_config(&CONFIG.read())
+        .get_billing_periods()
+        .await?;
     BILLING_PERIOD_STORE.write().billing_periods = billing_periods;
     info!("Loaded billing periods");
     Ok(())
@@ -50,7 +52,9 @@ pub async fn load_billing_periods() -> Result<(), ShiftyError> {
 
 pub async fn load_billing_period(billing_period_id: Uuid) -> Result<(), ShiftyError> {
     info!("Loading billing period {}", billing_period_id);
-    let billing_period = api::get_billing_period(CONFIG.read().clone(), billing_period_id).await?;
+    let billing_period = backend::from_config(&CONFIG.read())
+        .get_billing_period(billing_period_id)
+        .await?;
     BILLING_PERIOD_STORE.write().selected_billing_period = Some(billing_period);
     info!("Loaded billing period");
     Ok(())
@@ -58,7 +62,9 @@ pub async fn load_billing_period(billing_period_id: Uuid) -> Result<(), ShiftyEr
 
 pub async fn create_billing_period(end_date: time::Date) -> Result<(), ShiftyError> {
     info!("Creating billing period with end date {}", end_date);
-    api::post_billing_period(CONFIG.read().clone(), end_date).await?;
+    backend::from_config(&CONFIG.read())
+        .post_billing_period(end_date)
+        .await?;
     info!("Created billing period");
     load_billing_periods().await?;
     Ok(())
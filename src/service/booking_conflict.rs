@@ -1,13 +1,13 @@
 use std::rc::Rc;
 
 use dioxus::prelude::*;
-use futures_util::StreamExt;
 
 use crate::{error::ShiftyError, loader, state::shiftplan::BookingConflict};
 
 use super::{
     config::CONFIG,
     error::{ErrorStore, ERROR_STORE},
+    latest::run_latest,
 };
 
 pub static BOOKING_CONFLICTS_STORE: GlobalSignal<Rc<[BookingConflict]>> =
@@ -18,6 +18,13 @@ pub enum BookingConflictAction {
 }
 
 async fn load_booking_conflict_week(year: u32, week: u8) -> Result<(), ShiftyError> {
+    // A prefetched week shows up right away, an expired one is reloaded.
+    if let Some(remembered) = loader::remembered_bookings_conflicts_for_week(year, week) {
+        *BOOKING_CONFLICTS_STORE.write() = remembered.value.as_ref().clone();
+        if remembered.fresh {
+            return Ok(());
+        }
+    }
     let booking_conflicts =
         loader::load_bookings_conflicts_for_week(CONFIG.read().clone(), year, week).await?;
     *BOOKING_CONFLICTS_STORE.write() = booking_conflicts;
@@ -25,7 +32,7 @@ async fn load_booking_conflict_week(year: u32, week: u8) -> Result<(), ShiftyErr
 }
 
 pub async fn booking_conflicts_service(mut rx: UnboundedReceiver<BookingConflictAction>) {
-    while let Some(action) = rx.next().await {
+    run_latest(&mut rx, |action| async move {
         match match action {
             BookingConflictAction::LoadWeek(year, week) => {
                 load_booking_conflict_week(year, week).await
@@ -38,5 +45,6 @@ pub async fn booking_conflicts_service(mut rx: UnboundedReceiver<BookingConflict
                 };
             }
         }
-    }
+    })
+    .await;
 }
@@ -0,0 +1,175 @@
+//! Recurring booking patterns, kept in `localStorage`.
+//!
+//! The backend has no notion of recurring assignments, so the patterns live
+//! in the planner's browser. Materializing them creates regular bookings
+//! through the backend like any other booking.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+use uuid::Uuid;
+
+use crate::{
+    loader,
+    state::{
+        booking_pattern::{BookingPattern, PatternMaterialization},
+        week::Week,
+    },
+};
+
+use super::{
+    config::CONFIG,
+    error::{ErrorStore, ERROR_STORE},
+};
+
+#[allow(dead_code)]
+pub const BOOKING_PATTERNS_KEY: &str = "shifty.bookingPatterns";
+
+pub static BOOKING_PATTERNS: GlobalSignal<Rc<[BookingPattern]>> = Signal::global(load_patterns);
+/// Outcome of the last materialization, shown on the patterns page.
+pub static BOOKING_PATTERN_RESULT: GlobalSignal<Option<PatternMaterialization>> =
+    Signal::global(|| None);
+
+pub enum BookingPatternAction {
+    /// Adds the pattern or replaces the one with the same id.
+    Save(BookingPattern),
+    Delete(Uuid),
+    /// Creates the bookings of the given patterns from `from` through `until`.
+    Materialize {
+        pattern_ids: Rc<[Uuid]>,
+        from: Week,
+        until: Week,
+    },
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load_patterns() -> Rc<[BookingPattern]> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|s| s.get_item(BOOKING_PATTERNS_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str::<Vec<BookingPattern>>(&json).ok())
+        .map(Rc::from)
+        .unwrap_or_else(|| Rc::new([]))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_patterns(patterns: &[BookingPattern]) {
+    if let (Some(storage), Ok(json)) = (
+        web_sys::window().and_then(|w| w.local_storage().ok().flatten()),
+        serde_json::to_string(patterns),
+    ) {
+        let _ = storage.set_item(BOOKING_PATTERNS_KEY, &json);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_patterns() -> Rc<[BookingPattern]> {
+    Rc::new([])
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn save_patterns(_patterns: &[BookingPattern]) {}
+
+/// Replaces the pattern with the same id or appends it.
+pub(crate) fn upsert_pattern(
+    patterns: &[BookingPattern],
+    pattern: BookingPattern,
+) -> Rc<[BookingPattern]> {
+    let mut patterns = patterns.to_vec();
+    match patterns
+        .iter_mut()
+        .find(|existing| existing.id == pattern.id)
+    {
+        Some(existing) => *existing = pattern,
+        None => patterns.push(pattern),
+    }
+    patterns.into()
+}
+
+fn store_patterns(patterns: Rc<[BookingPattern]>) {
+    save_patterns(&patterns);
+    *BOOKING_PATTERNS.write() = patterns;
+}
+
+pub async fn booking_pattern_service(mut rx: UnboundedReceiver<BookingPatternAction>) {
+    while let Some(action) = rx.next().await {
+        match action {
+            BookingPatternAction::Save(pattern) => {
+                let patterns = upsert_pattern(&BOOKING_PATTERNS.read(), pattern);
+                store_patterns(patterns);
+            }
+            BookingPatternAction::Delete(id) => {
+                let patterns = BOOKING_PATTERNS
+                    .read()
+                    .iter()
+                    .filter(|pattern| pattern.id != id)
+                    .cloned()
+                    .collect();
+                store_patterns(patterns);
+            }
+            BookingPatternAction::Materialize {
+                pattern_ids,
+                from,
+                until,
+            } => {
+                let patterns: Vec<BookingPattern> = BOOKING_PATTERNS
+                    .read()
+                    .iter()
+                    .filter(|pattern| pattern_ids.contains(&pattern.id))
+                    .cloned()
+                    .collect();
+                *BOOKING_PATTERN_RESULT.write() = None;
+                match loader::materialize_booking_patterns(
+                    CONFIG.read().clone(),
+                    &patterns,
+                    from,
+                    until,
+                )
+                .await
+                {
+                    Ok(result) => *BOOKING_PATTERN_RESULT.write() = Some(result),
+                    Err(err) => {
+                        *ERROR_STORE.write() = ErrorStore { error: Some(err) };
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(id: u128, interval_weeks: u8) -> BookingPattern {
+        BookingPattern {
+            id: Uuid::from_u128(id),
+            sales_person_id: Uuid::nil(),
+            shiftplan_id: Uuid::nil(),
+            slot_id: Uuid::nil(),
+            interval_weeks,
+            valid_from: Week {
+                year: 2025,
+                week: 1,
+            },
+            valid_until: Week {
+                year: 2025,
+                week: 52,
+            },
+        }
+    }
+
+    #[test]
+    fn upsert_appends_new_patterns() {
+        let patterns = upsert_pattern(&[pattern(1, 1)], pattern(2, 1));
+        assert_eq!(patterns.len(), 2);
+    }
+
+    #[test]
+    fn upsert_replaces_patterns_with_the_same_id() {
+        let patterns = upsert_pattern(&[pattern(1, 1), pattern(2, 1)], pattern(1, 4));
+        assert_eq!(patterns.len(), 2);
+        assert_eq!(patterns[0].interval_weeks, 4);
+    }
+}
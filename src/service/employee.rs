@@ -8,7 +8,7 @@ use uuid::Uuid;
 use rest_types::ExtraHoursTO;
 
 use crate::{
-    api,
+    backend,
     error::ShiftyError,
     i18n::Key,
     js, loader,
@@ -95,20 +95,20 @@ pub async fn load_employee_data(
             .await?;
     let extra_hours =
         loader::load_extra_hours_per_year(CONFIG.read().clone(), year, sales_person_id).await?;
-    let custom_extra_hours_definitions =
-        match api::get_custom_extra_hours_by_sales_person(CONFIG.read().clone(), sales_person_id)
-            .await
-        {
-            Ok(hours) => {
-                let definitions: Rc<[CustomExtraHoursDefinition]> =
-                    hours.iter().map(|h| h.into()).collect();
-                definitions
-            }
-            Err(e) => {
-                info!("Failed to load custom extra hours definitions: {}", e);
-                Rc::new([])
-            }
-        };
+    let custom_extra_hours_definitions = match backend::from_config(&CONFIG.read())
+        .get_custom_extra_hours_by_sales_person(sales_person_id)
+        .await
+    {
+        Ok(hours) => {
+            let definitions: Rc<[CustomExtraHoursDefinition]> =
+                hours.iter().map(|h| h.into()).collect();
+            definitions
+        }
+        Err(e) => {
+            info!("Failed to load custom extra hours definitions: {}", e);
+            Rc::new([])
+        }
+    };
     super::employee_work_details::load_employee_work_details(sales_person_id).await?;
     *EMPLOYEE_STORE.write() = EmployeeStore {
         employee,
@@ -148,17 +148,23 @@ pub(crate) fn bump_employees_list_refresh() {
 }
 
 pub async fn delete_extra_hours(extra_hours_id: Uuid) -> Result<(), ShiftyError> {
-    api::delete_extra_hour(CONFIG.read().clone(), extra_hours_id).await?;
+    backend::from_config(&CONFIG.read())
+        .delete_extra_hour(extra_hours_id)
+        .await?;
     Ok(())
 }
 
 pub async fn update_extra_hours(extra_hours: ExtraHoursTO) -> Result<(), ShiftyError> {
-    api::update_extra_hour(CONFIG.read().clone(), extra_hours).await?;
+    backend::from_config(&CONFIG.read())
+        .update_extra_hour(extra_hours)
+        .await?;
     Ok(())
 }
 
 pub async fn delete_custom_extra_hours(custom_extra_hours_id: Uuid) -> Result<(), ShiftyError> {
-    api::delete_custom_extra_hours(CONFIG.read().clone(), custom_extra_hours_id).await?;
+    backend::from_config(&CONFIG.read())
+        .delete_custom_extra_hours(custom_extra_hours_id)
+        .await?;
     Ok(())
 }
 
@@ -217,6 +223,13 @@ pub async fn employee_service(mut rx: UnboundedReceiver<EmployeeAction>) {
                         };
                         refresh_result
                     }
+                    Err(conflict @ ShiftyError::VersionConflict(_)) => {
+                        let refresh_result = refresh_employee_data().await;
+                        *ERROR_STORE.write() = ErrorStore {
+                            error: Some(conflict),
+                        };
+                        refresh_result
+                    }
                     Err(other) => Err(other),
                 }
             }
@@ -280,10 +293,10 @@ mod tests {
     //! Service-level unit tests for `EmployeeAction`.
     //!
     //! The async coroutine handler in `employee_service` reaches into the
-    //! global `CONFIG` / `EMPLOYEE_STORE` / `ERROR_STORE` signals and issues
-    //! real `reqwest` calls via `api::*`. There is no mock-API layer in this
-    //! crate today, so we cannot drive the `UpdateExtraHours` arm end-to-end
-    //! from a unit test without standing up an HTTP server.
+    //! global `CONFIG` / `EMPLOYEE_STORE` / `ERROR_STORE` signals, which need
+    //! a running Dioxus runtime. The backend calls themselves can be served
+    //! by `backend::InMemoryBackend`, but the signal plumbing keeps the
+    //! `UpdateExtraHours` arm out of reach of a plain unit test.
     //!
     //! The behavior the user cares about is split as follows:
     //! - The `UpdateExtraHours` payload construction is covered here via
@@ -5,7 +5,7 @@ use futures_util::StreamExt;
 use uuid::Uuid;
 
 use crate::{
-    api,
+    backend,
     error::ShiftyError,
     loader,
     state::{employee_work_details::EmployeeWorkDetails, shiftplan::SalesPerson},
@@ -72,7 +72,9 @@ async fn new_employee_work_details_for_sales_person(
 }
 
 async fn delete_employee_work_details(employee_work_details_id: Uuid) -> Result<(), ShiftyError> {
-    api::delete_employee_work_details(CONFIG.read().clone(), employee_work_details_id).await?;
+    backend::from_config(&CONFIG.read())
+        .delete_employee_work_details(employee_work_details_id)
+        .await?;
     reload_employee_work_details().await?;
     Ok(())
 }
@@ -0,0 +1,83 @@
+//! Runs only the latest action of a loading service.
+//!
+//! Week navigation sends one load per click. A load which is still under way
+//! when the next action arrives is dropped together with its request, so a
+//! slow response for a week the user already left never reaches the store.
+
+use std::future::Future;
+
+use dioxus::prelude::UnboundedReceiver;
+use futures::{future::FutureExt, pin_mut, select};
+use futures_util::StreamExt;
+
+/// Calls `run` for every action received on `rx`. An action arriving while
+/// the previous one still runs cancels that one.
+pub async fn run_latest<A, F>(rx: &mut UnboundedReceiver<A>, mut run: impl FnMut(A) -> F)
+where
+    F: Future<Output = ()>,
+{
+    let Some(mut action) = rx.next().await else {
+        return;
+    };
+    loop {
+        let running = run(action).fuse();
+        pin_mut!(running);
+        let next = select! {
+            () = running => rx.next().await,
+            next = rx.next() => next,
+        };
+        match next {
+            Some(next) => action = next,
+            None => return,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use futures::{
+        channel::{mpsc::unbounded, oneshot},
+        executor::block_on,
+        join,
+    };
+
+    use super::*;
+
+    #[test]
+    fn a_newer_action_cancels_the_running_one() {
+        let applied = Rc::new(RefCell::new(Vec::new()));
+        let (sender, mut rx) = unbounded::<(u8, Option<oneshot::Receiver<()>>)>();
+        let (release_first, first_gate) = oneshot::channel();
+        let (release_second, second_gate) = oneshot::channel();
+
+        let service = {
+            let applied = applied.clone();
+            async move {
+                run_latest(&mut rx, |(week, gate)| {
+                    let applied = applied.clone();
+                    async move {
+                        if let Some(gate) = gate {
+                            let _ = gate.await;
+                        }
+                        applied.borrow_mut().push(week);
+                    }
+                })
+                .await
+            }
+        };
+        let clicks = async move {
+            sender.unbounded_send((12, Some(first_gate))).unwrap();
+            sender.unbounded_send((13, Some(second_gate))).unwrap();
+            sender.unbounded_send((14, None)).unwrap();
+            futures::pending!();
+            // The responses of the left weeks arrive last.
+            let _ = release_second.send(());
+            let _ = release_first.send(());
+        };
+        block_on(async { join!(service, clicks) });
+
+        assert_eq!(applied.borrow().as_slice(), &[14]);
+    }
+}
@@ -0,0 +1,284 @@
+//! Keeps an open shiftplan week in sync with what other planners do.
+//!
+//! The backend announces changes of a week through a server-sent event feed.
+//! Every announcement, and a periodic poll when the feed is not available,
+//! reloads the week and compares it with the last version the page showed.
+//! The same poll sends a presence heartbeat which returns the other users
+//! looking at the week.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use futures::{
+    channel::mpsc::{unbounded, UnboundedSender},
+    future::{Fuse, FusedFuture, FutureExt},
+    select,
+};
+use futures_util::StreamExt;
+use gloo_timers::future::TimeoutFuture;
+use time::PrimitiveDateTime;
+use tracing::{info, warn};
+use uuid::Uuid;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{closure::Closure, JsCast};
+
+use crate::{
+    error::ShiftyError,
+    loader::{self, cache::Invalidation},
+    state::{
+        booking_log,
+        live_update::{live_changes, LiveChanges},
+        Shiftplan,
+    },
+};
+
+use super::{config::CONFIG, slot_edit::trigger_shiftplan_refresh};
+
+/// Poll interval while the change feed is not connected.
+const POLL_INTERVAL_MS: u32 = 10_000;
+/// Poll interval while the feed is connected. It only has to keep the
+/// presence heartbeat within the backend's timeout.
+const HEARTBEAT_INTERVAL_MS: u32 = 30_000;
+/// How long changes of other planners stay highlighted.
+const HIGHLIGHT_MS: u32 = 4_000;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LiveMode {
+    #[default]
+    Off,
+    /// Connected to the change feed.
+    Feed,
+    /// The feed is unavailable, the week is reloaded periodically.
+    Polling,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LiveUpdateStore {
+    pub mode: LiveMode,
+    /// Other users who have the week open.
+    pub viewers: Rc<[Rc<str>]>,
+    /// Changes of the last update which are still highlighted.
+    pub changes: LiveChanges,
+    /// Time and author of the newest change of the week according to the
+    /// booking log, set when another planner changed the week.
+    pub last_change: Option<(PrimitiveDateTime, Option<Rc<str>>)>,
+}
+
+pub static LIVE_UPDATE: GlobalSignal<LiveUpdateStore> = Signal::global(LiveUpdateStore::default);
+
+pub enum LiveUpdateAction {
+    Watch {
+        shiftplan_id: Uuid,
+        year: u32,
+        week: u8,
+    },
+    /// The plan as the page currently shows it.
+    Baseline(Shiftplan),
+    Stop,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Watched {
+    shiftplan_id: Uuid,
+    year: u32,
+    week: u8,
+}
+
+#[cfg(target_arch = "wasm32")]
+struct Feed {
+    source: web_sys::EventSource,
+    _on_message: Closure<dyn FnMut(web_sys::MessageEvent)>,
+    _on_open: Closure<dyn FnMut(web_sys::Event)>,
+    _on_error: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Drop for Feed {
+    fn drop(&mut self) {
+        self.source.close();
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn set_mode(mode: LiveMode) {
+    if LIVE_UPDATE.read().mode != mode {
+        LIVE_UPDATE.write().mode = mode;
+    }
+}
+
+/// Opens the change feed of the week. Every message sends a nudge; while the
+/// feed is disconnected the mode falls back to polling.
+#[cfg(target_arch = "wasm32")]
+fn open_feed(watched: Watched, nudge: UnboundedSender<()>) -> Option<Feed> {
+    let config = CONFIG.read().clone();
+    if config.demo {
+        return None;
+    }
+    let url =
+        crate::api::shiftplan_feed_url(&config, watched.shiftplan_id, watched.year, watched.week);
+    let init = web_sys::EventSourceInit::new();
+    init.set_with_credentials(true);
+    let source = web_sys::EventSource::new_with_event_source_init_dict(&url, &init).ok()?;
+    let on_message = Closure::wrap(Box::new(move |_: web_sys::MessageEvent| {
+        let _ = nudge.unbounded_send(());
+    }) as Box<dyn FnMut(web_sys::MessageEvent)>);
+    let on_open = Closure::wrap(Box::new(move |_: web_sys::Event| set_mode(LiveMode::Feed))
+        as Box<dyn FnMut(web_sys::Event)>);
+    let on_error = Closure::wrap(
+        Box::new(move |_: web_sys::Event| set_mode(LiveMode::Polling))
+            as Box<dyn FnMut(web_sys::Event)>,
+    );
+    source.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    source.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+    Some(Feed {
+        source,
+        _on_message: on_message,
+        _on_open: on_open,
+        _on_error: on_error,
+    })
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+struct Feed;
+
+#[cfg(not(target_arch = "wasm32"))]
+fn open_feed(_watched: Watched, _nudge: UnboundedSender<()>) -> Option<Feed> {
+    None
+}
+
+/// Reloads the watched week and the viewers. Returns the changes against
+/// `baseline` and makes the reloaded plan the new baseline. Failing to load
+/// the viewers or the booking log keeps their old state; only the week
+/// itself decides whether the poll failed.
+async fn poll(
+    watched: Watched,
+    baseline: &mut Option<Shiftplan>,
+) -> Result<LiveChanges, ShiftyError> {
+    let config = CONFIG.read().clone();
+    match loader::load_shiftplan_viewers(
+        config.clone(),
+        watched.shiftplan_id,
+        watched.year,
+        watched.week,
+    )
+    .await
+    {
+        Ok(viewers) => {
+            if LIVE_UPDATE.read().viewers != viewers {
+                LIVE_UPDATE.write().viewers = viewers;
+            }
+        }
+        Err(err) => warn!("Loading the viewers of the shiftplan failed: {err}"),
+    }
+    let Some(old) = baseline.as_ref() else {
+        return Ok(LiveChanges::default());
+    };
+    // Other planners' changes do not pass the loader, so the cached week
+    // would hide them.
+    loader::cache::invalidate(Invalidation::Week {
+        year: watched.year,
+        week: watched.week,
+    });
+    let plan = loader::load_shift_plan(
+        config.clone(),
+        watched.shiftplan_id,
+        watched.week,
+        watched.year,
+    )
+    .await?;
+    let changes = live_changes(old, &plan);
+    *baseline = Some(plan);
+    if !changes.is_empty() {
+        match loader::load_booking_log(config, watched.year, watched.week).await {
+            Ok(log) => {
+                LIVE_UPDATE.write().last_change = booking_log::events(log.iter())
+                    .pop()
+                    .map(|event| (event.at, event.by));
+            }
+            Err(err) => warn!("Loading who changed the shiftplan failed: {err}"),
+        }
+    }
+    Ok(changes)
+}
+
+pub async fn live_update_service(mut rx: UnboundedReceiver<LiveUpdateAction>) {
+    let (nudge, mut nudges) = unbounded::<()>();
+    let mut watched: Option<Watched> = None;
+    let mut baseline: Option<Shiftplan> = None;
+    let mut feed: Option<Feed> = None;
+    let mut tick: Fuse<TimeoutFuture> = Fuse::terminated();
+    let mut clear_marks: Fuse<TimeoutFuture> = Fuse::terminated();
+
+    loop {
+        let poll_now = select! {
+            action = rx.next() => match action {
+                None => break,
+                Some(LiveUpdateAction::Watch { shiftplan_id, year, week }) => {
+                    let next = Watched { shiftplan_id, year, week };
+                    if watched == Some(next) {
+                        false
+                    } else {
+                        info!("Watch shiftplan {shiftplan_id} {week}/{year}");
+                        baseline = None;
+                        *LIVE_UPDATE.write() = LiveUpdateStore {
+                            mode: LiveMode::Polling,
+                            ..Default::default()
+                        };
+                        watched = Some(next);
+                        feed = open_feed(next, nudge.clone());
+                        true
+                    }
+                }
+                Some(LiveUpdateAction::Baseline(plan)) => {
+                    if watched.is_some_and(|watched| {
+                        watched.year == plan.year && watched.week == plan.week
+                    }) {
+                        baseline = Some(plan);
+                    }
+                    false
+                }
+                Some(LiveUpdateAction::Stop) => {
+                    watched = None;
+                    baseline = None;
+                    feed = None;
+                    tick = Fuse::terminated();
+                    *LIVE_UPDATE.write() = LiveUpdateStore::default();
+                    false
+                }
+            },
+            _ = nudges.next() => true,
+            _ = tick => true,
+            _ = clear_marks => {
+                LIVE_UPDATE.write().changes = LiveChanges::default();
+                false
+            }
+        };
+        let Some(current) = watched else {
+            continue;
+        };
+        if !poll_now {
+            if tick.is_terminated() {
+                tick = TimeoutFuture::new(POLL_INTERVAL_MS).fuse();
+            }
+            continue;
+        }
+        match poll(current, &mut baseline).await {
+            Ok(changes) if !changes.is_empty() => {
+                LIVE_UPDATE.write().changes = changes;
+                clear_marks = TimeoutFuture::new(HIGHLIGHT_MS).fuse();
+                trigger_shiftplan_refresh();
+            }
+            Ok(_) => {}
+            // A missed update is caught by the next poll, so this is not
+            // worth interrupting the user.
+            Err(err) => warn!("Live update of the shiftplan failed: {err}"),
+        }
+        let interval = if feed.is_some() && LIVE_UPDATE.read().mode == LiveMode::Feed {
+            HEARTBEAT_INTERVAL_MS
+        } else {
+            POLL_INTERVAL_MS
+        };
+        tick = TimeoutFuture::new(interval).fuse();
+    }
+}
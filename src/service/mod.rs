@@ -2,17 +2,23 @@ pub mod auth;
 pub mod billing_period;
 pub mod booking_conflict;
 pub mod booking_log;
+pub mod booking_pattern;
 pub mod config;
 pub mod dropdown;
 pub mod employee;
 pub mod employee_work_details;
 pub mod error;
 pub mod i18n;
+pub mod latest;
+pub mod live_update;
+pub mod offline_queue;
+pub mod shiftplan_draft;
 pub mod slot_edit;
 pub mod text_template;
 pub mod theme;
 pub mod tooltip;
 pub mod ui_prefs;
 pub mod user_management;
+pub mod version_conflict;
 pub mod weekly_summary;
 pub mod working_hours_mini;
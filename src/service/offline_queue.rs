@@ -0,0 +1,180 @@
+//! Booking and unavailability changes which could not reach the backend.
+//!
+//! The queue is kept in `localStorage`, so a reload on a flaky connection
+//! does not lose it. It is replayed in order when the browser reports that
+//! it is online again, periodically while items are pending, and on demand.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use futures::{
+    channel::mpsc::{unbounded, UnboundedSender},
+    future::{Fuse, FusedFuture, FutureExt},
+    select,
+};
+use futures_util::StreamExt;
+use gloo_timers::future::TimeoutFuture;
+use tracing::{info, warn};
+use uuid::Uuid;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{closure::Closure, JsCast};
+
+use crate::{
+    loader,
+    state::offline_queue::{OfflineQueue, QueuedMutation},
+};
+
+use super::{config::CONFIG, slot_edit::trigger_shiftplan_refresh};
+
+#[allow(dead_code)]
+pub const OFFLINE_QUEUE_KEY: &str = "shifty.offlineQueue";
+/// Retry interval while changes are pending.
+const RETRY_INTERVAL_MS: u32 = 30_000;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OfflineQueueStore {
+    pub queue: OfflineQueue,
+    pub replaying: bool,
+}
+
+pub static OFFLINE_QUEUE: GlobalSignal<OfflineQueueStore> = Signal::global(|| OfflineQueueStore {
+    queue: load_queue(),
+    replaying: false,
+});
+
+pub enum OfflineQueueAction {
+    Enqueue {
+        mutation: QueuedMutation,
+        summary: Rc<str>,
+    },
+    /// Replays every item, including the ones the backend rejected before.
+    Retry,
+    Discard(Uuid),
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load_queue() -> OfflineQueue {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|s| s.get_item(OFFLINE_QUEUE_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str::<OfflineQueue>(&json).ok())
+        .unwrap_or_default()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_queue(queue: &OfflineQueue) {
+    let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) else {
+        return;
+    };
+    if queue.items.is_empty() {
+        let _ = storage.remove_item(OFFLINE_QUEUE_KEY);
+    } else if let Ok(json) = serde_json::to_string(queue) {
+        let _ = storage.set_item(OFFLINE_QUEUE_KEY, &json);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_queue() -> OfflineQueue {
+    OfflineQueue::default()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn save_queue(_queue: &OfflineQueue) {}
+
+fn update_queue(update: impl FnOnce(&mut OfflineQueue)) {
+    let mut store = OFFLINE_QUEUE.write();
+    update(&mut store.queue);
+    save_queue(&store.queue);
+}
+
+/// Sends a nudge whenever the browser comes back online. The returned
+/// closure has to be kept alive.
+#[cfg(target_arch = "wasm32")]
+fn listen_online(nudge: UnboundedSender<()>) -> Option<Closure<dyn FnMut(web_sys::Event)>> {
+    let window = web_sys::window()?;
+    let on_online = Closure::wrap(Box::new(move |_: web_sys::Event| {
+        let _ = nudge.unbounded_send(());
+    }) as Box<dyn FnMut(web_sys::Event)>);
+    window
+        .add_event_listener_with_callback("online", on_online.as_ref().unchecked_ref())
+        .ok()?;
+    Some(on_online)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn listen_online(_nudge: UnboundedSender<()>) -> Option<()> {
+    None
+}
+
+/// Sends the queued items in order. The replay stops at the first item
+/// which still cannot reach the backend. An item the backend rejects keeps
+/// the reason and the replay continues with the next one.
+async fn replay(include_failed: bool) {
+    if !CONFIG.read().is_loaded() {
+        return;
+    }
+    let items = OFFLINE_QUEUE.read().queue.items.clone();
+    if items.is_empty() {
+        return;
+    }
+    info!("Replay {} queued changes", items.len());
+    OFFLINE_QUEUE.write().replaying = true;
+    let config = CONFIG.read().clone();
+    let mut replayed = false;
+    for item in items
+        .into_iter()
+        .filter(|item| include_failed || item.failure.is_none())
+    {
+        match loader::replay_queued_mutation(config.clone(), &item.mutation).await {
+            Ok(()) => {
+                update_queue(|queue| queue.remove(item.id));
+                replayed = true;
+            }
+            Err(err) if err.is_connectivity() => {
+                warn!("Backend still unreachable: {err}");
+                break;
+            }
+            Err(err) => update_queue(|queue| {
+                if let Some(failed) = queue.items.iter_mut().find(|queued| queued.id == item.id) {
+                    failed.failure = Some(err.to_string().into());
+                }
+            }),
+        }
+    }
+    OFFLINE_QUEUE.write().replaying = false;
+    if replayed {
+        trigger_shiftplan_refresh();
+    }
+}
+
+pub async fn offline_queue_service(mut rx: UnboundedReceiver<OfflineQueueAction>) {
+    let (nudge, mut nudges) = unbounded::<()>();
+    let _on_online = listen_online(nudge);
+    let mut retry: Fuse<TimeoutFuture> = Fuse::terminated();
+    if OFFLINE_QUEUE.read().queue.has_pending() {
+        retry = TimeoutFuture::new(0).fuse();
+    }
+
+    loop {
+        select! {
+            action = rx.next() => match action {
+                None => break,
+                Some(OfflineQueueAction::Enqueue { mutation, summary }) => {
+                    info!("Queue change: {summary}");
+                    update_queue(|queue| queue.push(mutation, summary));
+                }
+                Some(OfflineQueueAction::Retry) => replay(true).await,
+                Some(OfflineQueueAction::Discard(id)) => update_queue(|queue| queue.remove(id)),
+            },
+            _ = nudges.next() => replay(false).await,
+            _ = retry => replay(false).await,
+        }
+        if OFFLINE_QUEUE.read().queue.has_pending() {
+            if retry.is_terminated() {
+                retry = TimeoutFuture::new(RETRY_INTERVAL_MS).fuse();
+            }
+        } else {
+            retry = Fuse::terminated();
+        }
+    }
+}
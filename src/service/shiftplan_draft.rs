@@ -0,0 +1,128 @@
+//! Links between planning shiftplans and the live plans they draft,
+//! kept in `localStorage`.
+//!
+//! The backend only knows a shiftplan is a planning plan, not which live plan
+//! it was cloned from, so the planner's browser remembers the pairing.
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+use uuid::Uuid;
+
+use crate::state::shiftplan_draft::ShiftplanDraft;
+
+#[allow(dead_code)]
+pub const SHIFTPLAN_DRAFTS_KEY: &str = "shifty.shiftplanDrafts";
+
+pub static SHIFTPLAN_DRAFTS: GlobalSignal<Rc<[ShiftplanDraft]>> = Signal::global(load_drafts);
+
+pub enum ShiftplanDraftAction {
+    /// Links the draft to its live plan, replacing an earlier link.
+    Link(ShiftplanDraft),
+    /// Forgets the link of the given draft plan.
+    Unlink(Uuid),
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load_drafts() -> Rc<[ShiftplanDraft]> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|s| s.get_item(SHIFTPLAN_DRAFTS_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str::<Vec<ShiftplanDraft>>(&json).ok())
+        .map(Rc::from)
+        .unwrap_or_else(|| Rc::new([]))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_drafts(drafts: &[ShiftplanDraft]) {
+    if let (Some(storage), Ok(json)) = (
+        web_sys::window().and_then(|w| w.local_storage().ok().flatten()),
+        serde_json::to_string(drafts),
+    ) {
+        let _ = storage.set_item(SHIFTPLAN_DRAFTS_KEY, &json);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_drafts() -> Rc<[ShiftplanDraft]> {
+    Rc::new([])
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn save_drafts(_drafts: &[ShiftplanDraft]) {}
+
+/// The live plan `draft_id` is linked to.
+pub fn live_plan_of(draft_id: Uuid) -> Option<ShiftplanDraft> {
+    SHIFTPLAN_DRAFTS
+        .read()
+        .iter()
+        .find(|draft| draft.draft_id == draft_id)
+        .copied()
+}
+
+/// Replaces the link of the same draft plan or appends it.
+pub(crate) fn upsert_draft(
+    drafts: &[ShiftplanDraft],
+    draft: ShiftplanDraft,
+) -> Rc<[ShiftplanDraft]> {
+    let mut drafts = drafts.to_vec();
+    match drafts
+        .iter_mut()
+        .find(|existing| existing.draft_id == draft.draft_id)
+    {
+        Some(existing) => *existing = draft,
+        None => drafts.push(draft),
+    }
+    drafts.into()
+}
+
+fn store_drafts(drafts: Rc<[ShiftplanDraft]>) {
+    save_drafts(&drafts);
+    *SHIFTPLAN_DRAFTS.write() = drafts;
+}
+
+pub async fn shiftplan_draft_service(mut rx: UnboundedReceiver<ShiftplanDraftAction>) {
+    while let Some(action) = rx.next().await {
+        match action {
+            ShiftplanDraftAction::Link(draft) => {
+                let drafts = upsert_draft(&SHIFTPLAN_DRAFTS.read(), draft);
+                store_drafts(drafts);
+            }
+            ShiftplanDraftAction::Unlink(draft_id) => {
+                let drafts = SHIFTPLAN_DRAFTS
+                    .read()
+                    .iter()
+                    .filter(|draft| draft.draft_id != draft_id)
+                    .copied()
+                    .collect();
+                store_drafts(drafts);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draft(draft_id: u128, live_id: u128) -> ShiftplanDraft {
+        ShiftplanDraft {
+            draft_id: Uuid::from_u128(draft_id),
+            live_id: Uuid::from_u128(live_id),
+        }
+    }
+
+    #[test]
+    fn upsert_appends_new_links() {
+        let drafts = upsert_draft(&[draft(1, 10)], draft(2, 10));
+        assert_eq!(drafts.len(), 2);
+    }
+
+    #[test]
+    fn upsert_relinks_the_same_draft() {
+        let drafts = upsert_draft(&[draft(1, 10), draft(2, 10)], draft(1, 11));
+        assert_eq!(drafts.len(), 2);
+        assert_eq!(drafts[0].live_id, Uuid::from_u128(11));
+    }
+}
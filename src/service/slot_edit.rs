@@ -3,9 +3,9 @@ use futures_util::StreamExt;
 use uuid::Uuid;
 
 use crate::{
-    api,
+    backend,
     error::ShiftyError,
-    loader,
+    loader::{self, cache::Invalidation},
     state::slot_edit::{SlotEdit, SlotEditItem, SlotEditType},
 };
 
@@ -79,7 +79,11 @@ pub async fn cancel_slot_edit() -> Result<(), ShiftyError> {
 }
 
 pub async fn delete_slot_edit(id: Uuid, year: u32, week: u8) -> Result<(), ShiftyError> {
-    api::delete_slot_from(CONFIG.read().clone(), id, year, week).await?;
+    let result = backend::from_config(&CONFIG.read())
+        .delete_slot_from(id, year, week)
+        .await;
+    loader::cache::invalidate(Invalidation::Slots);
+    result?;
     trigger_shiftplan_refresh();
     Ok(())
 }
@@ -1,11 +1,14 @@
 //! Browser-local UI preferences backed by `localStorage`.
 //!
-//! Mirrors the shape of `service::theme` for a second per-browser preference:
+//! Mirrors the shape of `service::theme` for further per-browser preferences:
 //! the layout (cards or table) of the working-hours mini overview rendered
-//! below the shift plan.
+//! below the shift plan, and whether the week grid highlights staffing
+//! levels and booking conflicts.
 
 #[allow(dead_code)]
 pub const WORKING_HOURS_LAYOUT_KEY: &str = "shifty.ui.workingHoursLayout";
+#[allow(dead_code)]
+pub const STAFFING_HIGHLIGHT_KEY: &str = "shifty.ui.staffingHighlight";
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum WorkingHoursLayout {
@@ -57,6 +60,32 @@ pub fn get_working_hours_layout() -> WorkingHoursLayout {
 #[cfg(not(target_arch = "wasm32"))]
 pub fn set_working_hours_layout(_layout: WorkingHoursLayout) {}
 
+/// Whether the week grid tints cells by staffing level and marks
+/// conflicting bookings. On unless the user switched it off.
+#[cfg(target_arch = "wasm32")]
+pub fn get_staffing_highlight() -> bool {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|s| s.get_item(STAFFING_HIGHLIGHT_KEY).ok().flatten())
+        .map(|v| v != "off")
+        .unwrap_or(true)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn set_staffing_highlight(enabled: bool) {
+    if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) {
+        let _ = storage.set_item(STAFFING_HIGHLIGHT_KEY, if enabled { "on" } else { "off" });
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_staffing_highlight() -> bool {
+    true
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_staffing_highlight(_enabled: bool) {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,6 +128,7 @@ mod tests {
     #[test]
     fn storage_key_is_namespaced() {
         assert!(WORKING_HOURS_LAYOUT_KEY.starts_with("shifty.ui."));
+        assert!(STAFFING_HIGHLIGHT_KEY.starts_with("shifty.ui."));
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -114,4 +144,11 @@ mod tests {
         // No panic and no observable side effect.
         assert_eq!(get_working_hours_layout(), WorkingHoursLayout::Cards);
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn non_wasm_staffing_highlight_defaults_to_on() {
+        set_staffing_highlight(false);
+        assert!(get_staffing_highlight());
+    }
 }
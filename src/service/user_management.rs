@@ -12,7 +12,7 @@ use rest_types::ShiftplanTO;
 use crate::{
     base_types::ImStr,
     error::ShiftyError,
-    loader,
+    loader::{self, cache::Invalidation},
     state::{shiftplan::SalesPerson, ShiftplanAssignment, User},
 };
 
@@ -71,21 +71,25 @@ pub static USER_MANAGEMENT_STORE: GlobalSignal<UserManagementStore> =
 
 pub async fn load_shiftplan_catalog() {
     let config = CONFIG.read().clone();
-    match crate::api::get_all_shiftplans(config).await {
+    match crate::backend::from_config(&config)
+        .get_all_shiftplans()
+        .await
+    {
         Ok(catalog) => {
             USER_MANAGEMENT_STORE.write().shiftplan_catalog = catalog;
         }
         Err(err) => {
-            *ERROR_STORE.write() = ErrorStore {
-                error: Some(err.into()),
-            };
+            *ERROR_STORE.write() = ErrorStore { error: Some(err) };
         }
     }
 }
 
 pub async fn load_shiftplan_assignments(sales_person_id: Uuid) {
     let config = CONFIG.read().clone();
-    match crate::api::get_shiftplan_assignments(config, sales_person_id).await {
+    match crate::backend::from_config(&config)
+        .get_shiftplan_assignments(sales_person_id)
+        .await
+    {
         Ok(assignments) => {
             let mut store = USER_MANAGEMENT_STORE.write();
             if let Some(sp) = store.sales_person.as_mut() {
@@ -96,9 +100,7 @@ pub async fn load_shiftplan_assignments(sales_person_id: Uuid) {
             }
         }
         Err(err) => {
-            *ERROR_STORE.write() = ErrorStore {
-                error: Some(err.into()),
-            };
+            *ERROR_STORE.write() = ErrorStore { error: Some(err) };
         }
     }
 }
@@ -298,12 +300,13 @@ pub async fn save_sales_person() -> Result<(), ShiftyError> {
             if selected_sales_person.shiftplan_assignments
                 != loaded_sales_person.shiftplan_assignments
             {
-                crate::api::set_shiftplan_assignments(
-                    CONFIG.read().clone(),
-                    saved_id,
-                    &selected_sales_person.shiftplan_assignments,
-                )
-                .await?;
+                crate::backend::from_config(&CONFIG.read())
+                    .set_shiftplan_assignments(
+                        saved_id,
+                        &selected_sales_person.shiftplan_assignments,
+                    )
+                    .await?;
+                loader::cache::invalidate(Invalidation::ShiftplanAssignments);
             }
         }
     }
@@ -0,0 +1,44 @@
+//! Settles a version conflict which was stored in [`ERROR_STORE`] by
+//! `error_handler`. The conflict dialog sends the side the user settled on.
+
+use dioxus::prelude::*;
+use futures_util::StreamExt;
+use tracing::info;
+
+use crate::{error::ConflictEntity, loader};
+
+use super::{
+    config::CONFIG,
+    error::{ErrorStore, ERROR_STORE},
+};
+
+pub enum VersionConflictAction {
+    /// Drop the user's changes and show what the server has.
+    Reload,
+    /// Save the overwritten or merged entity.
+    Save(ConflictEntity),
+}
+
+fn reload_page() {
+    *ERROR_STORE.write() = ErrorStore::default();
+    if let Some(window) = web_sys::window() {
+        let _ = window.location().reload();
+    }
+}
+
+pub async fn version_conflict_service(mut rx: UnboundedReceiver<VersionConflictAction>) {
+    while let Some(action) = rx.next().await {
+        match action {
+            VersionConflictAction::Reload => reload_page(),
+            VersionConflictAction::Save(entity) => {
+                info!("Save resolved version conflict");
+                match loader::save_conflict_entity(CONFIG.read().clone(), entity).await {
+                    Ok(()) => reload_page(),
+                    // A renewed conflict replaces the one shown, so the
+                    // dialog offers the newest server state.
+                    Err(err) => *ERROR_STORE.write() = ErrorStore { error: Some(err) },
+                }
+            }
+        }
+    }
+}
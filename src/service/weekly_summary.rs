@@ -1,13 +1,13 @@
 use std::rc::Rc;
 
 use dioxus::prelude::*;
-use futures_util::StreamExt;
 
 use crate::{error::ShiftyError, loader, state::weekly_overview::WeeklySummary};
 
 use super::{
     config::CONFIG,
     error::{ErrorStore, ERROR_STORE},
+    latest::run_latest,
 };
 
 #[derive(Clone, Debug)]
@@ -43,7 +43,7 @@ async fn load_summary_for_week(year: u32, week: u8) -> Result<(), ShiftyError> {
 }
 
 pub async fn weekly_summary_service(mut rx: UnboundedReceiver<WeeklySummaryAction>) {
-    while let Some(action) = rx.next().await {
+    run_latest(&mut rx, |action| async move {
         match match action {
             WeeklySummaryAction::LoadYear(year) => load_weekly_summary_year(year).await,
             WeeklySummaryAction::LoadWeek(year, week) => load_summary_for_week(year, week).await,
@@ -55,5 +55,6 @@ pub async fn weekly_summary_service(mut rx: UnboundedReceiver<WeeklySummaryActio
                 };
             }
         }
-    }
+    })
+    .await;
 }
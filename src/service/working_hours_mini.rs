@@ -1,13 +1,13 @@
 use std::rc::Rc;
 
 use dioxus::prelude::*;
-use futures_util::StreamExt;
 
 use crate::{loader, state::employee_work_details::WorkingHoursMini};
 
 use super::{
     config::CONFIG,
     error::{ErrorStore, ERROR_STORE},
+    latest::run_latest,
 };
 
 pub static WORKING_HOURS_MINI: GlobalSignal<Rc<[WorkingHoursMini]>> = Signal::global(|| [].into());
@@ -17,9 +17,19 @@ pub enum WorkingHoursMiniAction {
 }
 
 pub async fn working_hours_mini_service(mut rx: UnboundedReceiver<WorkingHoursMiniAction>) {
-    while let Some(action) = rx.next().await {
+    run_latest(&mut rx, |action| async move {
         match action {
             WorkingHoursMiniAction::LoadWorkingHoursMini(year, week, fetch_balance) => {
+                // A prefetched week shows up right away, an expired one is
+                // reloaded.
+                if let Some(remembered) =
+                    loader::remembered_working_hours_minified_for_week(year, week, fetch_balance)
+                {
+                    *WORKING_HOURS_MINI.write() = remembered.value.as_ref().clone();
+                    if remembered.fresh {
+                        return;
+                    }
+                }
                 let working_hours = loader::load_working_hours_minified_for_week(
                     CONFIG.read().clone(),
                     year,
@@ -39,5 +49,6 @@ pub async fn working_hours_mini_service(mut rx: UnboundedReceiver<WorkingHoursMi
                 }
             }
         }
-    }
+    })
+    .await;
 }
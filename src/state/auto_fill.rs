@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use uuid::Uuid;
+
+use super::{
+    booking_history::BookingKey, employee_work_details::WorkingHoursMini,
+    sales_person_available::SalesPersonUnavailable, shiftplan::SalesPerson, Shiftplan, Slot,
+    Weekday,
+};
+
+/// A booking the auto-fill solver suggests for an under-staffed slot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AutoFillAssignment {
+    pub key: BookingKey,
+    pub sales_person_name: Rc<str>,
+    pub background_color: Rc<str>,
+}
+
+/// Result of [`propose_auto_fill`]: the suggested bookings plus the slots
+/// which stay under-staffed because nobody suitable was left.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AutoFillProposal {
+    pub assignments: Rc<[AutoFillAssignment]>,
+    /// Slot id and number of people still missing.
+    pub unfilled: Rc<[(Uuid, usize)]>,
+}
+
+impl AutoFillProposal {
+    pub fn is_empty(&self) -> bool {
+        self.assignments.is_empty()
+    }
+
+    pub fn keys(&self) -> Rc<[BookingKey]> {
+        self.assignments
+            .iter()
+            .map(|assignment| assignment.key)
+            .collect()
+    }
+
+    pub fn missing_count(&self) -> usize {
+        self.unfilled.iter().map(|(_, missing)| missing).sum()
+    }
+}
+
+fn overlaps(a: &Slot, b: &Slot) -> bool {
+    a.day_of_week == b.day_of_week && a.from < b.to && b.from < a.to
+}
+
+/// Greedy solver proposing bookings for the empty capacity of `shiftplan`.
+///
+/// Slots are filled in chronological order. For every missing person the
+/// candidate with the most open hours (expected minus actual minus already
+/// proposed) is picked. Candidates must be bookable, active, have open hours
+/// left, not be unavailable on that day and not already work an overlapping
+/// slot. Ties are broken by name so the proposal is stable.
+pub fn propose_auto_fill(
+    shiftplan: &Shiftplan,
+    bookable: &[SalesPerson],
+    working_hours: &[WorkingHoursMini],
+    unavailable: &[SalesPersonUnavailable],
+) -> AutoFillProposal {
+    let mut open_hours: HashMap<Uuid, f32> = working_hours
+        .iter()
+        .map(|hours| {
+            (
+                hours.sales_person_id,
+                hours.expected_hours - hours.actual_hours,
+            )
+        })
+        .collect();
+    // Slots each person works in this plan, including proposed ones.
+    let mut working: HashMap<Uuid, Vec<&Slot>> = HashMap::new();
+    for slot in shiftplan.slots.iter() {
+        for booking in slot.bookings.iter() {
+            working
+                .entry(booking.sales_person_id)
+                .or_default()
+                .push(slot);
+        }
+    }
+    let is_unavailable = |sales_person_id: Uuid, day_of_week: Weekday| {
+        unavailable
+            .iter()
+            .any(|day| day.sales_person_id == sales_person_id && day.day_of_week == day_of_week)
+    };
+
+    let mut slots: Vec<&Slot> = shiftplan.slots.iter().collect();
+    slots.sort_by_key(|slot| (slot.day_of_week.num_from_monday(), slot.from));
+
+    let mut assignments = Vec::new();
+    let mut unfilled = Vec::new();
+    for slot in slots {
+        let mut missing = (slot.min_resources as usize).saturating_sub(slot.bookings.len());
+        let hours = slot.to_hour() - slot.from_hour();
+        while missing > 0 {
+            let candidate = bookable
+                .iter()
+                .filter(|sales_person| !sales_person.inactive)
+                .filter(|sales_person| !is_unavailable(sales_person.id, slot.day_of_week))
+                .filter(|sales_person| {
+                    !working
+                        .get(&sales_person.id)
+                        .is_some_and(|slots| slots.iter().any(|other| overlaps(other, slot)))
+                })
+                .filter_map(|sales_person| {
+                    let open = *open_hours.get(&sales_person.id)?;
+                    (open > 0.0).then_some((sales_person, open))
+                })
+                .max_by(|(a, a_open), (b, b_open)| {
+                    a_open.total_cmp(b_open).then_with(|| b.name.cmp(&a.name))
+                });
+            let Some((sales_person, _)) = candidate else {
+                break;
+            };
+            *open_hours.entry(sales_person.id).or_default() -= hours;
+            working.entry(sales_person.id).or_default().push(slot);
+            assignments.push(AutoFillAssignment {
+                key: BookingKey {
+                    sales_person_id: sales_person.id,
+                    slot_id: slot.id,
+                    week: shiftplan.week,
+                    year: shiftplan.year,
+                },
+                sales_person_name: sales_person.name.clone(),
+                background_color: sales_person.background_color.clone(),
+            });
+            missing -= 1;
+        }
+        if missing > 0 {
+            unfilled.push((slot.id, missing));
+        }
+    }
+    AutoFillProposal {
+        assignments: assignments.into(),
+        unfilled: unfilled.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::shiftplan::Booking;
+
+    fn person(id: u128, name: &str) -> SalesPerson {
+        SalesPerson {
+            id: Uuid::from_u128(id),
+            name: name.into(),
+            background_color: "#dbe0ff".into(),
+            is_paid: true,
+            inactive: false,
+            version: Uuid::nil(),
+        }
+    }
+
+    fn hours(id: u128, expected: f32, actual: f32) -> WorkingHoursMini {
+        WorkingHoursMini {
+            sales_person_id: Uuid::from_u128(id),
+            expected_hours: expected,
+            actual_hours: actual,
+            ..Default::default()
+        }
+    }
+
+    fn slot(id: u128, day_of_week: Weekday, from: u8, to: u8, min: u8, booked: &[u128]) -> Slot {
+        let slot_id = Uuid::from_u128(id);
+        Slot {
+            id: slot_id,
+            day_of_week,
+            from: time::Time::from_hms(from, 0, 0).unwrap(),
+            to: time::Time::from_hms(to, 0, 0).unwrap(),
+            bookings: booked
+                .iter()
+                .map(|sales_person| Booking {
+                    id: Uuid::new_v4(),
+                    sales_person_id: Uuid::from_u128(*sales_person),
+                    slot_id,
+                    week: 23,
+                    year: 2025,
+                    label: "".into(),
+                    background_color: "".into(),
+                    self_added: false,
+                    created: None,
+                    created_by: None,
+                })
+                .collect(),
+            min_resources: min,
+        }
+    }
+
+    fn plan(slots: Vec<Slot>) -> Shiftplan {
+        Shiftplan {
+            week: 23,
+            year: 2025,
+            slots: slots.into(),
+        }
+    }
+
+    #[test]
+    fn picks_the_person_with_most_open_hours() {
+        let shiftplan = plan(vec![slot(10, Weekday::Monday, 9, 12, 1, &[])]);
+        let proposal = propose_auto_fill(
+            &shiftplan,
+            &[person(1, "Anna"), person(2, "Ben")],
+            &[hours(1, 10.0, 8.0), hours(2, 20.0, 5.0)],
+            &[],
+        );
+        assert_eq!(proposal.assignments.len(), 1);
+        assert_eq!(
+            proposal.assignments[0].key.sales_person_id,
+            Uuid::from_u128(2)
+        );
+        assert!(proposal.unfilled.is_empty());
+    }
+
+    #[test]
+    fn proposed_hours_count_against_the_budget() {
+        let shiftplan = plan(vec![
+            slot(10, Weekday::Monday, 9, 12, 1, &[]),
+            slot(11, Weekday::Tuesday, 9, 12, 1, &[]),
+        ]);
+        let proposal = propose_auto_fill(
+            &shiftplan,
+            &[person(1, "Anna"), person(2, "Ben")],
+            &[hours(1, 10.0, 5.0), hours(2, 10.0, 6.0)],
+            &[],
+        );
+        let people: Vec<u128> = proposal
+            .assignments
+            .iter()
+            .map(|assignment| assignment.key.sales_person_id.as_u128())
+            .collect();
+        assert_eq!(people, vec![1, 2]);
+    }
+
+    #[test]
+    fn skips_unavailable_overlapping_and_already_booked_people() {
+        let shiftplan = plan(vec![
+            slot(10, Weekday::Monday, 9, 12, 2, &[1]),
+            slot(11, Weekday::Monday, 11, 14, 1, &[2]),
+        ]);
+        let unavailable = [SalesPersonUnavailable {
+            id: Uuid::nil(),
+            sales_person_id: Uuid::from_u128(3),
+            year: 2025,
+            week: 23,
+            day_of_week: Weekday::Monday,
+            version: Uuid::nil(),
+        }];
+        let proposal = propose_auto_fill(
+            &shiftplan,
+            &[person(1, "Anna"), person(2, "Ben"), person(3, "Clara")],
+            &[
+                hours(1, 40.0, 0.0),
+                hours(2, 40.0, 0.0),
+                hours(3, 40.0, 0.0),
+            ],
+            &unavailable,
+        );
+        assert!(proposal.is_empty());
+        assert_eq!(proposal.missing_count(), 1);
+    }
+
+    #[test]
+    fn people_without_open_hours_are_not_proposed() {
+        let shiftplan = plan(vec![slot(10, Weekday::Monday, 9, 12, 1, &[])]);
+        let proposal = propose_auto_fill(
+            &shiftplan,
+            &[person(1, "Anna"), person(2, "Ben")],
+            &[hours(1, 10.0, 10.0)],
+            &[],
+        );
+        assert!(proposal.is_empty());
+        assert_eq!(proposal.unfilled.as_ref(), &[(Uuid::from_u128(10), 1)]);
+    }
+}
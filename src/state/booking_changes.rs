@@ -0,0 +1,352 @@
+use std::rc::Rc;
+
+use time::PrimitiveDateTime;
+
+use crate::i18n::{I18n, Key, Locale};
+
+use super::{booking_log::BookingLog, week::Week, Weekday};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BookingChangeKind {
+    Added,
+    Removed,
+}
+
+/// A booking added or removed after the report's cut-off.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookingChange {
+    pub kind: BookingChangeKind,
+    pub time_from: time::Time,
+    pub time_to: time::Time,
+    pub at: PrimitiveDateTime,
+    pub by: Option<Rc<str>>,
+}
+
+/// Changes of one person, ordered by weekday and start time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PersonBookingChanges {
+    pub sales_person_name: Rc<str>,
+    pub days: Vec<(Weekday, Vec<BookingChange>)>,
+}
+
+/// Bookings of one week added or removed after `since`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookingChangeReport {
+    pub week: Week,
+    pub since: PrimitiveDateTime,
+    /// Ordered by name.
+    pub persons: Vec<PersonBookingChanges>,
+}
+
+/// Parses the value of a `datetime-local` input (`YYYY-MM-DDTHH:MM`).
+pub fn parse_datetime_local(value: &str) -> Option<PrimitiveDateTime> {
+    let format = time::macros::format_description!("[year]-[month]-[day]T[hour]:[minute]");
+    PrimitiveDateTime::parse(value, &format).ok()
+}
+
+pub fn format_datetime_local(date_time: PrimitiveDateTime) -> String {
+    let format = time::macros::format_description!("[year]-[month]-[day]T[hour]:[minute]");
+    date_time.format(&format).unwrap_or_default()
+}
+
+impl BookingChangeReport {
+    /// Collects the changes after `since` from the booking log of `week`.
+    ///
+    /// A booking removed and re-added for the same person and slot after the
+    /// cut-off cancels out, as does one added and removed again.
+    pub fn build(log: &[BookingLog], week: Week, since: PrimitiveDateTime) -> Self {
+        let mut changes: Vec<(Rc<str>, Weekday, BookingChange)> = Vec::new();
+        for entry in log.iter() {
+            let added = entry.created > since;
+            let removed = entry.deleted.filter(|deleted| *deleted > since);
+            let change = |kind, at, by: &Option<Rc<str>>| BookingChange {
+                kind,
+                time_from: entry.time_from,
+                time_to: entry.time_to,
+                at,
+                by: by.clone(),
+            };
+            match (added, removed) {
+                (true, Some(_)) => {}
+                (true, None) => changes.push((
+                    entry.sales_person_name.clone(),
+                    entry.day_of_week,
+                    change(BookingChangeKind::Added, entry.created, &entry.created_by),
+                )),
+                (false, Some(deleted)) => changes.push((
+                    entry.sales_person_name.clone(),
+                    entry.day_of_week,
+                    change(BookingChangeKind::Removed, deleted, &entry.deleted_by),
+                )),
+                (false, None) => {}
+            }
+        }
+
+        // Drop remove/add pairs of the same person in the same slot.
+        let mut index = 0;
+        while index < changes.len() {
+            let (name, day, change) = &changes[index];
+            let counterpart = changes.iter().position(|(other_name, other_day, other)| {
+                other_name == name
+                    && other_day == day
+                    && other.time_from == change.time_from
+                    && other.time_to == change.time_to
+                    && other.kind != change.kind
+            });
+            match counterpart {
+                Some(other) => {
+                    changes.remove(index.max(other));
+                    changes.remove(index.min(other));
+                }
+                None => index += 1,
+            }
+        }
+
+        changes.sort_by(|(name, day, change), (other_name, other_day, other)| {
+            (name, day.num_from_monday(), change.time_from, change.at).cmp(&(
+                other_name,
+                other_day.num_from_monday(),
+                other.time_from,
+                other.at,
+            ))
+        });
+        let mut persons: Vec<PersonBookingChanges> = Vec::new();
+        for (name, day, change) in changes {
+            if persons
+                .last()
+                .is_none_or(|person| person.sales_person_name != name)
+            {
+                persons.push(PersonBookingChanges {
+                    sales_person_name: name,
+                    days: Vec::new(),
+                });
+            }
+            let Some(person) = persons.last_mut() else {
+                continue;
+            };
+            match person.days.last_mut() {
+                Some((last_day, day_changes)) if *last_day == day => day_changes.push(change),
+                _ => person.days.push((day, vec![change])),
+            }
+        }
+        Self {
+            week,
+            since,
+            persons,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.persons.is_empty()
+    }
+
+    pub fn count(&self, kind: BookingChangeKind) -> usize {
+        self.persons
+            .iter()
+            .flat_map(|person| person.days.iter())
+            .flat_map(|(_, changes)| changes.iter())
+            .filter(|change| change.kind == kind)
+            .count()
+    }
+
+    /// Plain text version to send to the team.
+    pub fn to_text(&self, i18n: &I18n<Key, Locale>) -> String {
+        let mut text = i18n
+            .t_m(
+                Key::BookingChangesTextHeader,
+                [
+                    ("week", self.week.week.to_string().as_str()),
+                    ("year", self.week.year.to_string().as_str()),
+                    ("date", i18n.format_date(&self.since.date()).as_ref()),
+                    ("time", format_time(self.since.time()).as_str()),
+                ]
+                .into(),
+            )
+            .to_string();
+        if self.is_empty() {
+            text.push_str("\n\n");
+            text.push_str(&i18n.t(Key::BookingChangesNone));
+            return text;
+        }
+        for person in self.persons.iter() {
+            text.push_str("\n\n");
+            text.push_str(&person.sales_person_name);
+            for (day, changes) in person.days.iter() {
+                let entries = changes
+                    .iter()
+                    .map(|change| {
+                        format!(
+                            "{} {}–{}",
+                            change_sign(change.kind),
+                            format_time(change.time_from),
+                            format_time(change.time_to)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                text.push_str(&format!("\n  {}: {}", day.i18n_string(i18n), entries));
+            }
+        }
+        text
+    }
+}
+
+pub fn change_sign(kind: BookingChangeKind) -> &'static str {
+    match kind {
+        BookingChangeKind::Added => "+",
+        BookingChangeKind::Removed => "−",
+    }
+}
+
+fn format_time(time: time::Time) -> String {
+    format!("{:02}:{:02}", time.hour(), time.minute())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{datetime, time};
+
+    const SINCE: PrimitiveDateTime = datetime!(2025-06-02 12:00);
+
+    fn entry(
+        name: &str,
+        day_of_week: Weekday,
+        from: time::Time,
+        created: PrimitiveDateTime,
+        deleted: Option<PrimitiveDateTime>,
+    ) -> BookingLog {
+        BookingLog {
+            year: 2025,
+            calendar_week: 23,
+            day_of_week,
+            sales_person_name: name.into(),
+            time_from: from,
+            time_to: from + time::Duration::hours(3),
+            created,
+            deleted,
+            created_by: Some("planner".into()),
+            deleted_by: deleted.map(|_| "planner".into()),
+            slot_id: None,
+            sales_person_id: None,
+        }
+    }
+
+    fn week() -> Week {
+        Week {
+            year: 2025,
+            week: 23,
+        }
+    }
+
+    #[test]
+    fn changes_after_the_cut_off_are_grouped_by_person_and_day() {
+        let log = [
+            entry(
+                "Ben",
+                Weekday::Tuesday,
+                time!(9:00),
+                datetime!(2025-06-03 8:00),
+                None,
+            ),
+            entry(
+                "Anna",
+                Weekday::Monday,
+                time!(12:00),
+                datetime!(2025-05-30 10:00),
+                Some(datetime!(2025-06-02 14:00)),
+            ),
+            entry(
+                "Anna",
+                Weekday::Monday,
+                time!(9:00),
+                datetime!(2025-06-02 13:00),
+                None,
+            ),
+            entry(
+                "Clara",
+                Weekday::Friday,
+                time!(9:00),
+                datetime!(2025-05-30 10:00),
+                None,
+            ),
+        ];
+        let report = BookingChangeReport::build(&log, week(), SINCE);
+        assert_eq!(report.persons.len(), 2);
+        assert_eq!(report.persons[0].sales_person_name.as_ref(), "Anna");
+        let (day, changes) = &report.persons[0].days[0];
+        assert_eq!(*day, Weekday::Monday);
+        assert_eq!(changes[0].kind, BookingChangeKind::Added);
+        assert_eq!(changes[1].kind, BookingChangeKind::Removed);
+        assert_eq!(report.count(BookingChangeKind::Added), 2);
+        assert_eq!(report.count(BookingChangeKind::Removed), 1);
+    }
+
+    #[test]
+    fn changes_which_cancel_out_are_dropped() {
+        let log = [
+            // Added and removed again after the cut-off.
+            entry(
+                "Anna",
+                Weekday::Monday,
+                time!(9:00),
+                datetime!(2025-06-02 13:00),
+                Some(datetime!(2025-06-02 14:00)),
+            ),
+            // Removed and re-added in the same slot.
+            entry(
+                "Ben",
+                Weekday::Monday,
+                time!(9:00),
+                datetime!(2025-05-30 13:00),
+                Some(datetime!(2025-06-02 14:00)),
+            ),
+            entry(
+                "Ben",
+                Weekday::Monday,
+                time!(9:00),
+                datetime!(2025-06-02 15:00),
+                None,
+            ),
+        ];
+        assert!(BookingChangeReport::build(&log, week(), SINCE).is_empty());
+    }
+
+    #[test]
+    fn text_lists_persons_with_their_days() {
+        let log = [
+            entry(
+                "Anna",
+                Weekday::Monday,
+                time!(9:00),
+                datetime!(2025-06-02 13:00),
+                None,
+            ),
+            entry(
+                "Anna",
+                Weekday::Monday,
+                time!(12:00),
+                datetime!(2025-05-30 10:00),
+                Some(datetime!(2025-06-02 14:00)),
+            ),
+        ];
+        let i18n = crate::i18n::generate(Locale::En);
+        let text = BookingChangeReport::build(&log, week(), SINCE).to_text(&i18n);
+        assert!(
+            text.starts_with("Changes to CW 23/2025 since 2025-06-02 12:00:"),
+            "{text}"
+        );
+        assert!(
+            text.contains("\n\nAnna\n  Monday: + 09:00–12:00, − 12:00–15:00"),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn datetime_local_round_trip() {
+        let parsed = parse_datetime_local("2025-06-02T12:00").unwrap();
+        assert_eq!(parsed, SINCE);
+        assert_eq!(format_datetime_local(parsed), "2025-06-02T12:00");
+        assert_eq!(parse_datetime_local("2025-06-02"), None);
+    }
+}
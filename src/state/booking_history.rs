@@ -0,0 +1,207 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::base_types::ImStr;
+
+use super::shiftplan::Booking;
+
+/// Maximum number of edits kept on the undo stack.
+pub const BOOKING_HISTORY_LIMIT: usize = 50;
+
+/// Identifies a booking independently of its id, which changes whenever a
+/// booking is removed and added again.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookingKey {
+    pub sales_person_id: Uuid,
+    pub slot_id: Uuid,
+    pub week: u8,
+    pub year: u32,
+}
+
+impl From<&Booking> for BookingKey {
+    fn from(booking: &Booking) -> Self {
+        Self {
+            sales_person_id: booking.sales_person_id,
+            slot_id: booking.slot_id,
+            week: booking.week,
+            year: booking.year,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BookingEditKind {
+    Add,
+    Remove,
+    Move,
+    CopyWeek,
+    AutoFill,
+}
+
+/// One reversible edit of the shift plan: the bookings it added and the
+/// bookings it removed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookingEdit {
+    pub kind: BookingEditKind,
+    pub summary: ImStr,
+    pub added: Rc<[BookingKey]>,
+    pub removed: Rc<[BookingKey]>,
+}
+
+impl BookingEdit {
+    /// The edit which reverts this one.
+    pub fn inverse(&self) -> Self {
+        Self {
+            kind: self.kind,
+            summary: self.summary.clone(),
+            added: self.removed.clone(),
+            removed: self.added.clone(),
+        }
+    }
+}
+
+/// Undo and redo stacks of the shift plan page.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BookingHistory {
+    undo: Vec<BookingEdit>,
+    redo: Vec<BookingEdit>,
+}
+
+impl BookingHistory {
+    /// Records a new edit. Any redo entries are dropped.
+    pub fn record(&mut self, edit: BookingEdit) {
+        if edit.added.is_empty() && edit.removed.is_empty() {
+            return;
+        }
+        self.redo.clear();
+        self.undo.push(edit);
+        if self.undo.len() > BOOKING_HISTORY_LIMIT {
+            self.undo.remove(0);
+        }
+    }
+
+    /// The edit an undo would revert.
+    pub fn next_undo(&self) -> Option<&BookingEdit> {
+        self.undo.last()
+    }
+
+    /// The edit a redo would apply again.
+    pub fn next_redo(&self) -> Option<&BookingEdit> {
+        self.redo.last()
+    }
+
+    /// Moves the latest edit to the redo stack once it has been reverted.
+    pub fn undone(&mut self) {
+        if let Some(edit) = self.undo.pop() {
+            self.redo.push(edit);
+        }
+    }
+
+    /// Moves the latest redo entry back once it has been applied again.
+    pub fn redone(&mut self) {
+        if let Some(edit) = self.redo.pop() {
+            self.undo.push(edit);
+        }
+    }
+
+    /// Undo entries, most recent first.
+    pub fn undo_entries(&self) -> impl Iterator<Item = &BookingEdit> {
+        self.undo.iter().rev()
+    }
+
+    /// Redo entries, next one first.
+    pub fn redo_entries(&self) -> impl Iterator<Item = &BookingEdit> {
+        self.redo.iter().rev()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.undo.is_empty() && self.redo.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(n: u128) -> BookingKey {
+        BookingKey {
+            sales_person_id: Uuid::from_u128(n),
+            slot_id: Uuid::from_u128(100 + n),
+            week: 23,
+            year: 2025,
+        }
+    }
+
+    fn add(n: u128) -> BookingEdit {
+        BookingEdit {
+            kind: BookingEditKind::Add,
+            summary: format!("add {n}").into(),
+            added: [key(n)].into(),
+            removed: [].into(),
+        }
+    }
+
+    #[test]
+    fn inverse_swaps_added_and_removed() {
+        let edit = BookingEdit {
+            kind: BookingEditKind::Move,
+            summary: "move".into(),
+            added: [key(1)].into(),
+            removed: [key(2)].into(),
+        };
+        let inverse = edit.inverse();
+        assert_eq!(inverse.added.as_ref(), &[key(2)]);
+        assert_eq!(inverse.removed.as_ref(), &[key(1)]);
+        assert_eq!(inverse.inverse(), edit);
+    }
+
+    #[test]
+    fn undo_then_redo_round_trips() {
+        let mut history = BookingHistory::default();
+        history.record(add(1));
+        history.record(add(2));
+        assert_eq!(history.next_undo(), Some(&add(2)));
+
+        history.undone();
+        assert_eq!(history.next_undo(), Some(&add(1)));
+        assert_eq!(history.next_redo(), Some(&add(2)));
+
+        history.redone();
+        assert_eq!(history.next_undo(), Some(&add(2)));
+        assert!(history.next_redo().is_none());
+    }
+
+    #[test]
+    fn recording_clears_redo() {
+        let mut history = BookingHistory::default();
+        history.record(add(1));
+        history.undone();
+        history.record(add(2));
+        assert!(history.next_redo().is_none());
+        assert_eq!(history.undo_entries().count(), 1);
+    }
+
+    #[test]
+    fn empty_edits_are_not_recorded() {
+        let mut history = BookingHistory::default();
+        history.record(BookingEdit {
+            kind: BookingEditKind::CopyWeek,
+            summary: "nothing copied".into(),
+            added: [].into(),
+            removed: [].into(),
+        });
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn undo_stack_is_capped() {
+        let mut history = BookingHistory::default();
+        for n in 0..(BOOKING_HISTORY_LIMIT as u128 + 5) {
+            history.record(add(n));
+        }
+        assert_eq!(history.undo_entries().count(), BOOKING_HISTORY_LIMIT);
+        assert_eq!(history.undo_entries().last(), Some(&add(5)));
+    }
+}
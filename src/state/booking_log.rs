@@ -2,8 +2,9 @@ use std::rc::Rc;
 
 use rest_types::BookingLogTO;
 use time::PrimitiveDateTime;
+use uuid::Uuid;
 
-use super::shiftplan::Weekday;
+use super::{booking_changes::BookingChangeKind, shiftplan::Weekday, week::Week, Slot};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct BookingLog {
@@ -17,6 +18,9 @@ pub struct BookingLog {
     pub deleted: Option<PrimitiveDateTime>,
     pub created_by: Option<Rc<str>>,
     pub deleted_by: Option<Rc<str>>,
+    /// Only known if the backend reports it, see [`BookingLogTO::slot_id`].
+    pub slot_id: Option<Uuid>,
+    pub sales_person_id: Option<Uuid>,
 }
 
 impl From<&BookingLogTO> for BookingLog {
@@ -32,6 +36,134 @@ impl From<&BookingLogTO> for BookingLog {
             deleted: log.deleted,
             created_by: log.created_by.as_ref().map(|s| s.to_string().into()),
             deleted_by: log.deleted_by.as_ref().map(|s| s.to_string().into()),
+            slot_id: log.slot_id,
+            sales_person_id: log.sales_person_id,
         }
     }
 }
+
+impl BookingLog {
+    /// The log only knows weekday and times, so this is how it finds its slot.
+    pub fn is_for_slot(&self, slot: &Slot) -> bool {
+        self.day_of_week == slot.day_of_week
+            && self.time_from == slot.from
+            && self.time_to == slot.to
+    }
+}
+
+/// One booking or removal from the log.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BookingLogEvent {
+    pub week: Week,
+    pub day_of_week: Weekday,
+    pub time_from: time::Time,
+    pub time_to: time::Time,
+    pub sales_person_name: Rc<str>,
+    pub kind: BookingChangeKind,
+    pub at: PrimitiveDateTime,
+    pub by: Option<Rc<str>>,
+}
+
+/// Every booking and removal in the log, oldest first.
+pub fn events<'a>(log: impl IntoIterator<Item = &'a BookingLog>) -> Vec<BookingLogEvent> {
+    let mut events: Vec<BookingLogEvent> = log
+        .into_iter()
+        .flat_map(|entry| {
+            let event = |kind, at, by: &Option<Rc<str>>| BookingLogEvent {
+                week: Week {
+                    year: entry.year,
+                    week: entry.calendar_week,
+                },
+                day_of_week: entry.day_of_week,
+                time_from: entry.time_from,
+                time_to: entry.time_to,
+                sales_person_name: entry.sales_person_name.clone(),
+                kind,
+                at,
+                by: by.clone(),
+            };
+            let added = event(BookingChangeKind::Added, entry.created, &entry.created_by);
+            let removed = entry
+                .deleted
+                .map(|deleted| event(BookingChangeKind::Removed, deleted, &entry.deleted_by));
+            std::iter::once(added).chain(removed)
+        })
+        .collect();
+    events.sort_by_key(|event| event.at);
+    events
+}
+
+/// Every booking and removal of `slot` in the log, oldest first.
+pub fn slot_history(log: &[BookingLog], slot: &Slot) -> Vec<BookingLogEvent> {
+    events(log.iter().filter(|entry| entry.is_for_slot(slot)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{datetime, time};
+    use uuid::Uuid;
+
+    fn entry(
+        name: &str,
+        from: time::Time,
+        created: PrimitiveDateTime,
+        deleted: Option<PrimitiveDateTime>,
+    ) -> BookingLog {
+        BookingLog {
+            year: 2025,
+            calendar_week: 23,
+            day_of_week: Weekday::Monday,
+            sales_person_name: name.into(),
+            time_from: from,
+            time_to: from + time::Duration::hours(3),
+            created,
+            deleted,
+            created_by: Some("planner".into()),
+            deleted_by: deleted.map(|_| "lead".into()),
+            slot_id: None,
+            sales_person_id: None,
+        }
+    }
+
+    #[test]
+    fn slot_history_lists_bookings_and_removals_of_the_slot() {
+        let slot = Slot {
+            id: Uuid::from_u128(1),
+            day_of_week: Weekday::Monday,
+            from: time!(9:00),
+            to: time!(12:00),
+            bookings: [].into(),
+            min_resources: 2,
+        };
+        let log = [
+            entry(
+                "Anna",
+                time!(9:00),
+                datetime!(2025-06-01 10:00),
+                Some(datetime!(2025-06-03 10:00)),
+            ),
+            entry("Ben", time!(9:00), datetime!(2025-06-02 10:00), None),
+            entry("Clara", time!(12:00), datetime!(2025-06-01 11:00), None),
+        ];
+        let history = slot_history(&log, &slot);
+        let summary: Vec<_> = history
+            .iter()
+            .map(|entry| {
+                (
+                    entry.kind,
+                    entry.sales_person_name.as_ref(),
+                    entry.by.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (BookingChangeKind::Added, "Anna", Some("planner")),
+                (BookingChangeKind::Added, "Ben", Some("planner")),
+                (BookingChangeKind::Removed, "Anna", Some("lead")),
+            ]
+        );
+    }
+}
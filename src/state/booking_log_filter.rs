@@ -0,0 +1,327 @@
+use std::{cmp::Ordering, rc::Rc};
+
+use crate::i18n::{I18n, Key, Locale};
+
+use super::{
+    booking_changes::{format_datetime_local, BookingChangeKind},
+    booking_log::BookingLogEvent,
+    week::Week,
+    Weekday,
+};
+
+/// Weekday and times of a slot valid in `week`. The booking log has no
+/// shiftplan, so these are what ties its entries to one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlotTimes {
+    pub week: Week,
+    pub day_of_week: Weekday,
+    pub from: time::Time,
+    pub to: time::Time,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BookingLogFilter {
+    /// Part of the person's name, case-insensitive.
+    pub person: String,
+    pub action: Option<BookingChangeKind>,
+    pub actor: Option<Rc<str>>,
+    /// Slots of the chosen shiftplan, `None` for all shiftplans.
+    pub slots: Option<Rc<[SlotTimes]>>,
+}
+
+impl BookingLogFilter {
+    pub fn matches(&self, event: &BookingLogEvent) -> bool {
+        let person = self.person.trim().to_lowercase();
+        (person.is_empty() || event.sales_person_name.to_lowercase().contains(&person))
+            && self.action.is_none_or(|action| action == event.kind)
+            && self
+                .actor
+                .as_ref()
+                .is_none_or(|actor| event.by.as_ref() == Some(actor))
+            && self.slots.as_ref().is_none_or(|slots| {
+                slots.iter().any(|slot| {
+                    slot.week == event.week
+                        && slot.day_of_week == event.day_of_week
+                        && slot.from == event.time_from
+                        && slot.to == event.time_to
+                })
+            })
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BookingLogColumn {
+    Week,
+    Day,
+    Time,
+    Name,
+    Action,
+    At,
+    By,
+}
+
+fn compare(a: &BookingLogEvent, b: &BookingLogEvent, column: BookingLogColumn) -> Ordering {
+    let slot = |event: &BookingLogEvent| {
+        (
+            event.week,
+            event.day_of_week.num_from_monday(),
+            event.time_from,
+        )
+    };
+    match column {
+        BookingLogColumn::Week => slot(a).cmp(&slot(b)),
+        BookingLogColumn::Day => (a.day_of_week.num_from_monday(), a.time_from)
+            .cmp(&(b.day_of_week.num_from_monday(), b.time_from)),
+        BookingLogColumn::Time => (a.time_from, a.time_to).cmp(&(b.time_from, b.time_to)),
+        BookingLogColumn::Name => a.sales_person_name.cmp(&b.sales_person_name),
+        BookingLogColumn::Action => {
+            (a.kind == BookingChangeKind::Removed).cmp(&(b.kind == BookingChangeKind::Removed))
+        }
+        BookingLogColumn::At => Ordering::Equal,
+        BookingLogColumn::By => a.by.cmp(&b.by),
+    }
+    .then(a.at.cmp(&b.at))
+}
+
+/// Sorts by `column`, ties broken by the time of the change.
+pub fn sort_events(events: &mut [BookingLogEvent], column: BookingLogColumn, ascending: bool) {
+    events.sort_by(|a, b| {
+        let ordering = compare(a, b, column);
+        if ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    });
+}
+
+/// Everyone who booked or removed one of `events`, sorted.
+pub fn actors(events: &[BookingLogEvent]) -> Vec<Rc<str>> {
+    let mut actors: Vec<Rc<str>> = events.iter().filter_map(|event| event.by.clone()).collect();
+    actors.sort();
+    actors.dedup();
+    actors
+}
+
+pub fn action_label(i18n: &I18n<Key, Locale>, kind: BookingChangeKind) -> Rc<str> {
+    match kind {
+        BookingChangeKind::Added => i18n.t(Key::BookingLogActionCreated),
+        BookingChangeKind::Removed => i18n.t(Key::BookingLogActionDeleted),
+    }
+}
+
+/// Quotes `value` for the export. Values a spreadsheet would run as a
+/// formula get a leading `'` so they stay plain text.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn format_time(time: time::Time) -> String {
+    format!("{:02}:{:02}", time.hour(), time.minute())
+}
+
+/// The events as comma separated values with a header row.
+pub fn to_csv(events: &[BookingLogEvent], i18n: &I18n<Key, Locale>) -> String {
+    let header = [
+        i18n.t(Key::BookingLogWeek),
+        i18n.t(Key::BookingLogDay),
+        i18n.t(Key::BookingLogTime),
+        i18n.t(Key::BookingLogName),
+        i18n.t(Key::BookingLogAction),
+        i18n.t(Key::BookingLogAt),
+        i18n.t(Key::BookingLogBy),
+    ];
+    let mut lines = vec![header
+        .iter()
+        .map(|title| csv_field(title))
+        .collect::<Vec<_>>()
+        .join(",")];
+    for event in events.iter() {
+        let fields = [
+            event.week.to_iso_string(),
+            event.day_of_week.i18n_string(i18n).to_string(),
+            format!(
+                "{}-{}",
+                format_time(event.time_from),
+                format_time(event.time_to)
+            ),
+            event.sales_person_name.to_string(),
+            action_label(i18n, event.kind).to_string(),
+            format_datetime_local(event.at).replace('T', " "),
+            event.by.as_deref().unwrap_or_default().to_string(),
+        ];
+        lines.push(
+            fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+    }
+    lines.join("\r\n")
+}
+
+/// A `data:` URL for downloading `csv` through a link.
+pub fn csv_data_url(csv: &str) -> String {
+    let mut url = String::from("data:text/csv;charset=utf-8,");
+    for byte in csv.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            url.push(byte as char);
+        } else {
+            url.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{datetime, time};
+
+    fn event(
+        name: &str,
+        kind: BookingChangeKind,
+        at: time::PrimitiveDateTime,
+        by: &str,
+    ) -> BookingLogEvent {
+        BookingLogEvent {
+            week: Week {
+                year: 2025,
+                week: 23,
+            },
+            day_of_week: Weekday::Monday,
+            time_from: time!(9:00),
+            time_to: time!(12:00),
+            sales_person_name: name.into(),
+            kind,
+            at,
+            by: Some(by.into()),
+        }
+    }
+
+    fn events() -> Vec<BookingLogEvent> {
+        vec![
+            event(
+                "Anna",
+                BookingChangeKind::Added,
+                datetime!(2025-06-01 10:00),
+                "planner",
+            ),
+            event(
+                "Ben",
+                BookingChangeKind::Removed,
+                datetime!(2025-06-02 10:00),
+                "lead",
+            ),
+            event(
+                "Annika",
+                BookingChangeKind::Removed,
+                datetime!(2025-06-03 10:00),
+                "planner",
+            ),
+        ]
+    }
+
+    #[test]
+    fn filter_combines_person_action_actor_and_slots() {
+        let names = |filter: &BookingLogFilter| {
+            events()
+                .into_iter()
+                .filter(|event| filter.matches(event))
+                .map(|event| event.sales_person_name.to_string())
+                .collect::<Vec<_>>()
+        };
+        let mut filter = BookingLogFilter {
+            person: "ann".into(),
+            ..Default::default()
+        };
+        assert_eq!(names(&filter), vec!["Anna", "Annika"]);
+        filter.action = Some(BookingChangeKind::Removed);
+        assert_eq!(names(&filter), vec!["Annika"]);
+        filter.actor = Some("lead".into());
+        assert!(names(&filter).is_empty());
+
+        let slots = |from| -> Rc<[SlotTimes]> {
+            Rc::from([SlotTimes {
+                week: Week {
+                    year: 2025,
+                    week: 23,
+                },
+                day_of_week: Weekday::Monday,
+                from,
+                to: time!(12:00),
+            }])
+        };
+        let mut filter = BookingLogFilter {
+            slots: Some(slots(time!(9:00))),
+            ..Default::default()
+        };
+        assert_eq!(names(&filter).len(), 3);
+        filter.slots = Some(slots(time!(8:00)));
+        assert!(names(&filter).is_empty());
+    }
+
+    #[test]
+    fn sorting_by_column_breaks_ties_by_time() {
+        let mut sorted = events();
+        sort_events(&mut sorted, BookingLogColumn::By, true);
+        let names: Vec<_> = sorted
+            .iter()
+            .map(|event| event.sales_person_name.as_ref())
+            .collect();
+        assert_eq!(names, vec!["Ben", "Anna", "Annika"]);
+        sort_events(&mut sorted, BookingLogColumn::Name, false);
+        let names: Vec<_> = sorted
+            .iter()
+            .map(|event| event.sales_person_name.as_ref())
+            .collect();
+        assert_eq!(names, vec!["Ben", "Annika", "Anna"]);
+        assert_eq!(actors(&sorted), vec![Rc::from("lead"), Rc::from("planner")]);
+    }
+
+    #[test]
+    fn csv_quotes_fields_and_encodes_for_download() {
+        let i18n = crate::i18n::generate(Locale::En);
+        let mut events = events();
+        events.truncate(1);
+        events[0].sales_person_name = "Doe, \"Anna\"".into();
+        let csv = to_csv(&events, &i18n);
+        assert_eq!(
+            csv,
+            "Week,Day,Time,Name,Action,When,By\r\n\
+             2025-W23,Monday,09:00-12:00,\"Doe, \"\"Anna\"\"\",Booked,2025-06-01 10:00,planner"
+        );
+        assert_eq!(
+            csv_data_url("a b,ä"),
+            "data:text/csv;charset=utf-8,a%20b%2C%C3%A4"
+        );
+    }
+
+    #[test]
+    fn csv_keeps_formulas_as_text() {
+        let i18n = crate::i18n::generate(Locale::En);
+        let mut events = events();
+        events.truncate(1);
+        events[0].sales_person_name = "=HYPERLINK(\"x\",\"y\")".into();
+        events[0].by = Some("@planner".into());
+        let csv = to_csv(&events, &i18n);
+        assert_eq!(
+            csv,
+            "Week,Day,Time,Name,Action,When,By\r\n\
+             2025-W23,Monday,09:00-12:00,\"'=HYPERLINK(\"\"x\"\",\"\"y\"\")\",Booked,2025-06-01 10:00,'@planner"
+        );
+        assert_eq!(csv_field("+1"), "'+1");
+        assert_eq!(csv_field("-x"), "'-x");
+        assert_eq!(csv_field("Anna"), "Anna");
+    }
+}
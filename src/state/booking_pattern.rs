@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{booking_history::BookingKey, week::Week};
+
+/// A recurring assignment: one person works one slot every `interval_weeks`
+/// weeks, starting at `valid_from` and ending with `valid_until`.
+///
+/// Patterns are only a planning aid. They turn into regular bookings when
+/// they are materialized for a horizon.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookingPattern {
+    pub id: Uuid,
+    pub sales_person_id: Uuid,
+    pub shiftplan_id: Uuid,
+    pub slot_id: Uuid,
+    pub interval_weeks: u8,
+    pub valid_from: Week,
+    pub valid_until: Week,
+}
+
+impl BookingPattern {
+    /// Whether the pattern has an occurrence in `week`.
+    pub fn occurs_in(&self, week: Week) -> bool {
+        if week < self.valid_from || week > self.valid_until {
+            return false;
+        }
+        let (Ok(start), Ok(monday)) = (self.valid_from.monday(), week.monday()) else {
+            return false;
+        };
+        (monday - start).whole_weeks() % i64::from(self.interval_weeks.max(1)) == 0
+    }
+
+    /// All weeks of the pattern between `from` and `until`, both inclusive.
+    pub fn occurrences(&self, from: Week, until: Week) -> Vec<Week> {
+        let from = from.max(self.valid_from);
+        let until = until.min(self.valid_until);
+        from.through(until)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|week| self.occurs_in(*week))
+            .collect()
+    }
+
+    /// Whether `key` is a booking this pattern produces.
+    pub fn covers(&self, key: &BookingKey) -> bool {
+        key.sales_person_id == self.sales_person_id
+            && key.slot_id == self.slot_id
+            && self.occurs_in(Week {
+                year: key.year,
+                week: key.week,
+            })
+    }
+}
+
+/// Outcome of materializing one or more patterns.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PatternMaterialization {
+    pub created: Vec<BookingKey>,
+    /// Occurrences which already had a booking.
+    pub existing: usize,
+    /// Occurrences where the slot does not exist in that week or the person
+    /// marked the day as unavailable.
+    pub skipped: usize,
+    /// Occurrences which could not be checked or booked because a request
+    /// failed; the others are still created.
+    pub failed: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn week(week: u8) -> Week {
+        Week { year: 2025, week }
+    }
+
+    fn pattern(interval_weeks: u8) -> BookingPattern {
+        BookingPattern {
+            id: Uuid::nil(),
+            sales_person_id: Uuid::from_u128(1),
+            shiftplan_id: Uuid::from_u128(2),
+            slot_id: Uuid::from_u128(3),
+            interval_weeks,
+            valid_from: week(10),
+            valid_until: week(20),
+        }
+    }
+
+    #[test]
+    fn weekly_pattern_occurs_every_week_in_range() {
+        let pattern = pattern(1);
+        assert!(!pattern.occurs_in(week(9)));
+        assert!(pattern.occurs_in(week(10)));
+        assert!(pattern.occurs_in(week(15)));
+        assert!(!pattern.occurs_in(week(21)));
+    }
+
+    #[test]
+    fn interval_counts_from_the_first_week() {
+        let weeks: Vec<u8> = pattern(3)
+            .occurrences(week(1), week(52))
+            .iter()
+            .map(|week| week.week)
+            .collect();
+        assert_eq!(weeks, vec![10, 13, 16, 19]);
+    }
+
+    #[test]
+    fn intervals_continue_across_years() {
+        let pattern = BookingPattern {
+            valid_from: Week {
+                year: 2024,
+                week: 51,
+            },
+            valid_until: Week {
+                year: 2025,
+                week: 4,
+            },
+            ..pattern(2)
+        };
+        let weeks: Vec<(u32, u8)> = pattern
+            .occurrences(pattern.valid_from, pattern.valid_until)
+            .iter()
+            .map(|week| (week.year, week.week))
+            .collect();
+        assert_eq!(weeks, vec![(2024, 51), (2025, 1), (2025, 3)]);
+    }
+
+    #[test]
+    fn covers_matches_person_slot_and_week() {
+        let pattern = pattern(2);
+        let key = BookingKey {
+            sales_person_id: Uuid::from_u128(1),
+            slot_id: Uuid::from_u128(3),
+            week: 12,
+            year: 2025,
+        };
+        assert!(pattern.covers(&key));
+        assert!(!pattern.covers(&BookingKey { week: 13, ..key }));
+        assert!(!pattern.covers(&BookingKey {
+            sales_person_id: Uuid::from_u128(9),
+            ..key
+        }));
+    }
+}
@@ -16,6 +16,7 @@ fn default_show_vacation() -> bool {
 
 #[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq)]
 pub struct Config {
+    #[serde(default)]
     pub backend: Rc<str>,
     #[serde(default = "default_application_title")]
     pub application_title: Rc<str>,
@@ -25,4 +26,48 @@ pub struct Config {
     pub env_short_description: Rc<str>,
     #[serde(default = "default_show_vacation")]
     pub show_vacation: bool,
+    /// Serve every request from the bundled fixture dataset persisted in
+    /// `localStorage` instead of talking to `backend`.
+    #[serde(default)]
+    pub demo: bool,
+    /// Timeout and retries of backend requests.
+    #[serde(default)]
+    pub requests: RequestPolicy,
+}
+
+impl Config {
+    /// Whether the application has something to talk to, either a backend
+    /// URL or the local demo store.
+    pub fn is_loaded(&self) -> bool {
+        self.demo || !self.backend.is_empty()
+    }
+}
+
+/// How requests to the backend are sent. Every attempt is given up after
+/// `timeout_ms`. GETs which fail on the way or hit a 502, 503 or 504 are sent
+/// again up to `retries` times, waiting `backoff_ms` before the first retry
+/// and twice as long before each further one.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct RequestPolicy {
+    pub timeout_ms: u32,
+    pub retries: u32,
+    pub backoff_ms: u32,
+}
+
+impl Default for RequestPolicy {
+    fn default() -> Self {
+        Self {
+            timeout_ms: 15_000,
+            retries: 2,
+            backoff_ms: 500,
+        }
+    }
+}
+
+impl RequestPolicy {
+    /// The wait before retry number `attempt`, counting from zero.
+    pub fn backoff_ms(&self, attempt: u32) -> u32 {
+        self.backoff_ms.saturating_mul(2u32.saturating_pow(attempt))
+    }
 }
@@ -0,0 +1,205 @@
+use std::rc::Rc;
+
+use crate::error::ShiftyError;
+
+use super::{week::Week, Shiftplan, Weekday};
+
+/// Range of weeks shown by the multi-week view.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MultiWeekRange {
+    /// The current week and the three following ones.
+    FourWeeks,
+    /// Every week touching the month the current week starts in.
+    Month,
+}
+
+impl MultiWeekRange {
+    pub fn weeks(self, anchor: Week) -> Result<Vec<Week>, ShiftyError> {
+        match self {
+            MultiWeekRange::FourWeeks => {
+                let until = Week::of_date(anchor.monday()? + time::Duration::weeks(3));
+                anchor.through(until)
+            }
+            MultiWeekRange::Month => {
+                let monday = anchor.monday()?;
+                let first = monday.replace_day(1)?;
+                let last = first.replace_day(first.month().length(first.year()))?;
+                Week::of_date(first).through(Week::of_date(last))
+            }
+        }
+    }
+}
+
+/// How well the slots of a day are staffed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CoverageLevel {
+    /// The plan has no slots on that day.
+    NoSlots,
+    /// Every slot has at least its minimum of people.
+    Covered,
+    /// At most half of the slots are under-staffed.
+    Partial,
+    /// More than half of the slots are under-staffed.
+    Uncovered,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayCoverage {
+    pub day_of_week: Weekday,
+    pub date: time::Date,
+    pub slots: usize,
+    /// Slots whose [`super::Slot::evaluation`] reports missing people.
+    pub understaffed: usize,
+    /// Filled positions, counting at most `min_resources` per slot.
+    pub filled: usize,
+    /// Sum of `min_resources` over all slots.
+    pub required: usize,
+}
+
+impl DayCoverage {
+    pub fn level(&self) -> CoverageLevel {
+        if self.slots == 0 {
+            CoverageLevel::NoSlots
+        } else if self.understaffed == 0 {
+            CoverageLevel::Covered
+        } else if self.understaffed * 2 <= self.slots {
+            CoverageLevel::Partial
+        } else {
+            CoverageLevel::Uncovered
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeekCoverage {
+    pub week: Week,
+    /// Monday through Sunday.
+    pub days: Rc<[DayCoverage]>,
+}
+
+impl WeekCoverage {
+    /// Summarizes `shiftplan` day by day.
+    pub fn from_shiftplan(shiftplan: &Shiftplan) -> Result<Self, ShiftyError> {
+        let week = Week {
+            year: shiftplan.year,
+            week: shiftplan.week,
+        };
+        let monday = week.monday()?;
+        let days = (0..7)
+            .map(|offset| {
+                let day_of_week = Weekday::from_num_from_monday(offset);
+                let slots = shiftplan.slots_by_weekday(day_of_week);
+                DayCoverage {
+                    day_of_week,
+                    date: monday + time::Duration::days(i64::from(offset)),
+                    slots: slots.len(),
+                    understaffed: slots
+                        .iter()
+                        .filter(|slot| slot.evaluation().is_faulty())
+                        .count(),
+                    filled: slots
+                        .iter()
+                        .map(|slot| slot.bookings.len().min(slot.min_resources as usize))
+                        .sum(),
+                    required: slots.iter().map(|slot| slot.min_resources as usize).sum(),
+                }
+            })
+            .collect();
+        Ok(Self { week, days })
+    }
+
+    pub fn understaffed_days(&self) -> usize {
+        self.days.iter().filter(|day| day.understaffed > 0).count()
+    }
+
+    /// Whether the plan has slots on Sunday this week.
+    pub fn has_sunday_slots(&self) -> bool {
+        self.days
+            .iter()
+            .any(|day| day.day_of_week == Weekday::Sunday && day.slots > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{shiftplan::Booking, Slot};
+    use uuid::Uuid;
+
+    fn slot(day_of_week: Weekday, min_resources: u8, booked: usize) -> Slot {
+        Slot {
+            id: Uuid::new_v4(),
+            day_of_week,
+            from: time::Time::from_hms(9, 0, 0).unwrap(),
+            to: time::Time::from_hms(12, 0, 0).unwrap(),
+            min_resources,
+            bookings: (0..booked)
+                .map(|_| Booking {
+                    id: Uuid::new_v4(),
+                    sales_person_id: Uuid::new_v4(),
+                    slot_id: Uuid::nil(),
+                    week: 23,
+                    year: 2025,
+                    label: "".into(),
+                    background_color: "".into(),
+                    self_added: false,
+                    created: None,
+                    created_by: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn four_weeks_cross_the_year_boundary() {
+        let weeks = MultiWeekRange::FourWeeks
+            .weeks(Week {
+                year: 2024,
+                week: 51,
+            })
+            .unwrap();
+        let weeks: Vec<(u32, u8)> = weeks.iter().map(|week| (week.year, week.week)).collect();
+        assert_eq!(weeks, vec![(2024, 51), (2024, 52), (2025, 1), (2025, 2)]);
+    }
+
+    #[test]
+    fn month_covers_every_week_touching_it() {
+        // June 2025 starts on a Sunday (week 22) and ends on a Monday (week 27).
+        let weeks = MultiWeekRange::Month
+            .weeks(Week {
+                year: 2025,
+                week: 24,
+            })
+            .unwrap();
+        assert_eq!(weeks.first().unwrap().week, 22);
+        assert_eq!(weeks.last().unwrap().week, 27);
+        assert_eq!(weeks.len(), 6);
+    }
+
+    #[test]
+    fn coverage_counts_understaffed_slots_per_day() {
+        let shiftplan = Shiftplan {
+            week: 23,
+            year: 2025,
+            slots: vec![
+                slot(Weekday::Monday, 2, 2),
+                slot(Weekday::Monday, 2, 3),
+                slot(Weekday::Tuesday, 2, 1),
+                slot(Weekday::Tuesday, 1, 1),
+                slot(Weekday::Wednesday, 1, 0),
+            ]
+            .into(),
+        };
+        let coverage = WeekCoverage::from_shiftplan(&shiftplan).unwrap();
+        assert_eq!(coverage.days.len(), 7);
+        assert_eq!(coverage.days[0].level(), CoverageLevel::Covered);
+        assert_eq!(coverage.days[0].filled, 4);
+        assert_eq!(coverage.days[1].level(), CoverageLevel::Partial);
+        assert_eq!(coverage.days[1].filled, 2);
+        assert_eq!(coverage.days[1].required, 3);
+        assert_eq!(coverage.days[2].level(), CoverageLevel::Uncovered);
+        assert_eq!(coverage.days[3].level(), CoverageLevel::NoSlots);
+        assert_eq!(coverage.days[2].date, time::macros::date!(2025 - 06 - 04));
+        assert_eq!(coverage.understaffed_days(), 2);
+    }
+}
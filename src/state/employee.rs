@@ -240,6 +240,7 @@ pub struct CustomExtraHoursDefinition {
     pub name: Rc<str>,
     pub description: Option<Rc<str>>,
     pub modifies_balance: bool,
+    pub version: Uuid,
 }
 
 impl From<&rest_types::CustomExtraHoursTO> for CustomExtraHoursDefinition {
@@ -252,6 +253,7 @@ impl From<&rest_types::CustomExtraHoursTO> for CustomExtraHoursDefinition {
                 .as_ref()
                 .map(|d| d.as_ref().into()),
             modifies_balance: custom_extra_hours.modifies_balance,
+            version: custom_extra_hours.version,
         }
     }
 }
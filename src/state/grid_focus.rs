@@ -0,0 +1,279 @@
+use std::rc::Rc;
+
+use uuid::Uuid;
+
+use super::{Slot, Weekday};
+
+/// Number of recently used persons reachable through the digit keys.
+pub const MAX_RECENT_PERSONS: usize = 9;
+
+/// Keyboard focus in the week grid: a slot, or one booking inside it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GridFocus {
+    pub slot_id: Uuid,
+    /// The focused booking, `None` while the slot itself is focused.
+    pub sales_person_id: Option<Uuid>,
+}
+
+impl GridFocus {
+    pub fn slot(slot_id: Uuid) -> Self {
+        Self {
+            slot_id,
+            sales_person_id: None,
+        }
+    }
+}
+
+/// Keys the week grid reacts to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GridKey {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    Delete,
+    /// Digit key `1`–`9`, as zero-based index into the recent persons.
+    Recent(usize),
+    PreviousWeek,
+    NextWeek,
+    Help,
+}
+
+impl GridKey {
+    /// Maps a `KeyboardEvent.key` value to a grid key.
+    pub fn parse(key: &str) -> Option<Self> {
+        match key {
+            "ArrowUp" => Some(GridKey::Up),
+            "ArrowDown" => Some(GridKey::Down),
+            "ArrowLeft" => Some(GridKey::Left),
+            "ArrowRight" => Some(GridKey::Right),
+            "Enter" => Some(GridKey::Enter),
+            "Delete" | "Backspace" => Some(GridKey::Delete),
+            "PageUp" => Some(GridKey::PreviousWeek),
+            "PageDown" => Some(GridKey::NextWeek),
+            "?" => Some(GridKey::Help),
+            _ => match key.parse::<usize>() {
+                Ok(digit @ 1..=9) => Some(GridKey::Recent(digit - 1)),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// What the week grid asks its owner to do after a key press.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GridCommand {
+    OpenPicker(Uuid),
+    RemoveBooking {
+        slot_id: Uuid,
+        sales_person_id: Uuid,
+    },
+    PickRecent {
+        slot_id: Uuid,
+        index: usize,
+    },
+    PreviousWeek,
+    NextWeek,
+    ShowHelp,
+}
+
+/// Slots of `day` ordered by start time.
+fn day_slots(slots: &[Slot], day: Weekday) -> Vec<&Slot> {
+    let mut day_slots: Vec<&Slot> = slots
+        .iter()
+        .filter(|slot| slot.day_of_week == day)
+        .collect();
+    day_slots.sort_by_key(|slot| slot.from);
+    day_slots
+}
+
+/// Focus stops of a day from top to bottom: every slot followed by its
+/// bookings.
+fn day_stops(slots: &[Slot], day: Weekday) -> Vec<GridFocus> {
+    day_slots(slots, day)
+        .into_iter()
+        .flat_map(|slot| {
+            std::iter::once(GridFocus::slot(slot.id)).chain(slot.bookings.iter().map(|booking| {
+                GridFocus {
+                    slot_id: slot.id,
+                    sales_person_id: Some(booking.sales_person_id),
+                }
+            }))
+        })
+        .collect()
+}
+
+/// Moves `focus` for an arrow key. Up and down walk through the slots and
+/// bookings of a day; left and right jump to the slot of the next day with
+/// slots that starts closest to the current one. Without a focus the first
+/// slot of the week is focused.
+pub fn move_focus(
+    slots: &[Slot],
+    days: &[Weekday],
+    focus: Option<GridFocus>,
+    key: GridKey,
+) -> Option<GridFocus> {
+    let current = focus.and_then(|focus| {
+        slots
+            .iter()
+            .find(|slot| slot.id == focus.slot_id)
+            .map(|slot| (focus, slot))
+    });
+    let Some((focus, slot)) = current else {
+        return days.iter().find_map(|day| {
+            day_slots(slots, *day)
+                .first()
+                .map(|slot| GridFocus::slot(slot.id))
+        });
+    };
+    match key {
+        GridKey::Up | GridKey::Down => {
+            let stops = day_stops(slots, slot.day_of_week);
+            let index = stops.iter().position(|stop| *stop == focus).unwrap_or(0);
+            let index = if key == GridKey::Up {
+                index.saturating_sub(1)
+            } else {
+                (index + 1).min(stops.len() - 1)
+            };
+            Some(stops[index])
+        }
+        GridKey::Left | GridKey::Right => {
+            let Some(day_index) = days.iter().position(|day| *day == slot.day_of_week) else {
+                return Some(focus);
+            };
+            let candidates: Vec<&Weekday> = if key == GridKey::Left {
+                days[..day_index].iter().rev().collect()
+            } else {
+                days[day_index + 1..].iter().collect()
+            };
+            candidates
+                .into_iter()
+                .find_map(|day| {
+                    day_slots(slots, *day)
+                        .into_iter()
+                        .min_by_key(|other| (other.from - slot.from).abs())
+                        .map(|other| GridFocus::slot(other.id))
+                })
+                .or(Some(focus))
+        }
+        _ => Some(focus),
+    }
+}
+
+/// Moves `sales_person_id` to the front of the recently used persons.
+pub fn remember_person(recent: &[Uuid], sales_person_id: Uuid) -> Rc<[Uuid]> {
+    std::iter::once(sales_person_id)
+        .chain(recent.iter().copied().filter(|id| *id != sales_person_id))
+        .take(MAX_RECENT_PERSONS)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::shiftplan::Booking;
+
+    fn slot(id: u128, day_of_week: Weekday, from: u8, booked: &[u128]) -> Slot {
+        let slot_id = Uuid::from_u128(id);
+        Slot {
+            id: slot_id,
+            day_of_week,
+            from: time::Time::from_hms(from, 0, 0).unwrap(),
+            to: time::Time::from_hms(from + 2, 0, 0).unwrap(),
+            min_resources: 1,
+            bookings: booked
+                .iter()
+                .map(|sales_person| Booking {
+                    id: Uuid::new_v4(),
+                    sales_person_id: Uuid::from_u128(*sales_person),
+                    slot_id,
+                    week: 23,
+                    year: 2025,
+                    label: "".into(),
+                    background_color: "".into(),
+                    self_added: false,
+                    created: None,
+                    created_by: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn week() -> Vec<Slot> {
+        vec![
+            slot(1, Weekday::Monday, 12, &[]),
+            slot(2, Weekday::Monday, 9, &[7]),
+            slot(3, Weekday::Wednesday, 13, &[]),
+            slot(4, Weekday::Wednesday, 8, &[]),
+        ]
+    }
+
+    const DAYS: [Weekday; 6] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+    ];
+
+    fn focus(id: u128) -> Option<GridFocus> {
+        Some(GridFocus::slot(Uuid::from_u128(id)))
+    }
+
+    #[test]
+    fn parse_maps_navigation_and_digit_keys() {
+        assert_eq!(GridKey::parse("ArrowLeft"), Some(GridKey::Left));
+        assert_eq!(GridKey::parse("PageDown"), Some(GridKey::NextWeek));
+        assert_eq!(GridKey::parse("Backspace"), Some(GridKey::Delete));
+        assert_eq!(GridKey::parse("1"), Some(GridKey::Recent(0)));
+        assert_eq!(GridKey::parse("9"), Some(GridKey::Recent(8)));
+        assert_eq!(GridKey::parse("0"), None);
+        assert_eq!(GridKey::parse("a"), None);
+    }
+
+    #[test]
+    fn first_key_focuses_the_earliest_slot_of_the_week() {
+        assert_eq!(move_focus(&week(), &DAYS, None, GridKey::Down), focus(2));
+    }
+
+    #[test]
+    fn up_and_down_walk_through_slots_and_bookings() {
+        let slots = week();
+        let booking = Some(GridFocus {
+            slot_id: Uuid::from_u128(2),
+            sales_person_id: Some(Uuid::from_u128(7)),
+        });
+        assert_eq!(move_focus(&slots, &DAYS, focus(2), GridKey::Down), booking);
+        assert_eq!(move_focus(&slots, &DAYS, booking, GridKey::Down), focus(1));
+        assert_eq!(move_focus(&slots, &DAYS, focus(1), GridKey::Down), focus(1));
+        assert_eq!(move_focus(&slots, &DAYS, focus(2), GridKey::Up), focus(2));
+    }
+
+    #[test]
+    fn left_and_right_skip_empty_days_and_keep_the_time() {
+        let slots = week();
+        assert_eq!(
+            move_focus(&slots, &DAYS, focus(1), GridKey::Right),
+            focus(3)
+        );
+        assert_eq!(move_focus(&slots, &DAYS, focus(4), GridKey::Left), focus(2));
+        assert_eq!(
+            move_focus(&slots, &DAYS, focus(3), GridKey::Right),
+            focus(3)
+        );
+    }
+
+    #[test]
+    fn remember_person_moves_to_front_and_caps_the_list() {
+        let recent: Vec<Uuid> = (1..=9).map(Uuid::from_u128).collect();
+        let recent = remember_person(&recent, Uuid::from_u128(5));
+        assert_eq!(recent[0], Uuid::from_u128(5));
+        assert_eq!(recent.len(), 9);
+        let recent = remember_person(&recent, Uuid::from_u128(42));
+        assert_eq!(recent[0], Uuid::from_u128(42));
+        assert_eq!(recent.len(), MAX_RECENT_PERSONS);
+        assert!(!recent.contains(&Uuid::from_u128(9)));
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::BTreeSet;
+
+use uuid::Uuid;
+
+use super::Shiftplan;
+
+/// What another planner changed between two loads of the same week.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LiveChanges {
+    /// Bookings which appeared.
+    pub bookings: BTreeSet<Uuid>,
+    /// Slots which appeared, changed their times or lost a booking.
+    pub slots: BTreeSet<Uuid>,
+}
+
+impl LiveChanges {
+    pub fn is_empty(&self) -> bool {
+        self.bookings.is_empty() && self.slots.is_empty()
+    }
+}
+
+/// Differences from `old` to `new`. Plans of different weeks have nothing in
+/// common, so they yield no changes.
+pub fn live_changes(old: &Shiftplan, new: &Shiftplan) -> LiveChanges {
+    let mut changes = LiveChanges::default();
+    if old.year != new.year || old.week != new.week {
+        return changes;
+    }
+    let old_bookings: BTreeSet<Uuid> = old
+        .slots
+        .iter()
+        .flat_map(|slot| slot.bookings.iter().map(|booking| booking.id))
+        .collect();
+    for slot in new.slots.iter() {
+        changes.bookings.extend(
+            slot.bookings
+                .iter()
+                .map(|booking| booking.id)
+                .filter(|id| !old_bookings.contains(id)),
+        );
+        let Some(old_slot) = old.slots.iter().find(|old_slot| old_slot.id == slot.id) else {
+            changes.slots.insert(slot.id);
+            continue;
+        };
+        let lost_booking = old_slot.bookings.iter().any(|old_booking| {
+            !slot
+                .bookings
+                .iter()
+                .any(|booking| booking.id == old_booking.id)
+        });
+        if lost_booking
+            || old_slot.from != slot.from
+            || old_slot.to != slot.to
+            || old_slot.min_resources != slot.min_resources
+        {
+            changes.slots.insert(slot.id);
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{shiftplan::Booking, Slot, Weekday};
+    use time::macros::time;
+
+    fn booking(id: u128, slot_id: Uuid) -> Booking {
+        Booking {
+            id: Uuid::from_u128(id),
+            sales_person_id: Uuid::nil(),
+            slot_id,
+            week: 23,
+            year: 2025,
+            label: "Anna".into(),
+            background_color: "#ffffff".into(),
+            self_added: false,
+            created: None,
+            created_by: None,
+        }
+    }
+
+    fn slot(id: u128, bookings: &[u128]) -> Slot {
+        let id = Uuid::from_u128(id);
+        Slot {
+            id,
+            day_of_week: Weekday::Monday,
+            from: time!(9:00),
+            to: time!(12:00),
+            bookings: bookings
+                .iter()
+                .map(|booking_id| booking(*booking_id, id))
+                .collect(),
+            min_resources: 1,
+        }
+    }
+
+    fn plan(week: u8, slots: Vec<Slot>) -> Shiftplan {
+        Shiftplan {
+            week,
+            year: 2025,
+            slots: slots.into(),
+        }
+    }
+
+    #[test]
+    fn reports_added_bookings_and_slots_which_lost_or_changed() {
+        let old = plan(23, vec![slot(1, &[10]), slot(2, &[20]), slot(3, &[])]);
+        let new = plan(
+            23,
+            vec![
+                slot(1, &[10, 11]),
+                slot(2, &[]),
+                Slot {
+                    to: time!(13:00),
+                    ..slot(3, &[])
+                },
+                slot(4, &[]),
+            ],
+        );
+        let changes = live_changes(&old, &new);
+        assert_eq!(changes.bookings, BTreeSet::from([Uuid::from_u128(11)]));
+        assert_eq!(
+            changes.slots,
+            BTreeSet::from([2, 3, 4].map(Uuid::from_u128))
+        );
+        assert!(live_changes(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn other_weeks_yield_no_changes() {
+        let old = plan(23, vec![slot(1, &[])]);
+        let new = plan(24, vec![slot(1, &[10])]);
+        assert!(live_changes(&old, &new).is_empty());
+    }
+}
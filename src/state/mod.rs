@@ -1,16 +1,32 @@
 pub mod auth_info;
+pub mod auto_fill;
+pub mod booking_changes;
+pub mod booking_history;
 pub mod booking_log;
+pub mod booking_log_filter;
+pub mod booking_pattern;
 pub mod config;
+pub mod coverage;
 pub mod dropdown;
 pub mod employee;
 pub mod employee_work_details;
+pub mod grid_focus;
+pub mod live_update;
+pub mod offline_queue;
 pub mod sales_person_available;
 pub mod shiftplan;
+pub mod shiftplan_draft;
 pub mod slot_edit;
+pub mod slot_structure;
+pub mod slot_timeline;
 pub mod text_template;
+pub mod time_travel;
 pub mod tooltip;
 pub mod user_management;
+pub mod version_conflict;
 pub mod week;
+pub mod week_copy;
+pub mod week_publication;
 pub mod weekly_overview;
 
 pub use auth_info::AuthInfo;
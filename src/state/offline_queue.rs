@@ -0,0 +1,353 @@
+use std::rc::Rc;
+
+use rest_types::DayOfWeekTO;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{booking_history::BookingKey, shiftplan::SalesPerson, Weekday};
+
+/// A booking or unavailability change which could not reach the backend.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QueuedMutation {
+    AddBooking(BookingKey),
+    RemoveBooking {
+        booking_id: Uuid,
+        key: BookingKey,
+    },
+    AddUnavailableDay {
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+        day_of_week: DayOfWeekTO,
+    },
+    RemoveUnavailableDay {
+        id: Uuid,
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+        day_of_week: DayOfWeekTO,
+    },
+}
+
+impl QueuedMutation {
+    /// Whether `other` undoes `self`, e.g. adding a booking again which is
+    /// queued for removal.
+    fn is_inverse_of(&self, other: &QueuedMutation) -> bool {
+        match (self, other) {
+            (QueuedMutation::AddBooking(add), QueuedMutation::RemoveBooking { key, .. })
+            | (QueuedMutation::RemoveBooking { key, .. }, QueuedMutation::AddBooking(add)) => {
+                add == key
+            }
+            (
+                QueuedMutation::AddUnavailableDay {
+                    sales_person_id,
+                    year,
+                    week,
+                    day_of_week,
+                },
+                QueuedMutation::RemoveUnavailableDay {
+                    sales_person_id: other_sales_person_id,
+                    year: other_year,
+                    week: other_week,
+                    day_of_week: other_day_of_week,
+                    ..
+                },
+            )
+            | (
+                QueuedMutation::RemoveUnavailableDay {
+                    sales_person_id,
+                    year,
+                    week,
+                    day_of_week,
+                    ..
+                },
+                QueuedMutation::AddUnavailableDay {
+                    sales_person_id: other_sales_person_id,
+                    year: other_year,
+                    week: other_week,
+                    day_of_week: other_day_of_week,
+                },
+            ) => {
+                sales_person_id == other_sales_person_id
+                    && year == other_year
+                    && week == other_week
+                    && day_of_week == other_day_of_week
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedItem {
+    pub id: Uuid,
+    pub mutation: QueuedMutation,
+    /// What the change does, e.g. `Anna · Monday 09:00–12:00`.
+    pub summary: Rc<str>,
+    /// Why the last replay of this item was rejected. Failed items are kept
+    /// until they are retried or discarded.
+    #[serde(default)]
+    pub failure: Option<Rc<str>>,
+}
+
+/// Booking changes of a week which wait for replay, as the week view shows
+/// them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PendingBooking {
+    pub key: BookingKey,
+    pub name: Rc<str>,
+    pub background_color: Rc<str>,
+    pub removal: bool,
+    pub failed: bool,
+}
+
+/// Changes in the order they were made.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OfflineQueue {
+    pub items: Vec<QueuedItem>,
+}
+
+impl OfflineQueue {
+    /// Appends the change. A change which undoes a queued one drops that one
+    /// instead, so neither reaches the backend.
+    pub fn push(&mut self, mutation: QueuedMutation, summary: Rc<str>) {
+        if let Some(index) = self
+            .items
+            .iter()
+            .rposition(|item| item.failure.is_none() && item.mutation.is_inverse_of(&mutation))
+        {
+            self.items.remove(index);
+            return;
+        }
+        self.items.push(QueuedItem {
+            id: Uuid::new_v4(),
+            mutation,
+            summary,
+            failure: None,
+        });
+    }
+
+    pub fn remove(&mut self, id: Uuid) {
+        self.items.retain(|item| item.id != id);
+    }
+
+    /// Items which have not been rejected yet.
+    pub fn has_pending(&self) -> bool {
+        self.items.iter().any(|item| item.failure.is_none())
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.failure.is_some())
+            .count()
+    }
+
+    pub fn pending_bookings(
+        &self,
+        year: u32,
+        week: u8,
+        sales_persons: &[SalesPerson],
+    ) -> Rc<[PendingBooking]> {
+        self.items
+            .iter()
+            .filter_map(|item| {
+                let (key, removal) = match &item.mutation {
+                    QueuedMutation::AddBooking(key) => (*key, false),
+                    QueuedMutation::RemoveBooking { key, .. } => (*key, true),
+                    _ => return None,
+                };
+                if key.year != year || key.week != week {
+                    return None;
+                }
+                let sales_person = sales_persons
+                    .iter()
+                    .find(|sales_person| sales_person.id == key.sales_person_id);
+                Some(PendingBooking {
+                    key,
+                    name: sales_person
+                        .map(|sales_person| sales_person.name.as_ref().into())
+                        .unwrap_or_else(|| item.summary.clone()),
+                    background_color: sales_person
+                        .map(|sales_person| sales_person.background_color.as_ref().into())
+                        .unwrap_or_else(|| "#ffffff".into()),
+                    removal,
+                    failed: item.failure.is_some(),
+                })
+            })
+            .collect()
+    }
+
+    /// `unavailable` with the queued unavailability changes of the person
+    /// in the week applied.
+    pub fn apply_unavailable_days(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+        unavailable: &[Weekday],
+    ) -> Rc<[Weekday]> {
+        let mut days = unavailable.to_vec();
+        for item in self.items.iter().filter(|item| item.failure.is_none()) {
+            match &item.mutation {
+                QueuedMutation::AddUnavailableDay {
+                    sales_person_id: id,
+                    year: y,
+                    week: w,
+                    day_of_week,
+                } if *id == sales_person_id && *y == year && *w == week => {
+                    let day = Weekday::from(*day_of_week);
+                    if !days.contains(&day) {
+                        days.push(day);
+                    }
+                }
+                QueuedMutation::RemoveUnavailableDay {
+                    sales_person_id: id,
+                    year: y,
+                    week: w,
+                    day_of_week,
+                    ..
+                } if *id == sales_person_id && *y == year && *w == week => {
+                    let day = Weekday::from(*day_of_week);
+                    days.retain(|existing| *existing != day);
+                }
+                _ => {}
+            }
+        }
+        days.into()
+    }
+
+    /// The queued unavailability change of the person for the day, if any.
+    pub fn unavailable_day_change(
+        &self,
+        sales_person_id: Uuid,
+        year: u32,
+        week: u8,
+        day: Weekday,
+    ) -> Option<&QueuedMutation> {
+        let day_of_week = DayOfWeekTO::from(&day);
+        self.items
+            .iter()
+            .filter(|item| item.failure.is_none())
+            .map(|item| &item.mutation)
+            .rfind(|mutation| match mutation {
+                QueuedMutation::AddUnavailableDay {
+                    sales_person_id: id,
+                    year: y,
+                    week: w,
+                    day_of_week: d,
+                }
+                | QueuedMutation::RemoveUnavailableDay {
+                    sales_person_id: id,
+                    year: y,
+                    week: w,
+                    day_of_week: d,
+                    ..
+                } => *id == sales_person_id && *y == year && *w == week && *d == day_of_week,
+                _ => false,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(sales_person: u128, slot: u128) -> BookingKey {
+        BookingKey {
+            sales_person_id: Uuid::from_u128(sales_person),
+            slot_id: Uuid::from_u128(slot),
+            week: 23,
+            year: 2025,
+        }
+    }
+
+    fn unavailable(add: bool) -> QueuedMutation {
+        if add {
+            QueuedMutation::AddUnavailableDay {
+                sales_person_id: Uuid::from_u128(1),
+                year: 2025,
+                week: 23,
+                day_of_week: DayOfWeekTO::Tuesday,
+            }
+        } else {
+            QueuedMutation::RemoveUnavailableDay {
+                id: Uuid::from_u128(9),
+                sales_person_id: Uuid::from_u128(1),
+                year: 2025,
+                week: 23,
+                day_of_week: DayOfWeekTO::Tuesday,
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_changes_cancel_out() {
+        let mut queue = OfflineQueue::default();
+        queue.push(QueuedMutation::AddBooking(key(1, 10)), "a".into());
+        queue.push(
+            QueuedMutation::RemoveBooking {
+                booking_id: Uuid::from_u128(5),
+                key: key(2, 10),
+            },
+            "b".into(),
+        );
+        queue.push(QueuedMutation::AddBooking(key(2, 10)), "c".into());
+        assert_eq!(queue.items.len(), 1);
+        assert_eq!(queue.items[0].summary.as_ref(), "a");
+
+        queue.push(unavailable(true), "d".into());
+        queue.push(unavailable(false), "e".into());
+        assert_eq!(queue.items.len(), 1);
+    }
+
+    #[test]
+    fn failed_items_are_not_cancelled() {
+        let mut queue = OfflineQueue::default();
+        queue.push(unavailable(true), "add".into());
+        queue.items[0].failure = Some("rejected".into());
+        queue.push(unavailable(false), "remove".into());
+        assert_eq!(queue.items.len(), 2);
+        assert!(queue.has_pending());
+        assert_eq!(queue.failed_count(), 1);
+    }
+
+    #[test]
+    fn pending_state_of_a_week() {
+        let mut queue = OfflineQueue::default();
+        queue.push(QueuedMutation::AddBooking(key(1, 10)), "a".into());
+        queue.push(
+            QueuedMutation::AddBooking(BookingKey {
+                week: 24,
+                ..key(1, 10)
+            }),
+            "b".into(),
+        );
+        queue.push(unavailable(true), "c".into());
+
+        let pending = queue.pending_bookings(2025, 23, &[]);
+        assert_eq!(
+            pending.as_ref(),
+            &[PendingBooking {
+                key: key(1, 10),
+                name: "a".into(),
+                background_color: "#ffffff".into(),
+                removal: false,
+                failed: false,
+            }]
+        );
+        let days = queue.apply_unavailable_days(
+            Uuid::from_u128(1),
+            2025,
+            23,
+            &[Weekday::Monday, Weekday::Tuesday],
+        );
+        assert_eq!(days.as_ref(), &[Weekday::Monday, Weekday::Tuesday]);
+        let days = queue.apply_unavailable_days(Uuid::from_u128(1), 2025, 23, &[]);
+        assert_eq!(days.as_ref(), &[Weekday::Tuesday]);
+        assert_eq!(
+            queue.unavailable_day_change(Uuid::from_u128(1), 2025, 23, Weekday::Tuesday),
+            Some(&unavailable(true))
+        );
+    }
+}
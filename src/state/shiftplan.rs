@@ -2,6 +2,7 @@ use std::rc::Rc;
 
 use crate::{
     base_types::ImStr,
+    error::ShiftyError,
     i18n::{I18n, Key, Locale},
 };
 use rest_types::{BookingConflictTO, BookingTO, DayOfWeekTO, SalesPersonTO, SlotTO};
@@ -108,11 +109,12 @@ impl From<&BookingTO> for Booking {
             slot_id: booking.slot_id,
             week: booking.calendar_week as u8,
             year: booking.year,
-            label: "value".into(),
+            label: "".into(),
             background_color: "#FFF".into(),
+            // Only the shiftplan week endpoint tells whether a booking was self-added.
             self_added: false,
-            created: None,
-            created_by: None,
+            created: booking.created,
+            created_by: booking.created_by.as_ref().map(|s| s.to_string().into()),
         }
     }
 }
@@ -223,6 +225,12 @@ pub struct Shiftplan {
 }
 
 impl Shiftplan {
+    /// Whether this is the plan of the given week. A plan of another week is
+    /// a late response from before the user navigated.
+    pub fn is_week(&self, year: u32, week: u8) -> bool {
+        self.year == year && self.week == week
+    }
+
     pub fn slots_by_weekday(&self, weekday: Weekday) -> Rc<[Slot]> {
         self.slots
             .iter()
@@ -261,10 +269,36 @@ pub struct BookingConflict {
     pub day_of_week: Weekday,
 }
 
+/// Moves the booking of `sales_person_id` from one slot to another slot of
+/// the same week.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BookingMove {
+    pub sales_person_id: Uuid,
+    pub from_slot_id: Uuid,
+    pub to_slot_id: Uuid,
+}
+
+/// How far a failed [`BookingMove`] got on the backend.
+#[derive(Debug)]
+pub enum BookingMoveFailure {
+    /// Removing the old booking failed, the backend is unchanged.
+    NothingApplied(ShiftyError),
+    /// The new booking failed and the old slot was booked again.
+    RolledBack(ShiftyError),
+    /// The new booking failed and booking the old slot again failed too, so
+    /// the person has lost the booking.
+    RollbackFailed {
+        error: ShiftyError,
+        rollback: ShiftyError,
+    },
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ViewMode {
     Week,
     Day,
+    /// Coverage of several weeks at once, see [`super::coverage`].
+    MultiWeek,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -0,0 +1,249 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{week::Week, Shiftplan, Slot, Weekday};
+
+/// Links a planning shiftplan to the live shiftplan it was cloned from and
+/// is published into.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShiftplanDraft {
+    pub draft_id: Uuid,
+    pub live_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DraftDiffKind {
+    /// Booked in the draft but not in the live plan.
+    Added,
+    /// Booked in the live plan but no longer in the draft.
+    Removed,
+}
+
+/// A booking which differs between a draft and its live plan in one week.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DraftBookingDiff {
+    pub kind: DraftDiffKind,
+    pub sales_person_id: Uuid,
+    pub label: Rc<str>,
+    pub day_of_week: Weekday,
+    pub from: time::Time,
+    pub to: time::Time,
+    pub live_slot_id: Uuid,
+    pub draft_slot_id: Uuid,
+    /// Id of the live booking, set for [`DraftDiffKind::Removed`].
+    pub live_booking_id: Option<Uuid>,
+}
+
+/// Booking to highlight in one of the two week grids of the comparison.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DraftDiffMark {
+    pub slot_id: Uuid,
+    pub sales_person_id: Uuid,
+    pub kind: DraftDiffKind,
+}
+
+/// The slot of `slots` with the same weekday and times as `slot`. Draft
+/// slots are copies with their own ids, so this is how they are matched.
+pub fn matching_slot<'a>(slots: &'a [Slot], slot: &Slot) -> Option<&'a Slot> {
+    slots.iter().find(|other| {
+        other.day_of_week == slot.day_of_week && other.from == slot.from && other.to == slot.to
+    })
+}
+
+/// Differences between a draft and its live plan in one week.
+///
+/// Only bookings of slots present in both plans are compared; structure
+/// differences are counted but never published.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DraftWeekDiff {
+    pub week: Week,
+    pub bookings: Vec<DraftBookingDiff>,
+    pub slots_only_in_draft: usize,
+    pub slots_only_in_live: usize,
+}
+
+impl DraftWeekDiff {
+    pub fn new(live: &Shiftplan, draft: &Shiftplan) -> Self {
+        let mut bookings = Vec::new();
+        for draft_slot in draft.slots.iter() {
+            let Some(live_slot) = matching_slot(&live.slots, draft_slot) else {
+                continue;
+            };
+            let booked = |slot: &Slot, sales_person_id: Uuid| {
+                slot.bookings
+                    .iter()
+                    .any(|booking| booking.sales_person_id == sales_person_id)
+            };
+            let diff =
+                |kind, booking: &super::shiftplan::Booking, live_booking_id| DraftBookingDiff {
+                    kind,
+                    sales_person_id: booking.sales_person_id,
+                    label: booking.label.clone(),
+                    day_of_week: draft_slot.day_of_week,
+                    from: draft_slot.from,
+                    to: draft_slot.to,
+                    live_slot_id: live_slot.id,
+                    draft_slot_id: draft_slot.id,
+                    live_booking_id,
+                };
+            bookings.extend(
+                draft_slot
+                    .bookings
+                    .iter()
+                    .filter(|booking| !booked(live_slot, booking.sales_person_id))
+                    .map(|booking| diff(DraftDiffKind::Added, booking, None)),
+            );
+            bookings.extend(
+                live_slot
+                    .bookings
+                    .iter()
+                    .filter(|booking| !booked(draft_slot, booking.sales_person_id))
+                    .map(|booking| diff(DraftDiffKind::Removed, booking, Some(booking.id))),
+            );
+        }
+        let unmatched = |slots: &[Slot], others: &[Slot]| {
+            slots
+                .iter()
+                .filter(|slot| matching_slot(others, slot).is_none())
+                .count()
+        };
+        Self {
+            week: Week {
+                year: draft.year,
+                week: draft.week,
+            },
+            bookings,
+            slots_only_in_draft: unmatched(&draft.slots, &live.slots),
+            slots_only_in_live: unmatched(&live.slots, &draft.slots),
+        }
+    }
+
+    pub fn count(&self, kind: DraftDiffKind) -> usize {
+        self.bookings
+            .iter()
+            .filter(|booking| booking.kind == kind)
+            .count()
+    }
+
+    /// Highlights for the live grid (`live == true`) or the draft grid.
+    /// Removed bookings only exist in the live plan, added ones only in the
+    /// draft.
+    pub fn marks(&self, live: bool) -> Rc<[DraftDiffMark]> {
+        self.bookings
+            .iter()
+            .filter(|booking| (booking.kind == DraftDiffKind::Removed) == live)
+            .map(|booking| DraftDiffMark {
+                slot_id: if live {
+                    booking.live_slot_id
+                } else {
+                    booking.draft_slot_id
+                },
+                sales_person_id: booking.sales_person_id,
+                kind: booking.kind,
+            })
+            .collect()
+    }
+}
+
+/// The live plan of a week next to its differences to the draft.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DraftComparison {
+    pub live: Shiftplan,
+    pub diff: DraftWeekDiff,
+}
+
+/// Outcome of publishing a draft into its live plan.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DraftPublishReport {
+    pub weeks: usize,
+    pub added: usize,
+    pub removed: usize,
+    /// Slots of the draft without a live counterpart; their bookings are not
+    /// published.
+    pub skipped_slots: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::shiftplan::Booking;
+
+    fn slot(id: u128, day_of_week: Weekday, from: u8, booked: &[u128]) -> Slot {
+        let slot_id = Uuid::from_u128(id);
+        Slot {
+            id: slot_id,
+            day_of_week,
+            from: time::Time::from_hms(from, 0, 0).unwrap(),
+            to: time::Time::from_hms(from + 3, 0, 0).unwrap(),
+            min_resources: 1,
+            bookings: booked
+                .iter()
+                .map(|sales_person| Booking {
+                    id: Uuid::from_u128(id * 1000 + sales_person),
+                    sales_person_id: Uuid::from_u128(*sales_person),
+                    slot_id,
+                    week: 23,
+                    year: 2025,
+                    label: format!("P{sales_person}").into(),
+                    background_color: "".into(),
+                    self_added: false,
+                    created: None,
+                    created_by: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn plan(slots: Vec<Slot>) -> Shiftplan {
+        Shiftplan {
+            week: 23,
+            year: 2025,
+            slots: slots.into(),
+        }
+    }
+
+    #[test]
+    fn diff_matches_slots_by_day_and_time() {
+        let live = plan(vec![
+            slot(1, Weekday::Monday, 9, &[7, 8]),
+            slot(2, Weekday::Monday, 13, &[7]),
+            slot(3, Weekday::Tuesday, 9, &[]),
+        ]);
+        let draft = plan(vec![
+            slot(11, Weekday::Monday, 9, &[7, 9]),
+            slot(12, Weekday::Monday, 13, &[7]),
+            slot(14, Weekday::Friday, 9, &[8]),
+        ]);
+        let diff = DraftWeekDiff::new(&live, &draft);
+        assert_eq!(diff.count(DraftDiffKind::Added), 1);
+        assert_eq!(diff.count(DraftDiffKind::Removed), 1);
+        assert_eq!(diff.slots_only_in_draft, 1);
+        assert_eq!(diff.slots_only_in_live, 1);
+
+        let removed = diff
+            .bookings
+            .iter()
+            .find(|booking| booking.kind == DraftDiffKind::Removed)
+            .unwrap();
+        assert_eq!(removed.sales_person_id, Uuid::from_u128(8));
+        assert_eq!(removed.live_booking_id, Some(Uuid::from_u128(1008)));
+        assert_eq!(removed.draft_slot_id, Uuid::from_u128(11));
+    }
+
+    #[test]
+    fn marks_point_at_the_slot_of_their_side() {
+        let live = plan(vec![slot(1, Weekday::Monday, 9, &[8])]);
+        let draft = plan(vec![slot(11, Weekday::Monday, 9, &[9])]);
+        let diff = DraftWeekDiff::new(&live, &draft);
+        let live_marks = diff.marks(true);
+        let draft_marks = diff.marks(false);
+        assert_eq!(live_marks.len(), 1);
+        assert_eq!(live_marks[0].slot_id, Uuid::from_u128(1));
+        assert_eq!(live_marks[0].kind, DraftDiffKind::Removed);
+        assert_eq!(draft_marks.len(), 1);
+        assert_eq!(draft_marks[0].slot_id, Uuid::from_u128(11));
+        assert_eq!(draft_marks[0].sales_person_id, Uuid::from_u128(9));
+    }
+}
@@ -0,0 +1,347 @@
+use std::rc::Rc;
+
+use uuid::Uuid;
+
+use super::{slot_edit::SlotEditItem, Weekday};
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+/// One slot in the structure editor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DraftSlot {
+    /// `Uuid::nil()` as id marks a slot which does not exist yet.
+    pub item: SlotEditItem,
+    pub selected: bool,
+    pub removed: bool,
+}
+
+impl DraftSlot {
+    pub fn is_new(&self) -> bool {
+        self.item.id.is_nil()
+    }
+}
+
+/// A write the structure editor has to send to the backend.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SlotStructureChange {
+    Create(SlotEditItem),
+    Update(SlotEditItem),
+    Delete(Uuid),
+}
+
+/// Outcome of saving a list of [`SlotStructureChange`]s.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlotStructureReport {
+    pub saved: usize,
+    /// New slots the backend refused because they overlap existing ones.
+    pub rejected: Vec<SlotEditItem>,
+    /// Changes which failed with an error; the others were still sent.
+    pub failed: Vec<(SlotStructureChange, Rc<str>)>,
+}
+
+/// Slots of one shiftplan in one week with the pending bulk edits.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlotStructureDraft {
+    pub original: Rc<[SlotEditItem]>,
+    pub slots: Vec<DraftSlot>,
+}
+
+fn minutes(time: time::Time) -> i64 {
+    i64::from(time.hour()) * 60 + i64::from(time.minute())
+}
+
+fn from_minutes(minutes: i64) -> Option<time::Time> {
+    time::Time::from_hms((minutes / 60) as u8, (minutes % 60) as u8, 0).ok()
+}
+
+impl SlotStructureDraft {
+    pub fn new(original: Rc<[SlotEditItem]>) -> Self {
+        let mut slots: Vec<DraftSlot> = original
+            .iter()
+            .map(|item| DraftSlot {
+                item: item.clone(),
+                selected: false,
+                removed: false,
+            })
+            .collect();
+        slots.sort_by_key(|slot| (slot.item.day_of_week.num_from_monday(), slot.item.from));
+        Self { original, slots }
+    }
+
+    pub fn selected_count(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.selected).count()
+    }
+
+    pub fn toggle(&mut self, index: usize) {
+        if let Some(slot) = self.slots.get_mut(index) {
+            slot.selected = !slot.selected;
+        }
+    }
+
+    /// Selects every remaining slot of `day`, or deselects them if all of
+    /// them are selected already.
+    pub fn toggle_day(&mut self, day: Weekday) {
+        let all_selected = self
+            .slots
+            .iter()
+            .filter(|slot| slot.item.day_of_week == day && !slot.removed)
+            .all(|slot| slot.selected);
+        for slot in self
+            .slots
+            .iter_mut()
+            .filter(|slot| slot.item.day_of_week == day && !slot.removed)
+        {
+            slot.selected = !all_selected;
+        }
+    }
+
+    /// Moves the selected slots by `delta` minutes. Nothing changes and
+    /// `false` is returned if a slot would leave its day.
+    pub fn shift_selected(&mut self, delta: i64) -> bool {
+        let fits = self.slots.iter().filter(|slot| slot.selected).all(|slot| {
+            minutes(slot.item.from) + delta >= 0 && minutes(slot.item.to) + delta < MINUTES_PER_DAY
+        });
+        if !fits {
+            return false;
+        }
+        for slot in self.slots.iter_mut().filter(|slot| slot.selected) {
+            if let (Some(from), Some(to)) = (
+                from_minutes(minutes(slot.item.from) + delta),
+                from_minutes(minutes(slot.item.to) + delta),
+            ) {
+                slot.item.from = from;
+                slot.item.to = to;
+            }
+        }
+        true
+    }
+
+    pub fn set_min_resources(&mut self, min_resources: u8) {
+        for slot in self.slots.iter_mut().filter(|slot| slot.selected) {
+            slot.item.min_resources = min_resources;
+        }
+    }
+
+    /// Marks the selected slots for deletion; selected new slots are dropped.
+    pub fn remove_selected(&mut self) {
+        self.slots.retain(|slot| !(slot.selected && slot.is_new()));
+        for slot in self.slots.iter_mut().filter(|slot| slot.selected) {
+            slot.removed = true;
+            slot.selected = false;
+        }
+    }
+
+    /// Copies the layout of `source` onto each of `targets`.
+    ///
+    /// With `shiftplan_id` set to another plan the copies are created there.
+    /// Otherwise `replace` marks the slots already on a target day for
+    /// deletion so the target day ends up with exactly the source layout.
+    pub fn duplicate_day(
+        &mut self,
+        source: Weekday,
+        targets: &[Weekday],
+        shiftplan_id: Option<Uuid>,
+        replace: bool,
+    ) {
+        let own_plan = self.original.first().and_then(|slot| slot.shiftplan_id);
+        let target_plan = shiftplan_id.or(own_plan);
+        let same_plan = target_plan == own_plan;
+        let layout: Vec<SlotEditItem> = self
+            .slots
+            .iter()
+            .filter(|slot| slot.item.day_of_week == source && !slot.removed)
+            .filter(|slot| slot.item.shiftplan_id == own_plan || own_plan.is_none())
+            .map(|slot| slot.item.clone())
+            .collect();
+        for target in targets
+            .iter()
+            .filter(|target| **target != source || !same_plan)
+        {
+            if same_plan && replace {
+                self.slots.retain(|slot| {
+                    !(slot.is_new()
+                        && slot.item.day_of_week == *target
+                        && slot.item.shiftplan_id == own_plan)
+                });
+                for slot in self.slots.iter_mut().filter(|slot| {
+                    slot.item.day_of_week == *target && slot.item.shiftplan_id == own_plan
+                }) {
+                    slot.removed = true;
+                    slot.selected = false;
+                }
+            }
+            for item in layout.iter() {
+                self.slots.push(DraftSlot {
+                    item: SlotEditItem {
+                        id: Uuid::nil(),
+                        day_of_week: *target,
+                        valid_to: None,
+                        version: Uuid::nil(),
+                        shiftplan_id: target_plan,
+                        ..item.clone()
+                    },
+                    selected: false,
+                    removed: false,
+                });
+            }
+        }
+        self.slots
+            .sort_by_key(|slot| (slot.item.day_of_week.num_from_monday(), slot.item.from));
+    }
+
+    /// Whether the draft slot differs from what the backend has.
+    pub fn is_changed(&self, slot: &DraftSlot) -> bool {
+        slot.is_new()
+            || slot.removed
+            || self
+                .original
+                .iter()
+                .find(|original| original.id == slot.item.id)
+                .is_some_and(|original| *original != slot.item)
+    }
+
+    /// Writes needed to turn the original structure into the draft. Deletes
+    /// come first so freed time ranges can be taken by moved or new slots.
+    pub fn changes(&self) -> Vec<SlotStructureChange> {
+        let deletes = self
+            .slots
+            .iter()
+            .filter(|slot| slot.removed && !slot.is_new())
+            .map(|slot| SlotStructureChange::Delete(slot.item.id));
+        let updates = self
+            .slots
+            .iter()
+            .filter(|slot| !slot.removed && !slot.is_new() && self.is_changed(slot))
+            .map(|slot| SlotStructureChange::Update(slot.item.clone()));
+        let creates = self
+            .slots
+            .iter()
+            .filter(|slot| !slot.removed && slot.is_new())
+            .map(|slot| SlotStructureChange::Create(slot.item.clone()));
+        deletes.chain(updates).chain(creates).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(id: u128, day_of_week: Weekday, from: u8, to: u8) -> SlotEditItem {
+        SlotEditItem {
+            id: Uuid::from_u128(id),
+            day_of_week,
+            from: time::Time::from_hms(from, 0, 0).unwrap(),
+            to: time::Time::from_hms(to, 0, 0).unwrap(),
+            min_resources: 1,
+            valid_from: time::macros::date!(2025 - 01 - 06),
+            valid_to: None,
+            version: Uuid::from_u128(id + 100),
+            shiftplan_id: Some(Uuid::from_u128(50)),
+        }
+    }
+
+    fn draft() -> SlotStructureDraft {
+        SlotStructureDraft::new(Rc::from([
+            item(2, Weekday::Monday, 13, 17),
+            item(1, Weekday::Monday, 9, 13),
+            item(3, Weekday::Tuesday, 10, 14),
+        ]))
+    }
+
+    #[test]
+    fn unchanged_draft_has_no_changes() {
+        let draft = draft();
+        assert_eq!(draft.slots[0].item.id, Uuid::from_u128(1));
+        assert!(draft.changes().is_empty());
+    }
+
+    #[test]
+    fn shift_and_min_resources_apply_to_selected_slots() {
+        let mut draft = draft();
+        draft.toggle_day(Weekday::Monday);
+        assert_eq!(draft.selected_count(), 2);
+        assert!(draft.shift_selected(30));
+        draft.set_min_resources(3);
+        assert_eq!(draft.slots[0].item.from, time::macros::time!(9:30));
+        assert_eq!(draft.slots[1].item.to, time::macros::time!(17:30));
+        let changes = draft.changes();
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().all(|change| matches!(
+            change,
+            SlotStructureChange::Update(item) if item.min_resources == 3
+        )));
+    }
+
+    #[test]
+    fn shift_refuses_to_leave_the_day() {
+        let mut draft = draft();
+        draft.toggle(0);
+        assert!(!draft.shift_selected(-10 * 60));
+        assert_eq!(draft.slots[0].item.from, time::macros::time!(9:00));
+    }
+
+    #[test]
+    fn duplicate_day_replaces_the_target_layout() {
+        let mut draft = draft();
+        draft.duplicate_day(
+            Weekday::Monday,
+            &[Weekday::Tuesday, Weekday::Wednesday],
+            None,
+            true,
+        );
+        let changes = draft.changes();
+        assert_eq!(changes[0], SlotStructureChange::Delete(Uuid::from_u128(3)));
+        let created: Vec<(Weekday, u8)> = changes
+            .iter()
+            .filter_map(|change| match change {
+                SlotStructureChange::Create(item) => Some((item.day_of_week, item.from.hour())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            created,
+            vec![
+                (Weekday::Tuesday, 9),
+                (Weekday::Tuesday, 13),
+                (Weekday::Wednesday, 9),
+                (Weekday::Wednesday, 13),
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_day_to_another_plan_keeps_the_own_plan() {
+        let mut draft = draft();
+        let other_plan = Uuid::from_u128(51);
+        draft.duplicate_day(
+            Weekday::Tuesday,
+            &[Weekday::Tuesday],
+            Some(other_plan),
+            true,
+        );
+        let changes = draft.changes();
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            SlotStructureChange::Create(item) if item.shiftplan_id == Some(other_plan)
+        ));
+    }
+
+    #[test]
+    fn removing_a_new_slot_drops_it() {
+        let mut draft = draft();
+        draft.duplicate_day(Weekday::Tuesday, &[Weekday::Friday], None, false);
+        let index = draft
+            .slots
+            .iter()
+            .position(|slot| slot.item.day_of_week == Weekday::Friday)
+            .unwrap();
+        draft.toggle(index);
+        draft.toggle(0);
+        draft.remove_selected();
+        assert_eq!(
+            draft.changes(),
+            vec![SlotStructureChange::Delete(Uuid::from_u128(1))]
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use std::ops::RangeInclusive;
+
+use crate::error::ShiftyError;
+
+use super::{slot_edit::SlotEditItem, week::Week};
+
+/// One slot and the weeks of the timeline it is valid in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlotTimelineRow {
+    pub slot: SlotEditItem,
+    /// Indices into [`SlotTimeline::weeks`], `None` if the slot is valid in
+    /// none of them.
+    pub active: Option<RangeInclusive<usize>>,
+}
+
+impl SlotTimelineRow {
+    pub fn is_active(&self, index: usize) -> bool {
+        self.active
+            .as_ref()
+            .is_some_and(|active| active.contains(&index))
+    }
+}
+
+/// Validity of every slot of a shiftplan over a range of weeks.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlotTimeline {
+    pub weeks: Vec<Week>,
+    /// Ordered by weekday, start time and `valid_from`.
+    pub rows: Vec<SlotTimelineRow>,
+    /// Indices of the weeks in which a slot starts or a slot ended the week
+    /// before.
+    pub changes: Vec<usize>,
+}
+
+fn active_in(slot: &SlotEditItem, monday: time::Date) -> bool {
+    let sunday = monday + time::Duration::days(6);
+    slot.valid_from <= sunday && slot.valid_to.is_none_or(|valid_to| valid_to >= monday)
+}
+
+/// Every ISO week of the years `from_year` through `until_year`.
+pub fn weeks_of_years(from_year: u32, until_year: u32) -> Result<Vec<Week>, ShiftyError> {
+    // December 28th always lies in the last ISO week of its year.
+    let last = time::Date::from_calendar_date(until_year as i32, time::Month::December, 28)?;
+    Week {
+        year: from_year,
+        week: 1,
+    }
+    .through(Week::of_date(last))
+}
+
+impl SlotTimeline {
+    pub fn build(weeks: Vec<Week>, slots: &[SlotEditItem]) -> Result<Self, ShiftyError> {
+        let mondays = weeks
+            .iter()
+            .map(Week::monday)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut rows: Vec<SlotTimelineRow> = slots
+            .iter()
+            .map(|slot| {
+                let first = mondays.iter().position(|monday| active_in(slot, *monday));
+                let last = mondays.iter().rposition(|monday| active_in(slot, *monday));
+                SlotTimelineRow {
+                    slot: slot.clone(),
+                    active: first.zip(last).map(|(first, last)| first..=last),
+                }
+            })
+            .collect();
+        rows.sort_by_key(|row| {
+            (
+                row.slot.day_of_week.num_from_monday(),
+                row.slot.from,
+                row.slot.valid_from,
+            )
+        });
+        let changes = (1..weeks.len())
+            .filter(|index| {
+                rows.iter()
+                    .any(|row| row.is_active(*index) != row.is_active(index - 1))
+            })
+            .collect();
+        Ok(Self {
+            weeks,
+            rows,
+            changes,
+        })
+    }
+
+    pub fn is_change(&self, index: usize) -> bool {
+        self.changes.contains(&index)
+    }
+
+    /// Number of slots starting in and ending right before week `index`.
+    pub fn change_summary(&self, index: usize) -> (usize, usize) {
+        let started = self
+            .rows
+            .iter()
+            .filter(|row| {
+                row.active
+                    .as_ref()
+                    .is_some_and(|active| *active.start() == index)
+            })
+            .count();
+        let ended = self
+            .rows
+            .iter()
+            .filter(|row| {
+                row.active
+                    .as_ref()
+                    .is_some_and(|active| *active.end() + 1 == index)
+            })
+            .count();
+        (started, ended)
+    }
+
+    /// Whether the ended slot at `row` can be made open-ended again without
+    /// overlapping a later slot at the same time.
+    pub fn can_reopen(&self, row: usize) -> bool {
+        let Some(slot) = self.rows.get(row).map(|row| &row.slot) else {
+            return false;
+        };
+        let Some(valid_to) = slot.valid_to else {
+            return false;
+        };
+        !self.rows.iter().any(|other| {
+            other.slot.id != slot.id
+                && other.slot.day_of_week == slot.day_of_week
+                && other.slot.from < slot.to
+                && slot.from < other.slot.to
+                && other
+                    .slot
+                    .valid_to
+                    .is_none_or(|other_to| other_to > valid_to)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Weekday;
+    use time::macros::{date, time};
+    use uuid::Uuid;
+
+    fn slot(
+        id: u128,
+        from: time::Time,
+        valid_from: time::Date,
+        valid_to: Option<time::Date>,
+    ) -> SlotEditItem {
+        SlotEditItem {
+            id: Uuid::from_u128(id),
+            day_of_week: Weekday::Monday,
+            from,
+            to: from + time::Duration::hours(3),
+            min_resources: 1,
+            valid_from,
+            valid_to,
+            version: Uuid::nil(),
+            shiftplan_id: None,
+        }
+    }
+
+    fn weeks() -> Vec<Week> {
+        Week {
+            year: 2025,
+            week: 1,
+        }
+        .through(Week {
+            year: 2025,
+            week: 8,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn rows_cover_their_validity_and_changes_are_detected() {
+        let timeline = SlotTimeline::build(
+            weeks(),
+            &[
+                // Ends after week 3, its successor starts in week 4.
+                slot(
+                    1,
+                    time!(9:00),
+                    date!(2024 - 06 - 03),
+                    Some(date!(2025 - 01 - 19)),
+                ),
+                slot(2, time!(9:00), date!(2025 - 01 - 20), None),
+                slot(
+                    3,
+                    time!(13:00),
+                    date!(2025 - 02 - 03),
+                    Some(date!(2025 - 02 - 09)),
+                ),
+            ],
+        )
+        .unwrap();
+        assert_eq!(timeline.rows[0].active, Some(0..=2));
+        assert_eq!(timeline.rows[1].active, Some(3..=7));
+        assert_eq!(timeline.rows[2].active, Some(5..=5));
+        assert_eq!(timeline.changes, vec![3, 5, 6]);
+        assert_eq!(timeline.change_summary(3), (1, 1));
+        assert_eq!(timeline.change_summary(6), (0, 1));
+    }
+
+    #[test]
+    fn slots_outside_the_range_have_no_active_weeks() {
+        let timeline = SlotTimeline::build(
+            weeks(),
+            &[slot(
+                1,
+                time!(9:00),
+                date!(2024 - 01 - 01),
+                Some(date!(2024 - 03 - 01)),
+            )],
+        )
+        .unwrap();
+        assert_eq!(timeline.rows[0].active, None);
+        assert!(timeline.changes.is_empty());
+    }
+
+    #[test]
+    fn weeks_of_years_include_week_53() {
+        let weeks = weeks_of_years(2026, 2026).unwrap();
+        assert_eq!(weeks.len(), 53);
+        assert_eq!(weeks.last().unwrap().week, 53);
+        assert_eq!(weeks_of_years(2025, 2026).unwrap().len(), 105);
+    }
+
+    #[test]
+    fn reopen_only_without_a_later_overlapping_slot() {
+        let timeline = SlotTimeline::build(
+            weeks(),
+            &[
+                slot(
+                    1,
+                    time!(9:00),
+                    date!(2024 - 06 - 03),
+                    Some(date!(2025 - 01 - 19)),
+                ),
+                slot(2, time!(10:00), date!(2025 - 01 - 20), None),
+                slot(
+                    3,
+                    time!(14:00),
+                    date!(2025 - 01 - 06),
+                    Some(date!(2025 - 01 - 26)),
+                ),
+            ],
+        )
+        .unwrap();
+        assert!(!timeline.can_reopen(0));
+        assert!(!timeline.can_reopen(1));
+        assert!(timeline.can_reopen(2));
+    }
+}
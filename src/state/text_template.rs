@@ -37,6 +37,7 @@ pub struct TextTemplate {
     pub template_engine: TemplateEngine,
     pub created_at: Option<time::PrimitiveDateTime>,
     pub created_by: Option<Rc<str>>,
+    pub version: Uuid,
 }
 
 impl From<&TextTemplateTO> for TextTemplate {
@@ -49,6 +50,7 @@ impl From<&TextTemplateTO> for TextTemplate {
             template_engine: TemplateEngine::from(&template.template_engine),
             created_at: template.created_at,
             created_by: template.created_by.as_ref().map(|s| s.to_string().into()),
+            version: template.version,
         }
     }
 }
@@ -69,6 +71,7 @@ impl TextTemplate {
             template_type: self.template_type.to_string().into(),
             template_text: self.template_text.to_string().into(),
             template_engine: TemplateEngineTO::from(&self.template_engine),
+            version: Some(self.version),
         }
     }
 }
@@ -124,6 +127,7 @@ mod tests {
             template_engine: TemplateEngine::MiniJinja,
             created_at: None,
             created_by: None,
+            version: Uuid::nil(),
         };
         let req = template.to_create_request();
         assert_eq!(req.template_engine, TemplateEngineTO::MiniJinja);
@@ -139,6 +143,7 @@ mod tests {
             template_engine: TemplateEngine::Tera,
             created_at: None,
             created_by: None,
+            version: Uuid::nil(),
         };
         let req = template.to_update_request();
         assert_eq!(req.template_engine, TemplateEngineTO::Tera);
@@ -205,6 +210,7 @@ mod tests {
             template_type: "billing-period".into(),
             template_text: "test".into(),
             template_engine: TemplateEngineTO::MiniJinja,
+            version: None,
         };
         let json = serde_json::to_value(&req).unwrap();
         assert_eq!(json["template_engine"], "minijinja");
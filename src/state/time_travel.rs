@@ -0,0 +1,238 @@
+use time::PrimitiveDateTime;
+use uuid::Uuid;
+
+use super::{
+    booking_log::BookingLog,
+    shiftplan::{Booking, SalesPerson},
+    Shiftplan, Slot,
+};
+
+/// Whether the logged booking existed at `moment`.
+pub fn active_at(entry: &BookingLog, moment: PrimitiveDateTime) -> bool {
+    entry.created <= moment && entry.deleted.is_none_or(|deleted| deleted > moment)
+}
+
+/// Every moment at which a booking of the log was created or deleted,
+/// sorted and without duplicates.
+pub fn change_moments(log: &[BookingLog]) -> Vec<PrimitiveDateTime> {
+    let mut moments: Vec<PrimitiveDateTime> = log
+        .iter()
+        .flat_map(|entry| std::iter::once(entry.created).chain(entry.deleted))
+        .collect();
+    moments.sort();
+    moments.dedup();
+    moments
+}
+
+/// Index of the last change at or before `moment`, `0` if there is none.
+pub fn moment_index(moments: &[PrimitiveDateTime], moment: PrimitiveDateTime) -> usize {
+    moments
+        .iter()
+        .rposition(|change| *change <= moment)
+        .unwrap_or(0)
+}
+
+/// The plan with the bookings it had at `moment`.
+///
+/// Entries are matched to the slots of `plan` by their slot id and to
+/// `sales_persons` by their id, so bookings of other shiftplans with the
+/// same times and people sharing a name stay apart. Entries without a slot
+/// id cannot be placed and are left out.
+pub fn plan_at(
+    plan: &Shiftplan,
+    log: &[BookingLog],
+    sales_persons: &[SalesPerson],
+    moment: PrimitiveDateTime,
+) -> Shiftplan {
+    let slots = plan
+        .slots
+        .iter()
+        .map(|slot| Slot {
+            bookings: log
+                .iter()
+                .filter(|entry| active_at(entry, moment) && entry.slot_id == Some(slot.id))
+                .map(|entry| {
+                    let sales_person = sales_persons
+                        .iter()
+                        .find(|sales_person| Some(sales_person.id) == entry.sales_person_id);
+                    Booking {
+                        id: Uuid::nil(),
+                        sales_person_id: entry.sales_person_id.unwrap_or_default(),
+                        slot_id: slot.id,
+                        week: plan.week,
+                        year: plan.year,
+                        label: entry.sales_person_name.clone(),
+                        background_color: sales_person
+                            .map(|sales_person| sales_person.background_color.clone())
+                            .unwrap_or_else(|| "#ffffff".into()),
+                        self_added: false,
+                        created: Some(entry.created),
+                        created_by: entry.created_by.clone(),
+                    }
+                })
+                .collect(),
+            ..slot.clone()
+        })
+        .collect();
+    Shiftplan {
+        week: plan.week,
+        year: plan.year,
+        slots,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Weekday;
+    use time::macros::{datetime, time};
+
+    const SLOT: Uuid = Uuid::from_u128(1);
+    const OTHER_PLANS_SLOT: Uuid = Uuid::from_u128(2);
+    const ANNA: Uuid = Uuid::from_u128(7);
+
+    fn entry(
+        name: &str,
+        sales_person_id: Option<Uuid>,
+        slot_id: Uuid,
+        created: PrimitiveDateTime,
+        deleted: Option<PrimitiveDateTime>,
+    ) -> BookingLog {
+        BookingLog {
+            year: 2025,
+            calendar_week: 23,
+            day_of_week: Weekday::Monday,
+            sales_person_name: name.into(),
+            time_from: time!(9:00),
+            time_to: time!(12:00),
+            created,
+            deleted,
+            created_by: None,
+            deleted_by: None,
+            slot_id: Some(slot_id),
+            sales_person_id,
+        }
+    }
+
+    fn plan() -> Shiftplan {
+        Shiftplan {
+            week: 23,
+            year: 2025,
+            slots: [Slot {
+                id: SLOT,
+                day_of_week: Weekday::Monday,
+                from: time!(9:00),
+                to: time!(12:00),
+                bookings: [].into(),
+                min_resources: 2,
+            }]
+            .into(),
+        }
+    }
+
+    fn log() -> Vec<BookingLog> {
+        vec![
+            entry(
+                "Anna",
+                Some(ANNA),
+                SLOT,
+                datetime!(2025-06-01 10:00),
+                Some(datetime!(2025-06-03 10:00)),
+            ),
+            entry("Ben", None, SLOT, datetime!(2025-06-02 10:00), None),
+            // Another shiftplan's slot.
+            entry(
+                "Clara",
+                None,
+                OTHER_PLANS_SLOT,
+                datetime!(2025-06-01 10:00),
+                None,
+            ),
+        ]
+    }
+
+    #[test]
+    fn plan_at_shows_the_bookings_of_that_moment() {
+        let persons = [SalesPerson {
+            id: ANNA,
+            name: "Anna".into(),
+            background_color: "#f3b4c4".into(),
+            ..Default::default()
+        }];
+        let labels = |moment| {
+            plan_at(&plan(), &log(), &persons, moment).slots[0]
+                .bookings
+                .iter()
+                .map(|booking| booking.label.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert!(labels(datetime!(2025-05-31 10:00)).is_empty());
+        assert_eq!(labels(datetime!(2025-06-02 12:00)), vec!["Anna", "Ben"]);
+        assert_eq!(labels(datetime!(2025-06-03 10:00)), vec!["Ben"]);
+
+        let monday = plan_at(&plan(), &log(), &persons, datetime!(2025-06-02 12:00));
+        assert_eq!(monday.slots[0].bookings[0].sales_person_id, ANNA);
+        assert_eq!(monday.slots[0].bookings[1].sales_person_id, Uuid::nil());
+    }
+
+    #[test]
+    fn plan_at_keeps_other_shiftplans_and_namesakes_apart() {
+        // Another plan has a slot at the same time, booked by a second Anna.
+        let other_anna = Uuid::from_u128(9);
+        let persons = [
+            SalesPerson {
+                id: other_anna,
+                name: "Anna".into(),
+                background_color: "#c4d7f3".into(),
+                ..Default::default()
+            },
+            SalesPerson {
+                id: ANNA,
+                name: "Anna".into(),
+                background_color: "#f3b4c4".into(),
+                ..Default::default()
+            },
+        ];
+        let mut log = log();
+        log.push(entry(
+            "Anna",
+            Some(other_anna),
+            OTHER_PLANS_SLOT,
+            datetime!(2025-06-01 10:00),
+            None,
+        ));
+        // An entry of a backend which does not report slots.
+        log.push(BookingLog {
+            slot_id: None,
+            ..entry("Dora", None, SLOT, datetime!(2025-06-01 10:00), None)
+        });
+
+        let monday = plan_at(&plan(), &log, &persons, datetime!(2025-06-02 12:00));
+        let bookings = &monday.slots[0].bookings;
+        assert_eq!(
+            bookings
+                .iter()
+                .map(|booking| booking.label.to_string())
+                .collect::<Vec<_>>(),
+            vec!["Anna", "Ben"]
+        );
+        assert_eq!(bookings[0].sales_person_id, ANNA);
+        assert_eq!(bookings[0].background_color.as_ref(), "#f3b4c4");
+    }
+
+    #[test]
+    fn moments_are_sorted_and_indexed() {
+        let moments = change_moments(&log());
+        assert_eq!(
+            moments,
+            vec![
+                datetime!(2025-06-01 10:00),
+                datetime!(2025-06-02 10:00),
+                datetime!(2025-06-03 10:00),
+            ]
+        );
+        assert_eq!(moment_index(&moments, datetime!(2025-06-02 11:00)), 1);
+        assert_eq!(moment_index(&moments, datetime!(2025-05-01 11:00)), 0);
+        assert_eq!(moment_index(&moments, datetime!(2025-07-01 11:00)), 2);
+    }
+}
@@ -0,0 +1,211 @@
+//! Field-wise comparison and merge of the two sides of a [`VersionConflict`].
+//!
+//! Entities are compared through their JSON representation, so every
+//! versioned transfer object works without per-type code.
+
+use std::{collections::BTreeSet, rc::Rc};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::error::{ConflictEntity, ShiftyError, VersionConflict};
+
+/// Bookkeeping fields which always come from the server.
+const SERVER_FIELDS: &[&str] = &[
+    "id",
+    "$version",
+    "created",
+    "deleted",
+    "created_at",
+    "created_by",
+    "deleted_by",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDiff {
+    pub field: Rc<str>,
+    pub yours: Rc<str>,
+    pub theirs: Rc<str>,
+}
+
+fn to_json<T: Serialize>(entity: &T) -> Result<Value, ShiftyError> {
+    serde_json::to_value(entity).map_err(|err| ShiftyError::Backend(err.to_string()))
+}
+
+fn from_json<T: DeserializeOwned>(value: Value) -> Result<T, ShiftyError> {
+    serde_json::from_value(value).map_err(|err| ShiftyError::Backend(err.to_string()))
+}
+
+impl ConflictEntity {
+    fn to_json(&self) -> Result<Value, ShiftyError> {
+        match self {
+            ConflictEntity::SalesPerson(entity) => to_json(entity),
+            ConflictEntity::EmployeeWorkDetails(entity) => to_json(entity),
+            ConflictEntity::ExtraHours(entity) => to_json(entity),
+            ConflictEntity::CustomExtraHours(entity) => to_json(entity),
+            ConflictEntity::TextTemplate(entity) => to_json(entity),
+            ConflictEntity::WeekMessage(entity) => to_json(entity),
+            ConflictEntity::Shiftplan(entity) => to_json(entity),
+        }
+    }
+
+    /// An entity of the same kind read from `value`.
+    fn with_json(&self, value: Value) -> Result<ConflictEntity, ShiftyError> {
+        Ok(match self {
+            ConflictEntity::SalesPerson(_) => ConflictEntity::SalesPerson(from_json(value)?),
+            ConflictEntity::EmployeeWorkDetails(_) => {
+                ConflictEntity::EmployeeWorkDetails(from_json(value)?)
+            }
+            ConflictEntity::ExtraHours(_) => ConflictEntity::ExtraHours(from_json(value)?),
+            ConflictEntity::CustomExtraHours(_) => {
+                ConflictEntity::CustomExtraHours(from_json(value)?)
+            }
+            ConflictEntity::TextTemplate(_) => ConflictEntity::TextTemplate(from_json(value)?),
+            ConflictEntity::WeekMessage(_) => ConflictEntity::WeekMessage(from_json(value)?),
+            ConflictEntity::Shiftplan(_) => ConflictEntity::Shiftplan(from_json(value)?),
+        })
+    }
+}
+
+fn display_value(value: &Value) -> Rc<str> {
+    match value {
+        Value::Null => "–".into(),
+        Value::String(value) => value.as_str().into(),
+        value => value.to_string().into(),
+    }
+}
+
+/// `is_paid` becomes `Is paid`.
+pub fn field_label(field: &str) -> String {
+    let label = field.replace('_', " ");
+    let mut chars = label.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => label,
+    }
+}
+
+impl VersionConflict {
+    /// The user-editable fields in which both sides differ, sorted by name.
+    pub fn diffs(&self) -> Result<Vec<FieldDiff>, ShiftyError> {
+        let yours = self.yours.to_json()?;
+        let theirs = self.theirs.to_json()?;
+        let (Some(yours), Some(theirs)) = (yours.as_object(), theirs.as_object()) else {
+            return Ok(Vec::new());
+        };
+        let mut fields: Vec<&String> = yours.keys().chain(theirs.keys()).collect();
+        fields.sort();
+        fields.dedup();
+        Ok(fields
+            .into_iter()
+            .filter(|field| !SERVER_FIELDS.contains(&field.as_str()))
+            .filter_map(|field| {
+                let mine = yours.get(field).unwrap_or(&Value::Null);
+                let other = theirs.get(field).unwrap_or(&Value::Null);
+                (mine != other).then(|| FieldDiff {
+                    field: field.as_str().into(),
+                    yours: display_value(mine),
+                    theirs: display_value(other),
+                })
+            })
+            .collect())
+    }
+
+    /// The server's entity with the fields in `keep_yours` taken from the
+    /// user's side. It carries the server's version, so saving it succeeds
+    /// unless the entity changed again.
+    pub fn merged(&self, keep_yours: &BTreeSet<Rc<str>>) -> Result<ConflictEntity, ShiftyError> {
+        let yours = self.yours.to_json()?;
+        let mut merged = self.theirs.to_json()?;
+        if let (Some(yours), Some(merged)) = (yours.as_object(), merged.as_object_mut()) {
+            for field in keep_yours.iter() {
+                if SERVER_FIELDS.contains(&field.as_ref()) {
+                    continue;
+                }
+                match yours.get(field.as_ref()) {
+                    Some(value) => merged.insert(field.to_string(), value.clone()),
+                    None => merged.remove(field.as_ref()),
+                };
+            }
+        }
+        self.theirs.with_json(merged)
+    }
+
+    /// The user's side with the server's version.
+    pub fn overwrite(&self) -> Result<ConflictEntity, ShiftyError> {
+        let fields = self
+            .diffs()?
+            .into_iter()
+            .map(|diff| diff.field)
+            .collect::<BTreeSet<_>>();
+        self.merged(&fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rest_types::SalesPersonTO;
+    use uuid::Uuid;
+
+    fn sales_person(name: &str, color: &str, version: u128) -> SalesPersonTO {
+        SalesPersonTO {
+            id: Uuid::from_u128(1),
+            name: name.into(),
+            background_color: color.into(),
+            is_paid: Some(true),
+            inactive: false,
+            deleted: None,
+            version: Uuid::from_u128(version),
+        }
+    }
+
+    fn conflict() -> VersionConflict {
+        VersionConflict {
+            yours: ConflictEntity::SalesPerson(sales_person("Anna B.", "#ff0000", 1)),
+            theirs: ConflictEntity::SalesPerson(sales_person("Anna", "#00ff00", 2)),
+        }
+    }
+
+    #[test]
+    fn diffs_list_changed_fields_without_bookkeeping() {
+        let diffs = conflict().diffs().unwrap();
+        assert_eq!(
+            diffs,
+            vec![
+                FieldDiff {
+                    field: "background_color".into(),
+                    yours: "#ff0000".into(),
+                    theirs: "#00ff00".into(),
+                },
+                FieldDiff {
+                    field: "name".into(),
+                    yours: "Anna B.".into(),
+                    theirs: "Anna".into(),
+                },
+            ]
+        );
+        assert_eq!(field_label("background_color"), "Background color");
+    }
+
+    #[test]
+    fn merge_keeps_chosen_fields_and_the_server_version() {
+        let conflict = conflict();
+        let ConflictEntity::SalesPerson(merged) = conflict
+            .merged(&BTreeSet::from([Rc::from("name")]))
+            .unwrap()
+        else {
+            panic!("expected a sales person");
+        };
+        assert_eq!(merged.name.as_ref(), "Anna B.");
+        assert_eq!(merged.background_color.as_ref(), "#00ff00");
+        assert_eq!(merged.version, Uuid::from_u128(2));
+
+        let ConflictEntity::SalesPerson(overwritten) = conflict.overwrite().unwrap() else {
+            panic!("expected a sales person");
+        };
+        assert_eq!(overwritten.name.as_ref(), "Anna B.");
+        assert_eq!(overwritten.background_color.as_ref(), "#ff0000");
+        assert_eq!(overwritten.version, Uuid::from_u128(2));
+    }
+}
@@ -1,5 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use crate::error::ShiftyError;
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Week {
     pub year: u32,
     pub week: u8,
@@ -16,4 +19,98 @@ impl Week {
             time::Date::from_iso_week_date(self.year as i32, self.week, time::Weekday::Sunday)?;
         Ok(date)
     }
+
+    /// The ISO week containing `date`.
+    pub fn of_date(date: time::Date) -> Self {
+        let (year, week, _) = date.to_iso_week_date();
+        Self {
+            year: year as u32,
+            week,
+        }
+    }
+
+    pub fn next(&self) -> Result<Self, ShiftyError> {
+        Ok(Self::of_date(self.monday()? + time::Duration::weeks(1)))
+    }
+
+    pub fn previous(&self) -> Result<Self, ShiftyError> {
+        Ok(Self::of_date(self.monday()? - time::Duration::weeks(1)))
+    }
+
+    /// All weeks from `self` through `until`, both inclusive. Empty if
+    /// `until` lies before `self`.
+    pub fn through(&self, until: Week) -> Result<Vec<Week>, ShiftyError> {
+        let mut weeks = Vec::new();
+        let mut current = *self;
+        while current <= until {
+            weeks.push(current);
+            current = current.next()?;
+        }
+        Ok(weeks)
+    }
+
+    /// Parses the `2025-W23` format used by `<input type="week">`.
+    pub fn parse_iso(value: &str) -> Option<Self> {
+        let (year, week) = value.trim().split_once("-W")?;
+        let week = Self {
+            year: year.parse().ok()?,
+            week: week.parse().ok()?,
+        };
+        week.monday().ok().map(|_| week)
+    }
+
+    /// Formats the week as `2025-W23`.
+    pub fn to_iso_string(self) -> String {
+        format!("{:04}-W{:02}", self.year, self.week)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_and_previous_cross_year_boundaries() {
+        let last_2020 = Week {
+            year: 2020,
+            week: 53,
+        };
+        let first_2021 = Week {
+            year: 2021,
+            week: 1,
+        };
+        assert_eq!(last_2020.next().unwrap(), first_2021);
+        assert_eq!(first_2021.previous().unwrap(), last_2020);
+    }
+
+    #[test]
+    fn through_is_inclusive_and_empty_for_reversed_ranges() {
+        let from = Week {
+            year: 2024,
+            week: 52,
+        };
+        let until = Week {
+            year: 2025,
+            week: 2,
+        };
+        assert_eq!(from.through(until).unwrap().len(), 3);
+        assert!(until.through(from).unwrap().is_empty());
+    }
+
+    #[test]
+    fn iso_string_round_trips() {
+        let week = Week {
+            year: 2025,
+            week: 3,
+        };
+        assert_eq!(week.to_iso_string(), "2025-W03");
+        assert_eq!(Week::parse_iso("2025-W03"), Some(week));
+    }
+
+    #[test]
+    fn parse_iso_rejects_invalid_weeks() {
+        assert_eq!(Week::parse_iso("2025-W54"), None);
+        assert_eq!(Week::parse_iso("2025-23"), None);
+        assert_eq!(Week::parse_iso(""), None);
+    }
 }
@@ -0,0 +1,284 @@
+use std::rc::Rc;
+
+use uuid::Uuid;
+
+use crate::error::ShiftyError;
+
+use super::{
+    booking_history::BookingKey, sales_person_available::SalesPersonUnavailable, week::Week,
+    Shiftplan, Weekday,
+};
+
+/// Maximum number of target weeks a single copy may cover.
+pub const WEEK_COPY_MAX_TARGET_WEEKS: usize = 26;
+
+/// What the planner asked the copy dialog for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WeekCopyRequest {
+    pub source: Week,
+    pub target_from: Week,
+    pub target_until: Week,
+    /// Restricts the copy to a single shiftplan; `None` copies all plans.
+    pub shiftplan_id: Option<Uuid>,
+    pub skip_unavailable: bool,
+}
+
+impl WeekCopyRequest {
+    /// The weeks the bookings are copied into, without the source week.
+    /// Fails if these are more than [`WEEK_COPY_MAX_TARGET_WEEKS`].
+    pub fn target_weeks(&self) -> Result<Vec<Week>, ShiftyError> {
+        let weeks: Vec<Week> = self
+            .target_from
+            .through(self.target_until)?
+            .into_iter()
+            .filter(|week| *week != self.source)
+            .collect();
+        if weeks.len() > WEEK_COPY_MAX_TARGET_WEEKS {
+            return Err(ShiftyError::WeekRangeTooLong {
+                weeks: weeks.len(),
+                max: WEEK_COPY_MAX_TARGET_WEEKS,
+            });
+        }
+        Ok(weeks)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WeekCopyStatus {
+    /// The booking will be created.
+    Create,
+    /// The booking will be created although the person marked the day as
+    /// unavailable.
+    Conflict,
+    /// The person marked the day as unavailable, so the booking is skipped.
+    SkippedUnavailable,
+    /// The person is already booked in the target slot.
+    AlreadyBooked,
+}
+
+impl WeekCopyStatus {
+    pub fn creates_booking(&self) -> bool {
+        matches!(self, WeekCopyStatus::Create | WeekCopyStatus::Conflict)
+    }
+}
+
+/// One booking of the source week as it would land in a target week.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProposedBooking {
+    pub key: BookingKey,
+    pub sales_person_name: Rc<str>,
+    pub day_of_week: Weekday,
+    pub from: time::Time,
+    pub to: time::Time,
+    pub status: WeekCopyStatus,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeekCopyPreviewWeek {
+    pub week: Week,
+    pub bookings: Vec<ProposedBooking>,
+}
+
+impl WeekCopyPreviewWeek {
+    pub fn count(&self, status: WeekCopyStatus) -> usize {
+        self.bookings
+            .iter()
+            .filter(|booking| booking.status == status)
+            .count()
+    }
+}
+
+/// Result of a dry run of a [`WeekCopyRequest`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WeekCopyPreview {
+    pub weeks: Vec<WeekCopyPreviewWeek>,
+}
+
+impl WeekCopyPreview {
+    /// The bookings which will be created when the preview is committed.
+    pub fn to_create(&self) -> Rc<[BookingKey]> {
+        self.weeks
+            .iter()
+            .flat_map(|week| week.bookings.iter())
+            .filter(|booking| booking.status.creates_booking())
+            .map(|booking| booking.key)
+            .collect()
+    }
+}
+
+/// Plans how the bookings of `source` land in `target`, both being the same
+/// shiftplan in two different weeks.
+///
+/// Bookings are only copied into slots which still exist in the target
+/// week. `unavailable` holds the unavailable days of the target week.
+pub fn plan_week_copy(
+    source: &Shiftplan,
+    target: &Shiftplan,
+    unavailable: &[SalesPersonUnavailable],
+    skip_unavailable: bool,
+) -> Vec<ProposedBooking> {
+    let mut proposed = Vec::new();
+    for source_slot in source.slots.iter() {
+        let Some(target_slot) = target.slots.iter().find(|slot| slot.id == source_slot.id) else {
+            continue;
+        };
+        for booking in source_slot.bookings.iter() {
+            let already_booked = target_slot
+                .bookings
+                .iter()
+                .any(|existing| existing.sales_person_id == booking.sales_person_id);
+            let is_unavailable = unavailable.iter().any(|day| {
+                day.sales_person_id == booking.sales_person_id
+                    && day.day_of_week == target_slot.day_of_week
+            });
+            let status = if already_booked {
+                WeekCopyStatus::AlreadyBooked
+            } else if is_unavailable && skip_unavailable {
+                WeekCopyStatus::SkippedUnavailable
+            } else if is_unavailable {
+                WeekCopyStatus::Conflict
+            } else {
+                WeekCopyStatus::Create
+            };
+            proposed.push(ProposedBooking {
+                key: BookingKey {
+                    sales_person_id: booking.sales_person_id,
+                    slot_id: target_slot.id,
+                    week: target.week,
+                    year: target.year,
+                },
+                sales_person_name: booking.label.clone(),
+                day_of_week: target_slot.day_of_week,
+                from: target_slot.from,
+                to: target_slot.to,
+                status,
+            });
+        }
+    }
+    proposed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{shiftplan::Booking, Slot};
+
+    fn booking(sales_person: u128, slot_id: Uuid, week: u8) -> Booking {
+        Booking {
+            id: Uuid::new_v4(),
+            sales_person_id: Uuid::from_u128(sales_person),
+            slot_id,
+            week,
+            year: 2025,
+            label: format!("P{sales_person}").into(),
+            background_color: "#FFF".into(),
+            self_added: false,
+            created: None,
+            created_by: None,
+        }
+    }
+
+    fn plan(week: u8, slots: &[(u128, Weekday, &[u128])]) -> Shiftplan {
+        Shiftplan {
+            week,
+            year: 2025,
+            slots: slots
+                .iter()
+                .map(|(id, day_of_week, sales_persons)| {
+                    let id = Uuid::from_u128(*id);
+                    Slot {
+                        id,
+                        day_of_week: *day_of_week,
+                        from: time::Time::from_hms(9, 0, 0).unwrap(),
+                        to: time::Time::from_hms(12, 0, 0).unwrap(),
+                        bookings: sales_persons
+                            .iter()
+                            .map(|sales_person| booking(*sales_person, id, week))
+                            .collect(),
+                        min_resources: 1,
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    fn unavailable(sales_person: u128, day_of_week: Weekday) -> SalesPersonUnavailable {
+        SalesPersonUnavailable {
+            id: Uuid::new_v4(),
+            sales_person_id: Uuid::from_u128(sales_person),
+            year: 2025,
+            week: 24,
+            day_of_week,
+            version: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn copies_into_the_target_week() {
+        let source = plan(23, &[(10, Weekday::Monday, &[1, 2])]);
+        let target = plan(24, &[(10, Weekday::Monday, &[])]);
+        let proposed = plan_week_copy(&source, &target, &[], true);
+        assert_eq!(proposed.len(), 2);
+        assert!(proposed
+            .iter()
+            .all(|booking| booking.status == WeekCopyStatus::Create && booking.key.week == 24));
+    }
+
+    #[test]
+    fn skips_slots_missing_in_the_target_week() {
+        let source = plan(
+            23,
+            &[(10, Weekday::Monday, &[1]), (11, Weekday::Tuesday, &[1])],
+        );
+        let target = plan(24, &[(10, Weekday::Monday, &[])]);
+        let proposed = plan_week_copy(&source, &target, &[], true);
+        assert_eq!(proposed.len(), 1);
+        assert_eq!(proposed[0].key.slot_id, Uuid::from_u128(10));
+    }
+
+    #[test]
+    fn marks_existing_bookings() {
+        let source = plan(23, &[(10, Weekday::Monday, &[1])]);
+        let target = plan(24, &[(10, Weekday::Monday, &[1])]);
+        let proposed = plan_week_copy(&source, &target, &[], true);
+        assert_eq!(proposed[0].status, WeekCopyStatus::AlreadyBooked);
+    }
+
+    #[test]
+    fn unavailable_people_are_skipped_or_flagged() {
+        let source = plan(23, &[(10, Weekday::Monday, &[1])]);
+        let target = plan(24, &[(10, Weekday::Monday, &[])]);
+        let days = [unavailable(1, Weekday::Monday)];
+        assert_eq!(
+            plan_week_copy(&source, &target, &days, true)[0].status,
+            WeekCopyStatus::SkippedUnavailable
+        );
+        assert_eq!(
+            plan_week_copy(&source, &target, &days, false)[0].status,
+            WeekCopyStatus::Conflict
+        );
+        let other_day = [unavailable(1, Weekday::Tuesday)];
+        assert_eq!(
+            plan_week_copy(&source, &target, &other_day, true)[0].status,
+            WeekCopyStatus::Create
+        );
+    }
+
+    #[test]
+    fn to_create_contains_new_and_conflicting_bookings() {
+        let source = plan(23, &[(10, Weekday::Monday, &[1, 2, 3])]);
+        let target = plan(24, &[(10, Weekday::Monday, &[3])]);
+        let days = [unavailable(2, Weekday::Monday)];
+        let preview = WeekCopyPreview {
+            weeks: vec![WeekCopyPreviewWeek {
+                week: Week {
+                    year: 2025,
+                    week: 24,
+                },
+                bookings: plan_week_copy(&source, &target, &days, false),
+            }],
+        };
+        assert_eq!(preview.to_create().len(), 2);
+        assert_eq!(preview.weeks[0].count(WeekCopyStatus::AlreadyBooked), 1);
+    }
+}
@@ -0,0 +1,27 @@
+use std::rc::Rc;
+
+use rest_types::WeekPublicationTO;
+use uuid::Uuid;
+
+/// A published calendar week. Its bookings are closed for everyone without
+/// the `shiftplanner` privilege.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeekPublication {
+    pub id: Uuid,
+    pub year: u32,
+    pub week: u8,
+    pub published_at: Option<time::PrimitiveDateTime>,
+    pub published_by: Option<Rc<str>>,
+}
+
+impl From<&WeekPublicationTO> for WeekPublication {
+    fn from(publication: &WeekPublicationTO) -> Self {
+        Self {
+            id: publication.id,
+            year: publication.year,
+            week: publication.calendar_week,
+            published_at: publication.published_at,
+            published_by: publication.published_by.as_deref().map(Rc::from),
+        }
+    }
+}
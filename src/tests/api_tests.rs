@@ -52,6 +52,8 @@ mod unit_tests {
             is_prod: false,
             env_short_description: "TEST".into(),
             show_vacation: true,
+            demo: false,
+            requests: Default::default(),
         };
         
         assert_eq!(config.backend.as_ref(), "http://localhost:3000");
@@ -68,6 +70,8 @@ mod unit_tests {
             is_prod: true,
             env_short_description: "PROD".into(),
             show_vacation: false,
+            demo: false,
+            requests: Default::default(),
         };
         
         assert!(prod_config.is_prod);
@@ -236,6 +240,8 @@ mod unit_tests {
                 is_prod,
                 env_short_description: env.into(),
                 show_vacation: !is_prod,
+                demo: false,
+                requests: Default::default(),
             };
             
             assert_eq!(config.env_short_description.as_ref(), env);
@@ -89,6 +89,7 @@ mod integration_tests {
             template_engine: TemplateEngine::Tera,
             created_at: None,
             created_by: None,
+            version: Uuid::nil(),
         };
 
         let employee_template = TextTemplate {
@@ -99,6 +100,7 @@ mod integration_tests {
             template_engine: TemplateEngine::Tera,
             created_at: None,
             created_by: None,
+            version: Uuid::nil(),
         };
 
         // Add templates to store
@@ -143,6 +145,8 @@ mod integration_tests {
             is_prod: false,
             env_short_description: "DEV".into(),
             show_vacation: true,
+            demo: false,
+            requests: Default::default(),
         };
 
         let auth_info = AuthInfo {
@@ -184,6 +188,8 @@ mod integration_tests {
             is_prod: false,
             env_short_description: "DEV".into(),
             show_vacation: true,
+            demo: false,
+            requests: Default::default(),
         };
 
         let prod_config = Config {
@@ -192,6 +198,8 @@ mod integration_tests {
             is_prod: true,
             env_short_description: "PROD".into(),
             show_vacation: false,
+            demo: false,
+            requests: Default::default(),
         };
 
         // Development should show vacation features
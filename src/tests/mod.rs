@@ -6,7 +6,7 @@ pub mod week_tests;
 #[cfg(test)]
 mod unit_tests {
     use crate::state::auth_info::AuthInfo;
-    use crate::state::config::Config;
+    use crate::state::config::{Config, RequestPolicy};
     use std::rc::Rc;
 
     #[test]
@@ -41,6 +41,8 @@ mod unit_tests {
             is_prod: false,
             env_short_description: "TEST".into(),
             show_vacation: true,
+            demo: false,
+            requests: Default::default(),
         };
 
         assert_eq!(config.backend.as_ref(), "http://localhost:3000");
@@ -48,6 +50,64 @@ mod unit_tests {
         assert!(!config.is_prod);
     }
 
+    #[test]
+    fn test_demo_config_needs_no_backend() {
+        let config: Config = serde_json::from_str(r#"{"demo": true}"#).unwrap();
+        assert!(config.demo);
+        assert!(config.backend.is_empty());
+        assert!(config.is_loaded());
+
+        let config: Config = serde_json::from_str(r#"{"backend": "http://localhost"}"#).unwrap();
+        assert!(!config.demo);
+        assert!(config.is_loaded());
+        assert!(!Config::default().is_loaded());
+    }
+
+    #[test]
+    fn test_request_policy_from_config() {
+        let config: Config = serde_json::from_str(r#"{"backend": "http://localhost"}"#).unwrap();
+        assert_eq!(config.requests, RequestPolicy::default());
+        assert_eq!(Config::default().requests, RequestPolicy::default());
+
+        let config: Config = serde_json::from_str(
+            r#"{"backend": "http://localhost", "requests": {"timeout_ms": 3000, "retries": 0}}"#,
+        )
+        .unwrap();
+        assert_eq!(config.requests.timeout_ms, 3000);
+        assert_eq!(config.requests.retries, 0);
+        assert_eq!(
+            config.requests.backoff_ms,
+            RequestPolicy::default().backoff_ms
+        );
+    }
+
+    #[test]
+    fn test_request_backoff_doubles() {
+        let policy = RequestPolicy {
+            timeout_ms: 1000,
+            retries: 3,
+            backoff_ms: 200,
+        };
+
+        assert_eq!(policy.backoff_ms(0), 200);
+        assert_eq!(policy.backoff_ms(1), 400);
+        assert_eq!(policy.backoff_ms(2), 800);
+        assert_eq!(policy.backoff_ms(40), u32::MAX);
+    }
+
+    #[test]
+    fn test_only_gateway_failures_are_retried() {
+        use crate::error::is_gateway_failure;
+        use reqwest::StatusCode;
+
+        assert!(is_gateway_failure(StatusCode::BAD_GATEWAY));
+        assert!(is_gateway_failure(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(is_gateway_failure(StatusCode::GATEWAY_TIMEOUT));
+        assert!(!is_gateway_failure(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!is_gateway_failure(StatusCode::CONFLICT));
+        assert!(!is_gateway_failure(StatusCode::OK));
+    }
+
     #[test]
     fn test_config_default() {
         let config = Config::default();
@@ -125,6 +185,7 @@ mod service_tests {
             template_engine: TemplateEngine::Tera,
             created_at: None,
             created_by: None,
+            version: Uuid::nil(),
         };
 
         assert_eq!(
@@ -25,6 +25,7 @@ mod text_template_service_tests {
             template_engine: TemplateEngine::Tera,
             created_at: None,
             created_by: None,
+            version: Uuid::nil(),
         };
         
         assert!(template.name.is_some());
@@ -46,6 +47,7 @@ mod text_template_service_tests {
                 template_engine: TemplateEngine::Tera,
                 created_at: None,
                 created_by: None,
+                version: Uuid::nil(),
             }),
         ];
         
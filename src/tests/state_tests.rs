@@ -164,6 +164,7 @@ mod text_template_tests {
             template_engine: TemplateEngine::Tera,
             created_at: None,
             created_by: None,
+            version: Uuid::nil(),
         };
         
         assert!(template.name.is_some());
@@ -182,6 +183,7 @@ mod text_template_tests {
             template_engine: TemplateEngine::Tera,
             created_at: None,
             created_by: None,
+            version: Uuid::nil(),
         };
         
         assert!(template.name.is_none());
@@ -263,6 +265,7 @@ mod text_template_tests {
             template_type: "billing-period".into(),
             template_text: "test".into(),
             template_engine: TemplateEngineTO::MiniJinja,
+            version: None,
         };
         let json = serde_json::to_value(&req).unwrap();
         assert_eq!(json["template_engine"], "minijinja");